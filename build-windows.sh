@@ -8,10 +8,10 @@ rustup target add x86_64-pc-windows-gnu
 
 # Build for Windows
 echo "Building CLI version for Windows..."
-cargo build --release --target x86_64-pc-windows-gnu --bin cli
+cargo build --release --target x86_64-pc-windows-gnu -p can-crc-cli
 
 echo "Building GUI version for Windows..."
-cargo build --release --target x86_64-pc-windows-gnu --bin gui
+cargo build --release --target x86_64-pc-windows-gnu -p can-crc-gui
 
 echo ""
 echo "Build complete!"
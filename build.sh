@@ -5,10 +5,10 @@ echo "=============================="
 
 # Build in release mode
 echo "Building CLI version..."
-cargo build --release --bin cli
+cargo build --release -p can-crc-cli
 
 echo "Building GUI version..."
-cargo build --release --bin gui
+cargo build --release -p can-crc-gui
 
 echo ""
 echo "Build complete!"
@@ -0,0 +1,18 @@
+#!/bin/bash
+set -e
+
+echo "Checking the no_std builds of can-crc-core..."
+echo "============================================="
+
+rustup target add thumbv7m-none-eabi
+
+# Library without std, with and without an allocator
+cargo clippy -p can-crc-core --no-default-features --target thumbv7m-none-eabi -- -D warnings
+cargo clippy -p can-crc-core --no-default-features --features alloc --target thumbv7m-none-eabi -- -D warnings
+
+# A bare-metal static library that links the core without a global allocator
+cargo clippy --manifest-path no-std-check/Cargo.toml --target thumbv7m-none-eabi -- -D warnings
+cargo build --manifest-path no-std-check/Cargo.toml --target thumbv7m-none-eabi
+
+echo ""
+echo "no_std checks passed!"
@@ -0,0 +1,181 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::time::Duration;
+
+pub const DEFAULT_FRAME_HISTORY: usize = 500;
+pub const THROUGHPUT_WINDOW: usize = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameStatus {
+    Computed,
+    Valid,
+    Mismatch,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameEntry {
+    pub time: Duration,
+    pub frame: String,
+    pub crc: String,
+    pub status: FrameStatus,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SecondSample {
+    pub frames: u64,
+    pub mismatches: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Dashboard {
+    frames: VecDeque<FrameEntry>,
+    capacity: usize,
+    samples: VecDeque<SecondSample>,
+    second: u64,
+    pub total: u64,
+    pub mismatches: u64,
+    pub errors: u64,
+}
+
+impl Default for Dashboard {
+    fn default() -> Self {
+        Self::new(DEFAULT_FRAME_HISTORY)
+    }
+}
+
+impl Dashboard {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            frames: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            samples: VecDeque::from([SecondSample::default()]),
+            second: 0,
+            total: 0,
+            mismatches: 0,
+            errors: 0,
+        }
+    }
+
+    pub fn push(&mut self, entry: FrameEntry) {
+        self.tick(entry.time);
+        let sample = self.samples.back_mut().expect("at least one sample");
+        sample.frames += 1;
+        self.total += 1;
+        if entry.status == FrameStatus::Mismatch {
+            sample.mismatches += 1;
+            self.mismatches += 1;
+        }
+        if self.frames.len() == self.capacity {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(entry);
+    }
+
+    pub fn record_error(&mut self) {
+        self.errors += 1;
+    }
+
+    pub fn tick(&mut self, now: Duration) {
+        let second = now.as_secs();
+        if second <= self.second {
+            return;
+        }
+        for _ in 0..(second - self.second).min(THROUGHPUT_WINDOW as u64) {
+            self.samples.push_back(SecondSample::default());
+            if self.samples.len() > THROUGHPUT_WINDOW {
+                self.samples.pop_front();
+            }
+        }
+        self.second = second;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new(self.capacity);
+    }
+
+    pub fn frames(&self) -> impl DoubleEndedIterator<Item = &FrameEntry> + ExactSizeIterator {
+        self.frames.iter()
+    }
+
+    pub fn samples(&self) -> impl DoubleEndedIterator<Item = &SecondSample> + ExactSizeIterator {
+        self.samples.iter()
+    }
+
+    pub fn frame_rate(&self) -> u64 {
+        self.samples.len().checked_sub(2).and_then(|index| self.samples.get(index)).map_or(0, |sample| sample.frames)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DashboardCommand {
+    Calc(String),
+    Frame(String),
+    Algorithm(String),
+    Pause,
+    Resume,
+    Clear,
+    Help,
+    Quit,
+}
+
+impl FromStr for DashboardCommand {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let line = line.trim();
+        let (command, argument) = line.split_once(char::is_whitespace).map_or((line, ""), |(command, rest)| (command, rest.trim()));
+        let with_argument = |make: fn(String) -> DashboardCommand| {
+            if argument.is_empty() { Err(command.to_string()) } else { Ok(make(argument.to_string())) }
+        };
+        match command.to_ascii_lowercase().as_str() {
+            "calc" | "c" => with_argument(DashboardCommand::Calc),
+            "frame" | "f" => with_argument(DashboardCommand::Frame),
+            "algo" | "algorithm" | "a" => with_argument(DashboardCommand::Algorithm),
+            "pause" | "p" => Ok(DashboardCommand::Pause),
+            "resume" | "r" => Ok(DashboardCommand::Resume),
+            "clear" => Ok(DashboardCommand::Clear),
+            "help" | "?" => Ok(DashboardCommand::Help),
+            "quit" | "exit" | "q" => Ok(DashboardCommand::Quit),
+            _ => Err(line.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn dashboard_keeps_counts_and_window(capacity in 1usize..20, frames in prop::collection::vec((0u64..200_000, any::<bool>()), 0..80)) {
+            let mut frames = frames;
+            frames.sort();
+            let mut dashboard = Dashboard::new(capacity);
+            for &(millis, mismatch) in &frames {
+                let status = if mismatch { FrameStatus::Mismatch } else { FrameStatus::Valid };
+                dashboard.push(FrameEntry { time: Duration::from_millis(millis), frame: String::new(), crc: String::new(), status });
+            }
+            prop_assert_eq!(dashboard.total, frames.len() as u64);
+            prop_assert_eq!(dashboard.mismatches, frames.iter().filter(|&&(_, mismatch)| mismatch).count() as u64);
+            prop_assert_eq!(dashboard.frames().len(), frames.len().min(capacity));
+            prop_assert!(dashboard.samples().len() <= THROUGHPUT_WINDOW);
+
+            let last_second = frames.last().map_or(0, |&(millis, _)| millis / 1000);
+            let in_window = frames.iter().filter(|&&(millis, _)| millis / 1000 + THROUGHPUT_WINDOW as u64 > last_second).count() as u64;
+            prop_assert_eq!(dashboard.samples().map(|sample| sample.frames).sum::<u64>(), in_window);
+
+            dashboard.clear();
+            prop_assert_eq!(dashboard.total, 0);
+            prop_assert_eq!(dashboard.frames().len(), 0);
+        }
+
+        #[test]
+        fn dashboard_commands_parse_their_argument(argument in "[0-9A-Fa-f]{1,8}( [0-9A-Fa-f]{1,8}){0,3}") {
+            prop_assert_eq!(format!("calc  {}", argument).parse::<DashboardCommand>(), Ok(DashboardCommand::Calc(argument.clone())));
+            prop_assert_eq!(format!("F {} ", argument).parse::<DashboardCommand>(), Ok(DashboardCommand::Frame(argument.clone())));
+            prop_assert_eq!("calc".parse::<DashboardCommand>(), Err("calc".to_string()));
+            prop_assert_eq!(" q ".parse::<DashboardCommand>(), Ok(DashboardCommand::Quit));
+        }
+    }
+}
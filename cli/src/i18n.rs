@@ -0,0 +1,1560 @@
+use can_crc_core::j1939::{J1939Id, GLOBAL_ADDRESS};
+use can_crc_core::modbus::Pdu;
+#[cfg(feature = "gpu")]
+use can_crc_core::GpuError;
+#[cfg(feature = "mqtt")]
+use can_crc_core::MqttError;
+use can_crc_core::{format_hex, ConfigError, DbcError, FrameError, ImageError, ManifestError, ParseError};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    #[default]
+    Pl,
+    En,
+}
+
+impl Lang {
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::Pl => "pl",
+            Lang::En => "en",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim().to_lowercase().as_str() {
+            "pl" => Some(Lang::Pl),
+            "en" => Some(Lang::En),
+            _ => None,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Lang::from_code(value.get(..2).unwrap_or("")))
+            .unwrap_or_default()
+    }
+
+    pub fn strings(self) -> &'static Strings {
+        match self {
+            Lang::Pl => &PL,
+            Lang::En => &EN,
+        }
+    }
+
+    pub fn error(self, message: &str) -> String {
+        format!("{}{}", self.strings().err_prefix, message)
+    }
+
+    pub fn describe(self, error: &ParseError) -> String {
+        let t = self.strings();
+        match error {
+            ParseError::Empty => t.err_empty_input.to_string(),
+            ParseError::InvalidBinaryChars { chars, index } => fill(t.err_invalid_binary_chars, &[chars, index]),
+            ParseError::NoBinaryData => t.err_no_binary_data.to_string(),
+            ParseError::BinaryTooLong { bits, max_bits } => {
+                fill(t.err_binary_too_long, &[bits, max_bits])
+            }
+            ParseError::InvalidHexChars { chars, index } => fill(t.err_invalid_hex_chars, &[chars, index]),
+            ParseError::NoHexData => t.err_no_hex_data.to_string(),
+            ParseError::OddHexLength { digits } => fill(t.err_odd_hex_length, &[digits]),
+            ParseError::HexTooLong { bytes, max_bytes } => {
+                fill(t.err_hex_too_long, &[bytes, &(bytes * 8), max_bytes, &(max_bytes * 8)])
+            }
+            ParseError::InvalidHexFormat => t.err_invalid_hex_format.to_string(),
+            ParseError::InvalidBase64Chars { chars, index } => fill(t.err_invalid_base64_chars, &[chars, index]),
+            ParseError::NoBase64Data => t.err_no_base64_data.to_string(),
+            ParseError::InvalidBase64Length { chars } => fill(t.err_invalid_base64_length, &[chars]),
+            ParseError::PartialByte { bits } => fill(t.err_partial_byte, &[bits]),
+            ParseError::InvalidArrayElement { element } => fill(t.err_invalid_array_element, &[element]),
+            ParseError::InvalidEscape { sequence, index } => fill(t.err_invalid_escape, &[sequence, index]),
+            ParseError::UnterminatedString { index } => fill(t.err_unterminated_string, &[index]),
+        }
+    }
+
+    pub fn describe_config(self, error: &ConfigError) -> String {
+        let t = self.strings();
+        match error {
+            ConfigError::InvalidWidth { width } => fill(t.err_invalid_width, &[width]),
+            ConfigError::PolyTooWide { poly, width } => {
+                fill(t.err_poly_too_wide, &[&format!("{:X}", poly), width])
+            }
+            ConfigError::EvenPoly { poly } => fill(t.err_even_poly, &[&format!("{:X}", poly)]),
+            ConfigError::InvalidNumber { value } => fill(t.err_invalid_number, &[value]),
+            ConfigError::InvalidDataWidth { width } => fill(t.err_invalid_data_width, &[width]),
+        }
+    }
+
+    pub fn describe_frame(self, error: &FrameError) -> String {
+        let t = self.strings();
+        match error {
+            FrameError::IdOutOfRange { id, extended } => {
+                let bits = if *extended { 29 } else { 11 };
+                fill(t.err_id_out_of_range, &[&format!("{:X}", id), &bits])
+            }
+            FrameError::DataTooLong { len, max_len } => fill(t.err_frame_data_too_long, &[len, max_len]),
+            FrameError::Syntax { content } => fill(t.err_frame_syntax, &[content]),
+            FrameError::TooShort { len, min_len } => fill(t.err_frame_too_short, &[len, min_len]),
+            FrameError::MissingCrc { bits, crc_bits } => fill(t.err_frame_missing_crc, &[bits, crc_bits]),
+            FrameError::SegmentSequence { index, sequence } => fill(t.err_frame_segment_sequence, &[&(index + 1), sequence]),
+            FrameError::CapacityExceeded { capacity } => fill(t.err_frame_capacity, &[capacity]),
+        }
+    }
+
+    pub fn describe_dbc(self, error: &DbcError) -> String {
+        let t = self.strings();
+        match error {
+            DbcError::Syntax { line, content } => fill(t.err_dbc_syntax, &[line, content]),
+            DbcError::UnknownMessage { name } => fill(t.err_dbc_unknown_message, &[name]),
+            DbcError::UnknownSignal { name } => fill(t.err_dbc_unknown_signal, &[name]),
+            DbcError::ValueOutOfRange { signal, value } => fill(t.err_dbc_value_out_of_range, &[value, signal]),
+            DbcError::Frame(e) => self.describe_frame(e),
+        }
+    }
+
+    pub fn describe_manifest(self, error: &ManifestError) -> String {
+        let t = self.strings();
+        match error {
+            ManifestError::Syntax { line, content } => fill(t.err_manifest_syntax, &[line, content]),
+            ManifestError::UnknownAlgorithm { name } => fill(t.err_manifest_unknown_algorithm, &[name]),
+        }
+    }
+
+    #[cfg(feature = "gpu")]
+    pub fn describe_gpu(self, error: &GpuError) -> String {
+        let t = self.strings();
+        match error {
+            GpuError::NoAdapter => t.err_gpu_no_adapter.to_string(),
+            GpuError::Device { message } => fill(t.err_gpu_device, &[message]),
+            GpuError::UnsupportedWidth { width, max_width } => fill(t.err_gpu_unsupported_width, &[width, max_width]),
+            GpuError::BatchLength { len, message_len } => fill(t.err_gpu_batch_length, &[len, message_len]),
+        }
+    }
+
+    #[cfg(feature = "mqtt")]
+    pub fn describe_mqtt(self, error: &MqttError) -> String {
+        let t = self.strings();
+        match error {
+            MqttError::Address { broker } => fill(t.err_mqtt_address, &[broker]),
+            MqttError::Connection { broker, message } => fill(t.err_mqtt_connection, &[broker, message]),
+            MqttError::Publish { message } => fill(t.err_mqtt_publish, &[message]),
+        }
+    }
+
+    pub fn describe_j1939(self, id: &J1939Id) -> String {
+        let t = self.strings();
+        let name = id.name().map(|name| format!(" {}", name)).unwrap_or_default();
+        let destination = match id.destination {
+            Some(destination) if destination != GLOBAL_ADDRESS => format!("0x{:02X}", destination),
+            _ => t.j1939_global.to_string(),
+        };
+        fill(t.j1939_id, &[&id.pgn, &format!("{:04X}", id.pgn), &name, &id.priority, &format!("{:02X}", id.source), &destination])
+    }
+
+    pub fn describe_pdu(self, pdu: &Pdu) -> String {
+        let t = self.strings();
+        let name = self.modbus_function_name(pdu.function());
+        match pdu {
+            Pdu::ReadRequest { start, count, .. } => fill(t.modbus_pdu_read_request, &[&name, start, count]),
+            Pdu::ReadBitsResponse { bits, .. } => {
+                let values: String = bits.iter().map(|&bit| if bit { '1' } else { '0' }).collect();
+                fill(t.modbus_pdu_bits_response, &[&name, &bits.len(), &values])
+            }
+            Pdu::ReadRegistersResponse { registers, .. } => {
+                let values = registers.iter().map(|value| format!("{} (0x{:04X})", value, value)).collect::<Vec<_>>().join(", ");
+                fill(t.modbus_pdu_registers_response, &[&name, &registers.len(), &values])
+            }
+            Pdu::WriteSingle { address, value, .. } => fill(t.modbus_pdu_write_single, &[&name, address, &format!("{:04X}", value)]),
+            Pdu::WriteMultipleRequest { start, count, data, .. } => {
+                fill(t.modbus_pdu_write_multiple_request, &[&name, start, count, &format_hex(data)])
+            }
+            Pdu::WriteMultipleResponse { start, count, .. } => fill(t.modbus_pdu_write_multiple_response, &[&name, count, start]),
+            Pdu::Exception { code, .. } => {
+                let reason = match code {
+                    0x01 => t.modbus_ex_illegal_function,
+                    0x02 => t.modbus_ex_illegal_data_address,
+                    0x03 => t.modbus_ex_illegal_data_value,
+                    0x04 => t.modbus_ex_server_failure,
+                    0x05 => t.modbus_ex_acknowledge,
+                    0x06 => t.modbus_ex_server_busy,
+                    0x08 => t.modbus_ex_memory_parity,
+                    0x0A => t.modbus_ex_gateway_path,
+                    0x0B => t.modbus_ex_gateway_target,
+                    _ => t.modbus_ex_other,
+                };
+                fill(t.modbus_pdu_exception, &[&name, &reason, &format!("{:02X}", code)])
+            }
+            Pdu::Malformed { len, .. } => fill(t.modbus_pdu_malformed, &[&name, len]),
+            Pdu::Other { data, .. } => fill(t.modbus_pdu_other, &[&name, &data.len()]),
+        }
+    }
+
+    pub fn modbus_function_name(self, function: u8) -> String {
+        let t = self.strings();
+        match function {
+            0x01 => t.modbus_fc_read_coils.to_string(),
+            0x02 => t.modbus_fc_read_discrete_inputs.to_string(),
+            0x03 => t.modbus_fc_read_holding_registers.to_string(),
+            0x04 => t.modbus_fc_read_input_registers.to_string(),
+            0x05 => t.modbus_fc_write_single_coil.to_string(),
+            0x06 => t.modbus_fc_write_single_register.to_string(),
+            0x0F => t.modbus_fc_write_multiple_coils.to_string(),
+            0x10 => t.modbus_fc_write_multiple_registers.to_string(),
+            _ => fill(t.modbus_fc_other, &[&format!("{:02X}", function)]),
+        }
+    }
+
+    pub fn describe_image(self, error: &ImageError) -> String {
+        let t = self.strings();
+        match error {
+            ImageError::Syntax { line } => fill(t.err_image_syntax, &[line]),
+            ImageError::Checksum { line, expected, actual } => fill(t.err_image_checksum, &[
+                line,
+                &format!("{:02X}", expected),
+                &format!("{:02X}", actual),
+            ]),
+            ImageError::UnsupportedRecord { line, kind } => fill(t.err_image_unsupported_record, &[kind, line]),
+            ImageError::Overlap { address } => fill(t.err_image_overlap, &[&format!("{:08X}", address)]),
+            ImageError::AddressOverflow { address } => fill(t.err_image_address_overflow, &[&format!("{:X}", address)]),
+            ImageError::MissingEndRecord => t.err_image_missing_end.to_string(),
+        }
+    }
+}
+
+pub fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut rest = template;
+
+    while let Some(pos) = rest.find("{}") {
+        result.push_str(&rest[..pos]);
+        if let Some(arg) = args.next() {
+            result.push_str(&arg.to_string());
+        }
+        rest = &rest[pos + 2..];
+    }
+    result.push_str(rest);
+
+    result
+}
+
+pub struct Strings {
+    pub app_title: &'static str,
+    pub err_prefix: &'static str,
+    pub err_empty_input: &'static str,
+    pub err_invalid_binary_chars: &'static str,
+    pub err_no_binary_data: &'static str,
+    pub err_binary_too_long: &'static str,
+    pub err_invalid_hex_chars: &'static str,
+    pub err_no_hex_data: &'static str,
+    pub err_odd_hex_length: &'static str,
+    pub err_hex_too_long: &'static str,
+    pub err_invalid_hex_format: &'static str,
+    pub err_invalid_width: &'static str,
+    pub err_poly_too_wide: &'static str,
+    pub err_even_poly: &'static str,
+    pub err_invalid_number: &'static str,
+    pub err_invalid_data_width: &'static str,
+    pub err_id_out_of_range: &'static str,
+    pub err_frame_data_too_long: &'static str,
+    pub err_dbc_syntax: &'static str,
+    pub err_dbc_unknown_message: &'static str,
+    pub err_dbc_unknown_signal: &'static str,
+    pub err_dbc_value_out_of_range: &'static str,
+
+    pub err_image_syntax: &'static str,
+    pub err_image_checksum: &'static str,
+    pub err_image_unsupported_record: &'static str,
+    pub err_image_overlap: &'static str,
+    pub err_image_address_overflow: &'static str,
+    pub err_image_missing_end: &'static str,
+    pub err_manifest_syntax: &'static str,
+    pub err_manifest_unknown_algorithm: &'static str,
+    #[cfg(feature = "gpu")]
+    pub err_gpu_no_adapter: &'static str,
+    #[cfg(feature = "gpu")]
+    pub err_gpu_device: &'static str,
+    #[cfg(feature = "gpu")]
+    pub err_gpu_unsupported_width: &'static str,
+    #[cfg(feature = "gpu")]
+    pub err_gpu_batch_length: &'static str,
+    pub err_invalid_base64_chars: &'static str,
+    pub err_no_base64_data: &'static str,
+    pub err_invalid_base64_length: &'static str,
+    pub err_partial_byte: &'static str,
+    pub err_invalid_array_element: &'static str,
+    pub err_invalid_escape: &'static str,
+    pub err_unterminated_string: &'static str,
+    pub err_frame_syntax: &'static str,
+    pub err_frame_too_short: &'static str,
+    #[cfg(feature = "mqtt")]
+    pub err_mqtt_address: &'static str,
+    #[cfg(feature = "mqtt")]
+    pub err_mqtt_connection: &'static str,
+    #[cfg(feature = "mqtt")]
+    pub err_mqtt_publish: &'static str,
+    pub modbus_fc_read_coils: &'static str,
+    pub modbus_fc_read_discrete_inputs: &'static str,
+    pub modbus_fc_read_holding_registers: &'static str,
+    pub modbus_fc_read_input_registers: &'static str,
+    pub modbus_fc_write_single_coil: &'static str,
+    pub modbus_fc_write_single_register: &'static str,
+    pub modbus_fc_write_multiple_coils: &'static str,
+    pub modbus_fc_write_multiple_registers: &'static str,
+    pub modbus_fc_other: &'static str,
+    pub modbus_ex_illegal_function: &'static str,
+    pub modbus_ex_illegal_data_address: &'static str,
+    pub modbus_ex_illegal_data_value: &'static str,
+    pub modbus_ex_server_failure: &'static str,
+    pub modbus_ex_acknowledge: &'static str,
+    pub modbus_ex_server_busy: &'static str,
+    pub modbus_ex_memory_parity: &'static str,
+    pub modbus_ex_gateway_path: &'static str,
+    pub modbus_ex_gateway_target: &'static str,
+    pub modbus_ex_other: &'static str,
+    pub modbus_pdu_read_request: &'static str,
+    pub modbus_pdu_bits_response: &'static str,
+    pub modbus_pdu_registers_response: &'static str,
+    pub modbus_pdu_write_single: &'static str,
+    pub modbus_pdu_write_multiple_request: &'static str,
+    pub modbus_pdu_write_multiple_response: &'static str,
+    pub modbus_pdu_exception: &'static str,
+    pub modbus_pdu_malformed: &'static str,
+    pub modbus_pdu_other: &'static str,
+    pub j1939_id: &'static str,
+    pub j1939_global: &'static str,
+    pub err_frame_missing_crc: &'static str,
+    pub err_frame_segment_sequence: &'static str,
+    pub err_frame_capacity: &'static str,
+
+    pub err_read_file: &'static str,
+
+    pub cli_prompt_format: &'static str,
+    pub cli_err_read_format: &'static str,
+    pub cli_err_invalid_format: &'static str,
+    pub cli_prompt_data: &'static str,
+    pub cli_err_read_data: &'static str,
+    pub cli_prompt_iterations: &'static str,
+    pub cli_err_read_iterations: &'static str,
+    pub cli_err_iterations_range: &'static str,
+    pub cli_hint_binary: &'static str,
+    pub cli_hint_hex: &'static str,
+    pub cli_err_no_input: &'static str,
+    pub cli_input_format: &'static str,
+    pub cli_input_data: &'static str,
+    pub cli_bit_count: &'static str,
+    pub cli_iteration_count: &'static str,
+    pub cli_parallel_notice: &'static str,
+    pub cli_results: &'static str,
+    pub cli_crc_hex: &'static str,
+    pub cli_crc_dec: &'static str,
+    pub cli_crc_bin: &'static str,
+    pub cli_performance: &'static str,
+    pub cli_total_time: &'static str,
+    pub cli_avg_time_ms: &'static str,
+    pub cli_avg_time_us: &'static str,
+    pub cli_throughput: &'static str,
+    pub cli_parallel_used: &'static str,
+    pub cli_dbc_messages: &'static str,
+    pub cli_dbc_message_line: &'static str,
+    pub cli_dbc_signal_line: &'static str,
+    pub cli_err_signal_format: &'static str,
+    pub cli_frame_payload: &'static str,
+    pub cli_frame_id: &'static str,
+    pub cli_frame_crc_bits: &'static str,
+    pub cli_frame_crc: &'static str,
+    pub cli_frame_j1939: &'static str,
+
+    pub cli_err_burst_format: &'static str,
+    pub cli_err_bit_out_of_range: &'static str,
+    pub cli_err_burst_out_of_range: &'static str,
+    pub cli_err_random_too_many: &'static str,
+    pub cli_inject_codeword: &'static str,
+    pub cli_inject_original: &'static str,
+    pub cli_inject_flipped: &'static str,
+    pub cli_inject_received: &'static str,
+    pub cli_inject_recomputed: &'static str,
+    pub cli_inject_detected: &'static str,
+    pub cli_inject_undetected: &'static str,
+    pub cli_analyze_header: &'static str,
+    pub cli_analyze_class: &'static str,
+    pub cli_analyze_tested: &'static str,
+    pub cli_analyze_undetected: &'static str,
+    pub cli_analyze_coverage: &'static str,
+    pub cli_analyze_single: &'static str,
+    pub cli_analyze_double: &'static str,
+    pub cli_analyze_burst: &'static str,
+    pub cli_analyze_random: &'static str,
+    pub cli_analyze_probability: &'static str,
+    pub cli_distribution_header: &'static str,
+    pub cli_distribution_exhaustive: &'static str,
+    pub cli_distribution_sampled: &'static str,
+    pub cli_distribution_buckets: &'static str,
+    pub cli_distribution_range: &'static str,
+    pub cli_distribution_collisions: &'static str,
+    pub cli_distribution_chi_square: &'static str,
+    pub cli_hamming_result: &'static str,
+    pub cli_hamming_above: &'static str,
+    pub cli_hamming_range: &'static str,
+    pub cli_hamming_range_above: &'static str,
+    pub cli_hamming_exhaustive: &'static str,
+    pub cli_hamming_sampled: &'static str,
+    pub cli_recover_searching: &'static str,
+    pub cli_recover_match: &'static str,
+    pub cli_recover_none: &'static str,
+    pub cli_recover_summary: &'static str,
+    pub cli_recover_ambiguous: &'static str,
+    pub cli_recover_too_wide: &'static str,
+    pub cli_err_pair_format: &'static str,
+    pub cli_err_pairs_missing: &'static str,
+    pub cli_preimage_header: &'static str,
+    pub cli_preimage_line: &'static str,
+    pub cli_preimage_none: &'static str,
+    pub cli_preimage_summary: &'static str,
+    pub cli_err_prefix_too_long: &'static str,
+    #[cfg(feature = "test-vectors")]
+    pub cli_selftest_pass: &'static str,
+    #[cfg(feature = "test-vectors")]
+    pub cli_selftest_fail: &'static str,
+    #[cfg(feature = "test-vectors")]
+    pub cli_selftest_summary: &'static str,
+    pub err_unknown_algorithm: &'static str,
+    pub cli_algorithm_used: &'static str,
+    pub cli_algorithms_header: &'static str,
+    pub cli_algorithms_line: &'static str,
+    pub cli_algorithms_aliases: &'static str,
+    pub cli_algorithms_checksums: &'static str,
+    pub cli_algorithms_checksum_line: &'static str,
+    pub err_checksum_unsupported: &'static str,
+    pub cli_file_line: &'static str,
+    pub cli_file_header: &'static str,
+    pub cli_e2e_protected: &'static str,
+    pub cli_e2e_summary: &'static str,
+    pub cli_e2e_check: &'static str,
+    pub cli_e2e_valid: &'static str,
+    pub cli_e2e_invalid: &'static str,
+    pub cli_err_e2e_too_short: &'static str,
+    pub cli_err_data_id_list: &'static str,
+    pub cli_file_image_line: &'static str,
+    pub cli_file_no_match: &'static str,
+    pub cli_file_is_directory: &'static str,
+    pub cli_manifest_written: &'static str,
+    pub cli_err_write_manifest: &'static str,
+    pub cli_manifest_ok: &'static str,
+    pub cli_manifest_mismatch: &'static str,
+    pub cli_manifest_unreadable: &'static str,
+    pub cli_manifest_summary: &'static str,
+    #[cfg(feature = "gpu")]
+    pub cli_gpu_header: &'static str,
+    #[cfg(feature = "gpu")]
+    pub cli_gpu_adapter: &'static str,
+    #[cfg(feature = "gpu")]
+    pub cli_gpu_cpu: &'static str,
+    #[cfg(feature = "gpu")]
+    pub cli_gpu_gpu: &'static str,
+    #[cfg(feature = "gpu")]
+    pub cli_gpu_speedup: &'static str,
+    #[cfg(feature = "gpu")]
+    pub cli_gpu_mismatch: &'static str,
+    pub cli_hint_escaped: &'static str,
+    pub cli_hint_base64: &'static str,
+    pub cli_err_algorithm_with_params: &'static str,
+    pub cli_err_fixed_crc: &'static str,
+    pub cli_err_cross_check_command: &'static str,
+    pub cli_verify_match: &'static str,
+    pub cli_checksum_result: &'static str,
+    pub cli_proto_frame: &'static str,
+    pub cli_err_proto_algorithm: &'static str,
+    pub cli_verify_mismatch: &'static str,
+    pub cli_line_error: &'static str,
+    pub cli_serve_listening: &'static str,
+    pub cli_err_bind: &'static str,
+    #[cfg(feature = "serde")]
+    pub cli_raw_listening: &'static str,
+    #[cfg(feature = "serde")]
+    pub cli_err_raw_connection: &'static str,
+    pub cli_udp_listening: &'static str,
+    pub cli_udp_datagram: &'static str,
+    pub cli_err_udp: &'static str,
+    pub cli_frame_standard: &'static str,
+    pub cli_frame_extended: &'static str,
+    pub cli_cross_check_ok: &'static str,
+    pub cli_cross_check_failed: &'static str,
+    pub cli_cross_check_line: &'static str,
+    pub cli_verify_residue_match: &'static str,
+    pub cli_verify_residue_mismatch: &'static str,
+    pub cli_frame_wire: &'static str,
+    pub cli_frame_wire_summary: &'static str,
+    #[cfg(feature = "config")]
+    pub err_settings_io: &'static str,
+    #[cfg(feature = "config")]
+    pub err_settings_syntax: &'static str,
+    #[cfg(feature = "config")]
+    pub cli_err_settings_value: &'static str,
+    #[cfg(feature = "parallel")]
+    pub cli_err_threads: &'static str,
+    pub cli_err_log_file: &'static str,
+    pub cli_explain_header: &'static str,
+    pub cli_explain_byte: &'static str,
+    pub cli_explain_final: &'static str,
+    pub cli_listen_modbus_ok: &'static str,
+    pub cli_listen_modbus_mismatch: &'static str,
+    #[cfg(feature = "mqtt")]
+    pub cli_listen_mqtt_connected: &'static str,
+    pub cli_metrics_listening: &'static str,
+    pub cli_listen_modbus_pdu: &'static str,
+    pub cli_listen_j1939: &'static str,
+    pub cli_transaction: &'static str,
+    pub cli_transaction_crc_ok: &'static str,
+    pub cli_transaction_crc_bad: &'static str,
+    pub cli_transaction_missing: &'static str,
+    pub cli_transaction_broadcast: &'static str,
+    pub cli_transactions_summary: &'static str,
+    pub cli_frame_fd_algorithm: &'static str,
+    pub cli_frame_fd_stuff_count: &'static str,
+    pub report_title: &'static str,
+    pub report_algorithm: &'static str,
+    pub report_parameters: &'static str,
+    pub report_input: &'static str,
+    pub report_result: &'static str,
+    pub report_value: &'static str,
+    pub report_path: &'static str,
+    pub report_input_format: &'static str,
+    pub report_data: &'static str,
+    pub report_bit_count: &'static str,
+    pub report_iterations: &'static str,
+    pub report_timing: &'static str,
+    pub report_total_time: &'static str,
+    pub report_avg_time: &'static str,
+    pub report_throughput: &'static str,
+    pub report_environment: &'static str,
+    pub report_generated: &'static str,
+    pub report_version: &'static str,
+    pub report_platform: &'static str,
+    pub report_cpus: &'static str,
+    pub report_threads: &'static str,
+    pub report_build: &'static str,
+    pub report_features: &'static str,
+    pub cli_report_written: &'static str,
+    pub cli_err_write_report: &'static str,
+    pub cli_sweep_header: &'static str,
+    pub cli_sweep_bits: &'static str,
+    pub cli_sweep_bitwise: &'static str,
+    pub cli_sweep_table: &'static str,
+    pub cli_sweep_speedup: &'static str,
+    pub cli_sweep_crossover: &'static str,
+    pub cli_sweep_no_crossover: &'static str,
+    #[cfg(feature = "parallel")]
+    pub cli_scaling_header: &'static str,
+    #[cfg(feature = "parallel")]
+    pub cli_scaling_threads: &'static str,
+    #[cfg(feature = "parallel")]
+    pub cli_scaling_median: &'static str,
+    #[cfg(feature = "parallel")]
+    pub cli_scaling_efficiency: &'static str,
+    #[cfg(feature = "parallel")]
+    pub cli_scaling_below_threshold: &'static str,
+    #[cfg(feature = "parallel")]
+    pub cli_scaling_best: &'static str,
+    #[cfg(feature = "parallel")]
+    pub cli_scaling_no_gain: &'static str,
+    pub cli_err_bench_data: &'static str,
+    pub cli_flips_header: &'static str,
+    pub cli_flips_single: &'static str,
+    pub cli_flips_double: &'static str,
+    pub cli_flips_detected: &'static str,
+    pub cli_flips_undetected: &'static str,
+    pub cli_flips_pattern: &'static str,
+    pub cli_flips_more: &'static str,
+    pub cli_check_frame_header: &'static str,
+    pub cli_check_frame_received: &'static str,
+    pub cli_check_frame_computed: &'static str,
+    pub cli_check_frame_pass: &'static str,
+    pub cli_check_frame_fail: &'static str,
+    pub cli_check_frame_bits: &'static str,
+    pub cli_check_frame_destuffed: &'static str,
+    pub cli_check_frame_stuff_error: &'static str,
+    pub cli_check_frame_stuff_fail: &'static str,
+    #[cfg(all(feature = "socketcan", target_os = "linux"))]
+    pub cli_gen_open_failed: &'static str,
+    #[cfg(all(feature = "socketcan", target_os = "linux"))]
+    pub cli_gen_send_failed: &'static str,
+    pub cli_gen_sent: &'static str,
+    #[cfg(all(feature = "serial", unix))]
+    pub cli_serial_open_failed: &'static str,
+    #[cfg(all(feature = "serial", unix))]
+    pub cli_serial_io_failed: &'static str,
+    #[cfg(all(feature = "serial", unix))]
+    pub cli_slave_empty_map: &'static str,
+    #[cfg(all(feature = "serial", unix))]
+    pub cli_slave_listening: &'static str,
+    #[cfg(all(feature = "serial", unix))]
+    pub cli_slave_rx: &'static str,
+    #[cfg(all(feature = "serial", unix))]
+    pub cli_slave_rx_invalid: &'static str,
+    #[cfg(all(feature = "serial", unix))]
+    pub cli_slave_rx_bad_crc: &'static str,
+    #[cfg(all(feature = "serial", unix))]
+    pub cli_slave_rx_ignored: &'static str,
+    #[cfg(all(feature = "serial", unix))]
+    pub cli_slave_tx: &'static str,
+    #[cfg(all(feature = "serial", unix))]
+    pub cli_slave_tx_exception: &'static str,
+    #[cfg(all(feature = "serial", unix))]
+    pub cli_slave_tx_corrupted: &'static str,
+    pub cli_poll_connect_failed: &'static str,
+    pub cli_poll_io_failed: &'static str,
+    pub cli_poll_started: &'static str,
+    pub cli_poll_ok: &'static str,
+    pub cli_poll_crc_error: &'static str,
+    pub cli_poll_unexpected: &'static str,
+    pub cli_poll_timeout: &'static str,
+    pub cli_poll_summary: &'static str,
+    pub cli_replay_mismatch: &'static str,
+    pub cli_replay_summary: &'static str,
+    pub cli_replay_frames: &'static str,
+    pub cli_replay_trace_rate: &'static str,
+    pub cli_replay_processing_rate: &'static str,
+    pub cli_replay_crc_checked: &'static str,
+    pub cli_replay_crc_missing: &'static str,
+    pub cli_replay_ids: &'static str,
+    pub cli_replay_column_frames: &'static str,
+    pub cli_replay_column_mismatches: &'static str,
+    pub cli_replay_lengths: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_frames: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_status: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_command: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_rate_graph: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_errors_graph: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_source: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_algorithm: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_total: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_rate: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_mismatches: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_errors: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_paused: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_no_source: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_help: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_unknown_command: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_calc_result: &'static str,
+    #[cfg(feature = "tui")]
+    pub cli_tui_terminal_error: &'static str,
+    #[cfg(feature = "watch")]
+    pub cli_watch_started: &'static str,
+    #[cfg(feature = "watch")]
+    pub cli_watch_changed: &'static str,
+    #[cfg(feature = "watch")]
+    pub cli_watch_failed: &'static str,
+    pub cli_bench_runs: &'static str,
+    pub cli_bench_min: &'static str,
+    pub cli_bench_median: &'static str,
+    pub cli_bench_p95: &'static str,
+    pub cli_bench_stddev: &'static str,
+    pub cli_bench_noisy: &'static str,
+    pub report_warmup: &'static str,
+    pub report_repetitions: &'static str,
+    pub report_min_time: &'static str,
+    pub report_median_time: &'static str,
+    pub report_p95_time: &'static str,
+    pub report_max_time: &'static str,
+    pub report_stddev: &'static str,
+    pub cli_file_strategy: &'static str,
+}
+
+static PL: Strings = Strings {
+    app_title: "Kalkulator CRC CAN",
+    err_prefix: "❌ Błąd: ",
+    err_empty_input: "Dane wejściowe są puste",
+    err_invalid_binary_chars: "Znaleziono nieprawidłowe znaki: '{}' (pierwszy pod indeksem {}; dozwolone tylko: 0, 1, spacje, podkreślniki, kropki, prefiks 0b)",
+    err_no_binary_data: "Brak prawidłowych danych binarnych (tylko 0 i 1)",
+    err_binary_too_long: "Dane za długie: {} bitów (maksymalnie dozwolone: {} bitów)",
+    err_invalid_hex_chars: "Znaleziono nieprawidłowe znaki: '{}' (pierwszy pod indeksem {}; dozwolone tylko: 0-9, A-F, spacje, przecinki, dwukropki, myślniki, prefiks 0x)",
+    err_no_hex_data: "Brak prawidłowych danych hex",
+    err_odd_hex_length: "Nieparzysta liczba znaków hex: {} (wymagana parzysta liczba)",
+    err_hex_too_long: "Dane za długie: {} bajtów = {} bitów (maksymalnie: {} bajtów = {} bitów)",
+    err_invalid_hex_format: "Nieprawidłowy format hex",
+    err_invalid_width: "Nieobsługiwana szerokość CRC: {} (dozwolone 1-64 bity)",
+    err_poly_too_wide: "Wielomian 0x{} nie mieści się w {} bitach",
+    err_even_poly: "Wielomian 0x{} nie zawiera wyrazu x^0",
+    err_invalid_number: "Nieprawidłowa liczba: '{}'",
+    err_invalid_data_width: "Nieobsługiwana szerokość danych: {} (dozwolone 1-64 bity)",
+    err_id_out_of_range: "Identyfikator 0x{} nie mieści się w {} bitach",
+    err_frame_data_too_long: "Za dużo danych w ramce: {} bajtów (maks. {})",
+    err_dbc_syntax: "Błąd składni DBC w linii {}: '{}'",
+    err_dbc_unknown_message: "Nieznana wiadomość DBC: '{}'",
+    err_dbc_unknown_signal: "Nieznany sygnał DBC: '{}'",
+    err_dbc_value_out_of_range: "Wartość {} nie mieści się w sygnale '{}'",
+
+    err_image_syntax: "nieprawidłowy rekord w linii {}",
+    err_image_checksum: "błędna suma kontrolna rekordu w linii {}: oczekiwano {}, jest {}",
+    err_image_unsupported_record: "nieobsługiwany typ rekordu {} w linii {}",
+    err_image_overlap: "rekordy danych nakładają się pod adresem 0x{}",
+    err_image_address_overflow: "dane pod adresem 0x{} wychodzą poza 32-bitową przestrzeń adresową",
+    err_image_missing_end: "brak rekordu końca pliku",
+    err_manifest_syntax: "Nieprawidłowy wpis manifestu w linii {}: '{}'",
+    err_manifest_unknown_algorithm: "Nieznany algorytm manifestu '{}'",
+    #[cfg(feature = "gpu")]
+    err_gpu_no_adapter: "Brak dostępnej karty graficznej (adaptera wgpu)",
+    #[cfg(feature = "gpu")]
+    err_gpu_device: "GPU: {}",
+    #[cfg(feature = "gpu")]
+    err_gpu_unsupported_width: "CRC o szerokości {} bitów nie jest obsługiwane na GPU (maks. {} bitów)",
+    #[cfg(feature = "gpu")]
+    err_gpu_batch_length: "Paczki {} bajtów nie da się podzielić na wiadomości po {} bajtów",
+    err_invalid_base64_chars: "Znaleziono nieprawidłowe znaki base64: '{}' (pierwszy pod indeksem {}; dozwolone: A-Z, a-z, 0-9, +, /, -, _, =)",
+    err_no_base64_data: "Brak prawidłowych danych base64",
+    err_invalid_base64_length: "Nieprawidłowa długość danych base64: {} znaków",
+    err_partial_byte: "Wejście ma {} bitów - wymagane są pełne bajty",
+    err_invalid_array_element: "Element tablicy '{}' nie jest wartością bajtu (0-255)",
+    err_invalid_escape: "Nieprawidłowa sekwencja ucieczki '{}' pod indeksem {}",
+    err_unterminated_string: "Łańcuch otwarty pod indeksem {} nie jest zamknięty",
+    err_frame_syntax: "Nierozpoznana ramka CAN: '{}'",
+    err_frame_too_short: "Za krótka ramka: {} bajtów (min. {})",
+    #[cfg(feature = "mqtt")]
+    err_mqtt_address: "Nieprawidłowy adres brokera MQTT '{}' (oczekiwano host[:port])",
+    #[cfg(feature = "mqtt")]
+    err_mqtt_connection: "Nie można połączyć się z brokerem MQTT {}: {}",
+    #[cfg(feature = "mqtt")]
+    err_mqtt_publish: "Nie można opublikować wiadomości MQTT: {}",
+    modbus_fc_read_coils: "Odczyt cewek (0x01)",
+    modbus_fc_read_discrete_inputs: "Odczyt wejść dyskretnych (0x02)",
+    modbus_fc_read_holding_registers: "Odczyt rejestrów pamiętających (0x03)",
+    modbus_fc_read_input_registers: "Odczyt rejestrów wejściowych (0x04)",
+    modbus_fc_write_single_coil: "Zapis pojedynczej cewki (0x05)",
+    modbus_fc_write_single_register: "Zapis pojedynczego rejestru (0x06)",
+    modbus_fc_write_multiple_coils: "Zapis wielu cewek (0x0F)",
+    modbus_fc_write_multiple_registers: "Zapis wielu rejestrów (0x10)",
+    modbus_fc_other: "Funkcja 0x{}",
+    modbus_ex_illegal_function: "niedozwolona funkcja",
+    modbus_ex_illegal_data_address: "niedozwolony adres danych",
+    modbus_ex_illegal_data_value: "niedozwolona wartość danych",
+    modbus_ex_server_failure: "awaria urządzenia",
+    modbus_ex_acknowledge: "potwierdzenie, przetwarzanie trwa",
+    modbus_ex_server_busy: "urządzenie zajęte",
+    modbus_ex_memory_parity: "błąd parzystości pamięci",
+    modbus_ex_gateway_path: "ścieżka bramy niedostępna",
+    modbus_ex_gateway_target: "urządzenie za bramą nie odpowiada",
+    modbus_ex_other: "nieznany wyjątek",
+    modbus_pdu_read_request: "{} - żądanie: adres początkowy {}, liczba {}",
+    modbus_pdu_bits_response: "{} - odpowiedź: {} bitów (od najmłodszego): {}",
+    modbus_pdu_registers_response: "{} - odpowiedź: {} rejestrów: {}",
+    modbus_pdu_write_single: "{}: adres {}, wartość 0x{}",
+    modbus_pdu_write_multiple_request: "{} - żądanie: adres początkowy {}, liczba {}, dane: {}",
+    modbus_pdu_write_multiple_response: "{} - odpowiedź: zapisano {} od adresu {}",
+    modbus_pdu_exception: "❌ Wyjątek dla {}: {} (kod 0x{})",
+    modbus_pdu_malformed: "{}: nieoczekiwana długość danych ({} bajtów)",
+    modbus_pdu_other: "{}: {} bajtów danych",
+    j1939_id: "J1939: PGN {} (0x{}{}), priorytet {}, nadawca 0x{}, odbiorca {}",
+    j1939_global: "wszyscy",
+    err_frame_missing_crc: "Ramka ma {} bitów - za mało na pole CRC ({} bitów)",
+    err_frame_segment_sequence: "Segment {} poza kolejnością (numer sekwencyjny {})",
+    err_frame_capacity: "Bufor o stałej pojemności {} bitów jest pełny",
+
+    err_read_file: "Nie udało się odczytać pliku '{}': {}",
+
+    cli_prompt_format: "\nWybierz format ('hex', 'bin', 'b64', 'esc') lub wpisz 'exit' aby zakończyć:",
+    cli_err_read_format: "Nie udało się odczytać formatu.",
+    cli_err_invalid_format: "Nieprawidłowy format. Wybierz 'hex', 'bin', 'b64' lub 'esc'.",
+    cli_prompt_data: "Podaj dane wejściowe:",
+    cli_err_read_data: "Nie udało się odczytać danych.",
+    cli_prompt_iterations: "Podaj liczbę iteracji (1 do 1,000,000,000):",
+    cli_err_read_iterations: "Nie udało się odczytać liczby iteracji.",
+    cli_err_iterations_range: "Liczba iteracji musi być między 1 a 1,000,000,000.",
+    cli_hint_binary: "\n💡 Wskazówka: Użyj tylko znaków '0' i '1'; grupy można oddzielać '_' lub '.'.",
+    cli_hint_hex: "\n💡 Wskazówka: Użyj tylko znaków 0-9 i A-F.",
+    cli_err_no_input: "Brak prawidłowych danych wejściowych",
+    cli_input_format: "📋 Format wejściowy: {}",
+    cli_input_data: "📝 Dane wejściowe: {}",
+    cli_bit_count: "🔢 Liczba bitów: {}",
+    cli_iteration_count: "🔄 Liczba iteracji: {}",
+    cli_parallel_notice: "ℹ️  Używanie przetwarzania równoległego dla {} iteracji (wątki: {})",
+    cli_results: "\n✅ Wyniki:",
+    cli_crc_hex: "🎯 Wartość CRC (hex):    0x{}",
+    cli_crc_dec: "🔢 Wartość CRC (dec):    {}",
+    cli_crc_bin: "🔢 Wartość CRC (bin):    {}",
+    cli_performance: "\n⚡ Wydajność:",
+    cli_total_time: "⏱️  Czas całkowity:      {} ms",
+    cli_avg_time_ms: "⏱️  Średni czas na CRC:  {} ms",
+    cli_avg_time_us: "⏱️  Średni czas na CRC:  {} µs",
+    cli_throughput: "📊 Przepustowość:        {} CRC/s",
+    cli_parallel_used: "\n💡 Uwaga: Użyto przetwarzania równoległego dla optymalnej wydajności.",
+    cli_dbc_messages: "Wiadomości w pliku DBC:",
+    cli_dbc_message_line: "  {} (ID 0x{}, DLC {}, sygnałów: {})",
+    cli_dbc_signal_line: "    {} [{}|{}] {}",
+    cli_err_signal_format: "Nieprawidłowa wartość sygnału '{}' (oczekiwano NAZWA=WARTOŚĆ)",
+    cli_frame_payload: "📦 Dane ramki:          {}",
+    cli_frame_id: "🆔 Identyfikator:       0x{} ({})",
+    cli_frame_crc_bits: "🔢 Bity objęte CRC:     {}",
+    cli_frame_crc: "🎯 CRC ramki (hex):     0x{}",
+    cli_frame_j1939: "🚛 {}",
+
+    cli_err_burst_format: "Nieprawidłowy zapis paczki błędów '{}' (oczekiwano START:DŁUGOŚĆ)",
+    cli_err_bit_out_of_range: "Pozycja bitu {} leży poza słowem kodowym ({} bitów, pozycje 0-{})",
+    cli_err_burst_out_of_range: "Paczka błędów {}:{} wychodzi poza słowo kodowe ({} bitów)",
+    cli_err_random_too_many: "Nie można odwrócić {} losowych bitów w słowie kodowym o długości {} bitów",
+    cli_inject_codeword: "📨 Słowo kodowe:         {} bitów danych + {} bitów CRC",
+    cli_inject_original: "🎯 CRC oryginalne:      0x{}",
+    cli_inject_flipped: "⚡ Odwrócone bity:       {}",
+    cli_inject_received: "📥 CRC odebrane:        0x{}",
+    cli_inject_recomputed: "🔁 CRC przeliczone:     0x{}",
+    cli_inject_detected: "✅ Błąd WYKRYTY",
+    cli_inject_undetected: "❌ Błąd NIEWYKRYTY",
+    cli_analyze_header: "📐 Analiza wykrywalności: CRC-{} (0x{}), {} bitów danych + {} bitów CRC",
+    cli_analyze_class: "Klasa błędów",
+    cli_analyze_tested: "Sprawdzono",
+    cli_analyze_undetected: "Niewykryte",
+    cli_analyze_coverage: "Pokrycie",
+    cli_analyze_single: "1 bit (wszystkie)",
+    cli_analyze_double: "2 bity (wszystkie)",
+    cli_analyze_burst: "paczki ciągłe ≤ {} (wszystkie)",
+    cli_analyze_random: "{} bitów (próbki)",
+    cli_analyze_probability: "📉 Szacowane prawdopodobieństwo niewykrytego błędu przy BER={}: {}",
+    cli_distribution_header: "📊 Rozkład wartości CRC-{} (0x{}): {} bitów danych, {} wiadomości ({})",
+    cli_distribution_exhaustive: "wszystkie",
+    cli_distribution_sampled: "losowe, ziarno {}",
+    cli_distribution_buckets: "Kubełki: {} (najstarsze {} bitów CRC), oczekiwano {} na kubełek",
+    cli_distribution_range: "Najmniej/najwięcej w kubełku: {} / {}, puste kubełki: {}",
+    cli_distribution_collisions: "Kolizje (wiadomości w zajętym już kubełku): {}",
+    cli_distribution_chi_square: "χ² = {} przy {} stopniach swobody, p = {}",
+    cli_hamming_result: "📏 Długość {} bitów: HD = {} ({})",
+    cli_hamming_above: "📏 Długość {} bitów: HD > {} ({})",
+    cli_hamming_range: "📏 {}–{} bitów danych: HD = {}",
+    cli_hamming_range_above: "📏 {}–{} bitów danych: HD > {}",
+    cli_hamming_exhaustive: "wyczerpująco",
+    cli_hamming_sampled: "próbkowanie, wynik przybliżony",
+    cli_recover_searching: "🔎 Przeszukiwanie wielomianów CRC-{} dla {} par (wiadomość, CRC)...",
+    cli_recover_match: "✅ width={} poly=0x{} init=0x{} refin={} refout={} xorout=0x{}",
+    cli_recover_none: "❌ Nie znaleziono pasującego wariantu CRC-{}",
+    cli_recover_summary: "📊 Znaleziono {} dopasowań w czasie {}",
+    cli_recover_ambiguous: "⚠️ Wiele dopasowań - dodaj więcej par (najlepiej o różnych długościach), aby zawęzić wynik",
+    cli_recover_too_wide: "❌ Pełne przeszukiwanie wielomianów obsługuje szerokość do {} bitów - podaj --poly",
+    cli_err_pair_format: "Nieprawidłowa para \"{}\" - oczekiwano DANE_HEX:CRC_HEX",
+    cli_err_pairs_missing: "Podaj co najmniej jedną parę --pair",
+    cli_preimage_header: "🎯 Szukanie wiadomości {}-bitowych o CRC 0x{} (stały prefiks: {} bitów)",
+    cli_preimage_line: "  {}  CRC=0x{}",
+    cli_preimage_none: "❌ Nie znaleziono wiadomości o zadanym CRC przy tych ograniczeniach",
+    cli_preimage_summary: "📊 Znaleziono {} wiadomości w czasie {}",
+    cli_err_prefix_too_long: "Prefiks ({} bitów) jest dłuższy niż wiadomość ({} bitów)",
+    #[cfg(feature = "test-vectors")]
+    cli_selftest_pass: "✅ {}: 0x{} ({})",
+    #[cfg(feature = "test-vectors")]
+    cli_selftest_fail: "❌ {}: oczekiwano 0x{}, otrzymano 0x{} ({})",
+    #[cfg(feature = "test-vectors")]
+    cli_selftest_summary: "📊 Autotest: {}/{} wektorów poprawnych",
+    err_unknown_algorithm: "Nieznany algorytm \"{}\". Dostępne: {}",
+    cli_algorithm_used: "🧮 Algorytm: {}",
+    cli_algorithms_header: "📚 Wbudowane algorytmy CRC:",
+    cli_algorithms_line: "  {} width={} poly=0x{} init=0x{} refin={} refout={} xorout=0x{} check=0x{} residue=0x{} {}",
+    cli_algorithms_aliases: "     aliasy: {}",
+    cli_algorithms_checksums: "🧮 Proste sumy kontrolne (tylko verify i batch):",
+    cli_algorithms_checksum_line: "  {} width={} check=0x{} {}",
+    err_checksum_unsupported: "\"{}\" to suma kontrolna, nie CRC - obsługują ją tylko polecenia calc, verify i batch",
+    cli_file_line: "{}  {}  ({} B)",
+    cli_file_header: "🧮 {} (width={}, refin={}, refout={})",
+    cli_e2e_protected: "🔐 Zabezpieczone dane: {}",
+    cli_e2e_summary: "🔐 E2E profil {}: licznik {}, CRC 0x{}",
+    cli_e2e_check: "🔍 E2E profil {}: licznik {}, CRC odebrane 0x{}, oczekiwane 0x{}",
+    cli_e2e_valid: "✅ CRC poprawne",
+    cli_e2e_invalid: "❌ CRC niepoprawne",
+    cli_err_e2e_too_short: "Dane E2E muszą mieć co najmniej 2 bajty (CRC i licznik)",
+    cli_err_data_id_list: "Profil 2 wymaga listy 16 identyfikatorów danych (--data-id-list), podano {}",
+    cli_file_image_line: "{}  {}  ({} B, 0x{}..0x{})",
+    cli_file_no_match: "żaden plik nie pasuje do wzorca",
+    cli_file_is_directory: "to katalog (użyj --recursive)",
+    cli_manifest_written: "✅ Zapisano manifest {} ({} plików, {})",
+    cli_err_write_manifest: "Nie udało się zapisać manifestu '{}': {}",
+    cli_manifest_ok: "✅ {}  OK",
+    cli_manifest_mismatch: "❌ {}  NIEZGODNY: oczekiwano {}, obliczono {}",
+    cli_manifest_unreadable: "❌ {}  BRAK: {}",
+    cli_manifest_summary: "📋 {} OK, {} niezgodnych, {} brakujących ({})",
+    #[cfg(feature = "gpu")]
+    cli_gpu_header: "🧪 GPU a rayon: {} ({} wiadomości po {} B)",
+    #[cfg(feature = "gpu")]
+    cli_gpu_adapter: "   Adapter: {}",
+    #[cfg(feature = "gpu")]
+    cli_gpu_cpu: "   CPU (rayon, {} wątków): {} ms, {} wiadomości/s",
+    #[cfg(feature = "gpu")]
+    cli_gpu_gpu: "   GPU (wgpu, z transferem danych): {} ms, {} wiadomości/s",
+    #[cfg(feature = "gpu")]
+    cli_gpu_speedup: "📈 GPU względem CPU: {}×",
+    #[cfg(feature = "gpu")]
+    cli_gpu_mismatch: "❌ Wyniki GPU różnią się od CPU",
+    cli_hint_escaped: "\n💡 Wskazówka: Podaj bajty jako sekwencje ucieczki, np. \"\\x01\\x04\\x00\\x00\"",
+    cli_hint_base64: "\n💡 Wskazówka: Wklej zakodowany ładunek, np. ESIzRA==",
+    cli_err_algorithm_with_params: "Opcji --algorithm nie można łączyć z --poly, --width, --refin, --init ani --xorout",
+    cli_err_fixed_crc: "Algorytmu {} nie można wybrać dla podkomendy {} - jej CRC wynika z protokołu",
+    cli_err_cross_check_command: "--cross-check działa tylko z podkomendami calc, verify, bench, batch i repl",
+    cli_verify_match: "✅ CRC zgodne: 0x{}",
+    cli_checksum_result: "{}: 0x{} ({} bitów)",
+    cli_proto_frame: "Ramka {}: {}",
+    cli_err_proto_algorithm: "--proto {} ustala algorytm; nie łącz go z --algorithm {}",
+    cli_verify_mismatch: "❌ CRC niezgodne: obliczone 0x{}, oczekiwane 0x{}",
+    cli_line_error: "❌ Linia {}: {}",
+    cli_serve_listening: "ℹ️  Nasłuchiwanie na {} - jedna wiadomość na linię, odpowiedź: CRC w hex",
+    cli_err_bind: "Nie można nasłuchiwać na {}: {}",
+    #[cfg(feature = "serde")]
+    cli_raw_listening: "ℹ️  Nasłuchiwanie na {} - ramki z prefiksem długości ({} B), CRC: {}, wynik: JSON",
+    #[cfg(feature = "serde")]
+    cli_err_raw_connection: "Połączenie {}: {}",
+    cli_udp_listening: "ℹ️  Nasłuchiwanie UDP na {} - jeden datagram to jedna wiadomość, CRC: {}",
+    cli_udp_datagram: "{}  {} B  CRC: {}",
+    cli_err_udp: "UDP: {}",
+    cli_frame_standard: "standardowy, 11 bitów",
+    cli_frame_extended: "rozszerzony, 29 bitów",
+    cli_cross_check_ok: "✅ Sprawdzenie krzyżowe: zgodne wyniki {} implementacji ({})",
+    cli_cross_check_failed: "❌ BŁĄD: Implementacje CRC dają różne wyniki dla tych samych danych:",
+    cli_cross_check_line: "   {} 0x{}",
+    cli_verify_residue_match: "✅ Słowo kodowe poprawne: reszta 0x{}",
+    cli_verify_residue_mismatch: "❌ Słowo kodowe błędne: reszta 0x{}, oczekiwana 0x{}",
+    cli_frame_wire: "〰️  Bity na magistrali:  {}",
+    cli_frame_wire_summary: "   {} bitów, w tym {} bitów wypychania [w nawiasach]; ACK jako potwierdzony (dominujący)",
+    #[cfg(feature = "config")]
+    err_settings_io: "Nie można odczytać pliku konfiguracyjnego {}: {}",
+    #[cfg(feature = "config")]
+    err_settings_syntax: "Nieprawidłowy plik konfiguracyjny {}: {}",
+    #[cfg(feature = "config")]
+    cli_err_settings_value: "Nieprawidłowa wartość '{}' dla '{}' w pliku konfiguracyjnym (dozwolone: {})",
+    #[cfg(feature = "parallel")]
+    cli_err_threads: "Nie można ustawić liczby wątków: {}",
+    cli_err_log_file: "Nie udało się otworzyć pliku dziennika '{}': {}",
+    cli_explain_header: "  krok  indeks  bit  CRCNXT  rejestr",
+    cli_explain_byte: "  ── po bajcie {}: rejestr 0x{}",
+    cli_explain_final: "  CRC po refout/xorout: 0x{}",
+    cli_listen_modbus_ok: "{}  ✅ adres {}  {}",
+    cli_listen_modbus_mismatch: "{}  ❌ adres {}, odebrano {}  {}",
+    #[cfg(feature = "mqtt")]
+    cli_listen_mqtt_connected: "ℹ️  Publikowanie wyników do brokera MQTT {} (tematy {}/can/<ID>, {}/modbus/<adres>)",
+    cli_metrics_listening: "ℹ️  Metryki Prometheus: http://{}{}",
+    cli_listen_modbus_pdu: "   ↳ {}",
+    cli_listen_j1939: "   ↳ {}",
+    cli_transaction: "{} adres {}  {}  żądanie: {}, odpowiedź: {}, czas odpowiedzi: {}",
+    cli_transaction_crc_ok: "CRC ok",
+    cli_transaction_crc_bad: "błędne CRC",
+    cli_transaction_missing: "brak",
+    cli_transaction_broadcast: "rozgłoszenie, bez odpowiedzi",
+    cli_transactions_summary: "Transakcje: {}, poprawne: {}, bez odpowiedzi: {}, błędy CRC: {}, wyjątki: {}",
+    cli_frame_fd_algorithm: "⚡ CAN FD:              {}, DLC {}",
+    cli_frame_fd_stuff_count: "🧮 Licznik wypychań:    {} → {} (kod Graya + parzystość, wliczony do CRC)",
+    report_title: "Raport CRC",
+    report_algorithm: "Algorytm",
+    report_parameters: "Parametry",
+    report_input: "Dane wejściowe",
+    report_result: "Wynik",
+    report_value: "Wartość",
+    report_path: "Ścieżka",
+    report_input_format: "Format danych",
+    report_data: "Dane",
+    report_bit_count: "Liczba bitów",
+    report_iterations: "Liczba iteracji",
+    report_timing: "Czasy",
+    report_total_time: "Czas całkowity [ms]",
+    report_avg_time: "Średni czas na iterację [µs]",
+    report_throughput: "Przepustowość [CRC/s]",
+    report_environment: "Środowisko",
+    report_generated: "Wygenerowano",
+    report_version: "Wersja",
+    report_platform: "System / architektura",
+    report_cpus: "Rdzenie CPU",
+    report_threads: "Wątki obliczeń",
+    report_build: "Kompilacja",
+    report_features: "Włączone funkcje",
+    cli_report_written: "✅ Zapisano raport do '{}'",
+    cli_err_write_report: "Nie udało się zapisać raportu '{}': {}",
+    cli_sweep_header: "🧪 Przepustowość w zależności od długości wiadomości: {} ({} ms na punkt)",
+    cli_sweep_bits: "bity",
+    cli_sweep_bitwise: "bitowo [CRC/s]",
+    cli_sweep_table: "tablica [CRC/s]",
+    cli_sweep_speedup: "przyspieszenie",
+    cli_sweep_crossover: "📈 Ścieżka tablicowa jest szybsza od bitowej od {} bitów wzwyż",
+    cli_sweep_no_crossover: "⚠️ Ścieżka tablicowa nie wyprzedziła bitowej w badanym zakresie",
+    #[cfg(feature = "parallel")]
+    cli_scaling_header: "🧪 Skalowanie obliczeń wsadowych: {} ({} bitów × {} iteracji, mediana z {} powtórzeń)",
+    #[cfg(feature = "parallel")]
+    cli_scaling_threads: "wątki",
+    #[cfg(feature = "parallel")]
+    cli_scaling_median: "mediana [ms]",
+    #[cfg(feature = "parallel")]
+    cli_scaling_efficiency: "wydajność",
+    #[cfg(feature = "parallel")]
+    cli_scaling_below_threshold: "⚠️ Poniżej {} iteracji obliczenia wsadowe nie używają wątków - zwiększ --iterations, aby zmierzyć ścieżkę równoległą",
+    #[cfg(feature = "parallel")]
+    cli_scaling_best: "📈 Najszybciej: {} wątków ({}× względem pierwszego pomiaru, wydajność {}%)",
+    #[cfg(feature = "parallel")]
+    cli_scaling_no_gain: "⚠️ Ścieżka równoległa nie daje na tym sprzęcie przyspieszenia powyżej {}×",
+    cli_err_bench_data: "Podaj dane (--data) albo użyj --sweep",
+    cli_flips_header: "🔎 Wiadomość: {} bitów, {} = 0x{}",
+    cli_flips_single: "Pojedyncze odwrócenia bitów",
+    cli_flips_double: "Podwójne odwrócenia bitów",
+    cli_flips_detected: "✅ {}: wykryto wszystkie ({})",
+    cli_flips_undetected: "❌ {}: niewykryte {} z {}",
+    cli_flips_pattern: "   bity {}",
+    cli_flips_more: "   … i {} więcej",
+    cli_check_frame_header: "🔎 Ramka: {} bitów danych + {} bitów pola CRC, {}",
+    cli_check_frame_received: "CRC odebrane:  ",
+    cli_check_frame_computed: "CRC obliczone: ",
+    cli_check_frame_pass: "✅ PASS - CRC zgodne",
+    cli_check_frame_fail: "❌ FAIL - CRC niezgodne, różnych bitów: {}",
+    cli_check_frame_bits: "Różniące się bity CRC (od {} = MSB): {}",
+    cli_check_frame_destuffed: "🧹 Surowy strumień: {} bitów, usunięto bitów wypychania: {}",
+    cli_check_frame_stuff_error: "❌ Błąd wypychania na bicie {}: szósty kolejny bit '{}'",
+    cli_check_frame_stuff_fail: "❌ FAIL - błędów wypychania: {}, CRC nie zostało sprawdzone",
+    #[cfg(all(feature = "socketcan", target_os = "linux"))]
+    cli_gen_open_failed: "Nie można otworzyć interfejsu CAN '{}': {}",
+    #[cfg(all(feature = "socketcan", target_os = "linux"))]
+    cli_gen_send_failed: "Nie można wysłać ramki na '{}': {}",
+    cli_gen_sent: "✅ Wysłano ramek: {} na {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_serial_open_failed: "Nie można otworzyć portu szeregowego '{}': {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_serial_io_failed: "Port szeregowy '{}': {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_empty_map: "⚠️ Nie skonfigurowano rejestrów - każde żądanie dostanie wyjątek 02",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_listening: "📟 Slave Modbus RTU {} na {} ({} bodów, parzystość {}): cewki {}, wejścia dyskretne {}, rejestry podtrzymujące {}, rejestry wejściowe {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_rx: "RX {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_rx_invalid: "RX {} - {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_rx_bad_crc: "RX {} - złe CRC (odebrane {}, obliczone {}), pominięto",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_rx_ignored: "RX {} - dla urządzenia {}, pominięto",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_tx: "TX {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_tx_exception: "TX {} - wyjątek {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_tx_corrupted: "TX {} - CRC celowo uszkodzone",
+    cli_poll_connect_failed: "Nie można połączyć się z '{}': {}",
+    cli_poll_io_failed: "'{}': {}",
+    cli_poll_started: "🔁 Odpytywanie urządzenia {} przez {}: rejestry podtrzymujące {}-{} co {} ms",
+    cli_poll_ok: "#{} {}",
+    cli_poll_crc_error: "#{} RX {} - złe CRC (odebrane {}, obliczone {})",
+    cli_poll_unexpected: "#{} RX {} - {}",
+    cli_poll_timeout: "#{} brak odpowiedzi w ciągu {} ms",
+    cli_poll_summary: "Zapytania: {}, poprawne: {}, bez odpowiedzi: {}, błędy CRC: {}, wyjątki: {}, nieoczekiwane: {}",
+    cli_replay_mismatch: "❌ Linia {}: CRC odebrane {}, obliczone {}  {}",
+    cli_replay_summary: "📊 Podsumowanie odtwarzania",
+    cli_replay_frames: "Ramki: {} (błędne linie: {})",
+    cli_replay_trace_rate: "Czas zapisu: {} s, {} ramek/s",
+    cli_replay_processing_rate: "Odtwarzanie: {} s, {} ramek/s",
+    cli_replay_crc_checked: "CRC sprawdzone: {}, niezgodne: {}",
+    cli_replay_crc_missing: "ℹ️ Zapis nie zawiera CRC - CRC zostały tylko przeliczone",
+    cli_replay_ids: "Ramki według ID (najczęstsze {} z {}):",
+    cli_replay_column_frames: "ramki",
+    cli_replay_column_mismatches: "błędy CRC",
+    cli_replay_lengths: "Histogram długości danych:",
+    #[cfg(feature = "tui")]
+    cli_tui_frames: " Ramki ",
+    #[cfg(feature = "tui")]
+    cli_tui_status: " Status CRC ",
+    #[cfg(feature = "tui")]
+    cli_tui_command: " Polecenie (Esc - wyjście) ",
+    #[cfg(feature = "tui")]
+    cli_tui_rate_graph: " Ramki/s (maks. {}) ",
+    #[cfg(feature = "tui")]
+    cli_tui_errors_graph: " Błędy CRC/s (maks. {}) ",
+    #[cfg(feature = "tui")]
+    cli_tui_source: "Źródło: {} ({})",
+    #[cfg(feature = "tui")]
+    cli_tui_algorithm: "Algorytm (calc): {}",
+    #[cfg(feature = "tui")]
+    cli_tui_total: "Ramki: {}",
+    #[cfg(feature = "tui")]
+    cli_tui_rate: "Ramki/s: {}",
+    #[cfg(feature = "tui")]
+    cli_tui_mismatches: "Błędy CRC: {}",
+    #[cfg(feature = "tui")]
+    cli_tui_errors: "Błędne linie: {}",
+    #[cfg(feature = "tui")]
+    cli_tui_paused: "⏸ Wstrzymano - resume wznawia",
+    #[cfg(feature = "tui")]
+    cli_tui_no_source: "brak (tylko polecenia)",
+    #[cfg(feature = "tui")]
+    cli_tui_help: "Polecenia: calc DANE, frame RAMKA, algo NAZWA, pause, resume, clear, help, quit",
+    #[cfg(feature = "tui")]
+    cli_tui_unknown_command: "❌ Nieznane polecenie lub brak argumentu: '{}' (help - lista poleceń)",
+    #[cfg(feature = "tui")]
+    cli_tui_calc_result: "✅ {}: CRC = 0x{} ({} bitów)",
+    #[cfg(feature = "tui")]
+    cli_tui_terminal_error: "Nie można przygotować terminala: {}",
+    #[cfg(feature = "watch")]
+    cli_watch_started: "ℹ️ Obserwuję plik {} - CRC zostanie przeliczone po każdej zmianie (Ctrl-C kończy)",
+    #[cfg(feature = "watch")]
+    cli_watch_changed: "ℹ️ Plik {} zmienił się",
+    #[cfg(feature = "watch")]
+    cli_watch_failed: "Nie można obserwować pliku {}: {}",
+    cli_bench_runs: "🔁 Powtórzenia:          {} mierzonych + {} rozgrzewkowych, po {} iteracji",
+    cli_bench_min: "⏱️  Czas powtórzenia min: {} ms",
+    cli_bench_median: "⏱️  Mediana:              {} ms",
+    cli_bench_p95: "⏱️  p95:                  {} ms",
+    cli_bench_stddev: "📐 Odchylenie std.:      {} ms ({}%)",
+    cli_bench_noisy: "⚠️  Duży rozrzut pomiarów - zamknij inne programy lub zwiększ --iterations / --repeat",
+    report_warmup: "Powtórzenia rozgrzewkowe",
+    report_repetitions: "Mierzone powtórzenia",
+    report_min_time: "Czas powtórzenia min [ms]",
+    report_median_time: "Czas powtórzenia - mediana [ms]",
+    report_p95_time: "Czas powtórzenia p95 [ms]",
+    report_max_time: "Czas powtórzenia maks. [ms]",
+    report_stddev: "Odchylenie standardowe [ms]",
+    cli_file_strategy: "ℹ️ Implementacja (auto, kalibracja przy starcie): tablica od {} B, slice-by-8 od {} B",
+};
+
+static EN: Strings = Strings {
+    app_title: "CAN CRC Calculator",
+    err_prefix: "❌ Error: ",
+    err_empty_input: "Input data is empty",
+    err_invalid_binary_chars: "Invalid characters found: '{}' (first at index {}; allowed: 0, 1, spaces, underscores, dots, 0b prefixes)",
+    err_no_binary_data: "No valid binary data (only 0 and 1)",
+    err_binary_too_long: "Data too long: {} bits (maximum allowed: {} bits)",
+    err_invalid_hex_chars: "Invalid characters found: '{}' (first at index {}; allowed: 0-9, A-F, spaces, commas, colons, dashes, 0x prefixes)",
+    err_no_hex_data: "No valid hex data",
+    err_odd_hex_length: "Odd number of hex characters: {} (an even number is required)",
+    err_hex_too_long: "Data too long: {} bytes = {} bits (maximum: {} bytes = {} bits)",
+    err_invalid_hex_format: "Invalid hex format",
+    err_invalid_width: "Unsupported CRC width: {} (allowed 1-64 bits)",
+    err_poly_too_wide: "Polynomial 0x{} does not fit in {} bits",
+    err_even_poly: "Polynomial 0x{} has no x^0 term",
+    err_invalid_number: "Invalid number: '{}'",
+    err_invalid_data_width: "Unsupported data width: {} (allowed 1-64 bits)",
+    err_id_out_of_range: "Identifier 0x{} does not fit in {} bits",
+    err_frame_data_too_long: "Too much frame data: {} bytes (max. {})",
+    err_dbc_syntax: "DBC syntax error on line {}: '{}'",
+    err_dbc_unknown_message: "Unknown DBC message: '{}'",
+    err_dbc_unknown_signal: "Unknown DBC signal: '{}'",
+    err_dbc_value_out_of_range: "Value {} does not fit in signal '{}'",
+
+    err_image_syntax: "malformed record on line {}",
+    err_image_checksum: "record checksum mismatch on line {}: expected {}, found {}",
+    err_image_unsupported_record: "unsupported record type {} on line {}",
+    err_image_overlap: "data records overlap at address 0x{}",
+    err_image_address_overflow: "data at address 0x{} exceeds the 32-bit address space",
+    err_image_missing_end: "missing end-of-file record",
+    err_manifest_syntax: "Malformed manifest entry on line {}: '{}'",
+    err_manifest_unknown_algorithm: "Unknown manifest algorithm '{}'",
+    #[cfg(feature = "gpu")]
+    err_gpu_no_adapter: "No GPU adapter (wgpu) available",
+    #[cfg(feature = "gpu")]
+    err_gpu_device: "GPU: {}",
+    #[cfg(feature = "gpu")]
+    err_gpu_unsupported_width: "{}-bit CRCs are not supported on the GPU (max {} bits)",
+    #[cfg(feature = "gpu")]
+    err_gpu_batch_length: "A batch of {} bytes cannot be split into messages of {} bytes",
+    err_invalid_base64_chars: "Invalid base64 characters found: '{}' (first at index {}; allowed: A-Z, a-z, 0-9, +, /, -, _, =)",
+    err_no_base64_data: "No valid base64 data",
+    err_invalid_base64_length: "Invalid base64 length: {} characters",
+    err_partial_byte: "Input has {} bits - whole bytes are required",
+    err_invalid_array_element: "Array element '{}' is not a byte value (0-255)",
+    err_invalid_escape: "Invalid escape sequence '{}' at index {}",
+    err_unterminated_string: "The string opened at index {} is not closed",
+    err_frame_syntax: "Unrecognized CAN frame: '{}'",
+    err_frame_too_short: "Frame too short: {} bytes (min. {})",
+    #[cfg(feature = "mqtt")]
+    err_mqtt_address: "Invalid MQTT broker address '{}' (expected host[:port])",
+    #[cfg(feature = "mqtt")]
+    err_mqtt_connection: "Cannot connect to the MQTT broker {}: {}",
+    #[cfg(feature = "mqtt")]
+    err_mqtt_publish: "Cannot publish an MQTT message: {}",
+    modbus_fc_read_coils: "Read Coils (0x01)",
+    modbus_fc_read_discrete_inputs: "Read Discrete Inputs (0x02)",
+    modbus_fc_read_holding_registers: "Read Holding Registers (0x03)",
+    modbus_fc_read_input_registers: "Read Input Registers (0x04)",
+    modbus_fc_write_single_coil: "Write Single Coil (0x05)",
+    modbus_fc_write_single_register: "Write Single Register (0x06)",
+    modbus_fc_write_multiple_coils: "Write Multiple Coils (0x0F)",
+    modbus_fc_write_multiple_registers: "Write Multiple Registers (0x10)",
+    modbus_fc_other: "Function 0x{}",
+    modbus_ex_illegal_function: "illegal function",
+    modbus_ex_illegal_data_address: "illegal data address",
+    modbus_ex_illegal_data_value: "illegal data value",
+    modbus_ex_server_failure: "server device failure",
+    modbus_ex_acknowledge: "acknowledge, processing in progress",
+    modbus_ex_server_busy: "server device busy",
+    modbus_ex_memory_parity: "memory parity error",
+    modbus_ex_gateway_path: "gateway path unavailable",
+    modbus_ex_gateway_target: "gateway target device failed to respond",
+    modbus_ex_other: "unknown exception",
+    modbus_pdu_read_request: "{} request: start address {}, count {}",
+    modbus_pdu_bits_response: "{} response: {} bits (LSB first): {}",
+    modbus_pdu_registers_response: "{} response: {} registers: {}",
+    modbus_pdu_write_single: "{}: address {}, value 0x{}",
+    modbus_pdu_write_multiple_request: "{} request: start address {}, count {}, data: {}",
+    modbus_pdu_write_multiple_response: "{} response: wrote {} starting at address {}",
+    modbus_pdu_exception: "❌ Exception for {}: {} (code 0x{})",
+    modbus_pdu_malformed: "{}: unexpected data length ({} bytes)",
+    modbus_pdu_other: "{}: {} data bytes",
+    j1939_id: "J1939: PGN {} (0x{}{}), priority {}, source 0x{}, destination {}",
+    j1939_global: "global",
+    err_frame_missing_crc: "Frame has {} bits - too few for the CRC field ({} bits)",
+    err_frame_segment_sequence: "Segment {} is out of sequence (sequence number {})",
+    err_frame_capacity: "Fixed-capacity buffer of {} bits is full",
+
+    err_read_file: "Failed to read file '{}': {}",
+
+    cli_prompt_format: "\nChoose a format ('hex', 'bin', 'b64', 'esc') or type 'exit' to quit:",
+    cli_err_read_format: "Failed to read the format.",
+    cli_err_invalid_format: "Invalid format. Choose 'hex', 'bin', 'b64' or 'esc'.",
+    cli_prompt_data: "Enter input data:",
+    cli_err_read_data: "Failed to read the data.",
+    cli_prompt_iterations: "Enter the number of iterations (1 to 1,000,000,000):",
+    cli_err_read_iterations: "Failed to read the number of iterations.",
+    cli_err_iterations_range: "The number of iterations must be between 1 and 1,000,000,000.",
+    cli_hint_binary: "\n💡 Hint: Use only the characters '0' and '1'; groups may be separated by '_' or '.'.",
+    cli_hint_hex: "\n💡 Hint: Use only the characters 0-9 and A-F.",
+    cli_err_no_input: "No valid input data",
+    cli_input_format: "📋 Input format: {}",
+    cli_input_data: "📝 Input data: {}",
+    cli_bit_count: "🔢 Bit count: {}",
+    cli_iteration_count: "🔄 Iterations: {}",
+    cli_parallel_notice: "ℹ️  Using parallel processing for {} iterations (threads: {})",
+    cli_results: "\n✅ Results:",
+    cli_crc_hex: "🎯 CRC value (hex):      0x{}",
+    cli_crc_dec: "🔢 CRC value (dec):      {}",
+    cli_crc_bin: "🔢 CRC value (bin):      {}",
+    cli_performance: "\n⚡ Performance:",
+    cli_total_time: "⏱️  Total time:           {} ms",
+    cli_avg_time_ms: "⏱️  Average time per CRC: {} ms",
+    cli_avg_time_us: "⏱️  Average time per CRC: {} µs",
+    cli_throughput: "📊 Throughput:           {} CRC/s",
+    cli_parallel_used: "\n💡 Note: Parallel processing was used for optimal performance.",
+    cli_dbc_messages: "Messages in the DBC file:",
+    cli_dbc_message_line: "  {} (ID 0x{}, DLC {}, signals: {})",
+    cli_dbc_signal_line: "    {} [{}|{}] {}",
+    cli_err_signal_format: "Invalid signal value '{}' (expected NAME=VALUE)",
+    cli_frame_payload: "📦 Frame payload:       {}",
+    cli_frame_id: "🆔 Identifier:          0x{} ({})",
+    cli_frame_crc_bits: "🔢 Bits covered by CRC: {}",
+    cli_frame_crc: "🎯 Frame CRC (hex):     0x{}",
+    cli_frame_j1939: "🚛 {}",
+
+    cli_err_burst_format: "Invalid burst '{}' (expected START:LENGTH)",
+    cli_err_bit_out_of_range: "Bit position {} is outside the codeword ({} bits, positions 0-{})",
+    cli_err_burst_out_of_range: "Burst {}:{} runs past the end of the codeword ({} bits)",
+    cli_err_random_too_many: "Cannot flip {} random bits in a {}-bit codeword",
+    cli_inject_codeword: "📨 Codeword:            {} data bits + {} CRC bits",
+    cli_inject_original: "🎯 Original CRC:        0x{}",
+    cli_inject_flipped: "⚡ Flipped bits:        {}",
+    cli_inject_received: "📥 Received CRC:        0x{}",
+    cli_inject_recomputed: "🔁 Recomputed CRC:      0x{}",
+    cli_inject_detected: "✅ Error DETECTED",
+    cli_inject_undetected: "❌ Error UNDETECTED",
+    cli_analyze_header: "📐 Detection analysis: CRC-{} (0x{}), {} data bits + {} CRC bits",
+    cli_analyze_class: "Error class",
+    cli_analyze_tested: "Tested",
+    cli_analyze_undetected: "Undetected",
+    cli_analyze_coverage: "Coverage",
+    cli_analyze_single: "1 bit (all)",
+    cli_analyze_double: "2 bits (all)",
+    cli_analyze_burst: "solid bursts ≤ {} (all)",
+    cli_analyze_random: "{} bits (sampled)",
+    cli_analyze_probability: "📉 Estimated undetected error probability at BER={}: {}",
+    cli_distribution_header: "📊 Distribution of CRC-{} (0x{}) values: {} data bits, {} messages ({})",
+    cli_distribution_exhaustive: "all",
+    cli_distribution_sampled: "random, seed {}",
+    cli_distribution_buckets: "Buckets: {} (top {} CRC bits), {} expected per bucket",
+    cli_distribution_range: "Fewest/most in a bucket: {} / {}, empty buckets: {}",
+    cli_distribution_collisions: "Collisions (messages in an already occupied bucket): {}",
+    cli_distribution_chi_square: "χ² = {} with {} degrees of freedom, p = {}",
+    cli_hamming_result: "📏 Length {} bits: HD = {} ({})",
+    cli_hamming_above: "📏 Length {} bits: HD > {} ({})",
+    cli_hamming_range: "📏 {}–{} data bits: HD = {}",
+    cli_hamming_range_above: "📏 {}–{} data bits: HD > {}",
+    cli_hamming_exhaustive: "exhaustive",
+    cli_hamming_sampled: "sampled, approximate",
+    cli_recover_searching: "🔎 Searching CRC-{} polynomials for {} (message, CRC) pairs...",
+    cli_recover_match: "✅ width={} poly=0x{} init=0x{} refin={} refout={} xorout=0x{}",
+    cli_recover_none: "❌ No matching CRC-{} variant found",
+    cli_recover_summary: "📊 Found {} matches in {}",
+    cli_recover_ambiguous: "⚠️ Several matches - add more pairs (ideally of different lengths) to narrow the result",
+    cli_recover_too_wide: "❌ Exhaustive polynomial search supports widths up to {} bits - pass --poly",
+    cli_err_pair_format: "Invalid pair \"{}\" - expected HEX_DATA:HEX_CRC",
+    cli_err_pairs_missing: "Provide at least one --pair",
+    cli_preimage_header: "🎯 Searching {}-bit messages with CRC 0x{} (fixed prefix: {} bits)",
+    cli_preimage_line: "  {}  CRC=0x{}",
+    cli_preimage_none: "❌ No message with the requested CRC exists under these constraints",
+    cli_preimage_summary: "📊 Found {} messages in {}",
+    cli_err_prefix_too_long: "Prefix ({} bits) is longer than the message ({} bits)",
+    #[cfg(feature = "test-vectors")]
+    cli_selftest_pass: "✅ {}: 0x{} ({})",
+    #[cfg(feature = "test-vectors")]
+    cli_selftest_fail: "❌ {}: expected 0x{}, got 0x{} ({})",
+    #[cfg(feature = "test-vectors")]
+    cli_selftest_summary: "📊 Self-test: {}/{} vectors passed",
+    err_unknown_algorithm: "Unknown algorithm \"{}\". Available: {}",
+    cli_algorithm_used: "🧮 Algorithm: {}",
+    cli_algorithms_header: "📚 Built-in CRC algorithms:",
+    cli_algorithms_line: "  {} width={} poly=0x{} init=0x{} refin={} refout={} xorout=0x{} check=0x{} residue=0x{} {}",
+    cli_algorithms_aliases: "     aliases: {}",
+    cli_algorithms_checksums: "🧮 Simple checksums (verify and batch only):",
+    cli_algorithms_checksum_line: "  {} width={} check=0x{} {}",
+    err_checksum_unsupported: "\"{}\" is a checksum, not a CRC - only the calc, verify and batch commands accept it",
+    cli_file_line: "{}  {}  ({} B)",
+    cli_file_header: "🧮 {} (width={}, refin={}, refout={})",
+    cli_e2e_protected: "🔐 Protected data: {}",
+    cli_e2e_summary: "🔐 E2E profile {}: counter {}, CRC 0x{}",
+    cli_e2e_check: "🔍 E2E profile {}: counter {}, received CRC 0x{}, expected 0x{}",
+    cli_e2e_valid: "✅ CRC valid",
+    cli_e2e_invalid: "❌ CRC invalid",
+    cli_err_e2e_too_short: "E2E data needs at least 2 bytes (CRC and counter)",
+    cli_err_data_id_list: "Profile 2 needs a list of 16 data IDs (--data-id-list), got {}",
+    cli_file_image_line: "{}  {}  ({} B, 0x{}..0x{})",
+    cli_file_no_match: "no file matches the pattern",
+    cli_file_is_directory: "is a directory (use --recursive)",
+    cli_manifest_written: "✅ Manifest {} written ({} files, {})",
+    cli_err_write_manifest: "Failed to write the manifest '{}': {}",
+    cli_manifest_ok: "✅ {}  OK",
+    cli_manifest_mismatch: "❌ {}  FAILED: expected {}, computed {}",
+    cli_manifest_unreadable: "❌ {}  MISSING: {}",
+    cli_manifest_summary: "📋 {} OK, {} failed, {} missing ({})",
+    #[cfg(feature = "gpu")]
+    cli_gpu_header: "🧪 GPU vs rayon: {} ({} messages of {} B)",
+    #[cfg(feature = "gpu")]
+    cli_gpu_adapter: "   Adapter: {}",
+    #[cfg(feature = "gpu")]
+    cli_gpu_cpu: "   CPU (rayon, {} threads): {} ms, {} messages/s",
+    #[cfg(feature = "gpu")]
+    cli_gpu_gpu: "   GPU (wgpu, including data transfer): {} ms, {} messages/s",
+    #[cfg(feature = "gpu")]
+    cli_gpu_speedup: "📈 GPU relative to CPU: {}×",
+    #[cfg(feature = "gpu")]
+    cli_gpu_mismatch: "❌ GPU results differ from the CPU",
+    cli_hint_escaped: "\n💡 Hint: Give the bytes as escape sequences, e.g. \"\\x01\\x04\\x00\\x00\"",
+    cli_hint_base64: "\n💡 Hint: Paste the encoded payload, e.g. ESIzRA==",
+    cli_err_algorithm_with_params: "--algorithm cannot be combined with --poly, --width, --refin, --init or --xorout",
+    cli_err_fixed_crc: "Algorithm {} cannot be selected for the {} subcommand - its CRC is fixed by the protocol",
+    cli_err_cross_check_command: "--cross-check only applies to the calc, verify, bench, batch and repl subcommands",
+    cli_verify_match: "✅ CRC matches: 0x{}",
+    cli_checksum_result: "{}: 0x{} ({} bits)",
+    cli_proto_frame: "{} frame: {}",
+    cli_err_proto_algorithm: "--proto {} selects the algorithm; do not combine it with --algorithm {}",
+    cli_verify_mismatch: "❌ CRC mismatch: computed 0x{}, expected 0x{}",
+    cli_line_error: "❌ Line {}: {}",
+    cli_serve_listening: "ℹ️  Listening on {} - one message per line, reply: CRC in hex",
+    cli_err_bind: "Cannot listen on {}: {}",
+    #[cfg(feature = "serde")]
+    cli_raw_listening: "ℹ️  Listening on {} - length-prefixed frames ({} B), CRC: {}, result: JSON",
+    #[cfg(feature = "serde")]
+    cli_err_raw_connection: "Connection {}: {}",
+    cli_udp_listening: "ℹ️  Listening for UDP on {} - one datagram is one message, CRC: {}",
+    cli_udp_datagram: "{}  {} B  CRC: {}",
+    cli_err_udp: "UDP: {}",
+    cli_frame_standard: "standard, 11-bit",
+    cli_frame_extended: "extended, 29-bit",
+    cli_cross_check_ok: "✅ Cross-check: {} implementations agree ({})",
+    cli_cross_check_failed: "❌ ERROR: CRC implementations disagree on the same input:",
+    cli_cross_check_line: "   {} 0x{}",
+    cli_verify_residue_match: "✅ Codeword valid: residue 0x{}",
+    cli_verify_residue_mismatch: "❌ Codeword invalid: residue 0x{}, expected 0x{}",
+    cli_frame_wire: "〰️  Bits on the bus:     {}",
+    cli_frame_wire_summary: "   {} bits including {} stuff bits [bracketed]; ACK shown as acknowledged (dominant)",
+    #[cfg(feature = "config")]
+    err_settings_io: "Cannot read config file {}: {}",
+    #[cfg(feature = "config")]
+    err_settings_syntax: "Invalid config file {}: {}",
+    #[cfg(feature = "config")]
+    cli_err_settings_value: "Invalid value '{}' for '{}' in the config file (allowed: {})",
+    #[cfg(feature = "parallel")]
+    cli_err_threads: "Cannot set the thread count: {}",
+    cli_err_log_file: "Failed to open the log file '{}': {}",
+    cli_explain_header: "  step   index  bit  CRCNXT  register",
+    cli_explain_byte: "  ── after byte {}: register 0x{}",
+    cli_explain_final: "  CRC after refout/xorout: 0x{}",
+    cli_listen_modbus_ok: "{}  ✅ address {}  {}",
+    cli_listen_modbus_mismatch: "{}  ❌ address {}, received {}  {}",
+    #[cfg(feature = "mqtt")]
+    cli_listen_mqtt_connected: "ℹ️  Publishing results to MQTT broker {} (topics {}/can/<ID>, {}/modbus/<address>)",
+    cli_metrics_listening: "ℹ️  Prometheus metrics: http://{}{}",
+    cli_listen_modbus_pdu: "   ↳ {}",
+    cli_listen_j1939: "   ↳ {}",
+    cli_transaction: "{} address {}  {}  request: {}, response: {}, response time: {}",
+    cli_transaction_crc_ok: "CRC ok",
+    cli_transaction_crc_bad: "bad CRC",
+    cli_transaction_missing: "missing",
+    cli_transaction_broadcast: "broadcast, none expected",
+    cli_transactions_summary: "Transactions: {}, ok: {}, unanswered: {}, CRC errors: {}, exceptions: {}",
+    cli_frame_fd_algorithm: "⚡ CAN FD:              {}, DLC {}",
+    cli_frame_fd_stuff_count: "🧮 Stuff count:         {} → {} (Gray code + parity, included in the CRC)",
+    report_title: "CRC report",
+    report_algorithm: "Algorithm",
+    report_parameters: "Parameters",
+    report_input: "Input",
+    report_result: "Result",
+    report_value: "Value",
+    report_path: "Path",
+    report_input_format: "Input format",
+    report_data: "Data",
+    report_bit_count: "Bit count",
+    report_iterations: "Iterations",
+    report_timing: "Timing",
+    report_total_time: "Total time [ms]",
+    report_avg_time: "Average time per iteration [µs]",
+    report_throughput: "Throughput [CRC/s]",
+    report_environment: "Environment",
+    report_generated: "Generated",
+    report_version: "Version",
+    report_platform: "OS / architecture",
+    report_cpus: "CPU cores",
+    report_threads: "Worker threads",
+    report_build: "Build",
+    report_features: "Enabled features",
+    cli_report_written: "✅ Report written to '{}'",
+    cli_err_write_report: "Failed to write the report '{}': {}",
+    cli_sweep_header: "🧪 Throughput by message length: {} ({} ms per point)",
+    cli_sweep_bits: "bits",
+    cli_sweep_bitwise: "bitwise [CRC/s]",
+    cli_sweep_table: "table [CRC/s]",
+    cli_sweep_speedup: "speedup",
+    cli_sweep_crossover: "📈 The table path is faster than the bitwise one from {} bits upwards",
+    cli_sweep_no_crossover: "⚠️ The table path did not overtake the bitwise one in the measured range",
+    #[cfg(feature = "parallel")]
+    cli_scaling_header: "🧪 Batch scaling: {} ({} bits × {} iterations, median of {} runs)",
+    #[cfg(feature = "parallel")]
+    cli_scaling_threads: "threads",
+    #[cfg(feature = "parallel")]
+    cli_scaling_median: "median [ms]",
+    #[cfg(feature = "parallel")]
+    cli_scaling_efficiency: "efficiency",
+    #[cfg(feature = "parallel")]
+    cli_scaling_below_threshold: "⚠️ Below {} iterations the batch computation does not use threads - raise --iterations to measure the parallel path",
+    #[cfg(feature = "parallel")]
+    cli_scaling_best: "📈 Fastest: {} threads ({}× the first measurement, {}% efficiency)",
+    #[cfg(feature = "parallel")]
+    cli_scaling_no_gain: "⚠️ The parallel path gives no speedup above {}× on this hardware",
+    cli_err_bench_data: "Provide data (--data) or use --sweep",
+    cli_flips_header: "🔎 Message: {} bits, {} = 0x{}",
+    cli_flips_single: "Single-bit flips",
+    cli_flips_double: "Double-bit flips",
+    cli_flips_detected: "✅ {}: all {} detected",
+    cli_flips_undetected: "❌ {}: {} of {} undetected",
+    cli_flips_pattern: "   bits {}",
+    cli_flips_more: "   … and {} more",
+    cli_check_frame_header: "🔎 Frame: {} data bits + {} CRC field bits, {}",
+    cli_check_frame_received: "CRC received: ",
+    cli_check_frame_computed: "CRC computed: ",
+    cli_check_frame_pass: "✅ PASS - CRC matches",
+    cli_check_frame_fail: "❌ FAIL - CRC mismatch, {} bits differ",
+    cli_check_frame_bits: "Differing CRC bits (numbered from {} = MSB): {}",
+    cli_check_frame_destuffed: "🧹 Raw stream: {} bits, {} stuff bits removed",
+    cli_check_frame_stuff_error: "❌ Stuff error at bit {}: sixth consecutive '{}'",
+    cli_check_frame_stuff_fail: "❌ FAIL - {} stuff errors, CRC not checked",
+    #[cfg(all(feature = "socketcan", target_os = "linux"))]
+    cli_gen_open_failed: "Cannot open CAN interface '{}': {}",
+    #[cfg(all(feature = "socketcan", target_os = "linux"))]
+    cli_gen_send_failed: "Cannot send a frame on '{}': {}",
+    cli_gen_sent: "✅ Sent {} frames on {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_serial_open_failed: "Cannot open serial port '{}': {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_serial_io_failed: "Serial port '{}': {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_empty_map: "⚠️ No registers configured - every request gets exception 02",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_listening: "📟 Modbus RTU slave {} on {} ({} baud, parity {}): {} coils, {} discrete inputs, {} holding registers, {} input registers",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_rx: "RX {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_rx_invalid: "RX {} - {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_rx_bad_crc: "RX {} - bad CRC (received {}, computed {}), ignored",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_rx_ignored: "RX {} - for slave {}, ignored",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_tx: "TX {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_tx_exception: "TX {} - exception {}",
+    #[cfg(all(feature = "serial", unix))]
+    cli_slave_tx_corrupted: "TX {} - CRC deliberately corrupted",
+    cli_poll_connect_failed: "Cannot connect to '{}': {}",
+    cli_poll_io_failed: "'{}': {}",
+    cli_poll_started: "🔁 Polling slave {} via {}: holding registers {}-{} every {} ms",
+    cli_poll_ok: "#{} {}",
+    cli_poll_crc_error: "#{} RX {} - bad CRC (received {}, computed {})",
+    cli_poll_unexpected: "#{} RX {} - {}",
+    cli_poll_timeout: "#{} no response within {} ms",
+    cli_poll_summary: "Polls: {}, OK: {}, timeouts: {}, CRC errors: {}, exceptions: {}, unexpected: {}",
+    cli_replay_mismatch: "❌ Line {}: CRC received {}, computed {}  {}",
+    cli_replay_summary: "📊 Replay summary",
+    cli_replay_frames: "Frames: {} (bad lines: {})",
+    cli_replay_trace_rate: "Trace time: {} s, {} frames/s",
+    cli_replay_processing_rate: "Replay: {} s, {} frames/s",
+    cli_replay_crc_checked: "CRC checked: {}, mismatches: {}",
+    cli_replay_crc_missing: "ℹ️ The trace has no CRC column - CRCs were only recomputed",
+    cli_replay_ids: "Frames per ID (top {} of {}):",
+    cli_replay_column_frames: "frames",
+    cli_replay_column_mismatches: "CRC errors",
+    cli_replay_lengths: "Payload length histogram:",
+    #[cfg(feature = "tui")]
+    cli_tui_frames: " Frames ",
+    #[cfg(feature = "tui")]
+    cli_tui_status: " CRC status ",
+    #[cfg(feature = "tui")]
+    cli_tui_command: " Command (Esc to quit) ",
+    #[cfg(feature = "tui")]
+    cli_tui_rate_graph: " Frames/s (max {}) ",
+    #[cfg(feature = "tui")]
+    cli_tui_errors_graph: " CRC errors/s (max {}) ",
+    #[cfg(feature = "tui")]
+    cli_tui_source: "Source: {} ({})",
+    #[cfg(feature = "tui")]
+    cli_tui_algorithm: "Algorithm (calc): {}",
+    #[cfg(feature = "tui")]
+    cli_tui_total: "Frames: {}",
+    #[cfg(feature = "tui")]
+    cli_tui_rate: "Frames/s: {}",
+    #[cfg(feature = "tui")]
+    cli_tui_mismatches: "CRC mismatches: {}",
+    #[cfg(feature = "tui")]
+    cli_tui_errors: "Bad lines: {}",
+    #[cfg(feature = "tui")]
+    cli_tui_paused: "⏸ Paused - resume to continue",
+    #[cfg(feature = "tui")]
+    cli_tui_no_source: "none (commands only)",
+    #[cfg(feature = "tui")]
+    cli_tui_help: "Commands: calc DATA, frame FRAME, algo NAME, pause, resume, clear, help, quit",
+    #[cfg(feature = "tui")]
+    cli_tui_unknown_command: "❌ Unknown command or missing argument: '{}' (help lists the commands)",
+    #[cfg(feature = "tui")]
+    cli_tui_calc_result: "✅ {}: CRC = 0x{} ({} bits)",
+    #[cfg(feature = "tui")]
+    cli_tui_terminal_error: "Cannot set up the terminal: {}",
+    #[cfg(feature = "watch")]
+    cli_watch_started: "ℹ️ Watching {} - the CRC is recomputed on every change (Ctrl-C to stop)",
+    #[cfg(feature = "watch")]
+    cli_watch_changed: "ℹ️ {} changed",
+    #[cfg(feature = "watch")]
+    cli_watch_failed: "Cannot watch {}: {}",
+    cli_bench_runs: "🔁 Repetitions:          {} measured + {} warmup, {} iterations each",
+    cli_bench_min: "⏱️  Repetition time min:  {} ms",
+    cli_bench_median: "⏱️  Median:               {} ms",
+    cli_bench_p95: "⏱️  p95:                  {} ms",
+    cli_bench_stddev: "📐 Std deviation:        {} ms ({}%)",
+    cli_bench_noisy: "⚠️  The measurements vary a lot - close other programs or raise --iterations / --repeat",
+    report_warmup: "Warmup repetitions",
+    report_repetitions: "Measured repetitions",
+    report_min_time: "Repetition time min [ms]",
+    report_median_time: "Repetition time median [ms]",
+    report_p95_time: "Repetition time p95 [ms]",
+    report_max_time: "Repetition time max [ms]",
+    report_stddev: "Standard deviation [ms]",
+    cli_file_strategy: "ℹ️ Implementation (auto, calibrated at startup): table from {} B, slice-by-8 from {} B",
+};
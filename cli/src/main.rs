@@ -0,0 +1,4071 @@
+#[cfg(feature = "tui")]
+mod dashboard;
+mod i18n;
+mod metrics;
+mod report;
+#[cfg(feature = "config")]
+mod settings;
+
+use can_crc_core::analysis::{
+    analyze_bursts, analyze_double_bit, analyze_random, analyze_single_bit, minimum_hamming_distance,
+    undetected_error_probability, ErrorClass, HammingDistance,
+};
+use can_crc_core::auto::AutoCrc;
+use can_crc_core::checksum::Checksum;
+use can_crc_core::codegen::{format_table, generate_hdl, HdlLanguage, TableLanguage};
+use can_crc_core::crosscheck::{cross_check, CrossCheck};
+use can_crc_core::dbc::Dbc;
+use can_crc_core::distribution::{crc_distribution, DEFAULT_BUCKET_BITS, DEFAULT_SAMPLES, MAX_BUCKET_BITS};
+use can_crc_core::e2e::{DataIdMode, E2eProfile};
+use can_crc_core::engine::parse_param;
+use can_crc_core::frame::{check_crc_field, destuff_bits, emit_frame, format_wire_bits, stuff_count_bits, CanFdFrame, STUFF_RUN};
+#[cfg(feature = "gpu")]
+use can_crc_core::gpu::{compare, random_batch, GpuCrc, DEFAULT_GPU_MESSAGES, DEFAULT_GPU_MESSAGE_LEN};
+#[cfg(feature = "gpu")]
+use can_crc_core::GpuError;
+use can_crc_core::image::ImageFormat;
+use can_crc_core::inject::{check_flips, error_positions, verify_double_flips, verify_single_flips, ErrorPattern};
+use can_crc_core::j1939::J1939Id;
+use can_crc_core::manifest::{EntryStatus, Manifest};
+use can_crc_core::master::{Poller, PollOutcome, RtuTransport, TcpTransport, READ_HOLDING_REGISTERS};
+use can_crc_core::modbus::{check_rtu, decode_adu, split_timestamp, Pdu, RtuCheck, RtuFrame, Transaction, TransactionTracker};
+use can_crc_core::generator::{DataMode, FrameGenerator, IdMode, LengthMode, PayloadGenerator};
+#[cfg(feature = "mqtt")]
+use can_crc_core::mqtt::{can_topic, modbus_topic, FrameReport, Publisher, DEFAULT_CLIENT_ID, DEFAULT_TOPIC_PREFIX};
+use can_crc_core::preimage::{find_collisions, find_preimages};
+use can_crc_core::protocol::Protocol;
+#[cfg(feature = "serde")]
+use can_crc_core::rawframe::{check_raw_frame, frame_json, read_frame, LengthPrefix, PrefixSize};
+use can_crc_core::recover::{recover, RecoverOptions, Sample, MAX_POLY_SEARCH_WIDTH};
+use can_crc_core::replay::{replay_delay, CsvLayout, ReplayStats, TraceRecord};
+use can_crc_core::sweep::{crossover, measure_point, sweep_lengths};
+use can_crc_core::timing::{measure, BenchPlan, DEFAULT_REPETITIONS, DEFAULT_WARMUP};
+#[cfg(feature = "parallel")]
+use can_crc_core::scaling::{best_point, measure_scaling_with_progress, thread_counts, MIN_USEFUL_SPEEDUP};
+#[cfg(all(feature = "serial", unix))]
+use can_crc_core::serial::{Parity, SerialPort, DEFAULT_BAUD};
+#[cfg(all(feature = "serial", unix))]
+use can_crc_core::slave::{RegisterBlock, RegisterMap, SlaveSimulator, Table};
+#[cfg(all(feature = "socketcan", target_os = "linux"))]
+use can_crc_core::socketcan::CanSocket;
+use can_crc_core::{
+    bits_to_bytes, bits_to_bytes_with, bytes_to_bits, current_num_threads, error_snippet, format_base64, format_binary, format_escaped, format_hex, parse_base64_input_with, parse_escaped_input_with, parse_binary_input_with, parse_hex_bytes,
+    parse_hex_input_with, base64_error_spans, binary_error_spans, checksum_reader, escaped_error_spans, hex_error_spans, compute_batch_checksums, BitOrder, BitPadding, CanFrame, CrcAlgorithm, CrcCatalog, CrcConfig,
+    ConfigError, CrcResult, Endianness, FrameError, ParseError, ParseOptions,
+    PARALLEL_THRESHOLD,
+};
+#[cfg(feature = "tui")]
+use can_crc_core::BitString;
+#[cfg(feature = "watch")]
+use can_crc_core::watch::FileWatcher;
+#[cfg(feature = "tui")]
+use crate::dashboard::{Dashboard, DashboardCommand, FrameEntry, FrameStatus};
+use crate::i18n::{fill, Lang};
+use crate::metrics::{serve_metrics, Metrics, METRICS_PATH};
+use crate::report::{CalculationReport, Environment, ReportFormat};
+#[cfg(feature = "config")]
+use crate::settings::Settings;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use std::ffi::OsString;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, UdpSocket};
+#[cfg(any(not(feature = "async"), feature = "serde"))]
+use std::net::TcpStream;
+#[cfg(feature = "parallel")]
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+const EXIT_PARSE_ERROR: i32 = 1;
+const EXIT_MISMATCH: i32 = 2;
+const EXIT_IO_ERROR: i32 = 3;
+const EXIT_CROSS_CHECK: i32 = 4;
+#[cfg(feature = "readline")]
+const REPL_PROMPT: &str = "> ";
+#[cfg(feature = "readline")]
+const HISTORY_SIZE: usize = 1000;
+
+#[derive(Debug, Clone, ValueEnum)]
+enum InputFormat {
+    #[value(name = "binarny", alias = "bin", alias = "binary")]
+    Binary,
+    #[value(name = "hex")]
+    Hex,
+    #[value(name = "b64", alias = "base64")]
+    Base64,
+    #[value(name = "esc", alias = "escaped")]
+    Escaped,
+}
+
+impl InputFormat {
+    fn parse(&self, input: &str, options: &ParseOptions) -> Result<Vec<bool>, ParseError> {
+        match self {
+            InputFormat::Binary => parse_binary_input_with(input, options),
+            InputFormat::Hex => parse_hex_input_with(input, options),
+            InputFormat::Base64 => parse_base64_input_with(input, options),
+            InputFormat::Escaped => parse_escaped_input_with(input, options),
+        }
+    }
+
+    fn parse_described(&self, input: &str, options: &ParseOptions, lang: Lang) -> Result<Vec<bool>, String> {
+        self.parse(input, options).map_err(|e| self.describe(input, &e, lang))
+    }
+
+    fn describe(&self, input: &str, error: &ParseError, lang: Lang) -> String {
+        let spans = match self {
+            InputFormat::Binary => binary_error_spans(input, error),
+            InputFormat::Hex => hex_error_spans(input, error),
+            InputFormat::Base64 => base64_error_spans(input, error),
+            InputFormat::Escaped => escaped_error_spans(input, error),
+        };
+        let message = lang.describe(error);
+        match error_snippet(input, &spans) {
+            Some(snippet) => snippet.lines().fold(message, |message, line| format!("{}\n   {}", message, line)),
+            None => message,
+        }
+    }
+
+    fn hint(&self, lang: Lang) -> &'static str {
+        let t = lang.strings();
+        match self {
+            InputFormat::Binary => t.cli_hint_binary,
+            InputFormat::Hex => t.cli_hint_hex,
+            InputFormat::Base64 => t.cli_hint_base64,
+            InputFormat::Escaped => t.cli_hint_escaped,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Radix {
+    Hex,
+    Dec,
+    Bin,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum SweepFormat {
+    Table,
+    Csv,
+    Gnuplot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum TraceFormat {
+    Auto,
+    Candump,
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevel> for LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Off => LevelFilter::OFF,
+            LogLevel::Error => LevelFilter::ERROR,
+            LogLevel::Warn => LevelFilter::WARN,
+            LogLevel::Info => LevelFilter::INFO,
+            LogLevel::Debug => LevelFilter::DEBUG,
+            LogLevel::Trace => LevelFilter::TRACE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum BitOrderArg {
+    Msb,
+    Lsb,
+}
+
+impl From<BitOrderArg> for BitOrder {
+    fn from(order: BitOrderArg) -> Self {
+        match order {
+            BitOrderArg::Msb => BitOrder::MsbFirst,
+            BitOrderArg::Lsb => BitOrder::LsbFirst,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum EndiannessArg {
+    Big,
+    Little,
+}
+
+impl From<EndiannessArg> for Endianness {
+    fn from(endianness: EndiannessArg) -> Self {
+        match endianness {
+            EndiannessArg::Big => Endianness::Big,
+            EndiannessArg::Little => Endianness::Little,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum ProtoArg {
+    Can,
+    #[value(name = "canfd")]
+    CanFd,
+    ModbusRtu,
+    ModbusAscii,
+    Generic,
+}
+
+impl From<ProtoArg> for Protocol {
+    fn from(proto: ProtoArg) -> Self {
+        match proto {
+            ProtoArg::Can => Protocol::Can,
+            ProtoArg::CanFd => Protocol::CanFd,
+            ProtoArg::ModbusRtu => Protocol::ModbusRtu,
+            ProtoArg::ModbusAscii => Protocol::ModbusAscii,
+            ProtoArg::Generic => Protocol::Generic,
+        }
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Kalkulator CRC CAN - Interfejs Linii Poleceń", long_about = None, arg_required_else_help = true)]
+struct Args {
+    #[arg(short, long, global = true, help = "Szczegółowe informacje")]
+    verbose: bool,
+
+    #[arg(long, global = true, value_parser = ["pl", "en"], help = "Język komunikatów / message language (pl, en)")]
+    lang: Option<String>,
+
+    #[arg(short, long, global = true, help = "Nazwa algorytmu z katalogu (np. modbus, crc-32, can) - lista: podkomenda algorithms")]
+    algorithm: Option<String>,
+
+    #[arg(long, global = true, value_enum, help = "Protokół: ustawia algorytm, limity wejścia, położenie CRC i postać ramki (calc, verify, batch)")]
+    proto: Option<ProtoArg>,
+
+    #[arg(long, global = true, help = "Porównaj wyniki wszystkich implementacji CRC i przerwij przy niezgodności (calc, verify, bench, batch, repl)")]
+    cross_check: bool,
+
+    #[arg(long, global = true, value_enum, default_value = "warn", help = "Poziom komunikatów diagnostycznych (debug/trace - zakresy z czasami parsowania, generowania tablic i obliczeń)")]
+    log_level: LogLevel,
+
+    #[arg(long, global = true, help = "Zapisuj komunikaty diagnostyczne do pliku zamiast na stderr")]
+    log_file: Option<PathBuf>,
+
+    #[cfg(feature = "parallel")]
+    #[arg(long, global = true, help = "Liczba wątków obliczeń równoległych (domyślnie liczba rdzeni; stała wartość daje powtarzalne pomiary)")]
+    threads: Option<NonZeroUsize>,
+
+    #[cfg(feature = "test-vectors")]
+    #[arg(long, help = "Sprawdź obliczenia na wektorach testowych ze specyfikacji CAN i Modbus")]
+    self_test: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    #[command(about = "Oblicz CRC jednej wiadomości")]
+    Calc(CalcArgs),
+    #[command(about = "Sprawdź, czy wiadomość ma oczekiwane CRC (kod wyjścia 2 przy niezgodności)")]
+    Verify(VerifyArgs),
+    #[command(about = "Sprawdź kompletną ramkę z polem CRC: odetnij CRC, przelicz i pokaż różniące się bity (kod wyjścia 2 przy niezgodności)")]
+    CheckFrame(CheckFrameArgs),
+    #[command(about = "Zmierz czas wielokrotnego obliczania CRC")]
+    Bench(BenchArgs),
+    #[command(about = "Oblicz CRC wielu wiadomości - po jednej w linii, z pliku lub stdin")]
+    Batch(BatchArgs),
+    #[command(about = "Wypisz tablicę CRC jako kod źródłowy (C, Rust, Python)")]
+    Table(TableArgs),
+    #[command(about = "Zbuduj ramkę CAN (CRC-15) lub CAN FD (CRC-17/21) i oblicz jej CRC")]
+    Frame(FrameArgs),
+    #[command(about = "Czytaj ramki w formacie candump (stdin lub plik) i wypisz ich CRC-15")]
+    Listen(ListenArgs),
+    #[cfg(feature = "tui")]
+    #[command(about = "Panel terminalowy: ramki na żywo, status CRC, wykresy przepustowości i linia poleceń")]
+    Tui(TuiArgs),
+    #[command(about = "Odtwórz zapis candump lub CSV, przelicz CRC i podsumuj stan magistrali (ramki/s, ID, błędy CRC, długości)")]
+    Replay(ReplayArgs),
+    #[command(about = "Generuj poprawne ramki CAN (losowe lub według szablonu) na interfejs vcan lub w formacie candump na stdout")]
+    Gen(GenArgs),
+    #[command(about = "Generuj powtarzalne losowe wiadomości testowe (hex lub binarne), opcjonalnie z ich CRC")]
+    GenData(GenDataArgs),
+    #[cfg(all(feature = "serial", unix))]
+    #[command(about = "Symuluj urządzenie Modbus RTU (slave) na porcie szeregowym z konfigurowalną mapą rejestrów")]
+    SlaveSim(SlaveSimArgs),
+    #[command(about = "Odpytuj cyklicznie urządzenie Modbus RTU (funkcja 3) przez port szeregowy lub TCP, sprawdzaj CRC odpowiedzi i zliczaj błędy")]
+    Poll(PollArgs),
+    #[command(about = "Udostępnij obliczanie CRC przez TCP - jedna wiadomość na linię")]
+    Serve(ServeArgs),
+    #[cfg(feature = "serde")]
+    #[command(about = "Odbieraj przez TCP surowe ramki z prefiksem długości, sprawdzaj CRC na ich końcu i zwracaj wyniki jako JSON")]
+    RawListen(RawListenArgs),
+    #[command(about = "Usługa CRC przez UDP - każdy datagram to jedna wiadomość, odpowiedź: jej CRC")]
+    Udp(UdpArgs),
+    #[command(about = "Tryb interaktywny")]
+    Repl,
+    #[command(about = "Wypisz wbudowane algorytmy CRC i sprawdź ich wartości kontrolne")]
+    Algorithms,
+    #[command(about = "Zabezpiecz lub sprawdź dane wg AUTOSAR E2E (profil 1 i 2)")]
+    E2e(E2eArgs),
+    #[command(about = "Oblicz CRC plików (domyślnie CRC-32 jak w Ethernet/ZIP)")]
+    File(FileArgs),
+    #[command(about = "Utwórz lub sprawdź manifest sum kontrolnych plików (format SFV: ścieżka CRC)")]
+    Manifest(ManifestArgs),
+    #[command(about = "Wygeneruj równoległy układ CRC w Verilog/VHDL")]
+    Hdl(HdlArgs),
+    #[command(about = "Zakoduj sygnały wiadomości z pliku DBC i oblicz CRC ramki")]
+    Dbc(DbcArgs),
+    #[command(about = "Wstrzyknij błędy bitowe i sprawdź, czy CRC je wykryje")]
+    Inject(InjectArgs),
+    #[command(about = "Odwróć kolejno każdy bit wiadomości (opcjonalnie każdą parę) i sprawdź, czy CRC się zmienia (kod wyjścia 2 przy niewykrytym wzorcu)")]
+    Flips(FlipsArgs),
+    #[command(about = "Oszacuj pokrycie wykrywania błędów i prawdopodobieństwo błędu niewykrytego")]
+    Analyze(AnalyzeArgs),
+    #[command(about = "Rozkład wartości CRC dla wszystkich lub losowych wiadomości danej długości (kolizje, test χ²)")]
+    Distribution(DistributionArgs),
+    #[command(about = "Oblicz minimalną odległość Hamminga kodu dla danej długości wiadomości")]
+    Hamming(HammingArgs),
+    #[command(about = "Odtwórz parametry CRC (poly, init, refin/refout, xorout) z par wiadomość/CRC")]
+    Recover(RecoverArgs),
+    #[command(about = "Znajdź wiadomości o zadanym CRC (przeciwobrazy) lub kolizje z podaną wiadomością")]
+    Preimage(PreimageArgs),
+}
+
+impl Command {
+    fn fixed_crc(&self) -> Option<&'static str> {
+        match self {
+            Command::Frame(_) => Some("frame"),
+            Command::Listen(_) => Some("listen"),
+            Command::Replay(_) => Some("replay"),
+            Command::Gen(_) => Some("gen"),
+            #[cfg(all(feature = "serial", unix))]
+            Command::SlaveSim(_) => Some("slave-sim"),
+            Command::Poll(_) => Some("poll"),
+            Command::E2e(_) => Some("e2e"),
+            Command::Dbc(_) => Some("dbc"),
+            Command::Recover(_) => Some("recover"),
+            Command::Manifest(ManifestArgs { action: ManifestAction::Verify(_) }) => Some("manifest verify"),
+            _ => None,
+        }
+    }
+
+    fn supports_cross_check(&self) -> bool {
+        matches!(self, Command::Calc(_) | Command::Verify(_) | Command::Bench(_) | Command::Batch(_) | Command::Repl)
+    }
+}
+
+#[derive(clap::Args, Debug)]
+#[cfg_attr(feature = "watch", command(
+    mut_arg("data", |arg| arg.required(false).default_value("").hide_default_value(true)),
+    group(clap::ArgGroup::new("source").args(["data", "watch"]).required(true)),
+))]
+struct CalcArgs {
+    #[command(flatten)]
+    input: DataArgs,
+
+    #[arg(short, long, conflicts_with = "verbose", help = "Wypisz tylko wartość CRC")]
+    quiet: bool,
+
+    #[arg(long, value_enum, default_value = "hex", requires = "quiet", help = "Zapis wartości CRC w trybie --quiet")]
+    radix: Radix,
+
+    #[arg(long, conflicts_with_all = ["quiet", "verbose"], help = "Wypisz dane z dołączonym CRC w kolejności protokołu (CAN: 15 bitów + ogranicznik, Modbus: 2 bajty little-endian)")]
+    emit_frame: bool,
+
+    #[arg(long, value_enum, requires = "emit_frame", help = "Kolejność bajtów dołączanego CRC: big (MSB-first, kolejność bitów CAN) lub little (Modbus); domyślnie według algorytmu")]
+    crc_endianness: Option<EndiannessArg>,
+
+    #[arg(long, conflicts_with_all = ["quiet", "emit_frame"], help = "Wypisz stan rejestru CRC po każdym bicie wejścia (do porównania z własną implementacją)")]
+    explain: bool,
+
+    #[arg(long, value_name = "PLIK", conflicts_with = "emit_frame", help = "Zapisz raport z danymi, parametrami algorytmu, CRC, czasami i środowiskiem (Markdown; HTML dla rozszerzenia .html)")]
+    report: Option<PathBuf>,
+
+    #[cfg(feature = "watch")]
+    #[arg(long, value_name = "PLIK", help = "Czytaj dane z pliku i przeliczaj CRC po każdej jego zmianie (Ctrl-C kończy)")]
+    watch: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+struct VerifyArgs {
+    #[command(flatten)]
+    input: DataArgs,
+
+    #[arg(long, required_unless_present = "residue", help = "Oczekiwana wartość CRC (hex)")]
+    crc: Option<String>,
+
+    #[arg(long, conflicts_with = "crc", help = "Dane zawierają dołączone CRC - sprawdź resztę (residue) rejestru po całym słowie kodowym")]
+    residue: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct CheckFrameArgs {
+    #[command(flatten)]
+    input: DataArgs,
+
+    #[arg(long, help = "Ramka kończy się ogranicznikiem CRC (jak w wyjściu calc --emit-frame dla CAN)")]
+    delimiter: bool,
+
+    #[arg(long, value_enum, help = "Kolejność bajtów pola CRC: big (MSB-first, kolejność bitów CAN) lub little (Modbus); domyślnie według algorytmu")]
+    crc_endianness: Option<EndiannessArg>,
+
+    #[arg(long, help = "Wejście to surowy strumień bitów z bitami wypychania; błędy wypychania są zgłaszane przed sprawdzeniem CRC")]
+    stuffed: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct BenchArgs {
+    #[command(flatten)]
+    input: Option<DataArgs>,
+
+    #[arg(short, long, default_value_t = 1_000_000, value_parser = clap::value_parser!(u64).range(1..=1_000_000_000), help = "Liczba iteracji (1 do 1 000 000 000)")]
+    iterations: u64,
+
+    #[arg(long, default_value_t = DEFAULT_WARMUP, value_parser = clap::value_parser!(u32).range(0..=1000), conflicts_with = "sweep", help = "Liczba powtórzeń rozgrzewkowych, których czas nie jest liczony")]
+    warmup: u32,
+
+    #[arg(short, long, default_value_t = DEFAULT_REPETITIONS, value_parser = clap::value_parser!(u32).range(1..=10_000), conflicts_with = "sweep", help = "Liczba mierzonych powtórzeń (wynik: min, mediana, p95, odchylenie standardowe)")]
+    repeat: u32,
+
+    #[arg(long, conflicts_with_all = ["data", "iterations", "report"], help = "Zmierz przepustowość ścieżki bitowej i tablicowej dla wiadomości 1-96 bitów (dłuższych dla algorytmów bajtowych)")]
+    sweep: bool,
+
+    #[cfg(feature = "parallel")]
+    #[arg(long, conflicts_with_all = ["sweep", "report"], help = "Powtórz pomiar dla 1, 2, 4, ... N wątków (N - --threads lub liczba rdzeni) i pokaż przyspieszenie oraz wydajność")]
+    scaling: bool,
+
+    #[cfg(feature = "gpu")]
+    #[arg(long, conflicts_with_all = ["data", "sweep", "scaling", "report"], help = "Porównaj CRC wielu różnych losowych wiadomości na GPU (wgpu) i na CPU (rayon)")]
+    gpu: bool,
+
+    #[cfg(feature = "gpu")]
+    #[arg(long, default_value_t = DEFAULT_GPU_MESSAGES, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=100_000_000), requires = "gpu", help = "Liczba wiadomości w porównaniu --gpu")]
+    messages: usize,
+
+    #[cfg(feature = "gpu")]
+    #[arg(long, default_value_t = DEFAULT_GPU_MESSAGE_LEN, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=4096), requires = "gpu", help = "Długość każdej wiadomości w bajtach w porównaniu --gpu")]
+    message_len: usize,
+
+    #[arg(long, value_enum, default_value = "table", requires = "sweep", help = "Format wyników --sweep (table - tabela, csv, gnuplot - skrypt do potoku `| gnuplot -p`)")]
+    sweep_format: SweepFormat,
+
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..=10_000), requires = "sweep", help = "Czas pomiaru każdej długości i ścieżki w ms")]
+    sweep_time: u64,
+
+    #[arg(long, value_name = "PLIK", help = "Zapisz raport z danymi, parametrami algorytmu, CRC, czasami i środowiskiem (Markdown; HTML dla rozszerzenia .html)")]
+    report: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+struct BatchArgs {
+    #[arg(help = "Plik z wiadomościami (domyślnie stdin)")]
+    input: Option<PathBuf>,
+
+    #[arg(short, long, value_enum, default_value = "hex", help = "Format danych wejściowych")]
+    format: InputFormat,
+
+    #[arg(long, value_enum, default_value = "msb", help = "Kolejność bitów w bajcie (msb - najstarszy pierwszy, lsb - najmłodszy pierwszy)")]
+    bit_order: BitOrderArg,
+
+    #[arg(long, help = "Wypisz wyniki jako CSV")]
+    csv: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct FrameArgs {
+    #[arg(long, help = "Identyfikator ramki (hex)")]
+    id: String,
+
+    #[arg(long, help = "Identyfikator rozszerzony (29 bitów)")]
+    extended: bool,
+
+    #[arg(long, conflicts_with = "fd", help = "Ramka zdalna (RTR)")]
+    rtr: bool,
+
+    #[arg(short, long, help = "Dane ramki w hex (maks. 8 bajtów, z --fd 64 - uzupełniane do długości z tabeli DLC)")]
+    data: Option<String>,
+
+    #[arg(long, help = "Ramka CAN FD z CRC-17/CRC-21 wg ISO 11898-1:2015 (licznik wypychań, stałe bity wypychania)")]
+    fd: bool,
+
+    #[arg(long, requires = "fd", help = "Przełączanie szybkości transmisji (BRS)")]
+    brs: bool,
+
+    #[arg(long, requires = "fd", conflicts_with = "wire", help = "CRC jak w kontrolerach sprzed ISO (bez bitów wypychania i licznika, rejestr od zera)")]
+    non_iso: bool,
+
+    #[arg(long, help = "Wypisz pełny strumień bitów ramki z bitami wypychania (stuff bits) w nawiasach")]
+    wire: bool,
+
+    #[arg(long, requires = "extended", help = "Rozłóż identyfikator 29-bitowy wg J1939 (PGN, priorytet, adres nadawcy i odbiorcy)")]
+    j1939: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct ListenArgs {
+    #[arg(help = "Plik z zapisem candump (domyślnie stdin)")]
+    input: Option<PathBuf>,
+
+    #[arg(long, help = "Wejście to ramki Modbus RTU w hex (z CRC na końcu) - sprawdź CRC każdej ramki (kod wyjścia 2 przy niezgodności)")]
+    modbus: bool,
+
+    #[arg(long, requires = "modbus", help = "Łącz żądania z odpowiedziami (adres, funkcja, czas) i wypisz wynik każdej transakcji zamiast pojedynczych ramek")]
+    transactions: bool,
+
+    #[arg(long, default_value_t = 1000, requires = "transactions", value_name = "MS", help = "Czas oczekiwania na odpowiedź w ms - później ramka nie jest łączona z żądaniem")]
+    response_timeout: u64,
+
+    #[arg(long, conflicts_with = "modbus", help = "Rozłóż identyfikatory 29-bitowe wg J1939 (PGN, priorytet, adres nadawcy i odbiorcy)")]
+    j1939: bool,
+
+    #[arg(long, value_name = "ADRES:PORT", help = "Udostępnij liczniki w formacie Prometheus pod http://ADRES:PORT/metrics")]
+    metrics: Option<String>,
+
+    #[cfg(feature = "mqtt")]
+    #[arg(long, value_name = "HOST[:PORT]", help = "Publikuj wynik każdej ramki jako JSON do brokera MQTT (temat według ID CAN / adresu Modbus)")]
+    mqtt: Option<String>,
+
+    #[cfg(feature = "mqtt")]
+    #[arg(long, default_value = DEFAULT_TOPIC_PREFIX, requires = "mqtt", help = "Prefiks tematów MQTT")]
+    mqtt_topic: String,
+}
+
+#[cfg(feature = "tui")]
+#[derive(clap::Args, Debug)]
+struct TuiArgs {
+    #[arg(help = "Plik lub potok z ramkami candump (domyślnie stdin, jeśli nie jest terminalem)")]
+    input: Option<PathBuf>,
+
+    #[arg(long, help = "Wejście to ramki Modbus RTU w hex (z CRC na końcu)")]
+    modbus: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct ReplayArgs {
+    #[arg(help = "Plik z zapisem (domyślnie stdin)")]
+    input: Option<PathBuf>,
+
+    #[arg(long, value_enum, default_value = "auto", help = "Format zapisu (auto - candump, jeśli linie zawierają '#', w przeciwnym razie CSV)")]
+    format: TraceFormat,
+
+    #[arg(long, default_value_t = 0.0, value_parser = parse_speed, help = "Szybkość odtwarzania względem znaczników czasu (1 - czas rzeczywisty, 10 - dziesięć razy szybciej, 0 - bez opóźnień)")]
+    speed: f64,
+
+    #[arg(long, default_value_t = 20, help = "Ile najczęstszych ID wypisać w podsumowaniu")]
+    top: usize,
+}
+
+#[derive(clap::Args, Debug)]
+struct ServeArgs {
+    #[arg(long, default_value = "127.0.0.1:7878", help = "Adres i port nasłuchiwania")]
+    bind: String,
+
+    #[arg(short, long, value_enum, default_value = "hex", help = "Format danych wejściowych")]
+    format: InputFormat,
+
+    #[arg(long, value_name = "ADRES:PORT", help = "Udostępnij liczniki w formacie Prometheus pod http://ADRES:PORT/metrics")]
+    metrics: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(clap::Args, Debug)]
+struct RawListenArgs {
+    #[arg(long, default_value = "127.0.0.1:7879", help = "Adres i port nasłuchiwania")]
+    bind: String,
+
+    #[arg(long, default_value = "2", value_parser = parse_prefix_bytes, help = "Rozmiar prefiksu długości przed każdą ramką w bajtach")]
+    prefix_bytes: PrefixSize,
+
+    #[arg(long, value_enum, default_value = "big", help = "Kolejność bajtów prefiksu długości")]
+    prefix_endianness: EndiannessArg,
+
+    #[arg(long, value_enum, help = "Kolejność bajtów pola CRC na końcu ramki: big lub little; domyślnie według algorytmu")]
+    crc_endianness: Option<EndiannessArg>,
+
+    #[arg(long, help = "Odsyłaj wyniki JSON nadawcy (jedna linia na ramkę) zamiast wypisywać je na stdout")]
+    reply: bool,
+
+    #[arg(long, value_name = "ADRES:PORT", help = "Udostępnij liczniki w formacie Prometheus pod http://ADRES:PORT/metrics")]
+    metrics: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+struct UdpArgs {
+    #[arg(long, default_value = "127.0.0.1:7880", help = "Adres i port nasłuchiwania")]
+    bind: String,
+
+    #[arg(long, help = "Tylko wypisuj CRC na stdout, bez odpowiedzi do nadawcy")]
+    no_reply: bool,
+
+    #[arg(long, conflicts_with = "no_reply", help = "Odpowiadaj samymi bajtami CRC zamiast tekstu hex")]
+    binary: bool,
+
+    #[arg(long, value_enum, requires = "binary", help = "Kolejność bajtów CRC w odpowiedzi binarnej: big lub little; domyślnie według algorytmu")]
+    crc_endianness: Option<EndiannessArg>,
+
+    #[arg(short, long, help = "Nie wypisuj linii dla każdego datagramu")]
+    quiet: bool,
+
+    #[arg(long, value_name = "ADRES:PORT", help = "Udostępnij liczniki w formacie Prometheus pod http://ADRES:PORT/metrics")]
+    metrics: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+struct FileArgs {
+    #[arg(required = true, help = "Ścieżki plików lub wzorce glob (np. 'firmware/*.bin', '**/*.hex')")]
+    paths: Vec<PathBuf>,
+
+    #[arg(short, long, help = "Przetwarzaj katalogi rekurencyjnie")]
+    recursive: bool,
+
+    #[arg(long, help = "Na końcu wypisz podsumowanie w formacie klucz=wartość (files, bytes, errors)")]
+    summary: bool,
+
+    #[arg(long, value_enum, default_value = "auto", help = "Format pliku (auto - według rozszerzenia)")]
+    format: FileFormat,
+
+    #[arg(long, help = "Adres początkowy zakresu obrazu (domyślnie pierwszy adres z danymi)")]
+    start: Option<String>,
+
+    #[arg(long, help = "Adres końcowy zakresu obrazu, wyłącznie (domyślnie koniec danych)")]
+    end: Option<String>,
+
+    #[arg(long, default_value = "0xFF", help = "Bajt wypełniający luki w obrazie")]
+    fill: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum FileFormat {
+    Auto,
+    Raw,
+    Ihex,
+    Srec,
+}
+
+impl FileFormat {
+    fn image_format(self, path: &Path) -> Option<ImageFormat> {
+        match self {
+            FileFormat::Auto => path
+                .extension()
+                .and_then(|extension| ImageFormat::from_extension(&extension.to_string_lossy())),
+            FileFormat::Raw => None,
+            FileFormat::Ihex => Some(ImageFormat::IntelHex),
+            FileFormat::Srec => Some(ImageFormat::SRecord),
+        }
+    }
+}
+
+#[derive(clap::Args, Debug, Clone)]
+struct DataArgs {
+    #[arg(short, long, help = "Dane wejściowe")]
+    data: String,
+
+    #[arg(short, long, value_enum, default_value = "hex", help = "Format danych wejściowych")]
+    format: InputFormat,
+
+    #[arg(long, value_enum, default_value = "msb", help = "Kolejność bitów w bajcie (msb - najstarszy pierwszy, lsb - najmłodszy pierwszy)")]
+    bit_order: BitOrderArg,
+}
+
+impl DataArgs {
+    fn parse_bits(&self, config: &CrcConfig, lang: Lang) -> Result<Vec<bool>, String> {
+        let options = ParseOptions { bit_order: self.bit_order.into(), ..ParseOptions::for_config(config) };
+        self.format.parse_described(&self.data, &options, lang)
+    }
+}
+
+#[derive(clap::Args, Debug)]
+struct PolyArgs {
+    #[arg(long, help = "Wielomian (hex z prefiksem 0x lub dziesiętnie, domyślnie 0x4599)")]
+    poly: Option<String>,
+
+    #[arg(long, help = "Szerokość CRC w bitach (domyślnie 15)")]
+    width: Option<u8>,
+
+    #[arg(long, help = "Odbicie bitów wejściowych (tablica odwrócona)")]
+    refin: bool,
+
+    #[arg(long, help = "Wartość początkowa rejestru CRC (domyślnie 0)")]
+    init: Option<String>,
+
+    #[arg(long, help = "Wartość XOR nakładana na wynik (domyślnie 0)")]
+    xorout: Option<String>,
+}
+
+impl PolyArgs {
+    fn to_config(&self, algorithm: Option<&str>, lang: Lang) -> Result<CrcConfig, String> {
+        let custom = self.poly.is_some() || self.width.is_some() || self.refin || self.init.is_some() || self.xorout.is_some();
+        if let Some(name) = algorithm {
+            if custom {
+                return Err(lang.strings().cli_err_algorithm_with_params.to_string());
+            }
+            return find_algorithm(name, lang).map(|algorithm| algorithm.config);
+        }
+        let param = |value: &Option<String>, default: &str| {
+            parse_param(value.as_deref().unwrap_or(default)).map_err(|e| lang.describe_config(&e))
+        };
+        let poly = param(&self.poly, "0x4599")?;
+        let init = param(&self.init, "0")?;
+        let xorout = param(&self.xorout, "0")?;
+        let config = CrcConfig {
+            init,
+            refin: self.refin,
+            refout: self.refin,
+            xorout,
+            ..CrcConfig::new(self.width.unwrap_or(15), poly)
+        };
+        config.validate().map_err(|e| lang.describe_config(&e))?;
+        Ok(config)
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum TableFormat {
+    C,
+    Rust,
+    Python,
+}
+
+#[derive(clap::Args, Debug)]
+struct TableArgs {
+    #[command(flatten)]
+    poly: PolyArgs,
+
+    #[arg(long, value_enum, default_value = "c", help = "Język wyjściowy")]
+    format: TableFormat,
+
+    #[arg(long, default_value = "crc_table", help = "Nazwa tablicy")]
+    name: String,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum HdlFormat {
+    Verilog,
+    Vhdl,
+}
+
+#[derive(clap::Args, Debug)]
+struct HdlArgs {
+    #[command(flatten)]
+    poly: PolyArgs,
+
+    #[arg(long, default_value_t = 8, help = "Liczba bitów danych na takt zegara")]
+    data_width: u8,
+
+    #[arg(long, value_enum, default_value = "verilog", help = "Język wyjściowy")]
+    format: HdlFormat,
+
+    #[arg(long, help = "Nazwa modułu (domyślnie crc<szerokość>_d<bity danych>)")]
+    module: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+struct DbcArgs {
+    #[arg(long, help = "Ścieżka do pliku DBC")]
+    file: PathBuf,
+
+    #[arg(long, help = "Nazwa wiadomości (bez niej wypisywana jest lista wiadomości)")]
+    message: Option<String>,
+
+    #[arg(long = "signal", value_name = "NAZWA=WARTOŚĆ", help = "Wartość fizyczna sygnału (można powtarzać)")]
+    signals: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum E2eProfileArg {
+    #[value(name = "1")]
+    P1,
+    #[value(name = "2")]
+    P2,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum DataIdModeArg {
+    Both,
+    Alt,
+    Low,
+}
+
+#[derive(clap::Args, Debug)]
+struct E2eArgs {
+    #[arg(short, long, help = "Dane w hex: bajt 0 - CRC, bajt 1 - licznik (młodsze 4 bity), dalej sygnały")]
+    data: String,
+
+    #[arg(long, value_enum, default_value = "1", help = "Profil E2E")]
+    profile: E2eProfileArg,
+
+    #[arg(long, default_value = "0", help = "Identyfikator danych (profil 1)")]
+    data_id: String,
+
+    #[arg(long, value_enum, default_value = "both", help = "Sposób uwzględnienia identyfikatora danych (profil 1)")]
+    data_id_mode: DataIdModeArg,
+
+    #[arg(long, value_delimiter = ',', help = "16 identyfikatorów danych rozdzielonych przecinkami (profil 2)")]
+    data_id_list: Vec<String>,
+
+    #[arg(long, help = "Zapisz licznik i CRC (bez tej opcji dane są tylko sprawdzane)")]
+    counter: Option<u8>,
+}
+
+#[derive(clap::Args, Debug)]
+struct GenArgs {
+    #[cfg(all(feature = "socketcan", target_os = "linux"))]
+    #[arg(long, value_name = "INTERFEJS", help = "Wysyłaj ramki na interfejs SocketCAN (np. vcan0) zamiast wypisywać je na stdout")]
+    interface: Option<String>,
+
+    #[arg(long, default_value = "r", help = "Identyfikator: r - losowy, i - kolejny, lub stały (hex)")]
+    id: IdMode,
+
+    #[arg(long, default_value = "r", help = "Dane: r - losowe, i - licznik ramek, lub stałe bajty (hex, np. 11.22.33)")]
+    data: DataMode,
+
+    #[arg(long, default_value = "r", help = "Długość danych (0-8) lub r - losowa; ignorowana dla stałych danych")]
+    len: LengthMode,
+
+    #[arg(long, help = "Identyfikatory rozszerzone (29 bitów)")]
+    extended: bool,
+
+    #[arg(short = 'n', long, help = "Liczba ramek (domyślnie bez końca)")]
+    count: Option<u64>,
+
+    #[arg(long, default_value_t = 200, help = "Odstęp między ramkami w ms")]
+    gap: u64,
+
+    #[arg(long, default_value_t = 0, help = "Ziarno generatora losowego")]
+    seed: u64,
+}
+
+#[derive(clap::Args, Debug)]
+struct GenDataArgs {
+    #[arg(long, default_value_t = 64, value_parser = clap::value_parser!(u64).range(1..=1_000_000), help = "Długość każdej wiadomości w bitach")]
+    bits: u64,
+
+    #[arg(short = 'n', long, default_value_t = 10, help = "Liczba wiadomości")]
+    count: u64,
+
+    #[arg(long, default_value_t = 0, help = "Ziarno generatora losowego")]
+    seed: u64,
+
+    #[arg(short, long, value_enum, default_value = "hex", help = "Format wyjścia (hex, b64 i esc wymagają pełnych bajtów)")]
+    format: InputFormat,
+
+    #[arg(long, help = "Poprzedź każdą wiadomość jej CRC (algorytm z -a), jak w wyjściu batch")]
+    crc: bool,
+}
+
+#[cfg(all(feature = "serial", unix))]
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum ParityArg {
+    None,
+    Even,
+    Odd,
+}
+
+#[cfg(all(feature = "serial", unix))]
+impl From<ParityArg> for Parity {
+    fn from(parity: ParityArg) -> Self {
+        match parity {
+            ParityArg::None => Parity::None,
+            ParityArg::Even => Parity::Even,
+            ParityArg::Odd => Parity::Odd,
+        }
+    }
+}
+
+#[cfg(all(feature = "serial", unix))]
+#[derive(clap::Args, Debug)]
+struct SlaveSimArgs {
+    #[arg(help = "Port szeregowy (np. /dev/ttyUSB0)")]
+    port: PathBuf,
+
+    #[arg(long, default_value_t = DEFAULT_BAUD, help = "Prędkość transmisji (1200-230400)")]
+    baud: u32,
+
+    #[arg(long, value_enum, default_value = "even", help = "Parzystość (none - z dwoma bitami stopu, jak wymaga Modbus RTU)")]
+    parity: ParityArg,
+
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=247), help = "Adres urządzenia (1-247)")]
+    address: u8,
+
+    #[arg(long, value_name = "START=WARTOŚCI", help = "Cewki (funkcje 1, 5, 15), np. 0=1,0,1 (można powtarzać)")]
+    coils: Vec<RegisterBlock>,
+
+    #[arg(long, value_name = "START=WARTOŚCI", help = "Wejścia dyskretne (funkcja 2), np. 100=1,1 (można powtarzać)")]
+    discrete_inputs: Vec<RegisterBlock>,
+
+    #[arg(long, value_name = "START=WARTOŚCI", help = "Rejestry podtrzymujące (funkcje 3, 6, 16), np. 0=0x1234,42 (można powtarzać)")]
+    holding: Vec<RegisterBlock>,
+
+    #[arg(long, value_name = "START=WARTOŚCI", help = "Rejestry wejściowe (funkcja 4), np. 30=7,8,9 (można powtarzać)")]
+    input_registers: Vec<RegisterBlock>,
+
+    #[arg(long, default_value_t = 0.0, value_parser = parse_rate, help = "Odsetek odpowiedzi z celowo uszkodzonym CRC (0-1)")]
+    bad_crc_rate: f64,
+
+    #[arg(long, default_value_t = 0, help = "Ziarno generatora losowego dla --bad-crc-rate")]
+    seed: u64,
+}
+
+#[derive(clap::Args, Debug)]
+#[command(group = clap::ArgGroup::new("target").required(true))]
+struct PollArgs {
+    #[cfg(all(feature = "serial", unix))]
+    #[arg(long, group = "target", value_name = "PORT", help = "Port szeregowy (np. /dev/ttyUSB0)")]
+    serial: Option<PathBuf>,
+
+    #[arg(long, group = "target", value_name = "HOST:PORT", help = "Bramka TCP przekazująca surowe ramki RTU (z CRC)")]
+    tcp: Option<String>,
+
+    #[cfg(all(feature = "serial", unix))]
+    #[arg(long, default_value_t = DEFAULT_BAUD, help = "Prędkość transmisji (1200-230400)")]
+    baud: u32,
+
+    #[cfg(all(feature = "serial", unix))]
+    #[arg(long, value_enum, default_value = "even", help = "Parzystość (none - z dwoma bitami stopu, jak wymaga Modbus RTU)")]
+    parity: ParityArg,
+
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=247), help = "Adres urządzenia (1-247)")]
+    address: u8,
+
+    #[arg(long, default_value_t = 0, help = "Adres pierwszego rejestru podtrzymującego")]
+    start: u16,
+
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..=125), help = "Liczba rejestrów (1-125)")]
+    count: u16,
+
+    #[arg(long, default_value_t = 1000, value_name = "MS", help = "Odstęp między zapytaniami w ms")]
+    interval: u64,
+
+    #[arg(long, default_value_t = 1000, value_name = "MS", help = "Czas oczekiwania na odpowiedź w ms")]
+    timeout: u64,
+
+    #[arg(short = 'n', long, help = "Liczba zapytań (domyślnie bez końca)")]
+    polls: Option<u64>,
+}
+
+#[derive(clap::Args, Debug)]
+#[command(group = clap::ArgGroup::new("pattern").required(true).multiple(false))]
+struct InjectArgs {
+    #[command(flatten)]
+    input: DataArgs,
+
+    #[arg(long = "bit", group = "pattern", value_name = "POZYCJA", help = "Pozycja bitu do odwrócenia w słowie kodowym (można powtarzać)")]
+    bits: Vec<usize>,
+
+    #[arg(long, group = "pattern", value_name = "START:DŁ", help = "Paczka błędów: odwróć DŁ kolejnych bitów od pozycji START")]
+    burst: Option<String>,
+
+    #[arg(long, group = "pattern", value_name = "N", help = "Odwróć N losowych bitów")]
+    random: Option<usize>,
+
+    #[arg(long, default_value_t = 0, help = "Ziarno generatora losowego")]
+    seed: u64,
+}
+
+#[derive(clap::Args, Debug)]
+struct FlipsArgs {
+    #[command(flatten)]
+    input: DataArgs,
+
+    #[arg(long, help = "Sprawdź także wszystkie pary bitów (równolegle)")]
+    pairs: bool,
+
+    #[arg(long, default_value_t = 20, help = "Ile niewykrytych wzorców wypisać")]
+    show: usize,
+}
+
+#[derive(clap::Args, Debug)]
+struct AnalyzeArgs {
+    #[command(flatten)]
+    poly: PolyArgs,
+
+    #[arg(long, default_value_t = 96, help = "Długość wiadomości w bitach")]
+    bits: usize,
+
+    #[arg(long, help = "Maksymalna długość paczki błędów (domyślnie 2 × szerokość CRC)")]
+    max_burst: Option<usize>,
+
+    #[arg(long, default_value_t = 6, help = "Największa waga losowych wzorców błędów")]
+    max_weight: usize,
+
+    #[arg(long, default_value_t = 1_000_000, help = "Liczba próbek na wagę")]
+    samples: u64,
+
+    #[arg(long, default_value_t = 0, help = "Ziarno generatora losowego")]
+    seed: u64,
+
+    #[arg(long, default_value_t = 1e-4, help = "Bitowa stopa błędów kanału")]
+    ber: f64,
+}
+
+#[derive(clap::Args, Debug)]
+struct DistributionArgs {
+    #[command(flatten)]
+    poly: PolyArgs,
+
+    #[arg(long, default_value_t = 16, help = "Długość wiadomości w bitach")]
+    bits: usize,
+
+    #[arg(long, default_value_t = DEFAULT_SAMPLES, help = "Liczba wiadomości; gdy 2^bits jej nie przekracza, sprawdzane są wszystkie")]
+    samples: u64,
+
+    #[arg(long, default_value_t = DEFAULT_BUCKET_BITS, value_parser = clap::value_parser!(u8).range(1..=MAX_BUCKET_BITS as i64), help = "Liczba najstarszych bitów CRC wyznaczających kubełek (1-20, najwyżej szerokość CRC)")]
+    bucket_bits: u8,
+
+    #[arg(long, default_value_t = 0, help = "Ziarno generatora losowego")]
+    seed: u64,
+
+    #[arg(long, help = "Wypisz liczności wszystkich kubełków jako CSV zamiast podsumowania")]
+    csv: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct HammingArgs {
+    #[command(flatten)]
+    poly: PolyArgs,
+
+    #[arg(long, default_value_t = 96, help = "Długość wiadomości w bitach")]
+    bits: usize,
+
+    #[arg(long, help = "Przeanalizuj wszystkie długości od 1 do podanej i wypisz zakresy HD")]
+    up_to: Option<usize>,
+
+    #[arg(long, default_value_t = 8, help = "Największa sprawdzana waga błędu")]
+    max_weight: usize,
+
+    #[arg(long, default_value_t = 10_000_000, help = "Limit kombinacji dla sprawdzenia wyczerpującego (powyżej: próbkowanie)")]
+    budget: u64,
+
+    #[arg(long, default_value_t = 0, help = "Ziarno generatora losowego")]
+    seed: u64,
+}
+
+#[derive(clap::Args, Debug)]
+struct RecoverArgs {
+    #[arg(long = "pair", value_name = "DANE:CRC", help = "Wiadomość i jej CRC w hex, np. 0102A0:1B2C (można powtarzać)")]
+    pairs: Vec<String>,
+
+    #[arg(long, default_value_t = 16, help = "Szerokość szukanego CRC w bitach")]
+    width: u8,
+
+    #[arg(long, help = "Znany wielomian (pomija przeszukiwanie wielomianów)")]
+    poly: Option<String>,
+
+    #[arg(long, default_value_t = 20, help = "Maksymalna liczba wypisanych dopasowań (0 = wszystkie)")]
+    max_results: usize,
+}
+
+#[derive(clap::Args, Debug)]
+struct PreimageArgs {
+    #[command(flatten)]
+    poly: PolyArgs,
+
+    #[arg(long, required_unless_present = "collide", help = "Docelowa wartość CRC (hex)")]
+    target: Option<String>,
+
+    #[arg(long, conflicts_with = "target", help = "Szukaj innych wiadomości o tym samym CRC co podana")]
+    collide: Option<String>,
+
+    #[arg(long, help = "Stały początek wiadomości")]
+    prefix: Option<String>,
+
+    #[arg(short, long, value_enum, default_value = "hex", help = "Format prefiksu, wiadomości i wyników")]
+    format: InputFormat,
+
+    #[arg(long, value_enum, default_value = "msb", help = "Kolejność bitów w bajcie (msb - najstarszy pierwszy, lsb - najmłodszy pierwszy)")]
+    bit_order: BitOrderArg,
+
+    #[arg(long, help = "Długość wiadomości w bitach (domyślnie 64 lub długość --collide)")]
+    bits: Option<usize>,
+
+    #[arg(long, default_value_t = 5, help = "Liczba szukanych wiadomości")]
+    count: usize,
+
+    #[arg(long, default_value_t = 0, help = "Ziarno generatora losowego")]
+    seed: u64,
+}
+
+#[derive(clap::Args, Debug)]
+struct ManifestArgs {
+    #[command(subcommand)]
+    action: ManifestAction,
+}
+
+#[derive(Subcommand, Debug)]
+enum ManifestAction {
+    #[command(about = "Zapisz manifest z CRC podanych plików (algorytm z -a, domyślnie CRC-32/ISO-HDLC)")]
+    Create(ManifestCreateArgs),
+    #[command(about = "Sprawdź pliki z manifestu (algorytm z nagłówka manifestu; kod wyjścia 2 przy niezgodności, 3 przy brakującym pliku)")]
+    Verify(ManifestVerifyArgs),
+}
+
+#[derive(clap::Args, Debug)]
+struct ManifestCreateArgs {
+    #[arg(required = true, help = "Ścieżki plików lub wzorce glob")]
+    paths: Vec<PathBuf>,
+
+    #[arg(short, long, help = "Przetwarzaj katalogi rekurencyjnie")]
+    recursive: bool,
+
+    #[arg(short, long, value_name = "PLIK", help = "Zapisz manifest do pliku (ścieżki względem jego katalogu) zamiast na standardowe wyjście")]
+    output: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+struct ManifestVerifyArgs {
+    #[arg(help = "Plik manifestu")]
+    manifest: PathBuf,
+
+    #[arg(short, long, help = "Wypisz tylko niezgodne i brakujące pliki")]
+    quiet: bool,
+}
+
+fn find_algorithm(name: &str, lang: Lang) -> Result<&'static CrcAlgorithm, String> {
+    CrcCatalog::find(name).ok_or_else(|| {
+        if Checksum::find(name).is_some() {
+            return fill(lang.strings().err_checksum_unsupported, &[&name]);
+        }
+        let names: Vec<&str> = CrcCatalog::all().iter().map(|algorithm| algorithm.name).collect();
+        fill(lang.strings().err_unknown_algorithm, &[&name, &names.join(", ")])
+    })
+}
+
+fn line_error(line: usize, message: &str, lang: Lang) -> String {
+    fill(lang.strings().cli_line_error, &[&line, &message])
+}
+
+fn selected_algorithm(name: Option<&str>, lang: Lang) -> &'static CrcAlgorithm {
+    match name.map(|name| find_algorithm(name, lang)) {
+        Some(Ok(algorithm)) => algorithm,
+        Some(Err(e)) => {
+            eprintln!("{}", lang.error(&e));
+            std::process::exit(EXIT_PARSE_ERROR);
+        }
+        None => CrcCatalog::can(),
+    }
+}
+
+fn main() {
+    let args = parse_args().unwrap_or_else(|e| {
+        let _ = e.print();
+        std::process::exit(if e.use_stderr() { EXIT_PARSE_ERROR } else { 0 });
+    });
+    let lang = args.lang.as_deref()
+        .and_then(Lang::from_code)
+        .unwrap_or_else(Lang::from_env);
+    let proto = args.proto.map(Protocol::from).filter(|proto| *proto != Protocol::Generic);
+    if let (Some(proto), Some(name)) = (proto, &args.algorithm) {
+        eprintln!("{}", lang.error(&fill(lang.strings().cli_err_proto_algorithm, &[&proto, name])));
+        std::process::exit(EXIT_PARSE_ERROR);
+    }
+    let algorithm = args.algorithm.as_deref().or_else(|| proto.and_then(Protocol::algorithm_name));
+    if let (Some(name), Some(command)) = (&args.algorithm, args.command.as_ref().and_then(Command::fixed_crc)) {
+        eprintln!("{}", lang.error(&fill(lang.strings().cli_err_fixed_crc, &[&name, &command])));
+        std::process::exit(EXIT_PARSE_ERROR);
+    }
+    if args.cross_check && !args.command.as_ref().is_some_and(Command::supports_cross_check) {
+        eprintln!("{}", lang.error(lang.strings().cli_err_cross_check_command));
+        std::process::exit(EXIT_PARSE_ERROR);
+    }
+    init_logging(args.log_level, args.log_file.as_deref(), lang);
+
+    #[cfg(feature = "parallel")]
+    if let Some(threads) = args.threads {
+        if let Err(e) = rayon::ThreadPoolBuilder::new().num_threads(threads.get()).build_global() {
+            eprintln!("{}", lang.error(&fill(lang.strings().cli_err_threads, &[&e])));
+            std::process::exit(EXIT_PARSE_ERROR);
+        }
+    }
+
+    #[cfg(feature = "test-vectors")]
+    if args.self_test {
+        run_self_test(lang);
+        return;
+    }
+
+    match &args.command {
+        Some(Command::Calc(calc_args)) => run_calc(calc_args, algorithm, proto, args.verbose, args.cross_check, lang),
+        Some(Command::Verify(verify_args)) => run_verify(verify_args, algorithm, proto, args.cross_check, lang),
+        Some(Command::CheckFrame(check_args)) => run_check_frame(check_args, algorithm, lang),
+        Some(Command::Bench(bench_args)) => run_bench(bench_args, algorithm, args.verbose, args.cross_check, lang),
+        Some(Command::Batch(batch_args)) => run_batch(batch_args, algorithm, proto, args.cross_check, lang),
+        Some(Command::Table(table_args)) => run_table(table_args, algorithm, lang),
+        Some(Command::Frame(frame_args)) => run_frame(frame_args, lang),
+        Some(Command::Listen(listen_args)) => run_listen(listen_args, lang),
+        #[cfg(feature = "tui")]
+        Some(Command::Tui(tui_args)) => run_tui(tui_args, algorithm, lang),
+        Some(Command::Replay(replay_args)) => run_replay(replay_args, args.verbose, lang),
+        Some(Command::Gen(gen_args)) => run_gen(gen_args, args.verbose, lang),
+        Some(Command::GenData(gen_args)) => run_gen_data(gen_args, algorithm, lang),
+        #[cfg(all(feature = "serial", unix))]
+        Some(Command::SlaveSim(slave_args)) => run_slave_sim(slave_args, lang),
+        Some(Command::Poll(poll_args)) => run_poll(poll_args, lang),
+        Some(Command::Serve(serve_args)) => run_serve(serve_args, algorithm, lang),
+        #[cfg(feature = "serde")]
+        Some(Command::RawListen(raw_args)) => run_raw_listen(raw_args, algorithm, lang),
+        Some(Command::Udp(udp_args)) => run_udp(udp_args, algorithm, lang),
+        Some(Command::Repl) => run_interactive(algorithm, args.verbose, args.cross_check, lang),
+        Some(Command::Algorithms) => run_algorithms(lang),
+        Some(Command::E2e(e2e_args)) => run_e2e(e2e_args, lang),
+        Some(Command::File(file_args)) => run_file(file_args, algorithm, args.verbose, lang),
+        Some(Command::Manifest(manifest_args)) => match &manifest_args.action {
+            ManifestAction::Create(create_args) => run_manifest_create(create_args, algorithm, lang),
+            ManifestAction::Verify(verify_args) => run_manifest_verify(verify_args, lang),
+        },
+        Some(Command::Hdl(hdl_args)) => run_hdl(hdl_args, algorithm, lang),
+        Some(Command::Dbc(dbc_args)) => run_dbc(dbc_args, lang),
+        Some(Command::Inject(inject_args)) => run_inject(inject_args, algorithm, lang),
+        Some(Command::Flips(flips_args)) => run_flips(flips_args, algorithm, lang),
+        Some(Command::Analyze(analyze_args)) => run_analyze(analyze_args, algorithm, lang),
+        Some(Command::Distribution(distribution_args)) => run_distribution(distribution_args, algorithm, lang),
+        Some(Command::Hamming(hamming_args)) => run_hamming(hamming_args, algorithm, lang),
+        Some(Command::Recover(recover_args)) => run_recover(recover_args, lang),
+        Some(Command::Preimage(preimage_args)) => run_preimage(preimage_args, algorithm, lang),
+        None => {
+            let _ = Args::command().print_help();
+            std::process::exit(EXIT_PARSE_ERROR);
+        }
+    }
+}
+
+fn init_logging(level: LogLevel, path: Option<&Path>, lang: Lang) {
+    let builder = tracing_subscriber::fmt()
+        .with_max_level(LevelFilter::from(level))
+        .with_span_events(FmtSpan::CLOSE);
+    match path {
+        Some(path) => {
+            let file = std::fs::OpenOptions::new().create(true).append(true).open(path).unwrap_or_else(|e| {
+                eprintln!("{}", lang.error(&fill(lang.strings().cli_err_log_file, &[&path.display(), &e])));
+                std::process::exit(EXIT_IO_ERROR);
+            });
+            builder.with_ansi(false).with_writer(Mutex::new(file)).init();
+        }
+        None => builder.with_writer(io::stderr).init(),
+    }
+}
+
+#[cfg(not(feature = "config"))]
+fn parse_args() -> Result<Args, clap::Error> {
+    let command = Args::command();
+    <Args as clap::FromArgMatches>::from_arg_matches(&command.clone().try_get_matches_from(one_shot_args(&command, std::env::args_os()))?)
+}
+
+#[cfg(feature = "config")]
+fn parse_args() -> Result<Args, clap::Error> {
+    let fallback_lang = Lang::from_env();
+    let settings = Settings::load_default().unwrap_or_else(|e| {
+        eprintln!("{}", fallback_lang.error(&e.describe(fallback_lang)));
+        std::process::exit(EXIT_PARSE_ERROR);
+    });
+    let lang = settings.lang.as_deref().and_then(Lang::from_code).unwrap_or(fallback_lang);
+    let command = apply_settings(Args::command(), &settings).unwrap_or_else(|(id, value, allowed)| {
+        eprintln!("{}", lang.error(&fill(lang.strings().cli_err_settings_value, &[&value, &id, &allowed])));
+        std::process::exit(EXIT_PARSE_ERROR);
+    });
+
+    let args = one_shot_args(&command, std::env::args_os());
+    <Args as clap::FromArgMatches>::from_arg_matches(&command.try_get_matches_from(args)?)
+}
+
+fn one_shot_args(command: &clap::Command, args: impl IntoIterator<Item = OsString>) -> Vec<OsString> {
+    let mut args: Vec<OsString> = args.into_iter().collect();
+    let Some(calc) = command.find_subcommand("calc") else {
+        return args;
+    };
+    let find = |token: &str| {
+        let matches = |arg: &&clap::Arg| match token.strip_prefix("--") {
+            Some(long) => arg.get_long() == Some(long.split('=').next().unwrap_or(long)),
+            None => token.chars().nth(1).is_some_and(|short| arg.get_short() == Some(short)),
+        };
+        command.get_arguments().find(matches).map(|arg| (arg, false))
+            .or_else(|| calc.get_arguments().find(matches).map(|arg| (arg, true)))
+    };
+
+    let mut calc_option = false;
+    let mut tokens = args.iter().skip(1);
+    while let Some(token) = tokens.next() {
+        let token = token.to_string_lossy();
+        if token == "--" || !token.starts_with('-') {
+            return args;
+        }
+        if let Some((arg, calc_only)) = find(&token) {
+            calc_option |= calc_only;
+            let inline = token.contains('=') || (!token.starts_with("--") && token.len() > 2);
+            if arg.get_action().takes_values() && !inline {
+                tokens.next();
+            }
+        }
+    }
+    if calc_option {
+        args.insert(1.min(args.len()), OsString::from("calc"));
+    }
+    args
+}
+
+#[cfg(feature = "config")]
+fn apply_settings(command: clap::Command, settings: &Settings) -> Result<clap::Command, (String, String, String)> {
+    let mut command = command;
+    let port = settings.serial.port.as_ref().map(|port| port.to_string_lossy().into_owned());
+    let threads = settings.threads.filter(|_| cfg!(feature = "parallel")).map(|threads| threads.to_string());
+    let defaults = [
+        ("lang", None, &settings.lang),
+        ("algorithm", None, &settings.algorithm),
+        ("format", None, &settings.format),
+        ("radix", None, &settings.radix),
+        ("input", Some("listen"), &port),
+        ("threads", None, &threads),
+    ];
+    for (id, subcommand, value) in defaults {
+        let Some(value) = value else {
+            continue;
+        };
+        let mut allowed = Vec::new();
+        let (updated, accepted) = with_default(command, id, subcommand, value.clone().leak(), &mut allowed);
+        if !accepted {
+            return Err((id.to_string(), value.clone(), allowed.join(", ")));
+        }
+        command = updated;
+    }
+    Ok(command)
+}
+
+#[cfg(feature = "config")]
+fn with_default(
+    command: clap::Command,
+    id: &str,
+    subcommand: Option<&str>,
+    value: &'static str,
+    allowed: &mut Vec<String>,
+) -> (clap::Command, bool) {
+    let mut command = command;
+    let mut accepted = false;
+    let names: Vec<String> = command.get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .filter(|name| subcommand.is_none_or(|wanted| wanted == name))
+        .collect();
+    for name in names {
+        command = command.mut_subcommand(&name, |sub| {
+            let (sub, sub_accepted) = with_default(sub, id, None, value, allowed);
+            accepted |= sub_accepted;
+            sub
+        });
+    }
+    if subcommand.is_some() {
+        return (command, accepted);
+    }
+
+    let possible = command.get_arguments()
+        .find(|arg| arg.get_id() == id)
+        .map(|arg| arg.get_possible_values());
+    if let Some(possible) = possible {
+        if possible.is_empty() || possible.iter().any(|candidate| candidate.matches(value, true)) {
+            command = command.mut_arg(id, |arg| arg.default_value(value));
+            accepted = true;
+        } else if allowed.is_empty() {
+            allowed.extend(possible.iter().map(|candidate| candidate.get_name().to_string()));
+        }
+    }
+    (command, accepted)
+}
+
+fn run_calc(calc_args: &CalcArgs, algorithm: Option<&str>, proto: Option<Protocol>, verbose: bool, cross_check: bool, lang: Lang) {
+    if let Some(checksum) = algorithm.and_then(Checksum::find) {
+        if let Err(e) = calculate_checksum(calc_args, checksum, proto, lang) {
+            eprintln!("{}", lang.error(&e));
+            std::process::exit(EXIT_PARSE_ERROR);
+        }
+        return;
+    }
+    let algorithm = selected_algorithm(algorithm, lang);
+    #[cfg(feature = "watch")]
+    if let Some(path) = &calc_args.watch {
+        watch_calc(calc_args, path, algorithm, proto, verbose, cross_check, lang);
+    }
+    if let Err(e) = calculate(calc_args, &calc_args.input, algorithm, proto, verbose, cross_check, lang) {
+        eprintln!("{}", lang.error(&e));
+        std::process::exit(EXIT_PARSE_ERROR);
+    }
+}
+
+#[cfg(feature = "watch")]
+fn watch_calc(calc_args: &CalcArgs, path: &Path, algorithm: &'static CrcAlgorithm, proto: Option<Protocol>, verbose: bool, cross_check: bool, lang: Lang) -> ! {
+    let t = lang.strings();
+    let watcher = FileWatcher::new(path).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&fill(t.cli_watch_failed, &[&path.display(), &e])));
+        std::process::exit(EXIT_IO_ERROR);
+    });
+    eprintln!("{}", fill(t.cli_watch_started, &[&path.display()]));
+    loop {
+        match std::fs::read_to_string(watcher.path()) {
+            Ok(data) => {
+                let input = DataArgs { data: data.trim().to_string(), ..calc_args.input.clone() };
+                if let Err(e) = calculate(calc_args, &input, algorithm, proto, verbose, cross_check, lang) {
+                    eprintln!("{}", lang.error(&e));
+                }
+            }
+            Err(e) => eprintln!("{}", lang.error(&fill(t.err_read_file, &[&path.display(), &e]))),
+        }
+        if let Err(e) = watcher.wait() {
+            eprintln!("{}", lang.error(&fill(t.cli_watch_failed, &[&path.display(), &e])));
+            std::process::exit(EXIT_IO_ERROR);
+        }
+        eprintln!("{}", fill(t.cli_watch_changed, &[&path.display()]));
+    }
+}
+
+fn calculate(calc_args: &CalcArgs, input: &DataArgs, algorithm: &CrcAlgorithm, proto: Option<Protocol>, verbose: bool, cross_check: bool, lang: Lang) -> Result<(), String> {
+    let bits = input.parse_bits(&algorithm.config, lang)?;
+    let algorithm = proto.and_then(|proto| proto.algorithm(bits.len())).unwrap_or(algorithm);
+    let framed = proto.map(|proto| proto.frame(&bits).map_err(|e| lang.describe(&e))).transpose()?.flatten();
+    if cross_check {
+        let check = cross_check_or_exit(&algorithm.config, &bits, lang);
+        if !calc_args.quiet {
+            print_cross_check(&check, lang);
+        }
+    }
+
+    if let (true, None, Some(framed)) = (calc_args.emit_frame, calc_args.crc_endianness, &framed) {
+        println!("{}", framed);
+        return Ok(());
+    }
+    if calc_args.emit_frame {
+        let endianness = calc_args.crc_endianness.map_or_else(|| Endianness::for_config(&algorithm.config), Endianness::from);
+        let frame = emit_frame(&algorithm.config, &bits, endianness);
+        println!("{}", format_message(&input.format, &frame, input.bit_order.into()));
+        return Ok(());
+    }
+
+    if calc_args.explain {
+        print_trace(&algorithm.config, &bits, lang);
+    }
+
+    if !calc_args.quiet {
+        let result = print_calculation(algorithm, &input.format, &input.data, &bits, &BenchPlan::single(1), verbose, lang);
+        if let (Some(proto), Some(framed)) = (proto, &framed) {
+            println!("{}", fill(lang.strings().cli_proto_frame, &[&proto, framed]));
+        }
+        write_report(calc_args.report.as_deref(), algorithm, input, bits.len(), 1, &result, lang);
+        return Ok(());
+    }
+
+    let start = Instant::now();
+    let crc_value = compute_batch_checksums(&algorithm.config, &bits, 1);
+    let result = CrcResult::for_algorithm(algorithm, crc_value, start.elapsed().as_secs_f64() * 1000.0).with_input(bits.len(), 1);
+    match calc_args.radix {
+        Radix::Hex => println!("{}", result.crc_hex),
+        Radix::Dec => println!("{}", result.crc_value),
+        Radix::Bin => println!("{}", result.crc_bin()),
+    }
+    write_report(calc_args.report.as_deref(), algorithm, input, bits.len(), 1, &result, lang);
+    Ok(())
+}
+
+fn calculate_checksum(calc_args: &CalcArgs, checksum: Checksum, proto: Option<Protocol>, lang: Lang) -> Result<(), String> {
+    let t = lang.strings();
+    let input = &calc_args.input;
+    let options = ParseOptions { max_bits: None, bit_order: input.bit_order.into() };
+    let bits = input.format.parse_described(&input.data, &options, lang)?;
+    let value = checksum.compute_bits(&bits).map_err(|e| lang.describe(&e))?;
+    let digits = (checksum.width() as usize).div_ceil(4);
+    let framed = proto.map(|proto| proto.frame(&bits).map_err(|e| lang.describe(&e))).transpose()?.flatten();
+
+    if calc_args.emit_frame {
+        match framed {
+            Some(framed) => println!("{}", framed),
+            None => {
+                let mut bytes = bits_to_bytes(&bits);
+                bytes.extend_from_slice(&value.to_be_bytes()[8 - checksum.field_len()..]);
+                println!("{}", format_message(&input.format, &bytes_to_bits(&bytes), input.bit_order.into()));
+            }
+        }
+        return Ok(());
+    }
+    if calc_args.quiet {
+        match calc_args.radix {
+            Radix::Hex => println!("{:0digits$X}", value),
+            Radix::Dec => println!("{}", value),
+            Radix::Bin => println!("{:0width$b}", value, width = checksum.width() as usize),
+        }
+        return Ok(());
+    }
+    println!("{}", fill(t.cli_checksum_result, &[&checksum, &format!("{:0digits$X}", value), &bits.len()]));
+    if let (Some(proto), Some(framed)) = (proto, &framed) {
+        println!("{}", fill(t.cli_proto_frame, &[&proto, framed]));
+    }
+    Ok(())
+}
+
+fn run_verify(verify_args: &VerifyArgs, algorithm: Option<&str>, proto: Option<Protocol>, cross_check: bool, lang: Lang) {
+    let t = lang.strings();
+    let fail = |message: String| -> ! {
+        eprintln!("{}", lang.error(&message));
+        std::process::exit(EXIT_PARSE_ERROR);
+    };
+
+    if let Some(checksum) = algorithm.and_then(Checksum::find) {
+        return verify_checksum(verify_args, checksum, lang);
+    }
+    let algorithm = selected_algorithm(algorithm, lang);
+    let config = &algorithm.config;
+
+    let Some(crc) = &verify_args.crc else {
+        let input = &verify_args.input;
+        let limit = ParseOptions::for_config(config).max_bits.map(|bits| bits + config.width as usize);
+        let options = ParseOptions { max_bits: limit, bit_order: input.bit_order.into() };
+        let codeword = input.format.parse_described(&input.data, &options, lang).unwrap_or_else(|e| fail(e));
+        let config = &proto.and_then(|proto| proto.algorithm(codeword.len().saturating_sub(17))).unwrap_or(algorithm).config;
+        let digits = (config.width as usize).div_ceil(4);
+        let actual = config.codeword_residue(&codeword);
+        let expected = config.residue();
+        if actual == expected {
+            println!("{}", fill(t.cli_verify_residue_match, &[&format!("{:0digits$X}", actual)]));
+        } else {
+            println!("{}", fill(t.cli_verify_residue_mismatch, &[&format!("{:0digits$X}", actual), &format!("{:0digits$X}", expected)]));
+            std::process::exit(EXIT_MISMATCH);
+        }
+        return;
+    };
+
+    let bits = verify_args.input.parse_bits(config, lang).unwrap_or_else(|e| fail(e));
+    let config = &proto.and_then(|proto| proto.algorithm(bits.len())).unwrap_or(algorithm).config;
+    let digits = (config.width as usize).div_ceil(4);
+    let expected = parse_param(&format!("0x{}", crc.trim().trim_start_matches("0x")))
+        .unwrap_or_else(|e| fail(lang.describe_config(&e)));
+    if cross_check {
+        print_cross_check(&cross_check_or_exit(config, &bits, lang), lang);
+    }
+    let actual = compute_batch_checksums(config, &bits, 1);
+
+    if actual == expected {
+        println!("{}", fill(t.cli_verify_match, &[&format!("{:0digits$X}", actual)]));
+    } else {
+        println!("{}", fill(t.cli_verify_mismatch, &[&format!("{:0digits$X}", actual), &format!("{:0digits$X}", expected)]));
+        std::process::exit(EXIT_MISMATCH);
+    }
+}
+
+fn verify_checksum(verify_args: &VerifyArgs, checksum: Checksum, lang: Lang) {
+    let t = lang.strings();
+    let fail = |message: String| -> ! {
+        eprintln!("{}", lang.error(&message));
+        std::process::exit(EXIT_PARSE_ERROR);
+    };
+    let digits = (checksum.width() as usize).div_ceil(4);
+    let input = &verify_args.input;
+    let options = ParseOptions { max_bits: None, bit_order: input.bit_order.into() };
+    let bits = input.format.parse_described(&input.data, &options, lang).unwrap_or_else(|e| fail(e));
+    let bytes = bits_to_bytes_with(&bits, BitPadding::Reject).unwrap_or_else(|e| fail(lang.describe(&e)));
+
+    let (actual, expected) = match &verify_args.crc {
+        Some(crc) => {
+            let expected = parse_param(&format!("0x{}", crc.trim().trim_start_matches("0x")))
+                .unwrap_or_else(|e| fail(lang.describe_config(&e)));
+            (checksum.compute(&bytes), expected)
+        }
+        None => checksum.check_codeword(&bytes)
+            .unwrap_or_else(|| fail(lang.describe_frame(&FrameError::TooShort { len: bytes.len(), min_len: checksum.field_len() }))),
+    };
+    if actual == expected {
+        println!("{}", fill(t.cli_verify_match, &[&format!("{:0digits$X}", actual)]));
+    } else {
+        println!("{}", fill(t.cli_verify_mismatch, &[&format!("{:0digits$X}", actual), &format!("{:0digits$X}", expected)]));
+        std::process::exit(EXIT_MISMATCH);
+    }
+}
+
+fn run_check_frame(check_args: &CheckFrameArgs, algorithm: Option<&str>, lang: Lang) {
+    let t = lang.strings();
+    let algorithm = selected_algorithm(algorithm, lang);
+    let config = &algorithm.config;
+    let endianness = check_args.crc_endianness.map_or_else(|| Endianness::for_config(config), Endianness::from);
+    let field_len = config.crc_field_len(endianness);
+    let input = &check_args.input;
+    let limit = ParseOptions::for_config(config).max_bits
+        .map(|bits| bits + field_len)
+        .map(|bits| if check_args.stuffed { bits + bits / (STUFF_RUN - 1) } else { bits })
+        .map(|bits| bits + usize::from(check_args.delimiter));
+    let options = ParseOptions { max_bits: limit, bit_order: input.bit_order.into() };
+    let mut frame = input.format.parse_described(&input.data, &options, lang).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&e));
+        std::process::exit(EXIT_PARSE_ERROR);
+    });
+    if check_args.stuffed {
+        let stuffed_len = frame.len() - usize::from(check_args.delimiter);
+        let destuffed = destuff_bits(&frame[..stuffed_len]);
+        println!("{}", fill(t.cli_check_frame_destuffed, &[&stuffed_len, &destuffed.stuff_positions.len()]));
+        if !destuffed.is_valid() {
+            for error in &destuffed.errors {
+                println!("{}", fill(t.cli_check_frame_stuff_error, &[&error.position, &u8::from(error.value)]));
+            }
+            println!("{}", fill(t.cli_check_frame_stuff_fail, &[&destuffed.errors.len()]));
+            std::process::exit(EXIT_MISMATCH);
+        }
+        frame.splice(..stuffed_len, destuffed.bits);
+    }
+    let check = check_crc_field(config, &frame, endianness, check_args.delimiter).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&lang.describe_frame(&e)));
+        std::process::exit(EXIT_PARSE_ERROR);
+    });
+
+    let digits = (config.width as usize).div_ceil(4);
+    let width = config.width as usize;
+    let data_bits = frame.len() - field_len - usize::from(check_args.delimiter);
+    println!("{}", fill(t.cli_check_frame_header, &[&data_bits, &field_len, &algorithm.name]));
+    println!("{}0x{:0digits$X}  {:0width$b}", t.cli_check_frame_received, check.received, check.received);
+    println!("{}0x{:0digits$X}  {:0width$b}", t.cli_check_frame_computed, check.computed, check.computed);
+    if check.passed() {
+        println!("{}", t.cli_check_frame_pass);
+        return;
+    }
+
+    let differing = check.differing_bits();
+    let indent = t.cli_check_frame_computed.chars().count() + 2 + digits + 2;
+    let markers: String = (0..config.width).rev().map(|bit| if differing.contains(&bit) { '^' } else { ' ' }).collect();
+    println!("{:indent$}{}", "", markers.trim_end());
+    println!("{}", fill(t.cli_check_frame_fail, &[&differing.len()]));
+    let list = differing.iter().map(u8::to_string).collect::<Vec<_>>().join(", ");
+    println!("{}", fill(t.cli_check_frame_bits, &[&(config.width - 1), &list]));
+    std::process::exit(EXIT_MISMATCH);
+}
+
+fn run_bench(bench_args: &BenchArgs, algorithm: Option<&str>, verbose: bool, cross_check: bool, lang: Lang) {
+    let algorithm = selected_algorithm(algorithm, lang);
+    if bench_args.sweep {
+        run_sweep(algorithm, bench_args.sweep_format, Duration::from_millis(bench_args.sweep_time), lang);
+        return;
+    }
+    #[cfg(feature = "gpu")]
+    if bench_args.gpu {
+        run_gpu_bench(algorithm, bench_args.messages, bench_args.message_len, lang);
+        return;
+    }
+    let Some(input) = &bench_args.input else {
+        eprintln!("{}", lang.error(lang.strings().cli_err_bench_data));
+        std::process::exit(EXIT_PARSE_ERROR);
+    };
+    let bits = input.parse_bits(&algorithm.config, lang).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&e));
+        std::process::exit(EXIT_PARSE_ERROR);
+    });
+    if cross_check {
+        print_cross_check(&cross_check_or_exit(&algorithm.config, &bits, lang), lang);
+    }
+    let plan = BenchPlan { iterations: bench_args.iterations, warmup: bench_args.warmup, repetitions: bench_args.repeat };
+    #[cfg(feature = "parallel")]
+    if bench_args.scaling {
+        run_scaling(algorithm, &bits, &plan, lang);
+        return;
+    }
+    let result = print_calculation(algorithm, &input.format, &input.data, &bits, &plan, verbose, lang);
+    write_report(bench_args.report.as_deref(), algorithm, input, bits.len(), bench_args.iterations, &result, lang);
+}
+
+#[cfg(feature = "parallel")]
+fn run_scaling(algorithm: &CrcAlgorithm, bits: &[bool], plan: &BenchPlan, lang: Lang) {
+    let t = lang.strings();
+    println!("{}", fill(t.cli_scaling_header, &[&algorithm.name, &bits.len(), &format_number(plan.iterations), &plan.repetitions]));
+    if plan.iterations < PARALLEL_THRESHOLD {
+        println!("{}", fill(t.cli_scaling_below_threshold, &[&format_number(PARALLEL_THRESHOLD)]));
+    }
+    println!("{:>8}  {:>14}  {:>14}  {:>10}", t.cli_scaling_threads, t.cli_scaling_median, t.cli_sweep_speedup, t.cli_scaling_efficiency);
+
+    let counts = thread_counts(current_num_threads());
+    let points = measure_scaling_with_progress(&algorithm.config, bits, plan, &counts, |point| {
+        println!("{:>8}  {:>14.3}  {:>13.2}×  {:>9.0}%", point.threads, point.timing.median_ms, point.speedup, point.efficiency() * 100.0);
+        let _ = io::stdout().flush();
+    })
+    .unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&fill(t.cli_err_threads, &[&e])));
+        std::process::exit(EXIT_IO_ERROR);
+    });
+
+    match best_point(&points) {
+        Some(best) if best.speedup >= MIN_USEFUL_SPEEDUP => println!(
+            "\n{}",
+            fill(t.cli_scaling_best, &[&best.threads, &format!("{:.2}", best.speedup), &format!("{:.0}", best.efficiency() * 100.0)])
+        ),
+        _ => println!("\n{}", fill(t.cli_scaling_no_gain, &[&MIN_USEFUL_SPEEDUP])),
+    }
+}
+
+#[cfg(feature = "gpu")]
+fn run_gpu_bench(algorithm: &CrcAlgorithm, messages: usize, message_len: usize, lang: Lang) {
+    let t = lang.strings();
+    let fail = |e: GpuError| -> ! {
+        eprintln!("{}", lang.error(&lang.describe_gpu(&e)));
+        std::process::exit(EXIT_IO_ERROR);
+    };
+    println!("{}", fill(t.cli_gpu_header, &[&algorithm.name, &format_number(messages as u64), &message_len]));
+    let gpu = GpuCrc::new().unwrap_or_else(|e| fail(e));
+    println!("{}", fill(t.cli_gpu_adapter, &[&gpu.adapter_name()]));
+    let batch = random_batch(messages, message_len, messages as u64);
+    let comparison = compare(&gpu, &algorithm.config, &batch, message_len).unwrap_or_else(|e| fail(e));
+
+    let rate = |per_second: f64| format_number(per_second as u64);
+    println!("{}", fill(t.cli_gpu_cpu, &[&current_num_threads(), &format!("{:.3}", comparison.cpu_ms), &rate(comparison.cpu_messages_per_second())]));
+    println!("{}", fill(t.cli_gpu_gpu, &[&format!("{:.3}", comparison.gpu_ms), &rate(comparison.gpu_messages_per_second())]));
+    println!("{}", fill(t.cli_gpu_speedup, &[&format!("{:.2}", comparison.speedup())]));
+    if !comparison.matches {
+        eprintln!("{}", t.cli_gpu_mismatch);
+        std::process::exit(EXIT_CROSS_CHECK);
+    }
+}
+
+fn run_sweep(algorithm: &CrcAlgorithm, format: SweepFormat, point_time: Duration, lang: Lang) {
+    let t = lang.strings();
+    let config = &algorithm.config;
+    let table = config.table();
+    match format {
+        SweepFormat::Table => {
+            println!("{}", fill(t.cli_sweep_header, &[&algorithm.name, &point_time.as_millis()]));
+            println!("{:>8}  {:>18}  {:>18}  {:>14}", t.cli_sweep_bits, t.cli_sweep_bitwise, t.cli_sweep_table, t.cli_sweep_speedup);
+        }
+        SweepFormat::Csv => println!("bits,bitwise_crc_per_s,table_crc_per_s,speedup"),
+        SweepFormat::Gnuplot => println!("$sweep << EOD"),
+    }
+
+    let mut points = Vec::new();
+    for bits in sweep_lengths(algorithm.max_input_bits) {
+        let point = measure_point(config, &table, bits, point_time);
+        match format {
+            SweepFormat::Table => println!(
+                "{:>8}  {:>18}  {:>18}  {:>13.2}×",
+                point.bits, format_number(point.bitwise as u64), format_number(point.table as u64), point.speedup()
+            ),
+            SweepFormat::Csv => println!("{},{:.0},{:.0},{:.3}", point.bits, point.bitwise, point.table, point.speedup()),
+            SweepFormat::Gnuplot => println!("{} {:.0} {:.0}", point.bits, point.bitwise, point.table),
+        }
+        let _ = io::stdout().flush();
+        points.push(point);
+    }
+
+    let crossover = crossover(&points);
+    match format {
+        SweepFormat::Table => match crossover {
+            Some(bits) => println!("\n{}", fill(t.cli_sweep_crossover, &[&bits])),
+            None => println!("\n{}", t.cli_sweep_no_crossover),
+        },
+        SweepFormat::Csv => {}
+        SweepFormat::Gnuplot => {
+            println!("EOD");
+            println!("set title \"{}\"", algorithm.name);
+            println!("set xlabel \"bits\"\nset ylabel \"CRC/s\"\nset logscale x 2\nset logscale y\nset grid");
+            if let Some(bits) = crossover {
+                println!("set arrow from {}, graph 0 to {}, graph 1 nohead dashtype 2", bits, bits);
+            }
+            println!("plot $sweep using 1:2 with linespoints title \"bitwise\", $sweep using 1:3 with linespoints title \"table\"");
+        }
+    }
+}
+
+fn write_report(
+    path: Option<&Path>,
+    algorithm: &CrcAlgorithm,
+    input: &DataArgs,
+    bits: usize,
+    iterations: u64,
+    result: &CrcResult,
+    lang: Lang,
+) {
+    let Some(path) = path else {
+        return;
+    };
+    let report = CalculationReport {
+        algorithm,
+        input_format: &format!("{:?}", input.format),
+        input: &input.data,
+        bits,
+        iterations,
+        result,
+        environment: Environment::current(),
+    };
+    let t = lang.strings();
+    match std::fs::write(path, report.render(ReportFormat::from_path(path), lang)) {
+        Ok(()) => eprintln!("{}", fill(t.cli_report_written, &[&path.display()])),
+        Err(e) => {
+            eprintln!("{}", lang.error(&fill(t.cli_err_write_report, &[&path.display(), &e])));
+            std::process::exit(EXIT_IO_ERROR);
+        }
+    }
+}
+
+fn open_input(path: &Option<PathBuf>, lang: Lang) -> Box<dyn BufRead> {
+    let Some(path) = path else {
+        return Box::new(io::stdin().lock());
+    };
+    match std::fs::File::open(path) {
+        Ok(file) => Box::new(BufReader::new(file)),
+        Err(e) => {
+            eprintln!("{}", lang.error(&fill(lang.strings().err_read_file, &[&path.display(), &e])));
+            std::process::exit(EXIT_IO_ERROR);
+        }
+    }
+}
+
+fn run_batch(batch_args: &BatchArgs, algorithm: Option<&str>, proto: Option<Protocol>, cross_check: bool, lang: Lang) {
+    if let Some(checksum) = algorithm.and_then(Checksum::find) {
+        let options = ParseOptions { max_bits: None, bit_order: batch_args.bit_order.into() };
+        return batch_lines(batch_args, &options, checksum.width(), |bits| checksum.compute_bits(bits), lang);
+    }
+    let algorithm = selected_algorithm(algorithm, lang);
+    let config = &algorithm.config;
+    let options = ParseOptions { bit_order: batch_args.bit_order.into(), ..ParseOptions::for_config(config) };
+    batch_lines(batch_args, &options, config.width, |bits| {
+        let config = &proto.and_then(|proto| proto.algorithm(bits.len())).unwrap_or(algorithm).config;
+        if cross_check {
+            cross_check_or_exit(config, bits, lang);
+        }
+        Ok(compute_batch_checksums(config, bits, 1))
+    }, lang);
+}
+
+fn batch_lines(batch_args: &BatchArgs, options: &ParseOptions, width: u8, compute: impl Fn(&[bool]) -> Result<u64, ParseError>, lang: Lang) {
+    let digits = (width as usize).div_ceil(4);
+    if batch_args.csv {
+        println!("line,input,bits,crc_hex,crc_dec,error");
+    }
+    let mut exit_code = 0;
+    for (index, line) in open_input(&batch_args.input, lang).lines().enumerate() {
+        let line = line.unwrap_or_else(|e| {
+            eprintln!("{}", lang.error(&e.to_string()));
+            std::process::exit(EXIT_IO_ERROR);
+        });
+        let message = line.trim();
+        if message.is_empty() {
+            continue;
+        }
+
+        match batch_args.format.parse(message, options).and_then(|bits| Ok((bits.len(), compute(&bits)?))) {
+            Ok((bits, crc)) => {
+                if batch_args.csv {
+                    println!("{},{},{},{:0digits$X},{},", index + 1, csv_field(message), bits, crc, crc);
+                } else {
+                    println!("{:0digits$X}  {}", crc, message);
+                }
+            }
+            Err(e) => {
+                if batch_args.csv {
+                    println!("{},{},,,,{}", index + 1, csv_field(message), csv_field(&e.to_string()));
+                }
+                eprintln!("{}", line_error(index + 1, &batch_args.format.describe(message, &e, lang), lang));
+                exit_code = EXIT_PARSE_ERROR;
+            }
+        }
+    }
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn run_frame(frame_args: &FrameArgs, lang: Lang) {
+    let t = lang.strings();
+    let fail = |message: String| -> ! {
+        eprintln!("{}", lang.error(&message));
+        std::process::exit(EXIT_PARSE_ERROR);
+    };
+
+    let id = parse_param(&format!("0x{}", frame_args.id.trim().trim_start_matches("0x")))
+        .and_then(|id| u32::try_from(id).map_err(|_| ConfigError::InvalidNumber { value: frame_args.id.clone() }))
+        .unwrap_or_else(|e| fail(lang.describe_config(&e)));
+    let data = frame_args.data.as_deref()
+        .map(|data| parse_hex_bytes(data).unwrap_or_else(|e| fail(InputFormat::Hex.describe(data, &e, lang))))
+        .unwrap_or_default();
+    if frame_args.fd {
+        let mut frame = CanFdFrame::new(id, frame_args.extended, &data).unwrap_or_else(|e| fail(lang.describe_frame(&e)));
+        frame.brs = frame_args.brs;
+        print_fd_frame(&frame, frame_args, lang);
+        return;
+    }
+    let frame = if frame_args.extended { CanFrame::new_extended(id, &data) } else { CanFrame::new(id, &data) };
+    let mut frame = frame.unwrap_or_else(|e| fail(lang.describe_frame(&e)));
+    frame.rtr = frame_args.rtr;
+
+    let kind = if frame.extended { t.cli_frame_extended } else { t.cli_frame_standard };
+    println!("{}", fill(t.cli_frame_id, &[&format!("{:X}", frame.id), &kind]));
+    if frame_args.j1939 {
+        println!("{}", fill(t.cli_frame_j1939, &[&lang.describe_j1939(&J1939Id::from_can_id(frame.id))]));
+    }
+    println!("{}", fill(t.cli_frame_payload, &[&format_hex(&frame.data)]));
+    println!("{}", fill(t.cli_frame_crc_bits, &[&frame.crc_bits().len()]));
+    println!("{}", fill(t.cli_frame_crc, &[&format!("{:04X}", frame.crc())]));
+
+    if frame_args.wire {
+        let wire = frame.wire_bits();
+        let stuffed = wire.iter().filter(|bit| bit.stuff).count();
+        println!("{}", fill(t.cli_frame_wire, &[&format_wire_bits(&wire)]));
+        println!("{}", fill(t.cli_frame_wire_summary, &[&wire.len(), &stuffed]));
+    }
+}
+
+fn print_fd_frame(frame: &CanFdFrame, frame_args: &FrameArgs, lang: Lang) {
+    let t = lang.strings();
+    let algorithm = frame.crc_algorithm();
+    let digits = (algorithm.config.width as usize).div_ceil(4);
+    let kind = if frame.extended { t.cli_frame_extended } else { t.cli_frame_standard };
+    println!("{}", fill(t.cli_frame_id, &[&format!("{:X}", frame.id), &kind]));
+    if frame_args.j1939 {
+        println!("{}", fill(t.cli_frame_j1939, &[&lang.describe_j1939(&J1939Id::from_can_id(frame.id))]));
+    }
+    println!("{}", fill(t.cli_frame_payload, &[&format_hex(&frame.data)]));
+    println!("{}", fill(t.cli_frame_fd_algorithm, &[&algorithm.name, &frame.dlc()]));
+    if frame_args.non_iso {
+        println!("{}", fill(t.cli_frame_crc_bits, &[&frame.crc_bits().len()]));
+        println!("{}", fill(t.cli_frame_crc, &[&format!("{:0digits$X}", frame.non_iso_crc())]));
+        return;
+    }
+
+    let stuff_count = frame.stuff_count();
+    let count_bits: String = stuff_count_bits(stuff_count).iter().map(|&bit| if bit { '1' } else { '0' }).collect();
+    println!("{}", fill(t.cli_frame_fd_stuff_count, &[&stuff_count, &count_bits]));
+    println!("{}", fill(t.cli_frame_crc_bits, &[&frame.iso_crc_bits().len()]));
+    println!("{}", fill(t.cli_frame_crc, &[&format!("{:0digits$X}", frame.crc())]));
+
+    if frame_args.wire {
+        let wire = frame.wire_bits();
+        let stuffed = wire.iter().filter(|bit| bit.stuff).count();
+        println!("{}", fill(t.cli_frame_wire, &[&format_wire_bits(&wire)]));
+        println!("{}", fill(t.cli_frame_wire_summary, &[&wire.len(), &stuffed]));
+    }
+}
+
+#[cfg(feature = "tui")]
+struct Tui {
+    dashboard: Dashboard,
+    algorithm: &'static CrcAlgorithm,
+    modbus: bool,
+    source: String,
+    started: Instant,
+    paused: bool,
+    command: String,
+    message: String,
+    lang: Lang,
+}
+
+#[cfg(feature = "tui")]
+impl Tui {
+    fn ingest(&mut self, line: &str) {
+        let time = self.started.elapsed();
+        let entry = if self.modbus {
+            let (_, text) = split_timestamp(line);
+            parse_hex_bytes(text)
+                .map_err(|e| self.lang.describe(&e))
+                .and_then(|adu| check_rtu(&adu).map_err(|e| self.lang.describe_frame(&e)).map(|check| (adu, check)))
+                .map(|(adu, check)| FrameEntry {
+                    time,
+                    frame: format_hex(&adu),
+                    crc: format!("{:04X}", check.computed),
+                    status: if check.is_valid() { FrameStatus::Valid } else { FrameStatus::Mismatch },
+                })
+        } else {
+            CanFrame::parse_candump(line)
+                .map_err(|e| self.lang.describe_frame(&e))
+                .map(|frame| FrameEntry { time, frame: frame.to_candump(), crc: format!("{:04X}", frame.crc()), status: FrameStatus::Computed })
+        };
+        match entry {
+            Ok(entry) => self.dashboard.push(entry),
+            Err(message) => {
+                self.dashboard.record_error();
+                self.message = self.lang.error(&message);
+            }
+        }
+    }
+
+    fn execute(&mut self) -> bool {
+        let t = self.lang.strings();
+        let line = std::mem::take(&mut self.command);
+        if line.trim().is_empty() {
+            return true;
+        }
+        match line.parse::<DashboardCommand>() {
+            Ok(DashboardCommand::Calc(data)) => {
+                let config = &self.algorithm.config;
+                self.message = match BitString::parse_with(&data, &ParseOptions::for_config(config)) {
+                    Ok(bits) => {
+                        let result = CrcResult::for_algorithm(self.algorithm, config.checksum_message(&bits.to_bools()), 0.0).with_input(bits.len(), 1);
+                        fill(t.cli_tui_calc_result, &[&self.algorithm.name, &result.crc_hex, &bits.len()])
+                    }
+                    Err(e) => self.lang.error(&self.lang.describe(&e)),
+                };
+            }
+            Ok(DashboardCommand::Frame(frame)) => {
+                self.message.clear();
+                self.ingest(&frame);
+            }
+            Ok(DashboardCommand::Algorithm(name)) => match find_algorithm(&name, self.lang) {
+                Ok(algorithm) => {
+                    self.algorithm = algorithm;
+                    self.message = fill(t.cli_algorithm_used, &[&algorithm.name]);
+                }
+                Err(e) => self.message = self.lang.error(&e),
+            },
+            Ok(DashboardCommand::Pause) => {
+                self.paused = true;
+                self.message = t.cli_tui_paused.to_string();
+            }
+            Ok(DashboardCommand::Resume) => {
+                self.paused = false;
+                self.message.clear();
+            }
+            Ok(DashboardCommand::Clear) => {
+                self.dashboard.clear();
+                self.started = Instant::now();
+                self.message.clear();
+            }
+            Ok(DashboardCommand::Help) => self.message = t.cli_tui_help.to_string(),
+            Ok(DashboardCommand::Quit) => return false,
+            Err(command) => self.message = fill(t.cli_tui_unknown_command, &[&command]),
+        }
+        true
+    }
+
+    fn draw(&self, frame: &mut ratatui::Frame) {
+        use ratatui::layout::{Constraint, Layout};
+        use ratatui::style::{Color, Style};
+        use ratatui::text::{Line, Span};
+        use ratatui::widgets::{Block, Paragraph, Sparkline};
+
+        let t = self.lang.strings();
+        let [top, graphs, command] = Layout::vertical([Constraint::Min(6), Constraint::Length(6), Constraint::Length(4)]).areas(frame.area());
+        let [frames_area, status_area] = Layout::horizontal([Constraint::Min(40), Constraint::Length(36)]).areas(top);
+        let [rate_area, errors_area] = Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).areas(graphs);
+
+        let visible = frames_area.height.saturating_sub(2) as usize;
+        let skip = self.dashboard.frames().len().saturating_sub(visible);
+        let frames: Vec<Line> = self.dashboard.frames()
+            .skip(skip)
+            .map(|entry| {
+                let (mark, color) = match entry.status {
+                    FrameStatus::Computed => ("·", Color::Gray),
+                    FrameStatus::Valid => ("✔", Color::Green),
+                    FrameStatus::Mismatch => ("✘", Color::Red),
+                };
+                Line::from(vec![
+                    Span::raw(format!("{:>9.3}  ", entry.time.as_secs_f64())),
+                    Span::styled(format!("{} {}  ", mark, entry.crc), Style::default().fg(color)),
+                    Span::raw(entry.frame.as_str()),
+                ])
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(frames).block(Block::bordered().title(t.cli_tui_frames)), frames_area);
+
+        let mismatch_style = if self.dashboard.mismatches > 0 { Style::default().fg(Color::Red) } else { Style::default().fg(Color::Green) };
+        let mut status = vec![
+            Line::from(fill(t.cli_tui_source, &[&self.source, &if self.modbus { "Modbus RTU" } else { "CAN" }])),
+            Line::from(fill(t.cli_tui_algorithm, &[&self.algorithm.name])),
+            Line::from(fill(t.cli_tui_total, &[&self.dashboard.total])),
+            Line::from(fill(t.cli_tui_rate, &[&self.dashboard.frame_rate()])),
+            Line::styled(fill(t.cli_tui_mismatches, &[&self.dashboard.mismatches]), mismatch_style),
+            Line::from(fill(t.cli_tui_errors, &[&self.dashboard.errors])),
+        ];
+        if self.paused {
+            status.push(Line::styled(t.cli_tui_paused, Style::default().fg(Color::Yellow)));
+        }
+        frame.render_widget(Paragraph::new(status).block(Block::bordered().title(t.cli_tui_status)), status_area);
+
+        let width = rate_area.width.saturating_sub(2) as usize;
+        let samples: Vec<_> = self.dashboard.samples().copied().collect();
+        let samples = &samples[samples.len().saturating_sub(width)..];
+        let rates: Vec<u64> = samples.iter().map(|sample| sample.frames).collect();
+        let errors: Vec<u64> = samples.iter().map(|sample| sample.mismatches).collect();
+        let rate_title = fill(t.cli_tui_rate_graph, &[&rates.iter().max().copied().unwrap_or(0)]);
+        let errors_title = fill(t.cli_tui_errors_graph, &[&errors.iter().max().copied().unwrap_or(0)]);
+        frame.render_widget(Sparkline::default().data(&rates).style(Style::default().fg(Color::Cyan)).block(Block::bordered().title(rate_title)), rate_area);
+        frame.render_widget(Sparkline::default().data(&errors).style(Style::default().fg(Color::Red)).block(Block::bordered().title(errors_title)), errors_area);
+
+        let lines = vec![Line::from(format!("> {}", self.command)), Line::styled(self.message.as_str(), Style::default().fg(Color::Yellow))];
+        frame.render_widget(Paragraph::new(lines).block(Block::bordered().title(t.cli_tui_command)), command);
+        frame.set_cursor_position((command.x + 3 + self.command.chars().count() as u16, command.y + 1));
+    }
+}
+
+#[cfg(feature = "tui")]
+fn run_tui(tui_args: &TuiArgs, algorithm: Option<&str>, lang: Lang) {
+    use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+    use std::io::IsTerminal;
+    use std::sync::mpsc;
+
+    let t = lang.strings();
+    let (sender, receiver) = mpsc::channel::<String>();
+    let source = match &tui_args.input {
+        Some(path) => path.display().to_string(),
+        None if !io::stdin().is_terminal() => "stdin".to_string(),
+        None => t.cli_tui_no_source.to_string(),
+    };
+    if tui_args.input.is_some() || !io::stdin().is_terminal() {
+        let input: Box<dyn BufRead + Send> = match &tui_args.input {
+            Some(path) => match std::fs::File::open(path) {
+                Ok(file) => Box::new(BufReader::new(file)),
+                Err(e) => {
+                    eprintln!("{}", lang.error(&fill(t.err_read_file, &[&path.display(), &e])));
+                    std::process::exit(EXIT_IO_ERROR);
+                }
+            },
+            None => Box::new(BufReader::new(io::stdin())),
+        };
+        std::thread::spawn(move || {
+            for line in input.lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    let mut tui = Tui {
+        dashboard: Dashboard::default(),
+        algorithm: selected_algorithm(algorithm, lang),
+        modbus: tui_args.modbus,
+        source,
+        started: Instant::now(),
+        paused: false,
+        command: String::new(),
+        message: t.cli_tui_help.to_string(),
+        lang,
+    };
+    let mut terminal = ratatui::try_init().unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&fill(t.cli_tui_terminal_error, &[&e])));
+        std::process::exit(EXIT_IO_ERROR);
+    });
+    loop {
+        if !tui.paused {
+            for line in receiver.try_iter().filter(|line| !line.trim().is_empty()) {
+                tui.ingest(&line);
+            }
+        }
+        tui.dashboard.tick(tui.started.elapsed());
+        if terminal.draw(|frame| tui.draw(frame)).is_err() {
+            break;
+        }
+
+        if !event::poll(Duration::from_millis(100)).unwrap_or(false) {
+            continue;
+        }
+        let Ok(Event::Key(key)) = event::read() else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Esc => break,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break,
+            KeyCode::Enter if !tui.execute() => break,
+            KeyCode::Backspace => {
+                tui.command.pop();
+            }
+            KeyCode::Char(c) => tui.command.push(c),
+            _ => {}
+        }
+    }
+    ratatui::restore();
+}
+
+fn parse_speed(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(speed) if speed >= 0.0 && speed.is_finite() => Ok(speed),
+        _ => Err(format!("'{}' is not a non-negative number", value)),
+    }
+}
+
+#[cfg(all(feature = "serial", unix))]
+fn parse_rate(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(rate) if (0.0..=1.0).contains(&rate) => Ok(rate),
+        _ => Err(format!("'{}' is not a number between 0 and 1", value)),
+    }
+}
+
+#[cfg(feature = "serde")]
+fn parse_prefix_bytes(value: &str) -> Result<PrefixSize, String> {
+    value.parse::<u8>().ok().and_then(PrefixSize::from_bytes).ok_or_else(|| format!("'{}' is not one of 1, 2, 4", value))
+}
+
+fn run_replay(replay_args: &ReplayArgs, verbose: bool, lang: Lang) {
+    let t = lang.strings();
+    let mut stats = ReplayStats::new();
+    let mut format = replay_args.format;
+    let mut layout = CsvLayout::default();
+    let mut previous_time = None;
+    let started = Instant::now();
+    for (index, line) in open_input(&replay_args.input, lang).lines().enumerate() {
+        let line = line.unwrap_or_else(|e| {
+            eprintln!("{}", lang.error(&e.to_string()));
+            std::process::exit(EXIT_IO_ERROR);
+        });
+        let text = line.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+        if format == TraceFormat::Auto {
+            format = if text.contains('#') || text.starts_with('(') { TraceFormat::Candump } else { TraceFormat::Csv };
+            if format == TraceFormat::Csv {
+                if let Some(header) = CsvLayout::from_header(text) {
+                    layout = header;
+                    continue;
+                }
+            }
+        } else if format == TraceFormat::Csv && stats.frames + stats.errors == 0 {
+            if let Some(header) = CsvLayout::from_header(text) {
+                layout = header;
+                continue;
+            }
+        }
+
+        let record = match format {
+            TraceFormat::Csv => layout.parse(text),
+            _ => TraceRecord::parse_candump(text),
+        };
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                eprintln!("{}", line_error(index + 1, &lang.describe_frame(&e), lang));
+                stats.record_error();
+                continue;
+            }
+        };
+
+        let delay = replay_delay(previous_time, record.time, replay_args.speed);
+        if !delay.is_zero() {
+            std::thread::sleep(delay);
+        }
+        previous_time = record.time.or(previous_time);
+
+        let computed = record.frame.crc();
+        match stats.record(&record) {
+            Some(false) => println!("{}", fill(t.cli_replay_mismatch, &[&(index + 1), &format!("{:04X}", record.crc.unwrap_or_default()), &format!("{:04X}", computed), &text])),
+            _ if verbose => println!("{:04X}  {}", computed, text),
+            _ => {}
+        }
+    }
+    print_replay_summary(&stats, started.elapsed(), replay_args.top, lang);
+
+    if stats.mismatches > 0 {
+        std::process::exit(EXIT_MISMATCH);
+    }
+    if stats.errors > 0 {
+        std::process::exit(EXIT_PARSE_ERROR);
+    }
+}
+
+fn print_replay_summary(stats: &ReplayStats, elapsed: Duration, top: usize, lang: Lang) {
+    let t = lang.strings();
+    println!("\n{}", t.cli_replay_summary);
+    println!("{}", fill(t.cli_replay_frames, &[&stats.frames, &stats.errors]));
+    if let (Some(duration), Some(rate)) = (stats.trace_duration(), stats.trace_rate()) {
+        println!("{}", fill(t.cli_replay_trace_rate, &[&format!("{:.3}", duration.as_secs_f64()), &format!("{:.1}", rate)]));
+    }
+    let seconds = elapsed.as_secs_f64();
+    if seconds > 0.0 {
+        println!("{}", fill(t.cli_replay_processing_rate, &[&format!("{:.3}", seconds), &format!("{:.0}", stats.frames as f64 / seconds)]));
+    }
+    if stats.checked > 0 {
+        println!("{}", fill(t.cli_replay_crc_checked, &[&stats.checked, &stats.mismatches]));
+    } else {
+        println!("{}", t.cli_replay_crc_missing);
+    }
+
+    if stats.per_id.is_empty() {
+        return;
+    }
+    println!("\n{}", fill(t.cli_replay_ids, &[&top.min(stats.per_id.len()), &stats.per_id.len()]));
+    println!("  {:<10}{:>12}{:>12}", "ID", t.cli_replay_column_frames, t.cli_replay_column_mismatches);
+    for ((extended, id), id_stats) in stats.busiest_ids().into_iter().take(top) {
+        let id = if extended { format!("{:08X}", id) } else { format!("{:03X}", id) };
+        println!("  {:<10}{:>12}{:>12}", id, id_stats.frames, id_stats.mismatches);
+    }
+
+    println!("\n{}", t.cli_replay_lengths);
+    let largest = stats.lengths.iter().copied().max().unwrap_or(0).max(1);
+    for (len, &count) in stats.lengths.iter().enumerate() {
+        let bar = "#".repeat((count * 40).div_ceil(largest) as usize);
+        println!("  {} B |{:<40} {}", len, bar, count);
+    }
+}
+
+fn run_gen(gen_args: &GenArgs, verbose: bool, lang: Lang) {
+    let t = lang.strings();
+    let mut generator = FrameGenerator::new(gen_args.id.clone(), gen_args.data.clone(), gen_args.len, gen_args.extended, gen_args.seed)
+        .unwrap_or_else(|e| {
+            eprintln!("{}", lang.error(&lang.describe_frame(&e)));
+            std::process::exit(EXIT_PARSE_ERROR);
+        });
+    #[cfg(all(feature = "socketcan", target_os = "linux"))]
+    let socket = gen_args.interface.as_deref().map(|interface| {
+        let socket = CanSocket::open(interface).unwrap_or_else(|e| {
+            eprintln!("{}", lang.error(&fill(t.cli_gen_open_failed, &[&interface, &e])));
+            std::process::exit(EXIT_IO_ERROR);
+        });
+        (socket, interface)
+    });
+    #[cfg(all(feature = "socketcan", target_os = "linux"))]
+    let target = socket.as_ref().map_or("stdout", |(_, interface)| interface);
+    #[cfg(not(all(feature = "socketcan", target_os = "linux")))]
+    let target = "stdout";
+
+    let gap = Duration::from_millis(gen_args.gap);
+    let mut out = io::stdout().lock();
+    let mut sent = 0u64;
+    while gen_args.count.is_none_or(|count| sent < count) {
+        if sent > 0 && !gap.is_zero() {
+            std::thread::sleep(gap);
+        }
+        let frame = generator.next_frame();
+        sent += 1;
+
+        #[cfg(all(feature = "socketcan", target_os = "linux"))]
+        if let Some((socket, interface)) = &socket {
+            if let Err(e) = socket.send(&frame) {
+                eprintln!("{}", lang.error(&fill(t.cli_gen_send_failed, &[interface, &e])));
+                std::process::exit(EXIT_IO_ERROR);
+            }
+            if !verbose {
+                continue;
+            }
+        }
+        if writeln!(out, "{}", frame.to_candump()).and_then(|_| out.flush()).is_err() {
+            break;
+        }
+    }
+
+    if verbose || target != "stdout" {
+        eprintln!("{}", fill(t.cli_gen_sent, &[&sent, &target]));
+    }
+}
+
+fn run_gen_data(gen_args: &GenDataArgs, algorithm: Option<&str>, lang: Lang) {
+    let fail = |error: ParseError| -> ! {
+        eprintln!("{}", lang.error(&lang.describe(&error)));
+        std::process::exit(EXIT_PARSE_ERROR);
+    };
+    let bits = gen_args.bits as usize;
+    let algorithm = gen_args.crc.then(|| selected_algorithm(algorithm, lang));
+    if let Some(max_bits) = algorithm.and_then(|algorithm| ParseOptions::for_config(&algorithm.config).max_bits).filter(|&max_bits| bits > max_bits) {
+        fail(ParseError::BinaryTooLong { bits, max_bits });
+    }
+    if !matches!(gen_args.format, InputFormat::Binary) && !bits.is_multiple_of(8) {
+        fail(ParseError::PartialByte { bits });
+    }
+
+    let mut out = io::stdout().lock();
+    for payload in PayloadGenerator::new(bits, gen_args.seed).take(gen_args.count as usize) {
+        let message = format_message(&gen_args.format, &payload, BitOrder::MsbFirst);
+        let written = match algorithm {
+            Some(algorithm) => {
+                let digits = (algorithm.config.width as usize).div_ceil(4);
+                writeln!(out, "{:0digits$X}  {}", compute_batch_checksums(&algorithm.config, &payload, 1), message)
+            }
+            None => writeln!(out, "{}", message),
+        };
+        if written.is_err() {
+            break;
+        }
+    }
+}
+
+fn run_poll(poll_args: &PollArgs, lang: Lang) {
+    let t = lang.strings();
+    #[cfg(all(feature = "serial", unix))]
+    if let Some(path) = &poll_args.serial {
+        let port = SerialPort::open(path, poll_args.baud, poll_args.parity.into()).unwrap_or_else(|e| {
+            eprintln!("{}", lang.error(&fill(t.cli_serial_open_failed, &[&path.display(), &e])));
+            std::process::exit(EXIT_IO_ERROR);
+        });
+        return poll_loop(port, &path.display().to_string(), poll_args, lang);
+    }
+    let address = poll_args.tcp.as_deref().expect("clap requires a poll target");
+    let transport = TcpTransport::connect(address).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&fill(t.cli_poll_connect_failed, &[&address, &e])));
+        std::process::exit(EXIT_IO_ERROR);
+    });
+    poll_loop(transport, address, poll_args, lang);
+}
+
+fn poll_loop<T: RtuTransport>(mut transport: T, target: &str, poll_args: &PollArgs, lang: Lang) {
+    let t = lang.strings();
+    let mut poller = Poller::new(poll_args.address, poll_args.start, poll_args.count);
+    let end = poll_args.start as u32 + poll_args.count as u32 - 1;
+    eprintln!("{}", fill(t.cli_poll_started, &[&poll_args.address, &target, &poll_args.start, &end, &poll_args.interval]));
+
+    let timeout = Duration::from_millis(poll_args.timeout);
+    let interval = Duration::from_millis(poll_args.interval);
+    let mut next = Instant::now();
+    while poll_args.polls.is_none_or(|polls| poller.stats.polls < polls) {
+        if poller.stats.polls > 0 {
+            next += interval;
+            std::thread::sleep(next.saturating_duration_since(Instant::now()));
+        }
+        let (response, outcome) = poller.poll(&mut transport, timeout).unwrap_or_else(|e| {
+            eprintln!("{}", lang.error(&fill(t.cli_poll_io_failed, &[&target, &e])));
+            std::process::exit(EXIT_IO_ERROR);
+        });
+        let (number, hex) = (poller.stats.polls, format_hex(&response));
+        let line = match outcome {
+            PollOutcome::Registers(registers) => {
+                let values = (poll_args.start..).zip(&registers)
+                    .map(|(register, value)| format!("{}={} (0x{:04X})", register, value, value))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                fill(t.cli_poll_ok, &[&number, &values])
+            }
+            PollOutcome::Exception(code) => {
+                let pdu = Pdu::Exception { function: READ_HOLDING_REGISTERS, code };
+                fill(t.cli_poll_unexpected, &[&number, &hex, &lang.describe_pdu(&pdu)])
+            }
+            PollOutcome::CrcError(check) => {
+                fill(t.cli_poll_crc_error, &[&number, &hex, &format!("{:04X}", check.received), &format!("{:04X}", check.computed)])
+            }
+            PollOutcome::Unexpected(pdu) => fill(t.cli_poll_unexpected, &[&number, &hex, &lang.describe_pdu(&pdu)]),
+            PollOutcome::Invalid(e) => fill(t.cli_poll_unexpected, &[&number, &hex, &lang.describe_frame(&e)]),
+            PollOutcome::Timeout => fill(t.cli_poll_timeout, &[&number, &poll_args.timeout]),
+        };
+        println!("{}", line);
+    }
+
+    let stats = poller.stats;
+    eprintln!("{}", fill(t.cli_poll_summary, &[&stats.polls, &stats.ok, &stats.timeouts, &stats.crc_errors, &stats.exceptions, &stats.unexpected]));
+    if stats.failures() > 0 {
+        std::process::exit(EXIT_MISMATCH);
+    }
+}
+
+#[cfg(all(feature = "serial", unix))]
+fn run_slave_sim(slave_args: &SlaveSimArgs, lang: Lang) {
+    let t = lang.strings();
+    let mut map = RegisterMap::default();
+    for (table, blocks) in [
+        (Table::Coils, &slave_args.coils),
+        (Table::DiscreteInputs, &slave_args.discrete_inputs),
+        (Table::HoldingRegisters, &slave_args.holding),
+        (Table::InputRegisters, &slave_args.input_registers),
+    ] {
+        for block in blocks {
+            map.insert(table, block);
+        }
+    }
+    if map.is_empty() {
+        eprintln!("{}", t.cli_slave_empty_map);
+    }
+    let port_name = slave_args.port.display();
+    let mut port = SerialPort::open(&slave_args.port, slave_args.baud, slave_args.parity.into()).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&fill(t.cli_serial_open_failed, &[&port_name, &e])));
+        std::process::exit(EXIT_IO_ERROR);
+    });
+    eprintln!("{}", fill(t.cli_slave_listening, &[
+        &slave_args.address,
+        &port_name,
+        &slave_args.baud,
+        &format!("{:?}", slave_args.parity).to_lowercase(),
+        &map.coils.len(),
+        &map.discrete_inputs.len(),
+        &map.holding_registers.len(),
+        &map.input_registers.len(),
+    ]));
+
+    let mut simulator = SlaveSimulator::new(slave_args.address, map).with_bad_crc_rate(slave_args.bad_crc_rate, slave_args.seed);
+    loop {
+        let request = match port.read_frame(None) {
+            Ok(Some(request)) if !request.is_empty() => request,
+            Ok(_) => continue,
+            Err(e) => {
+                eprintln!("{}", lang.error(&fill(t.cli_serial_io_failed, &[&port_name, &e])));
+                std::process::exit(EXIT_IO_ERROR);
+            }
+        };
+        let hex = format_hex(&request);
+        let reply = match simulator.handle(&request) {
+            Ok(reply) => reply,
+            Err(e) => {
+                println!("{}", fill(t.cli_slave_rx_invalid, &[&hex, &lang.describe_frame(&e)]));
+                continue;
+            }
+        };
+        let check = check_rtu(&request).expect("request was already checked");
+        let Some(reply) = reply else {
+            let line = if !check.is_valid() {
+                fill(t.cli_slave_rx_bad_crc, &[&hex, &format!("{:04X}", check.received), &format!("{:04X}", check.computed)])
+            } else {
+                fill(t.cli_slave_rx_ignored, &[&hex, &check.address])
+            };
+            println!("{}", line);
+            continue;
+        };
+        println!("{}", fill(t.cli_slave_rx, &[&hex]));
+        if let Err(e) = port.write_frame(&reply.adu) {
+            eprintln!("{}", lang.error(&fill(t.cli_serial_io_failed, &[&port_name, &e])));
+            std::process::exit(EXIT_IO_ERROR);
+        }
+        let hex = format_hex(&reply.adu);
+        let line = match (reply.exception, reply.corrupted) {
+            (_, true) => fill(t.cli_slave_tx_corrupted, &[&hex]),
+            (Some(code), false) => fill(t.cli_slave_tx_exception, &[&hex, &format!("{:02X}", code)]),
+            (None, false) => fill(t.cli_slave_tx, &[&hex]),
+        };
+        println!("{}", line);
+    }
+}
+
+fn run_listen(listen_args: &ListenArgs, lang: Lang) {
+    let t = lang.strings();
+    let reporter = Reporter::connect(listen_args, lang);
+    let metrics = start_metrics(listen_args.metrics.as_deref(), lang);
+    let mut tracker = listen_args.transactions.then(|| TransactionTracker::new(Duration::from_millis(listen_args.response_timeout)));
+    let mut summary = TransactionSummary::default();
+    let capture_started = Instant::now();
+    let mut exit_code = 0;
+    for (index, line) in open_input(&listen_args.input, lang).lines().enumerate() {
+        let line = line.unwrap_or_else(|e| {
+            eprintln!("{}", lang.error(&e.to_string()));
+            std::process::exit(EXIT_IO_ERROR);
+        });
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let started = Instant::now();
+        if listen_args.modbus {
+            let (time, text) = split_timestamp(&line);
+            let check = parse_hex_bytes(text)
+                .map_err(|e| lang.describe(&e))
+                .and_then(|adu| {
+                    let check = check_rtu(&adu).map_err(|e| lang.describe_frame(&e))?;
+                    Ok((adu, check))
+                });
+            match check {
+                Ok((adu, check)) => {
+                    let len = adu.len();
+                    metrics.record_frame(len, started.elapsed());
+                    if !check.is_valid() {
+                        metrics.record_mismatch();
+                        if exit_code == 0 {
+                            exit_code = EXIT_MISMATCH;
+                        }
+                    }
+                    reporter.modbus(&check, lang);
+                    if let Some(tracker) = &mut tracker {
+                        let time = time.unwrap_or_else(|| capture_started.elapsed());
+                        for transaction in tracker.push(&adu, time).unwrap_or_default() {
+                            summary.print(&transaction, lang);
+                        }
+                        continue;
+                    }
+
+                    let crc = format!("{:04X}", check.computed);
+                    if check.is_valid() {
+                        println!("{}", fill(t.cli_listen_modbus_ok, &[&crc, &check.address, &line.trim()]));
+                    } else {
+                        let received = format!("{:04X}", check.received);
+                        println!("{}", fill(t.cli_listen_modbus_mismatch, &[&crc, &check.address, &received, &line.trim()]));
+                    }
+                    if let Ok(pdu) = decode_adu(&adu) {
+                        println!("{}", fill(t.cli_listen_modbus_pdu, &[&lang.describe_pdu(&pdu)]));
+                    }
+                }
+                Err(message) => {
+                    eprintln!("{}", line_error(index + 1, &message, lang));
+                    metrics.record_error();
+                    exit_code = EXIT_PARSE_ERROR;
+                }
+            }
+            continue;
+        }
+
+        match CanFrame::parse_candump(&line) {
+            Ok(frame) => {
+                let crc = frame.crc();
+                metrics.record_frame(frame.data.len(), started.elapsed());
+                println!("{:04X}  {}", crc, line.trim());
+                if listen_args.j1939 && frame.extended {
+                    println!("{}", fill(t.cli_listen_j1939, &[&lang.describe_j1939(&J1939Id::from_can_id(frame.id))]));
+                }
+                reporter.can(&frame, lang);
+            }
+            Err(e) => {
+                eprintln!("{}", line_error(index + 1, &lang.describe_frame(&e), lang));
+                metrics.record_error();
+                exit_code = EXIT_PARSE_ERROR;
+            }
+        }
+    }
+    if let Some(tracker) = &mut tracker {
+        if let Some(transaction) = tracker.finish() {
+            summary.print(&transaction, lang);
+        }
+        println!("{}", fill(t.cli_transactions_summary, &[&summary.total, &summary.ok, &summary.unanswered, &summary.crc_errors, &summary.exceptions]));
+    }
+    reporter.finish();
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+}
+
+#[derive(Default)]
+struct TransactionSummary {
+    total: usize,
+    ok: usize,
+    unanswered: usize,
+    crc_errors: usize,
+    exceptions: usize,
+}
+
+impl TransactionSummary {
+    fn print(&mut self, transaction: &Transaction, lang: Lang) {
+        let t = lang.strings();
+        let status = |frame: &Option<RtuFrame>| match frame {
+            Some(frame) if frame.check.is_valid() => t.cli_transaction_crc_ok,
+            Some(_) => t.cli_transaction_crc_bad,
+            None => t.cli_transaction_missing,
+        };
+        let response = if transaction.is_broadcast() { t.cli_transaction_broadcast } else { status(&transaction.response) };
+        let time = transaction.response_time()
+            .map(|time| format!("{:.1} ms", time.as_secs_f64() * 1000.0))
+            .unwrap_or_else(|| "-".to_string());
+        let icon = if transaction.is_ok() { "✅" } else { "❌" };
+        let function = lang.modbus_function_name(transaction.function());
+        println!(
+            "{}",
+            fill(t.cli_transaction, &[&icon, &transaction.address(), &function, &status(&transaction.request), &response, &time])
+        );
+        if let Some(response) = transaction.response.as_ref().filter(|_| transaction.exception().is_some()) {
+            println!("{}", fill(t.cli_listen_modbus_pdu, &[&lang.describe_pdu(&response.pdu)]));
+        }
+
+        self.total += 1;
+        self.ok += transaction.is_ok() as usize;
+        self.unanswered += (transaction.request.is_some() && transaction.response.is_none() && !transaction.is_broadcast()) as usize;
+        self.crc_errors += transaction.crc_errors();
+        self.exceptions += transaction.exception().is_some() as usize;
+    }
+}
+
+#[cfg(feature = "mqtt")]
+struct Reporter {
+    publisher: Option<Publisher>,
+    prefix: String,
+}
+
+#[cfg(feature = "mqtt")]
+impl Reporter {
+    fn connect(listen_args: &ListenArgs, lang: Lang) -> Self {
+        let t = lang.strings();
+        let publisher = listen_args.mqtt.as_deref().map(|broker| {
+            let publisher = Publisher::connect(broker, DEFAULT_CLIENT_ID).unwrap_or_else(|e| {
+                eprintln!("{}", lang.error(&lang.describe_mqtt(&e)));
+                std::process::exit(EXIT_IO_ERROR);
+            });
+            let prefix = &listen_args.mqtt_topic;
+            eprintln!("{}", fill(t.cli_listen_mqtt_connected, &[&broker, prefix, prefix]));
+            publisher
+        });
+        Self { publisher, prefix: listen_args.mqtt_topic.clone() }
+    }
+
+    fn can(&self, frame: &CanFrame, lang: Lang) {
+        self.publish(&can_topic(&self.prefix, frame), &FrameReport::can(frame), lang);
+    }
+
+    fn modbus(&self, check: &RtuCheck, lang: Lang) {
+        self.publish(&modbus_topic(&self.prefix, check.address), &FrameReport::modbus(check), lang);
+    }
+
+    fn finish(self) {
+        drop(self.publisher);
+    }
+
+    fn publish(&self, topic: &str, report: &FrameReport, lang: Lang) {
+        if let Some(publisher) = &self.publisher {
+            if let Err(e) = publisher.publish(topic, report) {
+                eprintln!("{}", lang.error(&lang.describe_mqtt(&e)));
+                std::process::exit(EXIT_IO_ERROR);
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "mqtt"))]
+struct Reporter;
+
+#[cfg(not(feature = "mqtt"))]
+impl Reporter {
+    fn connect(_listen_args: &ListenArgs, _lang: Lang) -> Self {
+        Reporter
+    }
+
+    fn can(&self, _frame: &CanFrame, _lang: Lang) {}
+
+    fn modbus(&self, _check: &RtuCheck, _lang: Lang) {}
+
+    fn finish(self) {}
+}
+
+fn run_serve(serve_args: &ServeArgs, algorithm: Option<&str>, lang: Lang) {
+    let t = lang.strings();
+    let algorithm = selected_algorithm(algorithm, lang);
+    let listener = TcpListener::bind(&serve_args.bind).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&fill(t.cli_err_bind, &[&serve_args.bind, &e])));
+        std::process::exit(EXIT_IO_ERROR);
+    });
+    let address = listener.local_addr().map(|address| address.to_string()).unwrap_or_else(|_| serve_args.bind.clone());
+    println!("{}", fill(t.cli_serve_listening, &[&address]));
+    let metrics = start_metrics(serve_args.metrics.as_deref(), lang);
+
+    #[cfg(feature = "async")]
+    if let Err(e) = accept_connections(listener, algorithm, serve_args.format.clone(), metrics) {
+        eprintln!("{}", lang.error(&fill(t.cli_err_bind, &[&address, &e])));
+        std::process::exit(EXIT_IO_ERROR);
+    }
+
+    #[cfg(not(feature = "async"))]
+    for stream in listener.incoming().flatten() {
+        let format = serve_args.format.clone();
+        let metrics = Arc::clone(&metrics);
+        std::thread::spawn(move || serve_connection(stream, algorithm, &format, &metrics));
+    }
+}
+
+#[cfg(feature = "async")]
+fn accept_connections(listener: TcpListener, algorithm: &'static CrcAlgorithm, format: InputFormat, metrics: Arc<Metrics>) -> io::Result<()> {
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async move {
+        listener.set_nonblocking(true)?;
+        let listener = tokio::net::TcpListener::from_std(listener)?;
+        let format = Arc::new(format);
+        loop {
+            let Ok((stream, _)) = listener.accept().await else {
+                continue;
+            };
+            let format = Arc::clone(&format);
+            let metrics = Arc::clone(&metrics);
+            tokio::spawn(async move {
+                let config = &algorithm.config;
+                let options = ParseOptions::for_config(config);
+                serve_lines(stream, config, &metrics, |line| format.parse(line, &options)).await
+            });
+        }
+    })
+}
+
+#[cfg(feature = "async")]
+async fn serve_lines<S, F>(stream: S, config: &CrcConfig, metrics: &Metrics, parse: F) -> io::Result<()>
+where
+    S: AsyncRead + AsyncWrite,
+    F: Fn(&str) -> Result<Vec<bool>, ParseError>,
+{
+    let digits = (config.width as usize).div_ceil(4);
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut lines = tokio::io::BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let started = Instant::now();
+        let reply = match parse(&line) {
+            Ok(bits) => {
+                let crc = compute_batch_checksums(config, &bits, 1);
+                metrics.record_frame(bits.len().div_ceil(8), started.elapsed());
+                format!("{:0digits$X}\n", crc)
+            }
+            Err(e) => {
+                metrics.record_error();
+                format!("ERR {} {}\n", e.code(), e)
+            }
+        };
+        writer.write_all(reply.as_bytes()).await?;
+    }
+    writer.flush().await
+}
+
+#[cfg(not(feature = "async"))]
+fn serve_connection(stream: TcpStream, algorithm: &CrcAlgorithm, format: &InputFormat, metrics: &Metrics) -> io::Result<()> {
+    let config = &algorithm.config;
+    let options = ParseOptions::for_config(config);
+    let digits = (config.width as usize).div_ceil(4);
+    let mut writer = stream.try_clone()?;
+
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let started = Instant::now();
+        match format.parse(&line, &options) {
+            Ok(bits) => {
+                let crc = compute_batch_checksums(config, &bits, 1);
+                metrics.record_frame(bits.len().div_ceil(8), started.elapsed());
+                writeln!(writer, "{:0digits$X}", crc)?
+            }
+            Err(e) => {
+                metrics.record_error();
+                writeln!(writer, "ERR {} {}", e.code(), e)?
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+fn run_raw_listen(raw_args: &RawListenArgs, algorithm: Option<&str>, lang: Lang) {
+    let t = lang.strings();
+    let algorithm = selected_algorithm(algorithm, lang);
+    let listener = TcpListener::bind(&raw_args.bind).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&fill(t.cli_err_bind, &[&raw_args.bind, &e])));
+        std::process::exit(EXIT_IO_ERROR);
+    });
+    let address = listener.local_addr().map(|address| address.to_string()).unwrap_or_else(|_| raw_args.bind.clone());
+    println!("{}", fill(t.cli_raw_listening, &[&address, &raw_args.prefix_bytes.bytes(), &algorithm.name]));
+    let metrics = start_metrics(raw_args.metrics.as_deref(), lang);
+    let prefix = LengthPrefix::new(raw_args.prefix_bytes, raw_args.prefix_endianness.into());
+    let endianness = raw_args.crc_endianness.map_or_else(|| Endianness::for_config(&algorithm.config), Endianness::from);
+    let reply = raw_args.reply;
+
+    for stream in listener.incoming().flatten() {
+        let metrics = Arc::clone(&metrics);
+        std::thread::spawn(move || {
+            let peer = stream.peer_addr().map(|peer| peer.to_string()).unwrap_or_default();
+            if let Err(e) = raw_connection(stream, &peer, &algorithm.config, prefix, endianness, reply, &metrics) {
+                eprintln!("{}", lang.error(&fill(t.cli_err_raw_connection, &[&peer, &e])));
+            }
+        });
+    }
+}
+
+#[cfg(feature = "serde")]
+fn raw_connection(stream: TcpStream, peer: &str, config: &CrcConfig, prefix: LengthPrefix, endianness: Endianness, reply: bool, metrics: &Metrics) -> io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+    let mut index = 0;
+
+    while let Some(frame) = read_frame(&mut reader, prefix)? {
+        index += 1;
+        let started = Instant::now();
+        let result = check_raw_frame(config, &frame, endianness);
+        match &result {
+            Ok(check) => {
+                metrics.record_frame(frame.len(), started.elapsed());
+                if !check.passed() {
+                    metrics.record_mismatch();
+                }
+            }
+            Err(_) => metrics.record_error(),
+        }
+        let json = frame_json(peer, index, &frame, &result);
+        if reply {
+            writeln!(writer, "{}", json)?;
+        } else {
+            println!("{}", json);
+        }
+    }
+    Ok(())
+}
+
+fn run_udp(udp_args: &UdpArgs, algorithm: Option<&str>, lang: Lang) {
+    let t = lang.strings();
+    let algorithm = selected_algorithm(algorithm, lang);
+    let config = &algorithm.config;
+    let socket = UdpSocket::bind(&udp_args.bind).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&fill(t.cli_err_bind, &[&udp_args.bind, &e])));
+        std::process::exit(EXIT_IO_ERROR);
+    });
+    let address = socket.local_addr().map(|address| address.to_string()).unwrap_or_else(|_| udp_args.bind.clone());
+    println!("{}", fill(t.cli_udp_listening, &[&address, &algorithm.name]));
+    let metrics = start_metrics(udp_args.metrics.as_deref(), lang);
+    let endianness = udp_args.crc_endianness.map_or_else(|| Endianness::for_config(config), Endianness::from);
+    let digits = (config.width as usize).div_ceil(4);
+    let mut buffer = vec![0u8; 65_536];
+
+    loop {
+        let (len, peer) = match socket.recv_from(&mut buffer) {
+            Ok(received) => received,
+            Err(e) => {
+                eprintln!("{}", lang.error(&fill(t.cli_err_udp, &[&e])));
+                continue;
+            }
+        };
+        let started = Instant::now();
+        let crc = config.checksum(&buffer[..len]);
+        metrics.record_frame(len, started.elapsed());
+        let hex = format!("{:0digits$X}", crc);
+        if !udp_args.quiet {
+            println!("{}", fill(t.cli_udp_datagram, &[&peer, &len, &hex]));
+        }
+        if udp_args.no_reply {
+            continue;
+        }
+        let reply = if udp_args.binary { config.crc_bytes(crc, endianness) } else { hex.into_bytes() };
+        if let Err(e) = socket.send_to(&reply, peer) {
+            eprintln!("{}", lang.error(&fill(t.cli_err_udp, &[&e])));
+        }
+    }
+}
+
+fn start_metrics(address: Option<&str>, lang: Lang) -> Arc<Metrics> {
+    let t = lang.strings();
+    let metrics = Arc::new(Metrics::new());
+    if let Some(address) = address {
+        let listener = TcpListener::bind(address).unwrap_or_else(|e| {
+            eprintln!("{}", lang.error(&fill(t.cli_err_bind, &[&address, &e])));
+            std::process::exit(EXIT_IO_ERROR);
+        });
+        let bound = listener.local_addr().map(|bound| bound.to_string()).unwrap_or_else(|_| address.to_string());
+        eprintln!("{}", fill(t.cli_metrics_listening, &[&bound, &METRICS_PATH]));
+        let metrics = Arc::clone(&metrics);
+        std::thread::spawn(move || serve_metrics(listener, metrics));
+    }
+    metrics
+}
+
+fn run_algorithms(lang: Lang) {
+    let t = lang.strings();
+    println!("{}", t.cli_algorithms_header);
+    for algorithm in CrcCatalog::all() {
+        let config = &algorithm.config;
+        let digits = (config.width as usize).div_ceil(4);
+        let verified = config.checksum(b"123456789") == algorithm.check && config.residue() == algorithm.residue;
+        println!("{}", fill(t.cli_algorithms_line, &[
+            &format!("{:<16}", algorithm.name),
+            &format!("{:<2}", config.width),
+            &format!("{:0digits$X}", config.poly),
+            &format!("{:0digits$X}", config.init),
+            &config.refin,
+            &config.refout,
+            &format!("{:0digits$X}", config.xorout),
+            &format!("{:0digits$X}", algorithm.check),
+            &format!("{:0digits$X}", algorithm.residue),
+            &if verified { "✅" } else { "❌" },
+        ]));
+        println!("{}", fill(t.cli_algorithms_aliases, &[&algorithm.aliases.join(", ")]));
+    }
+    println!("{}", t.cli_algorithms_checksums);
+    for checksum in Checksum::ALL {
+        let digits = (checksum.width() as usize).div_ceil(4);
+        let verified = checksum.compute(b"123456789") == checksum.check();
+        println!("{}", fill(t.cli_algorithms_checksum_line, &[
+            &format!("{:<16}", checksum.name()),
+            &format!("{:<2}", checksum.width()),
+            &format!("{:0digits$X}", checksum.check()),
+            &if verified { "✅" } else { "❌" },
+        ]));
+        println!("{}", fill(t.cli_algorithms_aliases, &[&checksum.aliases().join(", ")]));
+    }
+}
+
+fn run_e2e(e2e_args: &E2eArgs, lang: Lang) {
+    let t = lang.strings();
+    let fail = |message: String| -> ! {
+        eprintln!("{}", lang.error(&message));
+        std::process::exit(EXIT_PARSE_ERROR);
+    };
+
+    let mut data = parse_hex_bytes(&e2e_args.data).unwrap_or_else(|e| fail(InputFormat::Hex.describe(&e2e_args.data, &e, lang)));
+    if data.len() < 2 {
+        fail(t.cli_err_e2e_too_short.to_string());
+    }
+
+    let (profile, number) = match e2e_args.profile {
+        E2eProfileArg::P1 => {
+            let data_id = parse_param(&e2e_args.data_id).unwrap_or_else(|e| fail(lang.describe_config(&e)));
+            let mode = match e2e_args.data_id_mode {
+                DataIdModeArg::Both => DataIdMode::Both,
+                DataIdModeArg::Alt => DataIdMode::Alt,
+                DataIdModeArg::Low => DataIdMode::Low,
+            };
+            (E2eProfile::Profile1 { data_id: data_id as u16, mode }, 1)
+        }
+        E2eProfileArg::P2 => {
+            let ids: Vec<u8> = e2e_args.data_id_list.iter()
+                .map(|id| parse_param(id).unwrap_or_else(|e| fail(lang.describe_config(&e))) as u8)
+                .collect();
+            let data_id_list: [u8; 16] = ids.as_slice().try_into()
+                .unwrap_or_else(|_| fail(fill(t.cli_err_data_id_list, &[&ids.len()])));
+            (E2eProfile::Profile2 { data_id_list }, 2)
+        }
+    };
+
+    if let Some(counter) = e2e_args.counter {
+        profile.protect(&mut data, counter);
+        println!("{}", fill(t.cli_e2e_protected, &[&format_hex(&data)]));
+        println!("{}", fill(t.cli_e2e_summary, &[&number, &(data[1] & 0x0F), &format!("{:02X}", data[0])]));
+        return;
+    }
+
+    let Some(check) = profile.check(&data) else {
+        fail(t.cli_err_e2e_too_short.to_string());
+    };
+    println!("{}", fill(t.cli_e2e_check, &[
+        &number,
+        &check.counter,
+        &format!("{:02X}", check.received_crc),
+        &format!("{:02X}", check.expected_crc),
+    ]));
+    if check.valid() {
+        println!("{}", t.cli_e2e_valid);
+    } else {
+        println!("{}", t.cli_e2e_invalid);
+        std::process::exit(EXIT_MISMATCH);
+    }
+}
+
+fn run_file(file_args: &FileArgs, algorithm: Option<&str>, verbose: bool, lang: Lang) {
+    let t = lang.strings();
+    let algorithm = selected_algorithm(algorithm.or(Some("CRC-32/ISO-HDLC")), lang);
+    let config = &algorithm.config;
+    let digits = (config.width as usize).div_ceil(4);
+
+    let auto = AutoCrc::new(*config);
+    if verbose {
+        println!("{}", fill(t.cli_file_header, &[&algorithm.name, &config.width, &config.refin, &config.refout]));
+        let calibration = auto.calibration();
+        let from = |bytes: usize| if bytes == usize::MAX { "-".to_string() } else { format_number(bytes as u64) };
+        println!("{}", fill(t.cli_file_strategy, &[&from(calibration.table_from), &from(calibration.slice8_from)]));
+    }
+
+    let fail = |message: String| -> ! {
+        eprintln!("{}", lang.error(&message));
+        std::process::exit(EXIT_PARSE_ERROR);
+    };
+    let address = |value: &Option<String>| {
+        value.as_deref().map(|v| parse_param(v).unwrap_or_else(|e| fail(lang.describe_config(&e))))
+    };
+    let start = address(&file_args.start);
+    let end = address(&file_args.end);
+    let fill_byte = parse_param(&file_args.fill)
+        .and_then(|value| u8::try_from(value).map_err(|_| ConfigError::InvalidNumber { value: file_args.fill.clone() }))
+        .unwrap_or_else(|e| fail(lang.describe_config(&e)));
+
+    let mut exit_code = 0;
+    let mut files = 0u64;
+    let mut bytes = 0u64;
+    let mut errors = 0u64;
+    let mut report_error = |path: &Path, message: &dyn std::fmt::Display, code: i32| {
+        eprintln!("{}", lang.error(&fill(t.err_read_file, &[&path.display(), message])));
+        exit_code = exit_code.max(code);
+        errors += 1;
+    };
+    for entry in expand_paths(&file_args.paths, file_args.recursive, lang) {
+        let path = match entry {
+            Ok(path) => path,
+            Err((path, message)) => {
+                report_error(&path, &message, EXIT_IO_ERROR);
+                continue;
+            }
+        };
+        let path = path.as_path();
+        if let Some(format) = file_args.format.image_format(path) {
+            let image = std::fs::read_to_string(path)
+                .map_err(|e| (EXIT_IO_ERROR, e.to_string()))
+                .and_then(|text| format.parse(&text).map_err(|e| (EXIT_PARSE_ERROR, lang.describe_image(&e))));
+            match image {
+                Ok(image) => {
+                    let first = start.or(image.start()).unwrap_or(0);
+                    let last = end.or(image.end()).unwrap_or(first).max(first);
+                    let (crc, _) = checksum_reader(config, &mut image.reader(first, last, fill_byte))
+                        .unwrap_or_else(|e| fail(e.to_string()));
+                    files += 1;
+                    bytes += last - first;
+                    println!("{}", fill(t.cli_file_image_line, &[
+                        &format!("{:0digits$X}", crc),
+                        &path.display(),
+                        &format_number(last - first),
+                        &format!("{:08X}", first),
+                        &format!("{:08X}", last),
+                    ]));
+                }
+                Err((code, e)) => report_error(path, &e, code),
+            }
+            continue;
+        }
+
+        let checksum = std::fs::File::open(path)
+            .and_then(|mut file| checksum_reader(config, &mut file));
+        match checksum {
+            Ok((crc, total)) => {
+                files += 1;
+                bytes += total;
+                println!("{}", fill(t.cli_file_line, &[&format!("{:0digits$X}", crc), &path.display(), &format_number(total)]));
+            }
+            Err(e) => report_error(path, &e, EXIT_IO_ERROR),
+        }
+    }
+    if file_args.summary {
+        println!("files={} bytes={} errors={} algorithm={}", files, bytes, errors, algorithm.name);
+    }
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+}
+
+fn run_manifest_create(create_args: &ManifestCreateArgs, algorithm: Option<&str>, lang: Lang) {
+    let t = lang.strings();
+    let algorithm = selected_algorithm(algorithm.or(Some("CRC-32/ISO-HDLC")), lang);
+    let base = create_args.output.as_deref().and_then(Path::parent).unwrap_or(Path::new(""));
+    let mut manifest = Manifest::new(algorithm);
+    let mut exit_code = 0;
+    let output = create_args.output.as_deref().and_then(|output| output.canonicalize().ok());
+    for entry in expand_paths(&create_args.paths, create_args.recursive, lang) {
+        if let (Ok(path), Some(output)) = (&entry, &output) {
+            if path.canonicalize().is_ok_and(|path| path == *output) {
+                continue;
+            }
+        }
+        let checksum = entry.and_then(|path| {
+            std::fs::File::open(&path)
+                .and_then(|mut file| checksum_reader(&algorithm.config, &mut file))
+                .map(|(crc, _)| (path.clone(), crc))
+                .map_err(|e| (path, e.to_string()))
+        });
+        match checksum {
+            Ok((path, crc)) => {
+                let relative = path.strip_prefix(base).unwrap_or(&path);
+                manifest.push(&relative.to_string_lossy(), crc);
+            }
+            Err((path, message)) => {
+                eprintln!("{}", lang.error(&fill(t.err_read_file, &[&path.display(), &message])));
+                exit_code = EXIT_IO_ERROR;
+            }
+        }
+    }
+
+    match &create_args.output {
+        Some(output) => {
+            if let Err(e) = std::fs::write(output, manifest.to_string()) {
+                eprintln!("{}", lang.error(&fill(t.cli_err_write_manifest, &[&output.display(), &e])));
+                std::process::exit(EXIT_IO_ERROR);
+            }
+            eprintln!("{}", fill(t.cli_manifest_written, &[&output.display(), &manifest.entries.len(), &algorithm.name]));
+        }
+        None => print!("{}", manifest),
+    }
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+}
+
+fn run_manifest_verify(verify_args: &ManifestVerifyArgs, lang: Lang) {
+    let t = lang.strings();
+    let path = &verify_args.manifest;
+    let text = std::fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&fill(t.err_read_file, &[&path.display(), &e])));
+        std::process::exit(EXIT_IO_ERROR);
+    });
+    let manifest = Manifest::parse(&text).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&lang.describe_manifest(&e)));
+        std::process::exit(EXIT_PARSE_ERROR);
+    });
+    let digits = (manifest.algorithm.config.width as usize).div_ceil(4);
+    let base = path.parent().unwrap_or(Path::new(""));
+
+    let (mut ok, mut failed, mut missing) = (0, 0, 0);
+    for (entry, status) in manifest.verify(base) {
+        match status {
+            EntryStatus::Ok => {
+                ok += 1;
+                if !verify_args.quiet {
+                    println!("{}", fill(t.cli_manifest_ok, &[&entry.path]));
+                }
+            }
+            EntryStatus::Mismatch { computed } => {
+                failed += 1;
+                println!("{}", fill(t.cli_manifest_mismatch, &[&entry.path, &format!("{:0digits$X}", entry.crc), &format!("{:0digits$X}", computed)]));
+            }
+            EntryStatus::Unreadable { message } => {
+                missing += 1;
+                println!("{}", fill(t.cli_manifest_unreadable, &[&entry.path, &message]));
+            }
+        }
+    }
+    println!("{}", fill(t.cli_manifest_summary, &[&ok, &failed, &missing, &manifest.algorithm.name]));
+    if missing > 0 {
+        std::process::exit(EXIT_IO_ERROR);
+    }
+    if failed > 0 {
+        std::process::exit(EXIT_MISMATCH);
+    }
+}
+
+fn expand_paths(patterns: &[PathBuf], recursive: bool, lang: Lang) -> Vec<Result<PathBuf, (PathBuf, String)>> {
+    let t = lang.strings();
+    let mut entries = Vec::new();
+    for pattern in patterns {
+        let text = pattern.to_string_lossy();
+        let matches: Vec<PathBuf> = if !pattern.exists() && text.contains(['*', '?', '[']) {
+            match glob::glob(&text) {
+                Ok(paths) => paths.filter_map(Result::ok).collect(),
+                Err(e) => {
+                    entries.push(Err((pattern.clone(), e.to_string())));
+                    continue;
+                }
+            }
+        } else {
+            vec![pattern.clone()]
+        };
+        if matches.is_empty() {
+            entries.push(Err((pattern.clone(), t.cli_file_no_match.to_string())));
+        }
+        for path in matches {
+            if !path.is_dir() {
+                entries.push(Ok(path));
+            } else if recursive {
+                walk_directory(&path, &mut entries);
+            } else {
+                entries.push(Err((path, t.cli_file_is_directory.to_string())));
+            }
+        }
+    }
+    entries
+}
+
+fn walk_directory(directory: &Path, entries: &mut Vec<Result<PathBuf, (PathBuf, String)>>) {
+    let mut children: Vec<PathBuf> = match std::fs::read_dir(directory) {
+        Ok(children) => children.filter_map(|child| child.ok().map(|child| child.path())).collect(),
+        Err(e) => {
+            entries.push(Err((directory.to_path_buf(), e.to_string())));
+            return;
+        }
+    };
+    children.sort();
+    for child in children {
+        if child.is_dir() {
+            walk_directory(&child, entries);
+        } else {
+            entries.push(Ok(child));
+        }
+    }
+}
+
+fn run_table(table_args: &TableArgs, algorithm: Option<&str>, lang: Lang) {
+    let config = match table_args.poly.to_config(algorithm, lang) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", lang.error(&e));
+            std::process::exit(EXIT_PARSE_ERROR);
+        }
+    };
+
+    let language = match table_args.format {
+        TableFormat::C => TableLanguage::C,
+        TableFormat::Rust => TableLanguage::Rust,
+        TableFormat::Python => TableLanguage::Python,
+    };
+
+    match format_table(&config, language, &table_args.name) {
+        Ok(source) => print!("{}", source),
+        Err(e) => {
+            eprintln!("{}", lang.error(&lang.describe_config(&e)));
+            std::process::exit(EXIT_PARSE_ERROR);
+        }
+    }
+}
+
+fn run_hdl(hdl_args: &HdlArgs, algorithm: Option<&str>, lang: Lang) {
+    let config = match hdl_args.poly.to_config(algorithm, lang) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", lang.error(&e));
+            std::process::exit(EXIT_PARSE_ERROR);
+        }
+    };
+
+    let language = match hdl_args.format {
+        HdlFormat::Verilog => HdlLanguage::Verilog,
+        HdlFormat::Vhdl => HdlLanguage::Vhdl,
+    };
+    let module = hdl_args.module.clone()
+        .unwrap_or_else(|| format!("crc{}_d{}", config.width, hdl_args.data_width));
+
+    match generate_hdl(&config, hdl_args.data_width, language, &module) {
+        Ok(source) => print!("{}", source),
+        Err(e) => {
+            eprintln!("{}", lang.error(&lang.describe_config(&e)));
+            std::process::exit(EXIT_PARSE_ERROR);
+        }
+    }
+}
+
+fn run_dbc(dbc_args: &DbcArgs, lang: Lang) {
+    let t = lang.strings();
+    let fail = |message: String| -> ! {
+        eprintln!("{}", lang.error(&message));
+        std::process::exit(EXIT_PARSE_ERROR);
+    };
+
+    let text = std::fs::read_to_string(&dbc_args.file).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&fill(t.err_read_file, &[&dbc_args.file.display(), &e])));
+        std::process::exit(EXIT_IO_ERROR);
+    });
+    let dbc = Dbc::parse(&text).unwrap_or_else(|e| fail(lang.describe_dbc(&e)));
+
+    let Some(message_name) = &dbc_args.message else {
+        println!("{}", t.cli_dbc_messages);
+        for message in &dbc.messages {
+            println!("{}", fill(t.cli_dbc_message_line, &[
+                &message.name,
+                &format!("{:X}", message.id),
+                &message.dlc,
+                &message.signals.len(),
+            ]));
+            for signal in &message.signals {
+                println!("{}", fill(t.cli_dbc_signal_line, &[&signal.name, &signal.min, &signal.max, &signal.unit]));
+            }
+        }
+        return;
+    };
+
+    let message = dbc.message(message_name).unwrap_or_else(|e| fail(lang.describe_dbc(&e)));
+    let values: Vec<(String, f64)> = dbc_args.signals.iter()
+        .map(|entry| {
+            entry.split_once('=')
+                .and_then(|(name, value)| Some((name.trim().to_string(), value.trim().parse().ok()?)))
+                .unwrap_or_else(|| fail(fill(t.cli_err_signal_format, &[entry])))
+        })
+        .collect();
+
+    let data = message.encode(&values).unwrap_or_else(|e| fail(lang.describe_dbc(&e)));
+    let frame = message.to_frame(&data).unwrap_or_else(|e| fail(lang.describe_dbc(&e)));
+    let payload = format_hex(&data);
+
+    println!("{}", fill(t.cli_frame_id, &[&format!("{:X}", frame.id), &message.name]));
+    println!("{}", fill(t.cli_frame_payload, &[&payload]));
+    println!("{}", fill(t.cli_frame_crc_bits, &[&frame.crc_bits().len()]));
+    println!("{}", fill(t.cli_frame_crc, &[&format!("{:04X}", frame.crc())]));
+}
+
+fn run_inject(inject_args: &InjectArgs, algorithm: Option<&str>, lang: Lang) {
+    let t = lang.strings();
+    let config = &selected_algorithm(algorithm, lang).config;
+    let fail = |message: String| -> ! {
+        eprintln!("{}", lang.error(&message));
+        std::process::exit(EXIT_PARSE_ERROR);
+    };
+
+    let bits = inject_args.input.parse_bits(config, lang).unwrap_or_else(|e| fail(e));
+    let pattern = inject_pattern(inject_args, bits.len() + config.width as usize, lang).unwrap_or_else(|e| fail(e));
+    let flipped = error_positions(&pattern, bits.len() + config.width as usize);
+    let result = check_flips(config, &bits, &flipped);
+    let digits = (config.width as usize).div_ceil(4);
+    let flipped = flipped.iter()
+        .map(|p| p.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    println!("{}", fill(t.cli_inject_codeword, &[&bits.len(), &config.width]));
+    println!("{}", fill(t.cli_inject_original, &[&format!("{:0digits$X}", result.original_crc)]));
+    println!("{}", fill(t.cli_inject_flipped, &[&flipped]));
+    println!("{}", fill(t.cli_inject_received, &[&format!("{:0digits$X}", result.received_crc)]));
+    println!("{}", fill(t.cli_inject_recomputed, &[&format!("{:0digits$X}", result.recomputed_crc)]));
+    println!("{}", if result.detected { t.cli_inject_detected } else { t.cli_inject_undetected });
+}
+
+fn inject_pattern(inject_args: &InjectArgs, codeword_len: usize, lang: Lang) -> Result<ErrorPattern, String> {
+    let t = lang.strings();
+    if let Some(burst) = &inject_args.burst {
+        let (start, len): (usize, usize) = burst.split_once(':')
+            .and_then(|(start, len)| Some((start.trim().parse().ok()?, len.trim().parse().ok()?)))
+            .ok_or_else(|| fill(t.cli_err_burst_format, &[burst]))?;
+        if start.checked_add(len).is_none_or(|end| end > codeword_len) {
+            return Err(fill(t.cli_err_burst_out_of_range, &[&start, &len, &codeword_len]));
+        }
+        Ok(ErrorPattern::Burst { start, len })
+    } else if let Some(flips) = inject_args.random {
+        if flips > codeword_len {
+            return Err(fill(t.cli_err_random_too_many, &[&flips, &codeword_len]));
+        }
+        Ok(ErrorPattern::Random { flips, seed: inject_args.seed })
+    } else {
+        if let Some(position) = inject_args.bits.iter().find(|&&position| position >= codeword_len) {
+            return Err(fill(t.cli_err_bit_out_of_range, &[position, &codeword_len, &(codeword_len - 1)]));
+        }
+        Ok(ErrorPattern::Bits(inject_args.bits.clone()))
+    }
+}
+
+fn run_flips(flips_args: &FlipsArgs, algorithm: Option<&str>, lang: Lang) {
+    let t = lang.strings();
+    let algorithm = selected_algorithm(algorithm, lang);
+    let config = &algorithm.config;
+    let bits = flips_args.input.parse_bits(config, lang).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&e));
+        std::process::exit(EXIT_PARSE_ERROR);
+    });
+    let digits = (config.width as usize).div_ceil(4);
+    let crc = compute_batch_checksums(config, &bits, 1);
+    println!("{}", fill(t.cli_flips_header, &[&bits.len(), &algorithm.name, &format!("{:0digits$X}", crc)]));
+
+    let mut reports = vec![verify_single_flips(config, &bits)];
+    if flips_args.pairs {
+        reports.push(verify_double_flips(config, &bits));
+    }
+
+    let mut undetected = false;
+    for report in &reports {
+        let label = if report.flips == 1 { t.cli_flips_single } else { t.cli_flips_double };
+        if report.all_detected() {
+            println!("{}", fill(t.cli_flips_detected, &[&label, &report.tested]));
+            continue;
+        }
+        undetected = true;
+        println!("{}", fill(t.cli_flips_undetected, &[&label, &report.undetected.len(), &report.tested]));
+        for pattern in report.undetected.iter().take(flips_args.show) {
+            let positions: Vec<String> = pattern.iter().map(|position| position.to_string()).collect();
+            println!("{}", fill(t.cli_flips_pattern, &[&positions.join(", ")]));
+        }
+        if report.undetected.len() > flips_args.show {
+            println!("{}", fill(t.cli_flips_more, &[&(report.undetected.len() - flips_args.show)]));
+        }
+    }
+    if undetected {
+        std::process::exit(EXIT_MISMATCH);
+    }
+}
+
+fn run_analyze(analyze_args: &AnalyzeArgs, algorithm: Option<&str>, lang: Lang) {
+    let t = lang.strings();
+    let config = analyze_args.poly.to_config(algorithm, lang).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&e));
+        std::process::exit(EXIT_PARSE_ERROR);
+    });
+    let width = config.width as usize;
+    let max_burst = analyze_args.max_burst.unwrap_or(2 * width);
+
+    println!("{}", fill(t.cli_analyze_header, &[
+        &config.width,
+        &format!("{:X}", config.poly),
+        &analyze_args.bits,
+        &width,
+    ]));
+    println!();
+
+    let mut reports = vec![
+        analyze_single_bit(&config, analyze_args.bits),
+        analyze_double_bit(&config, analyze_args.bits),
+        analyze_bursts(&config, analyze_args.bits, max_burst),
+    ];
+    for weight in 3..=analyze_args.max_weight {
+        let seed = analyze_args.seed.wrapping_add(weight as u64);
+        reports.push(analyze_random(&config, analyze_args.bits, weight, analyze_args.samples, seed));
+    }
+
+    println!("{:<32} {:>16} {:>12} {:>12}", t.cli_analyze_class, t.cli_analyze_tested, t.cli_analyze_undetected, t.cli_analyze_coverage);
+    for report in &reports {
+        let class = match report.class {
+            ErrorClass::SingleBit => t.cli_analyze_single.to_string(),
+            ErrorClass::DoubleBit => t.cli_analyze_double.to_string(),
+            ErrorClass::Burst { max_len } => fill(t.cli_analyze_burst, &[&max_len]),
+            ErrorClass::Random { weight } => fill(t.cli_analyze_random, &[&weight]),
+        };
+        println!(
+            "{:<32} {:>16} {:>12} {:>11.6}%",
+            class,
+            format_number(report.tested),
+            format_number(report.undetected),
+            report.coverage() * 100.0
+        );
+    }
+
+    let probability = undetected_error_probability(&config, analyze_args.bits, analyze_args.ber, &reports);
+    println!();
+    println!("{}", fill(t.cli_analyze_probability, &[&analyze_args.ber, &format!("{:.3e}", probability)]));
+}
+
+fn run_distribution(distribution_args: &DistributionArgs, algorithm: Option<&str>, lang: Lang) {
+    let t = lang.strings();
+    let config = distribution_args.poly.to_config(algorithm, lang).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&e));
+        std::process::exit(EXIT_PARSE_ERROR);
+    });
+    let distribution = crc_distribution(&config, distribution_args.bits, distribution_args.bucket_bits, distribution_args.samples, distribution_args.seed);
+
+    if distribution_args.csv {
+        println!("bucket,count");
+        for (bucket, count) in distribution.buckets.iter().enumerate() {
+            println!("{},{}", bucket, count);
+        }
+        return;
+    }
+    let mode = if distribution.exhaustive {
+        t.cli_distribution_exhaustive.to_string()
+    } else {
+        fill(t.cli_distribution_sampled, &[&distribution_args.seed])
+    };
+    println!("{}", fill(t.cli_distribution_header, &[
+        &config.width,
+        &format!("{:X}", config.poly),
+        &distribution.data_bits,
+        &format_number(distribution.inputs),
+        &mode,
+    ]));
+    println!();
+    println!("{}", fill(t.cli_distribution_buckets, &[
+        &format_number(distribution.buckets.len() as u64),
+        &distribution.bucket_bits,
+        &format!("{:.2}", distribution.expected()),
+    ]));
+    println!("{}", fill(t.cli_distribution_range, &[&distribution.min(), &distribution.max(), &distribution.empty_buckets()]));
+    println!("{}", fill(t.cli_distribution_collisions, &[&format_number(distribution.collisions())]));
+    println!("{}", fill(t.cli_distribution_chi_square, &[
+        &format!("{:.2}", distribution.chi_square()),
+        &distribution.degrees_of_freedom(),
+        &format!("{:.4}", distribution.p_value()),
+    ]));
+}
+
+fn run_hamming(hamming_args: &HammingArgs, algorithm: Option<&str>, lang: Lang) {
+    let t = lang.strings();
+    let config = hamming_args.poly.to_config(algorithm, lang).unwrap_or_else(|e| {
+        eprintln!("{}", lang.error(&e));
+        std::process::exit(EXIT_PARSE_ERROR);
+    });
+    let measure = |bits: usize| {
+        minimum_hamming_distance(&config, bits, hamming_args.max_weight, hamming_args.budget, hamming_args.seed)
+    };
+
+    let Some(up_to) = hamming_args.up_to else {
+        let result = measure(hamming_args.bits);
+        let method = if result.exhaustive { t.cli_hamming_exhaustive } else { t.cli_hamming_sampled };
+        match result.distance {
+            Some(distance) => println!("{}", fill(t.cli_hamming_result, &[&hamming_args.bits, &distance, &method])),
+            None => println!("{}", fill(t.cli_hamming_above, &[&hamming_args.bits, &result.checked_up_to, &method])),
+        }
+        return;
+    };
+
+    let print_range = |from: usize, to: usize, result: &HammingDistance| match result.distance {
+        Some(distance) => println!("{}", fill(t.cli_hamming_range, &[&from, &to, &distance])),
+        None => println!("{}", fill(t.cli_hamming_range_above, &[&from, &to, &result.checked_up_to])),
+    };
+
+    let mut range_start = 1;
+    let mut current = measure(1);
+    for bits in 2..=up_to {
+        let result = measure(bits);
+        if result.distance != current.distance {
+            print_range(range_start, bits - 1, &current);
+            range_start = bits;
+            current = result;
+        }
+    }
+    print_range(range_start, up_to, &current);
+}
+
+fn run_recover(recover_args: &RecoverArgs, lang: Lang) {
+    let t = lang.strings();
+    let fail = |message: String| -> ! {
+        eprintln!("{}", lang.error(&message));
+        std::process::exit(EXIT_PARSE_ERROR);
+    };
+
+    if recover_args.pairs.is_empty() {
+        fail(t.cli_err_pairs_missing.to_string());
+    }
+    let poly = recover_args.poly.as_deref()
+        .map(|p| parse_param(p).unwrap_or_else(|e| fail(lang.describe_config(&e))));
+    CrcConfig::new(recover_args.width, 1)
+        .validate()
+        .unwrap_or_else(|e| fail(lang.describe_config(&e)));
+    if poly.is_none() && recover_args.width > MAX_POLY_SEARCH_WIDTH {
+        fail(fill(t.cli_recover_too_wide, &[&MAX_POLY_SEARCH_WIDTH]));
+    }
+
+    let samples: Vec<Sample> = recover_args.pairs.iter()
+        .map(|entry| {
+            let (data, crc) = entry.rsplit_once(':')
+                .unwrap_or_else(|| fail(fill(t.cli_err_pair_format, &[entry])));
+            let data = parse_hex_bytes(data).unwrap_or_else(|e| fail(InputFormat::Hex.describe(data, &e, lang)));
+            let crc = parse_param(&format!("0x{}", crc.trim().trim_start_matches("0x")))
+                .unwrap_or_else(|e| fail(lang.describe_config(&e)));
+            Sample { data, crc }
+        })
+        .collect();
+
+    println!("{}", fill(t.cli_recover_searching, &[&recover_args.width, &samples.len()]));
+    let start = Instant::now();
+    let options = RecoverOptions { poly, max_results: recover_args.max_results };
+    let found = recover(recover_args.width, &samples, &options);
+    let duration = start.elapsed();
+
+    if found.is_empty() {
+        fail(fill(t.cli_recover_none, &[&recover_args.width]));
+    }
+
+    let digits = (recover_args.width as usize).div_ceil(4);
+    for config in &found {
+        println!("{}", fill(t.cli_recover_match, &[
+            &config.width,
+            &format!("{:0digits$X}", config.poly),
+            &format!("{:0digits$X}", config.init),
+            &config.refin,
+            &config.refout,
+            &format!("{:0digits$X}", config.xorout),
+        ]));
+    }
+    println!("{}", fill(t.cli_recover_summary, &[&found.len(), &format!("{:.2?}", duration)]));
+    if found.len() > 1 {
+        println!("{}", t.cli_recover_ambiguous);
+    }
+}
+
+fn run_preimage(preimage_args: &PreimageArgs, algorithm: Option<&str>, lang: Lang) {
+    let t = lang.strings();
+    let fail = |message: String| -> ! {
+        eprintln!("{}", lang.error(&message));
+        std::process::exit(EXIT_PARSE_ERROR);
+    };
+
+    let config = preimage_args.poly.to_config(algorithm, lang).unwrap_or_else(|e| fail(e));
+    let order = BitOrder::from(preimage_args.bit_order);
+    let options = ParseOptions { bit_order: order, ..ParseOptions::for_config(&config) };
+    let parse = |input: &str| preimage_args.format.parse_described(input, &options, lang).unwrap_or_else(|e| fail(e));
+
+    let prefix = preimage_args.prefix.as_deref().map(parse).unwrap_or_default();
+    let collide = preimage_args.collide.as_deref().map(parse);
+    let bits = preimage_args.bits
+        .or(collide.as_ref().map(|data| data.len()))
+        .unwrap_or(64);
+    if prefix.len() > bits {
+        fail(fill(t.cli_err_prefix_too_long, &[&prefix.len(), &bits]));
+    }
+
+    let target = match (&collide, &preimage_args.target) {
+        (Some(data), _) => config.checksum_message(data),
+        (None, Some(target)) => parse_param(&format!("0x{}", target.trim().trim_start_matches("0x")))
+            .unwrap_or_else(|e| fail(lang.describe_config(&e))),
+        (None, None) => unreachable!(),
+    };
+    let digits = (config.width as usize).div_ceil(4);
+
+    println!("{}", fill(t.cli_preimage_header, &[&bits, &format!("{:0digits$X}", target), &prefix.len()]));
+    let start = Instant::now();
+    let found = match &collide {
+        Some(data) => {
+            let mut data = data.clone();
+            data.resize(bits, false);
+            data[..prefix.len()].copy_from_slice(&prefix);
+            find_collisions(&config, &data, prefix.len(), preimage_args.count, preimage_args.seed)
+        }
+        None => find_preimages(&config, &prefix, bits, target, preimage_args.count, preimage_args.seed),
+    };
+    let duration = start.elapsed();
+
+    if found.is_empty() {
+        fail(t.cli_preimage_none.to_string());
+    }
+    for message in &found {
+        let text = format_message(&preimage_args.format, message, order);
+        println!("{}", fill(t.cli_preimage_line, &[&text, &format!("{:0digits$X}", config.checksum_message(message))]));
+    }
+    println!("{}", fill(t.cli_preimage_summary, &[&found.len(), &format!("{:.2?}", duration)]));
+}
+
+#[cfg(feature = "test-vectors")]
+fn run_self_test(lang: Lang) {
+    let t = lang.strings();
+    let results = can_crc_core::test_vectors::self_test();
+
+    for result in &results {
+        let vector = result.vector;
+        if result.passed() {
+            println!("{}", fill(t.cli_selftest_pass, &[&vector.name, &format!("{:04X}", vector.expected), &vector.source]));
+        } else {
+            println!("{}", fill(t.cli_selftest_fail, &[
+                &vector.name,
+                &format!("{:04X}", vector.expected),
+                &format!("{:04X}", result.actual),
+                &vector.source,
+            ]));
+        }
+    }
+
+    let passed = results.iter().filter(|r| r.passed()).count();
+    println!("{}", fill(t.cli_selftest_summary, &[&passed, &results.len()]));
+    if passed != results.len() {
+        std::process::exit(EXIT_MISMATCH);
+    }
+}
+
+fn run_interactive(algorithm: Option<&str>, verbose: bool, cross_check: bool, lang: Lang) {
+    let t = lang.strings();
+    let algorithm = selected_algorithm(algorithm, lang);
+    if verbose {
+        println!("{}", fill(t.cli_algorithm_used, &[&algorithm.name]));
+    }
+
+    let mut reader = LineReader::new();
+    loop {
+        println!("{}", t.cli_prompt_format);
+        let format_input = match reader.read_line() {
+            Ok(Some(line)) => line,
+            Ok(None) => return,
+            Err(_) => {
+                eprintln!("{}", lang.error(t.cli_err_read_format));
+                continue;
+            }
+        };
+
+        let format = match format_input.trim().to_lowercase().as_str() {
+            "hex" => InputFormat::Hex,
+            "bin" => InputFormat::Binary,
+            "b64" | "base64" => InputFormat::Base64,
+            "esc" | "escaped" => InputFormat::Escaped,
+            "exit" => return,
+            _ => {
+                eprintln!("{}", lang.error(t.cli_err_invalid_format));
+                continue;
+            }
+        };
+
+        println!("{}", t.cli_prompt_data);
+        let data_input = match reader.read_line() {
+            Ok(Some(line)) => line,
+            Ok(None) => return,
+            Err(_) => {
+                eprintln!("{}", lang.error(t.cli_err_read_data));
+                continue;
+            }
+        };
+        let data_input = data_input.trim();
+
+        println!("{}", t.cli_prompt_iterations);
+        let iterations_input = match reader.read_line() {
+            Ok(Some(line)) => line,
+            Ok(None) => return,
+            Err(_) => {
+                eprintln!("{}", lang.error(t.cli_err_read_iterations));
+                continue;
+            }
+        };
+        let iterations: u64 = match iterations_input.trim().parse() {
+            Ok(n) if (1..=1_000_000_000).contains(&n) => n,
+            _ => {
+                eprintln!("{}", lang.error(t.cli_err_iterations_range));
+                continue;
+            }
+        };
+
+        let options = ParseOptions::for_config(&algorithm.config);
+        let bits = match format.parse_described(data_input, &options, lang) {
+            Ok(bits) => bits,
+            Err(e) => {
+                eprintln!("{}", lang.error(&e));
+                eprintln!("{}", format.hint(lang));
+                continue;
+            }
+        };
+
+        if bits.is_empty() {
+            eprintln!("{}", lang.error(t.cli_err_no_input));
+            continue;
+        }
+
+        if cross_check {
+            print_cross_check(&cross_check_or_exit(&algorithm.config, &bits, lang), lang);
+        }
+        print_calculation(algorithm, &format, data_input, &bits, &BenchPlan::single(iterations), verbose, lang);
+    }
+}
+
+#[cfg(feature = "readline")]
+struct LineReader {
+    editor: Option<rustyline::DefaultEditor>,
+    history: Option<PathBuf>,
+}
+
+#[cfg(feature = "readline")]
+impl LineReader {
+    fn new() -> Self {
+        let config = rustyline::Config::builder()
+            .auto_add_history(true)
+            .max_history_size(HISTORY_SIZE)
+            .map(|builder| builder.build())
+            .unwrap_or_default();
+        let history = history_path();
+        let editor = rustyline::DefaultEditor::with_config(config).ok().map(|mut editor| {
+            if let Some(path) = &history {
+                let _ = editor.load_history(path);
+            }
+            editor
+        });
+        Self { editor, history }
+    }
+
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        use rustyline::error::ReadlineError;
+
+        let Some(editor) = &mut self.editor else {
+            return read_stdin_line();
+        };
+        match editor.readline(REPL_PROMPT) {
+            Ok(line) => Ok(Some(line)),
+            Err(ReadlineError::Eof | ReadlineError::Interrupted) => Ok(None),
+            Err(ReadlineError::Io(e)) => Err(e),
+            Err(e) => Err(io::Error::other(e)),
+        }
+    }
+}
+
+#[cfg(feature = "readline")]
+impl Drop for LineReader {
+    fn drop(&mut self) {
+        if let (Some(editor), Some(path)) = (&mut self.editor, &self.history) {
+            if let Some(dir) = path.parent() {
+                let _ = std::fs::create_dir_all(dir);
+            }
+            let _ = editor.save_history(path);
+        }
+    }
+}
+
+#[cfg(feature = "readline")]
+fn history_path() -> Option<PathBuf> {
+    std::env::var_os("CAN_CRC_HISTORY")
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .or_else(|| std::env::var_os("USERPROFILE"))
+                .map(|home| PathBuf::from(home).join(".config").join("can-crc").join("history"))
+        })
+}
+
+#[cfg(not(feature = "readline"))]
+struct LineReader;
+
+#[cfg(not(feature = "readline"))]
+impl LineReader {
+    fn new() -> Self {
+        LineReader
+    }
+
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        read_stdin_line()
+    }
+}
+
+fn read_stdin_line() -> io::Result<Option<String>> {
+    let mut line = String::new();
+    match io::stdin().read_line(&mut line)? {
+        0 => Ok(None),
+        _ => Ok(Some(line)),
+    }
+}
+
+fn format_message(format: &InputFormat, bits: &[bool], order: BitOrder) -> String {
+    let mut ordered = bits.to_vec();
+    order.reorder_bits(&mut ordered);
+    match format {
+        InputFormat::Hex if bits.len().is_multiple_of(8) => format_hex(&bits_to_bytes(&ordered)),
+        InputFormat::Base64 if bits.len().is_multiple_of(8) => format_base64(&bits_to_bytes(&ordered)),
+        InputFormat::Escaped if bits.len().is_multiple_of(8) => format_escaped(&bits_to_bytes(&ordered)),
+        _ => format_binary(&ordered),
+    }
+}
+
+fn cross_check_or_exit(config: &CrcConfig, bits: &[bool], lang: Lang) -> CrossCheck {
+    let t = lang.strings();
+    let check = cross_check(config, bits);
+    if !check.agrees() {
+        let digits = (config.width as usize).div_ceil(4);
+        eprintln!("{}", t.cli_cross_check_failed);
+        for result in &check.results {
+            eprintln!("{}", fill(t.cli_cross_check_line, &[&format!("{:<12}", result.name), &format!("{:0digits$X}", result.crc)]));
+        }
+        std::process::exit(EXIT_CROSS_CHECK);
+    }
+    check
+}
+
+fn print_cross_check(check: &CrossCheck, lang: Lang) {
+    let names: Vec<&str> = check.results.iter().map(|result| result.name).collect();
+    println!("{}", fill(lang.strings().cli_cross_check_ok, &[&names.len(), &names.join(", ")]));
+}
+
+fn print_calculation(
+    algorithm: &CrcAlgorithm,
+    format: &InputFormat,
+    data_input: &str,
+    bits: &[bool],
+    plan: &BenchPlan,
+    verbose: bool,
+    lang: Lang,
+) -> CrcResult {
+    let t = lang.strings();
+    let iterations = plan.iterations;
+    if verbose {
+        println!("\n╔══════════════════════════════════════╗");
+        println!("║ {:^36} ║", t.app_title);
+        println!("╚══════════════════════════════════════╝");
+        println!("{}", fill(t.cli_input_format, &[&format!("{:?}", format)]));
+        println!("{}", fill(t.cli_input_data, &[&data_input]));
+        println!("{}", fill(t.cli_bit_count, &[&bits.len()]));
+        println!("{}", fill(t.cli_iteration_count, &[&format_number(iterations)]));
+        println!();
+    }
+
+    if verbose && iterations >= PARALLEL_THRESHOLD {
+        println!("{}", fill(t.cli_parallel_notice, &[&iterations, &current_num_threads()]));
+    }
+
+    let result = if plan.is_repeated() {
+        let (crc_value, timing) = measure(plan, |iterations| compute_batch_checksums(&algorithm.config, bits, iterations));
+        CrcResult::for_algorithm(algorithm, crc_value, 0.0).with_timing(timing)
+    } else {
+        let start = Instant::now();
+        let crc_value = compute_batch_checksums(&algorithm.config, bits, iterations);
+        CrcResult::for_algorithm(algorithm, crc_value, start.elapsed().as_secs_f64() * 1000.0)
+    }
+    .with_input(bits.len(), iterations);
+
+    println!("{}", t.cli_results);
+    println!("═══════════════════════════════════════");
+    println!("{}", fill(t.cli_crc_hex, &[&result.crc_hex]));
+    println!("{}", fill(t.cli_crc_dec, &[&result.crc_value]));
+    println!("{}", fill(t.cli_crc_bin, &[&result.crc_bin()]));
+
+    println!("{}", t.cli_performance);
+    println!("═══════════════════════════════════════");
+    match &result.timing {
+        Some(timing) => {
+            println!("{}", fill(t.cli_bench_runs, &[&timing.repetitions, &timing.warmup, &format_number(iterations)]));
+            println!("{}", fill(t.cli_bench_min, &[&format!("{:.3}", timing.min_ms)]));
+            println!("{}", fill(t.cli_bench_median, &[&format!("{:.3}", timing.median_ms)]));
+            println!("{}", fill(t.cli_bench_p95, &[&format!("{:.3}", timing.p95_ms)]));
+            println!("{}", fill(t.cli_bench_stddev, &[&format!("{:.3}", timing.stddev_ms), &format!("{:.1}", timing.relative_stddev())]));
+        }
+        None => println!("{}", fill(t.cli_total_time, &[&format!("{:.3}", result.duration_ms)])),
+    }
+
+    if iterations > 1 {
+        let avg_time = result.duration_ms / iterations as f64;
+        println!("{}", fill(t.cli_avg_time_ms, &[&format!("{:.6}", avg_time)]));
+        println!("{}", fill(t.cli_avg_time_us, &[&format!("{:.3}", avg_time * 1000.0)]));
+
+        let ops_per_sec = (iterations as f64 / result.duration_ms) * 1000.0;
+        println!("{}", fill(t.cli_throughput, &[&format_number(ops_per_sec as u64)]));
+    }
+    if result.timing.is_some_and(|timing| timing.is_noisy()) {
+        println!("{}", t.cli_bench_noisy);
+    }
+
+    if verbose && iterations >= PARALLEL_THRESHOLD {
+        println!("{}", t.cli_parallel_used);
+    }
+    result
+}
+
+fn print_trace(config: &CrcConfig, bits: &[bool], lang: Lang) {
+    let t = lang.strings();
+    let digits = (config.width as usize).div_ceil(4);
+    let width = config.width as usize;
+    let steps = config.trace(bits);
+
+    println!("{}", t.cli_explain_header);
+    for (position, step) in steps.iter().enumerate() {
+        println!(
+            "{:>6}  {:>6}  {:>3}  {:>6}  0x{:0digits$X}  {:0width$b}",
+            position, step.index, step.bit as u8, step.feedback as u8, step.register, step.register,
+        );
+        if (position + 1) % 8 == 0 {
+            println!("{}", fill(t.cli_explain_byte, &[&((position + 1) / 8), &format!("{:0digits$X}", step.register)]));
+        }
+    }
+    println!("{}", fill(t.cli_explain_final, &[&format!("{:0digits$X}", config.finish_trace(&steps))]));
+    println!();
+}
+
+fn format_number(num: u64) -> String {
+    let s = num.to_string();
+    let mut result = String::new();
+    
+    for (count, ch) in s.chars().rev().enumerate() {
+        if count > 0 && count % 3 == 0 {
+            result.push(' ');
+        }
+        result.push(ch);
+    }
+    
+    result.chars().rev().collect()
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "async")]
+    use can_crc_core::{calculate_can_crc, parse_hex_input};
+    use proptest::prelude::*;
+
+    fn inject_args(args: &[&str]) -> Result<InjectArgs, clap::Error> {
+        let args = Args::try_parse_from(["cli", "inject", "--data", "10101100", "-f", "bin"].iter().copied().chain(args.iter().copied()))?;
+        match args.command {
+            Some(Command::Inject(inject_args)) => Ok(inject_args),
+            command => panic!("unexpected command {:?}", command),
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+        #[test]
+        fn inject_rejects_combined_patterns(bit in 0usize..23, start in 0usize..19, flips in 1usize..23) {
+            let bit = bit.to_string();
+            let burst = format!("{}:4", start);
+            let flips = flips.to_string();
+            for args in [
+                vec!["--bit", &bit, "--burst", &burst],
+                vec!["--bit", &bit, "--random", &flips],
+                vec!["--burst", &burst, "--random", &flips],
+                vec!["--bit", &bit, "--burst", &burst, "--random", &flips],
+            ] {
+                let error = inject_args(&args).unwrap_err();
+                prop_assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+            }
+        }
+
+        #[test]
+        fn inject_rejects_patterns_outside_the_codeword(position in 0usize..2000, len in 1usize..30, flips in 0usize..50) {
+            let codeword_len = 8 + CrcConfig::CAN.width as usize;
+            let bits = inject_args(&["--bit", "0", "--bit", &position.to_string()]).unwrap();
+            prop_assert_eq!(inject_pattern(&bits, codeword_len, Lang::En).is_ok(), position < codeword_len);
+            let burst = inject_args(&["--burst", &format!("{}:{}", position, len)]).unwrap();
+            prop_assert_eq!(inject_pattern(&burst, codeword_len, Lang::En).is_ok(), position + len <= codeword_len);
+            let random = inject_args(&["--random", &flips.to_string()]).unwrap();
+            prop_assert_eq!(inject_pattern(&random, codeword_len, Lang::En).is_ok(), flips <= codeword_len);
+        }
+
+        #[cfg(feature = "async")]
+        #[test]
+        fn serve_lines_answers_every_line(bytes in prop::collection::vec(any::<u8>(), 0..11)) {
+            use tokio::io::AsyncReadExt;
+
+            let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+            let hex = format_hex(&[&[0xA5], &bytes[..]].concat());
+            let replies = runtime.block_on(async {
+                let (mut client, server) = tokio::io::duplex(1 << 16);
+                client.write_all(format!("{}\nzz\n", hex).as_bytes()).await.unwrap();
+                client.shutdown().await.unwrap();
+                let metrics = Metrics::new();
+                serve_lines(server, &CrcConfig::CAN, &metrics, parse_hex_input).await.unwrap();
+                let mut replies = String::new();
+                client.read_to_string(&mut replies).await.unwrap();
+                (replies, metrics.frames(), metrics.errors())
+            });
+            let crc = calculate_can_crc(&parse_hex_input(&hex).unwrap());
+            let error = parse_hex_input("zz").unwrap_err();
+            prop_assert_eq!(replies, (format!("{:04X}\nERR {} {}\n", crc, error.code(), error), 1, 1));
+        }
+    }
+}
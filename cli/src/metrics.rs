@@ -0,0 +1,156 @@
+use std::fmt::Write as _;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const METRICS_PATH: &str = "/metrics";
+pub const LATENCY_BUCKETS: [f64; 10] = [0.000_001, 0.000_005, 0.000_01, 0.000_05, 0.000_1, 0.000_5, 0.001, 0.005, 0.01, 0.1];
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Default)]
+pub struct Metrics {
+    frames: AtomicU64,
+    mismatches: AtomicU64,
+    errors: AtomicU64,
+    bytes: AtomicU64,
+    latency_buckets: [AtomicU64; LATENCY_BUCKETS.len()],
+    latency_count: AtomicU64,
+    latency_sum_nanos: AtomicU64,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_frame(&self, bytes: usize, latency: Duration) {
+        self.frames.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+        let seconds = latency.as_secs_f64();
+        if let Some(bucket) = LATENCY_BUCKETS.iter().position(|&bound| seconds <= bound) {
+            self.latency_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        }
+        self.latency_count.fetch_add(1, Ordering::Relaxed);
+        self.latency_sum_nanos.fetch_add(latency.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    pub fn record_mismatch(&self) {
+        self.mismatches.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn frames(&self) -> u64 {
+        self.frames.load(Ordering::Relaxed)
+    }
+
+    pub fn mismatches(&self) -> u64 {
+        self.mismatches.load(Ordering::Relaxed)
+    }
+
+    pub fn errors(&self) -> u64 {
+        self.errors.load(Ordering::Relaxed)
+    }
+
+    pub fn bytes(&self) -> u64 {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let counters = [
+            ("can_crc_frames_total", "Frames whose CRC was computed", self.frames()),
+            ("can_crc_mismatches_total", "Frames whose received CRC did not match", self.mismatches()),
+            ("can_crc_errors_total", "Input lines that could not be parsed", self.errors()),
+            ("can_crc_bytes_total", "Message bytes processed", self.bytes()),
+        ];
+        for (name, help, value) in counters {
+            let _ = writeln!(out, "# HELP {} {}\n# TYPE {} counter\n{} {}", name, help, name, name, value);
+        }
+
+        let name = "can_crc_latency_seconds";
+        let _ = writeln!(out, "# HELP {} Time to parse a frame and compute its CRC\n# TYPE {} histogram", name, name);
+        let mut cumulative = 0;
+        for (bound, bucket) in LATENCY_BUCKETS.iter().zip(&self.latency_buckets) {
+            cumulative += bucket.load(Ordering::Relaxed);
+            let _ = writeln!(out, "{}_bucket{{le=\"{}\"}} {}", name, bound, cumulative);
+        }
+        let count = self.latency_count.load(Ordering::Relaxed);
+        let sum = self.latency_sum_nanos.load(Ordering::Relaxed) as f64 / 1e9;
+        let _ = writeln!(out, "{}_bucket{{le=\"+Inf\"}} {}\n{}_sum {}\n{}_count {}", name, count, name, sum, name, count);
+        out
+    }
+}
+
+pub fn serve_metrics(listener: TcpListener, metrics: Arc<Metrics>) {
+    for stream in listener.incoming().flatten() {
+        let metrics = Arc::clone(&metrics);
+        std::thread::spawn(move || {
+            if let Err(e) = answer_scrape(stream, &metrics) {
+                tracing::debug!(error = %e, "metrics request failed");
+            }
+        });
+    }
+}
+
+fn answer_scrape(stream: TcpStream, metrics: &Metrics) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request = String::new();
+    reader.read_line(&mut request)?;
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && !header.trim().is_empty() {
+        header.clear();
+    }
+
+    let mut parts = request.split_whitespace();
+    let (status, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some(path)) if path.split('?').next() == Some(METRICS_PATH) => ("200 OK", metrics.render()),
+        (Some("GET"), Some(_)) => ("404 Not Found", "not found\n".to_string()),
+        _ => ("405 Method Not Allowed", "method not allowed\n".to_string()),
+    };
+    let mut writer = stream;
+    write!(
+        writer,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        CONTENT_TYPE,
+        body.len(),
+        body
+    )?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn metrics_histogram_counts_every_frame(frames in prop::collection::vec((0usize..64, 0u64..200_000_000), 0..50)) {
+            let metrics = Metrics::new();
+            for &(bytes, nanos) in &frames {
+                metrics.record_frame(bytes, Duration::from_nanos(nanos));
+            }
+            prop_assert_eq!(metrics.frames(), frames.len() as u64);
+            prop_assert_eq!(metrics.bytes(), frames.iter().map(|&(bytes, _)| bytes as u64).sum::<u64>());
+
+            let text = metrics.render();
+            let buckets: Vec<u64> = text.lines()
+                .filter(|line| line.starts_with("can_crc_latency_seconds_bucket"))
+                .map(|line| line.rsplit(' ').next().unwrap().parse().unwrap())
+                .collect();
+            prop_assert_eq!(buckets.len(), LATENCY_BUCKETS.len() + 1);
+            prop_assert!(buckets.windows(2).all(|pair| pair[0] <= pair[1]));
+            prop_assert_eq!(buckets.last().copied(), Some(frames.len() as u64));
+            let within_last_bound = frames.iter().filter(|&&(_, nanos)| nanos <= 100_000_000).count() as u64;
+            prop_assert_eq!(buckets[LATENCY_BUCKETS.len() - 1], within_last_bound);
+        }
+    }
+}
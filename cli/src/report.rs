@@ -0,0 +1,233 @@
+use crate::i18n::Lang;
+use can_crc_core::{current_num_threads, CrcAlgorithm, CrcResult};
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const FEATURES: [(&str, bool); 8] = [
+    ("parallel", cfg!(feature = "parallel")),
+    ("serde", cfg!(feature = "serde")),
+    ("test-vectors", cfg!(feature = "test-vectors")),
+    ("config", cfg!(feature = "config")),
+    ("mqtt", cfg!(feature = "mqtt")),
+    ("socketcan", cfg!(feature = "socketcan")),
+    ("serial", cfg!(feature = "serial")),
+    ("watch", cfg!(feature = "watch")),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("html" | "htm") => ReportFormat::Html,
+            _ => ReportFormat::Markdown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Environment {
+    pub version: &'static str,
+    pub os: &'static str,
+    pub arch: &'static str,
+    pub cpus: usize,
+    pub threads: usize,
+    pub release: bool,
+    pub generated: u64,
+}
+
+impl Environment {
+    pub fn current() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            os: std::env::consts::OS,
+            arch: std::env::consts::ARCH,
+            cpus: std::thread::available_parallelism().map_or(1, |cpus| cpus.get()),
+            threads: current_num_threads(),
+            release: !cfg!(debug_assertions),
+            generated: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CalculationReport<'a> {
+    pub algorithm: &'a CrcAlgorithm,
+    pub input_format: &'a str,
+    pub input: &'a str,
+    pub bits: usize,
+    pub iterations: u64,
+    pub result: &'a CrcResult,
+    pub environment: Environment,
+}
+
+impl CalculationReport<'_> {
+    pub fn render(&self, format: ReportFormat, lang: Lang) -> String {
+        let t = lang.strings();
+        let sections = self.sections(lang);
+        match format {
+            ReportFormat::Markdown => {
+                let mut out = format!("# {}\n", t.report_title);
+                for (heading, rows) in sections {
+                    let _ = write!(out, "\n## {}\n\n| | {} |\n|---|---|\n", heading, t.report_value);
+                    for (key, value) in rows {
+                        let _ = writeln!(out, "| {} | {} |", key, value.replace('|', "\\|"));
+                    }
+                }
+                out
+            }
+            ReportFormat::Html => {
+                let mut out = format!(
+                    "<!DOCTYPE html>\n<html lang=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+                     <style>body{{font-family:sans-serif}}table{{border-collapse:collapse}}th,td{{border:1px solid #999;padding:4px 8px;text-align:left}}td{{font-family:monospace}}</style>\n\
+                     </head>\n<body>\n<h1>{}</h1>\n",
+                    lang.code(), t.report_title, t.report_title
+                );
+                for (heading, rows) in sections {
+                    let _ = writeln!(out, "<h2>{}</h2>\n<table>", escape_html(heading));
+                    for (key, value) in rows {
+                        let _ = writeln!(out, "<tr><th>{}</th><td>{}</td></tr>", escape_html(key), escape_html(&value));
+                    }
+                    out.push_str("</table>\n");
+                }
+                out.push_str("</body>\n</html>\n");
+                out
+            }
+        }
+    }
+
+    fn sections(&self, lang: Lang) -> Vec<(&'static str, Vec<(&'static str, String)>)> {
+        let t = lang.strings();
+        let config = &self.algorithm.config;
+        let result = self.result;
+        let digits = (config.width as usize).div_ceil(4);
+        let hex = |value: u64| format!("0x{:0digits$X}", value);
+
+        let mut timing = match &result.timing {
+            Some(stats) => vec![
+                (t.report_warmup, stats.warmup.to_string()),
+                (t.report_repetitions, stats.repetitions.to_string()),
+                (t.report_min_time, format!("{:.3}", stats.min_ms)),
+                (t.report_median_time, format!("{:.3}", stats.median_ms)),
+                (t.report_p95_time, format!("{:.3}", stats.p95_ms)),
+                (t.report_max_time, format!("{:.3}", stats.max_ms)),
+                (t.report_stddev, format!("{:.3} ({:.1}%)", stats.stddev_ms, stats.relative_stddev())),
+            ],
+            None => vec![(t.report_total_time, format!("{:.3}", result.duration_ms))],
+        };
+        if self.iterations > 1 {
+            let average_ms = result.duration_ms / self.iterations as f64;
+            timing.push((t.report_avg_time, format!("{:.3}", average_ms * 1000.0)));
+            if result.duration_ms > 0.0 {
+                timing.push((t.report_throughput, format!("{:.0}", self.iterations as f64 / result.duration_ms * 1000.0)));
+            }
+        }
+
+        let environment = &self.environment;
+        let features: Vec<&str> = FEATURES.iter().filter(|(_, enabled)| *enabled).map(|(name, _)| *name).collect();
+        vec![
+            (t.report_input, vec![
+                (t.report_input_format, self.input_format.to_string()),
+                (t.report_data, self.input.to_string()),
+                (t.report_bit_count, self.bits.to_string()),
+                (t.report_iterations, self.iterations.to_string()),
+                (t.report_path, result.implementation.to_string()),
+            ]),
+            (t.report_parameters, vec![
+                (t.report_algorithm, self.algorithm.name.to_string()),
+                ("width", config.width.to_string()),
+                ("poly", hex(config.poly)),
+                ("init", hex(config.init)),
+                ("refin", config.refin.to_string()),
+                ("refout", config.refout.to_string()),
+                ("xorout", hex(config.xorout)),
+                ("check", hex(self.algorithm.check)),
+                ("residue", hex(self.algorithm.residue)),
+            ]),
+            (t.report_result, vec![
+                ("CRC (hex)", hex(result.crc_value)),
+                ("CRC (dec)", result.crc_value.to_string()),
+                ("CRC (bin)", result.crc_bin()),
+            ]),
+            (t.report_timing, timing),
+            (t.report_environment, vec![
+                (t.report_generated, format_utc(environment.generated)),
+                (t.report_version, format!("can-crc-cli {}", environment.version)),
+                (t.report_platform, format!("{} / {}", environment.os, environment.arch)),
+                (t.report_cpus, environment.cpus.to_string()),
+                (t.report_threads, environment.threads.to_string()),
+                (t.report_build, if environment.release { "release" } else { "debug" }.to_string()),
+                (t.report_features, features.join(", ")),
+            ]),
+        ]
+    }
+}
+
+pub fn format_utc(seconds: u64) -> String {
+    let days = (seconds / 86_400) as i64;
+    let time = seconds % 86_400;
+    let era = (days + 719_468).div_euclid(146_097);
+    let day_of_era = (days + 719_468).rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year, month, day, time / 3600, time / 60 % 60, time % 60
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use can_crc_core::{bytes_to_bits, calculate_can_crc, format_hex, CrcCatalog};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn report_shows_crc_in_every_radix(data in prop::collection::vec(any::<u8>(), 1..64), day in 0u64..200_000, second in 0u64..86_400) {
+            let algorithm = CrcCatalog::can();
+            let bits = bytes_to_bits(&data);
+            let crc = calculate_can_crc(&bits);
+            let result = CrcResult::new(crc, 0.5);
+            let report = CalculationReport {
+                algorithm,
+                input_format: "Hex",
+                input: &format_hex(&data),
+                bits: bits.len(),
+                iterations: 1,
+                result: &result,
+                environment: Environment::current(),
+            };
+            let markdown = report.render(ReportFormat::Markdown, Lang::En);
+            let rows = [
+                format!("| CRC (hex) | 0x{:04X} |", crc),
+                format!("| CRC (dec) | {} |", crc),
+                format!("| CRC (bin) | {:015b} |", crc),
+            ];
+            for row in rows {
+                prop_assert!(markdown.contains(&row), "missing {}", row);
+            }
+
+            let today = format_utc(day * 86_400 + second);
+            let tomorrow = format_utc((day + 1) * 86_400 + second);
+            prop_assert!(today < tomorrow);
+            let time = format!("{:02}:{:02}:{:02} UTC", second / 3600, second / 60 % 60, second % 60);
+            prop_assert!(today.ends_with(&time));
+            prop_assert_eq!(&today[10..], &tomorrow[10..]);
+            prop_assert_eq!(format_utc(951_782_400), "2000-02-29 00:00:00 UTC");
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::i18n::{fill, Lang};
+use serde::Deserialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+pub const CONFIG_ENV: &str = "CAN_CRC_CONFIG";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    pub lang: Option<String>,
+    pub algorithm: Option<String>,
+    pub format: Option<String>,
+    pub radix: Option<String>,
+    pub threads: Option<usize>,
+    pub serial: SerialSettings,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SerialSettings {
+    pub port: Option<PathBuf>,
+}
+
+impl Settings {
+    pub fn parse(text: &str, path: &Path) -> Result<Self, SettingsError> {
+        toml::from_str(text).map_err(|e| SettingsError::Syntax {
+            path: path.display().to_string(),
+            message: e.message().to_string(),
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, SettingsError> {
+        let text = std::fs::read_to_string(path).map_err(|e| SettingsError::Io {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        Self::parse(&text, path)
+    }
+
+    pub fn load_default() -> Result<Self, SettingsError> {
+        match default_path() {
+            Some(path) if path.exists() || std::env::var_os(CONFIG_ENV).is_some() => Self::load(&path),
+            _ => Ok(Self::default()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingsError {
+    Io { path: String, message: String },
+    Syntax { path: String, message: String },
+}
+
+impl SettingsError {
+    pub fn describe(&self, lang: Lang) -> String {
+        let t = lang.strings();
+        match self {
+            SettingsError::Io { path, message } => fill(t.err_settings_io, &[path, message]),
+            SettingsError::Syntax { path, message } => fill(t.err_settings_syntax, &[path, message]),
+        }
+    }
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettingsError::Io { path, message } => write!(f, "cannot read config file {}: {}", path, message),
+            SettingsError::Syntax { path, message } => write!(f, "invalid config file {}: {}", path, message),
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {}
+
+pub fn default_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(CONFIG_ENV) {
+        return Some(PathBuf::from(path));
+    }
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .or_else(|| std::env::var_os("USERPROFILE"))
+                .map(|home| PathBuf::from(home).join(".config"))
+        })?;
+    Some(config_dir.join("can-crc").join("config.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use can_crc_core::CrcCatalog;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn settings_file_round_trips(index in 0..CrcCatalog::all().len(), threads in 1usize..256, port in "[a-zA-Z0-9/_]{1,20}") {
+            let algorithm = CrcCatalog::all()[index].name;
+            let text = format!("algorithm = \"{}\"\nthreads = {}\n\n[serial]\nport = \"{}\"\n", algorithm, threads, port);
+            let settings = Settings::parse(&text, Path::new("config.toml")).unwrap();
+            prop_assert_eq!(settings.algorithm.as_deref(), Some(algorithm));
+            prop_assert_eq!(settings.threads, Some(threads));
+            prop_assert_eq!(settings.serial.port, Some(PathBuf::from(port)));
+            prop_assert_eq!(settings.lang, None);
+            prop_assert!(Settings::parse("thread = 4\n", Path::new("config.toml")).is_err());
+        }
+    }
+}
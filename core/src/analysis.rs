@@ -0,0 +1,289 @@
+use crate::engine::CrcConfig;
+use crate::par::*;
+use crate::rng::SplitMix64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    SingleBit,
+    DoubleBit,
+    Burst { max_len: usize },
+    Random { weight: usize },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageReport {
+    pub class: ErrorClass,
+    pub exhaustive: bool,
+    pub tested: u64,
+    pub undetected: u64,
+}
+
+impl CoverageReport {
+    pub fn coverage(&self) -> f64 {
+        if self.tested == 0 {
+            return 1.0;
+        }
+        1.0 - self.undetected as f64 / self.tested as f64
+    }
+}
+
+pub fn bit_syndromes(config: &CrcConfig, codeword_len: usize) -> Vec<u64> {
+    let mask = config.mask();
+    let top = 1u64 << (config.width - 1);
+    let mut syndromes = vec![0u64; codeword_len];
+    let mut syndrome = 1u64;
+
+    for slot in syndromes.iter_mut().rev() {
+        *slot = syndrome;
+        syndrome = if syndrome & top != 0 {
+            ((syndrome << 1) ^ config.poly) & mask
+        } else {
+            (syndrome << 1) & mask
+        };
+    }
+
+    syndromes
+}
+
+pub fn analyze_single_bit(config: &CrcConfig, data_bits: usize) -> CoverageReport {
+    let syndromes = bit_syndromes(config, data_bits + config.width as usize);
+    CoverageReport {
+        class: ErrorClass::SingleBit,
+        exhaustive: true,
+        tested: syndromes.len() as u64,
+        undetected: syndromes.iter().filter(|&&s| s == 0).count() as u64,
+    }
+}
+
+pub fn analyze_double_bit(config: &CrcConfig, data_bits: usize) -> CoverageReport {
+    let syndromes = bit_syndromes(config, data_bits + config.width as usize);
+    let n = syndromes.len();
+
+    let undetected: u64 = (0..n)
+        .into_par_iter()
+        .map(|i| {
+            syndromes[i + 1..]
+                .iter()
+                .filter(|&&s| s == syndromes[i])
+                .count() as u64
+        })
+        .sum();
+
+    CoverageReport {
+        class: ErrorClass::DoubleBit,
+        exhaustive: true,
+        tested: (n * n.saturating_sub(1) / 2) as u64,
+        undetected,
+    }
+}
+
+pub fn analyze_bursts(config: &CrcConfig, data_bits: usize, max_len: usize) -> CoverageReport {
+    let syndromes = bit_syndromes(config, data_bits + config.width as usize);
+    let n = syndromes.len();
+    let max_len = max_len.min(n);
+
+    let counts: Vec<(u64, u64)> = (0..n)
+        .into_par_iter()
+        .map(|start| {
+            let mut tested = 0u64;
+            let mut undetected = 0u64;
+            let mut prefix = 0u64;
+            for syndrome in &syndromes[start..n.min(start + max_len)] {
+                prefix ^= syndrome;
+                tested += 1;
+                if prefix == 0 {
+                    undetected += 1;
+                }
+            }
+            (tested, undetected)
+        })
+        .collect();
+    let tested = counts.iter().map(|c| c.0).sum();
+    let undetected = counts.iter().map(|c| c.1).sum();
+
+    CoverageReport {
+        class: ErrorClass::Burst { max_len },
+        exhaustive: true,
+        tested,
+        undetected,
+    }
+}
+
+pub fn analyze_random(
+    config: &CrcConfig,
+    data_bits: usize,
+    weight: usize,
+    samples: u64,
+    seed: u64,
+) -> CoverageReport {
+    let syndromes = bit_syndromes(config, data_bits + config.width as usize);
+    let n = syndromes.len();
+    let weight = weight.min(n);
+    let threads = current_num_threads().max(1) as u64;
+    let per_thread = samples / threads;
+
+    let undetected: u64 = (0..threads)
+        .into_par_iter()
+        .map(|thread| {
+            let count = if thread == threads - 1 {
+                samples - per_thread * (threads - 1)
+            } else {
+                per_thread
+            };
+            let mut rng = SplitMix64::new(seed ^ thread.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+            let mut positions = Vec::with_capacity(weight);
+            let mut undetected = 0u64;
+
+            for _ in 0..count {
+                positions.clear();
+                while positions.len() < weight {
+                    let pos = rng.below(n as u64) as usize;
+                    if !positions.contains(&pos) {
+                        positions.push(pos);
+                    }
+                }
+                let syndrome = positions.iter().fold(0u64, |acc, &p| acc ^ syndromes[p]);
+                if syndrome == 0 {
+                    undetected += 1;
+                }
+            }
+            undetected
+        })
+        .sum();
+
+    CoverageReport {
+        class: ErrorClass::Random { weight },
+        exhaustive: false,
+        tested: samples,
+        undetected,
+    }
+}
+
+pub fn undetected_error_probability(
+    config: &CrcConfig,
+    data_bits: usize,
+    bit_error_rate: f64,
+    reports: &[CoverageReport],
+) -> f64 {
+    let n = data_bits + config.width as usize;
+    let fallback = 2f64.powi(-(config.width as i32));
+
+    (1..=n)
+        .map(|weight| {
+            let fraction = reports
+                .iter()
+                .find(|r| match r.class {
+                    ErrorClass::SingleBit => weight == 1,
+                    ErrorClass::DoubleBit => weight == 2,
+                    ErrorClass::Random { weight: w } => weight == w,
+                    ErrorClass::Burst { .. } => false,
+                })
+                .map(|r| 1.0 - r.coverage())
+                .unwrap_or(fallback);
+            let log_patterns = ln_binomial(n, weight);
+            let log_p = weight as f64 * bit_error_rate.ln()
+                + (n - weight) as f64 * (1.0 - bit_error_rate).ln();
+            fraction * (log_patterns + log_p).exp()
+        })
+        .sum()
+}
+
+fn ln_binomial(n: usize, k: usize) -> f64 {
+    let k = k.min(n - k);
+    (0..k).map(|i| ((n - i) as f64).ln() - ((i + 1) as f64).ln()).sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HammingDistance {
+    pub distance: Option<usize>,
+    pub checked_up_to: usize,
+    pub exhaustive: bool,
+}
+
+pub fn minimum_hamming_distance(
+    config: &CrcConfig,
+    data_bits: usize,
+    max_weight: usize,
+    budget: u64,
+    seed: u64,
+) -> HammingDistance {
+    let syndromes = bit_syndromes(config, data_bits + config.width as usize);
+    let n = syndromes.len();
+    let mut exhaustive = true;
+
+    for weight in 1..=max_weight.min(n) {
+        let half = weight / 2;
+        let rest = weight - half;
+
+        let found = if binomial(n, rest) <= budget as f64 {
+            has_codeword_of_weight(&syndromes, half, rest)
+        } else {
+            exhaustive = false;
+            let report = analyze_random(config, data_bits, weight, budget, seed.wrapping_add(weight as u64));
+            report.undetected > 0
+        };
+
+        if found {
+            return HammingDistance {
+                distance: Some(weight),
+                checked_up_to: weight,
+                exhaustive,
+            };
+        }
+    }
+
+    HammingDistance {
+        distance: None,
+        checked_up_to: max_weight.min(n),
+        exhaustive,
+    }
+}
+
+fn has_codeword_of_weight(syndromes: &[u64], half: usize, rest: usize) -> bool {
+    if half == 0 {
+        return syndromes.contains(&0);
+    }
+
+    let mut left = subset_xors(syndromes, half);
+    left.par_sort_unstable();
+
+    if half == rest {
+        return left.windows(2).any(|pair| pair[0] == pair[1]);
+    }
+
+    subset_xors(syndromes, rest)
+        .par_iter()
+        .any(|value| left.binary_search(value).is_ok())
+}
+
+fn subset_xors(syndromes: &[u64], size: usize) -> Vec<u64> {
+    fn collect(syndromes: &[u64], start: usize, size: usize, acc: u64, out: &mut Vec<u64>) {
+        if size == 0 {
+            out.push(acc);
+            return;
+        }
+        for i in start..=syndromes.len() - size {
+            collect(syndromes, i + 1, size - 1, acc ^ syndromes[i], out);
+        }
+    }
+
+    if size == 0 || size > syndromes.len() {
+        return Vec::new();
+    }
+
+    (0..=syndromes.len() - size)
+        .into_par_iter()
+        .flat_map_iter(|first| {
+            let mut out = Vec::new();
+            collect(syndromes, first + 1, size - 1, syndromes[first], &mut out);
+            out
+        })
+        .collect()
+}
+
+fn binomial(n: usize, k: usize) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    ln_binomial(n, k).exp()
+}
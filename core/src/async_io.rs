@@ -0,0 +1,82 @@
+use crate::frame::CanFrame;
+use crate::hasher::{CrcHasher, STREAM_CHUNK_SIZE};
+use crate::modbus::{check_rtu, split_timestamp, RtuCheck};
+use crate::{parse_hex_bytes, CrcConfig, FrameError, ParseError};
+use std::io;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, Lines};
+
+pub async fn crc_from_async_reader<R: AsyncRead + Unpin>(mut reader: R, config: &CrcConfig) -> io::Result<u64> {
+    let mut hasher = CrcHasher::new(*config);
+    let mut buffer = vec![0u8; STREAM_CHUNK_SIZE];
+    loop {
+        match reader.read(&mut buffer).await {
+            Ok(0) => return Ok(hasher.value()),
+            Ok(n) => hasher.update(&buffer[..n]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameSource {
+    Candump,
+    ModbusRtu,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrameVerdict {
+    Can(CanFrame),
+    Modbus { adu: Vec<u8>, check: RtuCheck },
+    Parse(ParseError),
+    Frame(FrameError),
+}
+
+impl FrameVerdict {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            FrameVerdict::Can(_) => true,
+            FrameVerdict::Modbus { check, .. } => check.is_valid(),
+            FrameVerdict::Parse(_) | FrameVerdict::Frame(_) => false,
+        }
+    }
+}
+
+pub fn verify_line(source: FrameSource, line: &str) -> FrameVerdict {
+    match source {
+        FrameSource::Candump => match CanFrame::parse_candump(line) {
+            Ok(frame) => FrameVerdict::Can(frame),
+            Err(e) => FrameVerdict::Frame(e),
+        },
+        FrameSource::ModbusRtu => {
+            let (_, text) = split_timestamp(line);
+            match parse_hex_bytes(text) {
+                Ok(adu) => match check_rtu(&adu) {
+                    Ok(check) => FrameVerdict::Modbus { adu, check },
+                    Err(e) => FrameVerdict::Frame(e),
+                },
+                Err(e) => FrameVerdict::Parse(e),
+            }
+        }
+    }
+}
+
+pub struct FrameVerifier<R> {
+    lines: Lines<R>,
+    source: FrameSource,
+}
+
+impl<R: AsyncBufRead + Unpin> FrameVerifier<R> {
+    pub fn new(reader: R, source: FrameSource) -> Self {
+        Self { lines: reader.lines(), source }
+    }
+
+    pub async fn next_verdict(&mut self) -> io::Result<Option<FrameVerdict>> {
+        while let Some(line) = self.lines.next_line().await? {
+            if !line.trim().is_empty() {
+                return Ok(Some(verify_line(self.source, &line)));
+            }
+        }
+        Ok(None)
+    }
+}
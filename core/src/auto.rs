@@ -0,0 +1,159 @@
+use crate::engine::CrcConfig;
+use crate::rng::SplitMix64;
+use crate::sweep::throughput;
+use crate::PackedBits;
+use std::fmt;
+use std::hint::black_box;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+pub const CALIBRATION_LENGTHS: [usize; 10] = [1, 2, 4, 8, 16, 32, 64, 256, 1024, 4096];
+pub const CALIBRATION_POINT_TIME: Duration = Duration::from_micros(200);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Strategy {
+    Bitwise,
+    Table,
+    Slice8,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 3] = [Strategy::Bitwise, Strategy::Table, Strategy::Slice8];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Strategy::Bitwise => "bitwise",
+            Strategy::Table => "table",
+            Strategy::Slice8 => "slice-by-8",
+        }
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Calibration {
+    pub table_from: usize,
+    pub slice8_from: usize,
+}
+
+impl Default for Calibration {
+    fn default() -> Self {
+        Self { table_from: 1, slice8_from: 64 }
+    }
+}
+
+impl Calibration {
+    pub fn strategy_for(&self, bytes: usize) -> Strategy {
+        if bytes >= self.slice8_from {
+            Strategy::Slice8
+        } else if bytes >= self.table_from {
+            Strategy::Table
+        } else {
+            Strategy::Bitwise
+        }
+    }
+}
+
+pub fn calibration() -> Calibration {
+    static CALIBRATION: OnceLock<Calibration> = OnceLock::new();
+    *CALIBRATION.get_or_init(|| calibrate(&CrcConfig::CRC32, CALIBRATION_POINT_TIME))
+}
+
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(config), fields(width = config.width)))]
+pub fn calibrate(config: &CrcConfig, point_time: Duration) -> Calibration {
+    let table = config.table();
+    let slice8 = config.slice8_table();
+    let mut rng = SplitMix64::new(CALIBRATION_LENGTHS.len() as u64);
+    let mut table_wins = Vec::new();
+    let mut slice8_wins = Vec::new();
+    for &len in &CALIBRATION_LENGTHS {
+        let bytes: Vec<u8> = (0..len).map(|_| rng.next_u64() as u8).collect();
+        let bitwise = throughput(point_time, || config.update_bitwise(config.start(), black_box(&bytes)));
+        let table = throughput(point_time, || config.update_with_table(&table, config.start(), black_box(&bytes)));
+        let slice8 = throughput(point_time, || config.update_slice8(&slice8, config.start(), black_box(&bytes)));
+        table_wins.push((len, table > bitwise));
+        slice8_wins.push((len, slice8 > table));
+    }
+    let calibration = Calibration { table_from: winning_from(&table_wins), slice8_from: winning_from(&slice8_wins) };
+    #[cfg(feature = "tracing")]
+    tracing::debug!(table_from = calibration.table_from, slice8_from = calibration.slice8_from, "calibrated CRC implementations");
+    calibration
+}
+
+fn winning_from(wins: &[(usize, bool)]) -> usize {
+    match wins.iter().rposition(|&(_, wins)| !wins) {
+        Some(last_loss) => wins.get(last_loss + 1).map_or(usize::MAX, |&(len, _)| len),
+        None => wins.first().map_or(0, |&(len, _)| len),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AutoCrc {
+    config: CrcConfig,
+    table: Vec<u64>,
+    slice8: Box<[[u64; 256]; 8]>,
+    calibration: Calibration,
+}
+
+impl AutoCrc {
+    pub fn new(config: CrcConfig) -> Self {
+        Self::with_calibration(config, calibration())
+    }
+
+    pub fn with_calibration(config: CrcConfig, calibration: Calibration) -> Self {
+        Self { table: config.table(), slice8: config.slice8_table(), config, calibration }
+    }
+
+    pub fn config(&self) -> &CrcConfig {
+        &self.config
+    }
+
+    pub fn calibration(&self) -> Calibration {
+        self.calibration
+    }
+
+    pub fn strategy_for(&self, bytes: usize) -> Strategy {
+        self.calibration.strategy_for(bytes)
+    }
+
+    pub fn update(&self, crc: u64, bytes: &[u8]) -> u64 {
+        self.update_with(self.strategy_for(bytes.len()), crc, bytes)
+    }
+
+    pub fn update_with(&self, strategy: Strategy, crc: u64, bytes: &[u8]) -> u64 {
+        match strategy {
+            Strategy::Bitwise => self.config.update_bitwise(crc, bytes),
+            Strategy::Table => self.config.update_with_table(&self.table, crc, bytes),
+            Strategy::Slice8 => self.config.update_slice8(&self.slice8, crc, bytes),
+        }
+    }
+
+    pub fn checksum(&self, bytes: &[u8]) -> u64 {
+        self.config.finalize(self.update(self.config.start(), bytes))
+    }
+
+    pub fn checksum_with(&self, strategy: Strategy, bytes: &[u8]) -> u64 {
+        self.config.finalize(self.update_with(strategy, self.config.start(), bytes))
+    }
+
+    pub fn checksum_packed(&self, bits: &PackedBits) -> u64 {
+        if bits.is_byte_aligned() {
+            return self.checksum(bits.as_bytes());
+        }
+        if self.config.refin {
+            return self.config.checksum_bits(&bits.to_bools());
+        }
+        self.config.finish_tail(self.update(self.config.start(), bits.whole_bytes()), bits.tail())
+    }
+
+    pub fn checksum_message(&self, bits: &[bool]) -> u64 {
+        self.checksum_packed(&PackedBits::from_bools(bits))
+    }
+}
@@ -0,0 +1,120 @@
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PackedBits {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl PackedBits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(bits.div_ceil(8)),
+            len: 0,
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self::from_vec(bytes.to_vec())
+    }
+
+    pub fn from_vec(bytes: Vec<u8>) -> Self {
+        let len = bytes.len() * 8;
+        Self { bytes, len }
+    }
+
+    pub fn from_bools(bits: &[bool]) -> Self {
+        let mut packed = Self::with_capacity(bits.len());
+        packed.extend(bits.iter().copied());
+        packed
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_byte_aligned(&self) -> bool {
+        self.len.is_multiple_of(8)
+    }
+
+    pub fn get(&self, index: usize) -> Option<bool> {
+        (index < self.len).then(|| (self.bytes[index / 8] >> (7 - index % 8)) & 1 == 1)
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len, "bit index {} out of range for {} bits", index, self.len);
+        let mask = 0x80 >> (index % 8);
+        if value {
+            self.bytes[index / 8] |= mask;
+        } else {
+            self.bytes[index / 8] &= !mask;
+        }
+    }
+
+    pub fn push(&mut self, bit: bool) {
+        if self.is_byte_aligned() {
+            self.bytes.push(0);
+        }
+        if bit {
+            self.bytes[self.len / 8] |= 0x80 >> (self.len % 8);
+        }
+        self.len += 1;
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn whole_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len / 8]
+    }
+
+    pub fn tail(&self) -> impl Iterator<Item = bool> + '_ {
+        (self.len / 8 * 8..self.len).filter_map(|index| self.get(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).filter_map(|index| self.get(index))
+    }
+
+    pub fn to_bools(&self) -> Vec<bool> {
+        self.iter().collect()
+    }
+}
+
+impl From<&[bool]> for PackedBits {
+    fn from(bits: &[bool]) -> Self {
+        Self::from_bools(bits)
+    }
+}
+
+impl From<Vec<u8>> for PackedBits {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::from_vec(bytes)
+    }
+}
+
+impl Extend<bool> for PackedBits {
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        for bit in iter {
+            self.push(bit);
+        }
+    }
+}
+
+impl FromIterator<bool> for PackedBits {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut packed = Self::new();
+        packed.extend(iter);
+        packed
+    }
+}
@@ -0,0 +1,207 @@
+use crate::bits::PackedBits;
+use crate::text::binary_chars;
+use crate::{parse_hex_bytes, ParseError, ParseOptions};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+pub const DEFAULT_GROUP: usize = 8;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitString(PackedBits);
+
+impl BitString {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self(PackedBits::from_bytes(bytes))
+    }
+
+    pub fn from_bools(bits: &[bool]) -> Self {
+        Self(PackedBits::from_bools(bits))
+    }
+
+    pub fn parse_binary(input: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        if input.trim().is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let binary_chars = binary_chars(input);
+        let invalid_chars: Vec<(usize, char)> = binary_chars.iter()
+            .copied()
+            .filter(|&(_, c)| c != '0' && c != '1')
+            .collect();
+
+        if let Some(&(first, _)) = invalid_chars.first() {
+            return Err(ParseError::InvalidBinaryChars {
+                chars: invalid_chars.iter().take(5).map(|&(_, c)| c).collect(),
+                index: input[..first].chars().count(),
+            });
+        }
+
+        let mut bits: Vec<bool> = binary_chars.iter()
+            .map(|&(_, c)| c == '1')
+            .collect();
+
+        if bits.is_empty() {
+            return Err(ParseError::NoBinaryData);
+        }
+
+        if let Some(max_bits) = options.max_bits.filter(|&max_bits| bits.len() > max_bits) {
+            return Err(ParseError::BinaryTooLong {
+                bits: bits.len(),
+                max_bits,
+            });
+        }
+
+        options.bit_order.reorder_bits(&mut bits);
+        Ok(Self::from_bools(&bits))
+    }
+
+    pub fn parse_hex(input: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        Self::from_parsed_bytes(parse_hex_bytes(input)?, options)
+    }
+
+    pub(crate) fn from_parsed_bytes(bytes: Vec<u8>, options: &ParseOptions) -> Result<Self, ParseError> {
+        if let Some(max_bytes) = options.max_bits.map(|bits| bits / 8).filter(|&max| bytes.len() > max) {
+            return Err(ParseError::HexTooLong {
+                bytes: bytes.len(),
+                max_bytes,
+            });
+        }
+        let bytes = bytes.into_iter().map(|byte| options.bit_order.apply(byte)).collect::<Vec<u8>>();
+        Ok(Self(PackedBits::from_vec(bytes)))
+    }
+
+    pub fn parse_with(input: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        let trimmed = input.trim_start();
+        if let Some(hex) = trimmed.strip_prefix("0x").or_else(|| trimmed.strip_prefix("0X")) {
+            return Self::parse_hex(hex, options);
+        }
+        if binary_chars(trimmed).iter().all(|&(_, c)| c == '0' || c == '1') {
+            Self::parse_binary(trimmed, options)
+        } else {
+            Self::parse_hex(input, options)
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn byte_len(&self) -> usize {
+        self.0.len().div_ceil(8)
+    }
+
+    pub fn is_byte_aligned(&self) -> bool {
+        self.0.is_byte_aligned()
+    }
+
+    pub fn get(&self, index: usize) -> Option<bool> {
+        self.0.get(index)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        self.0.iter()
+    }
+
+    pub fn as_packed(&self) -> &PackedBits {
+        &self.0
+    }
+
+    pub fn into_packed(self) -> PackedBits {
+        self.0
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.as_bytes().to_vec()
+    }
+
+    pub fn to_bools(&self) -> Vec<bool> {
+        self.0.to_bools()
+    }
+
+    pub fn grouped(&self, group: usize) -> Grouped<'_> {
+        Grouped { bits: self, group }
+    }
+}
+
+impl FromStr for BitString {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(input, &ParseOptions { max_bits: None, ..ParseOptions::default() })
+    }
+}
+
+impl fmt::Display for BitString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let group = if f.alternate() { DEFAULT_GROUP } else { 0 };
+        fmt::Display::fmt(&self.grouped(group), f)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Grouped<'a> {
+    bits: &'a BitString,
+    group: usize,
+}
+
+impl fmt::Display for Grouped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, bit) in self.bits.iter().enumerate() {
+            if index > 0 && self.group > 0 && index % self.group == 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(if bit { "1" } else { "0" })?;
+        }
+        Ok(())
+    }
+}
+
+impl From<PackedBits> for BitString {
+    fn from(bits: PackedBits) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<BitString> for PackedBits {
+    fn from(bits: BitString) -> Self {
+        bits.0
+    }
+}
+
+impl From<&[bool]> for BitString {
+    fn from(bits: &[bool]) -> Self {
+        Self::from_bools(bits)
+    }
+}
+
+impl From<&[u8]> for BitString {
+    fn from(bytes: &[u8]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl From<Vec<u8>> for BitString {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(PackedBits::from_vec(bytes))
+    }
+}
+
+impl FromIterator<bool> for BitString {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
@@ -0,0 +1,99 @@
+use crate::engine::CrcConfig;
+use crate::{format_hex, FrameError};
+
+pub const SDO_BLOCK_CRC: CrcConfig = CrcConfig {
+    width: 16,
+    poly: 0x1021,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+};
+
+pub const SEGMENT_LEN: usize = 8;
+pub const SEGMENT_DATA_LEN: usize = 7;
+pub const MAX_BLOCK_SIZE: u8 = 127;
+pub const LAST_SEGMENT: u8 = 0x80;
+pub const END_BLOCK: u8 = 0xC1;
+pub const END_BLOCK_MASK: u8 = 0xE3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockTransfer {
+    pub data: Vec<u8>,
+    pub received: u16,
+    pub computed: u16,
+}
+
+impl BlockTransfer {
+    pub fn is_valid(&self) -> bool {
+        self.received == self.computed
+    }
+}
+
+pub fn block_crc(data: &[u8]) -> u16 {
+    SDO_BLOCK_CRC.checksum(data) as u16
+}
+
+pub fn segments(data: &[u8], block_size: u8) -> Vec<[u8; SEGMENT_LEN]> {
+    let block_size = block_size.clamp(1, MAX_BLOCK_SIZE) as usize;
+    let chunks: Vec<&[u8]> = if data.is_empty() { vec![&[]] } else { data.chunks(SEGMENT_DATA_LEN).collect() };
+    let last = chunks.len() - 1;
+    chunks.into_iter().enumerate()
+        .map(|(index, chunk)| {
+            let mut segment = [0u8; SEGMENT_LEN];
+            segment[0] = (index % block_size) as u8 + 1;
+            if index == last {
+                segment[0] |= LAST_SEGMENT;
+            }
+            segment[1..=chunk.len()].copy_from_slice(chunk);
+            segment
+        })
+        .collect()
+}
+
+pub fn end_block(data: &[u8]) -> [u8; SEGMENT_LEN] {
+    let unused = if data.is_empty() { SEGMENT_DATA_LEN } else { (SEGMENT_DATA_LEN - data.len() % SEGMENT_DATA_LEN) % SEGMENT_DATA_LEN };
+    let mut frame = [0u8; SEGMENT_LEN];
+    frame[0] = END_BLOCK | ((unused as u8) << 2);
+    frame[1..3].copy_from_slice(&block_crc(data).to_le_bytes());
+    frame
+}
+
+pub fn parse_end_block(frame: &[u8]) -> Result<(usize, u16), FrameError> {
+    if frame.len() < 3 {
+        return Err(FrameError::TooShort { len: frame.len(), min_len: 3 });
+    }
+    let unused = ((frame[0] >> 2) & 0x07) as usize;
+    if frame[0] & END_BLOCK_MASK != END_BLOCK {
+        return Err(FrameError::Syntax { content: format_hex(frame) });
+    }
+    Ok((unused, u16::from_le_bytes([frame[1], frame[2]])))
+}
+
+pub fn reassemble(segments: &[[u8; SEGMENT_LEN]], unused: usize) -> Result<Vec<u8>, FrameError> {
+    if segments.is_empty() {
+        return Err(FrameError::TooShort { len: 0, min_len: 1 });
+    }
+    let last = segments.len() - 1;
+    let mut previous = 0;
+    let mut data = Vec::with_capacity(segments.len() * SEGMENT_DATA_LEN);
+    for (index, segment) in segments.iter().enumerate() {
+        let sequence = segment[0] & !LAST_SEGMENT;
+        let in_order = sequence == 1 || (sequence == previous + 1 && sequence <= MAX_BLOCK_SIZE);
+        if !in_order || (segment[0] & LAST_SEGMENT != 0) != (index == last) {
+            return Err(FrameError::SegmentSequence { index, sequence });
+        }
+        previous = sequence;
+        data.extend_from_slice(&segment[1..]);
+    }
+    data.truncate(data.len() - unused.min(SEGMENT_DATA_LEN));
+    Ok(data)
+}
+
+pub fn check_block_transfer(segments: &[[u8; SEGMENT_LEN]], end_block: &[u8]) -> Result<BlockTransfer, FrameError> {
+    let (unused, received) = parse_end_block(end_block)?;
+    let data = reassemble(segments, unused)?;
+    let computed = block_crc(&data);
+    Ok(BlockTransfer { data, received, computed })
+}
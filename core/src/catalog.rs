@@ -0,0 +1,217 @@
+use crate::canopen::SDO_BLOCK_CRC;
+use crate::dnp3::DNP3_CRC;
+use crate::e2e::{CRC8_H2F, CRC8_SAE_J1850};
+use crate::engine::CrcConfig;
+use crate::flexray::{FRAME_CRC_A, FRAME_CRC_B, FRAME_CRC_MAX_BITS, HEADER_CRC, HEADER_CRC_BITS};
+use crate::MAX_INPUT_BITS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrcAlgorithm {
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub config: CrcConfig,
+    pub check: u64,
+    pub residue: u64,
+    pub max_input_bits: Option<usize>,
+}
+
+impl CrcAlgorithm {
+    pub fn matches(&self, name: &str) -> bool {
+        let wanted = normalize(name);
+        normalize(self.name) == wanted || self.aliases.iter().any(|alias| normalize(alias) == wanted)
+    }
+}
+
+const fn preset(width: u8, poly: u64, init: u64, reflected: bool, xorout: u64) -> CrcConfig {
+    CrcConfig {
+        width,
+        poly,
+        init,
+        refin: reflected,
+        refout: reflected,
+        xorout,
+    }
+}
+
+pub const CAN_FD_HEADER_BITS: usize = 64;
+pub const CAN_FD_17_MAX_BITS: usize = 16 * 8 + CAN_FD_HEADER_BITS;
+pub const CAN_FD_21_MAX_BITS: usize = 64 * 8 + CAN_FD_HEADER_BITS;
+pub const MODBUS_MAX_BITS: usize = 256 * 8;
+
+pub struct CrcCatalog;
+
+impl CrcCatalog {
+    pub const ALGORITHMS: &'static [CrcAlgorithm] = &[
+        CrcAlgorithm {
+            name: "CRC-15/CAN",
+            aliases: &["can", "can-15", "crc-15"],
+            config: CrcConfig::CAN,
+            check: 0x059E,
+            residue: 0x0000,
+            max_input_bits: Some(MAX_INPUT_BITS),
+        },
+        CrcAlgorithm {
+            name: "CRC-17/CAN-FD",
+            aliases: &["canfd-17", "can-fd-17", "crc-17"],
+            config: preset(17, 0x1_685B, 0, false, 0),
+            check: 0x0_4F03,
+            residue: 0x0_0000,
+            max_input_bits: Some(CAN_FD_17_MAX_BITS),
+        },
+        CrcAlgorithm {
+            name: "CRC-21/CAN-FD",
+            aliases: &["canfd-21", "can-fd-21", "crc-21"],
+            config: preset(21, 0x10_2899, 0, false, 0),
+            check: 0x0E_D841,
+            residue: 0x00_0000,
+            max_input_bits: Some(CAN_FD_21_MAX_BITS),
+        },
+        CrcAlgorithm {
+            name: "CRC-11/FLEXRAY",
+            aliases: &["flexray-header", "flexray-11", "crc-11"],
+            config: HEADER_CRC,
+            check: 0x5A3,
+            residue: 0x000,
+            max_input_bits: Some(HEADER_CRC_BITS),
+        },
+        CrcAlgorithm {
+            name: "CRC-24/FLEXRAY-A",
+            aliases: &["flexray-a", "flexray", "flexray-24"],
+            config: FRAME_CRC_A,
+            check: 0x79_79BD,
+            residue: 0x00_0000,
+            max_input_bits: Some(FRAME_CRC_MAX_BITS),
+        },
+        CrcAlgorithm {
+            name: "CRC-24/FLEXRAY-B",
+            aliases: &["flexray-b"],
+            config: FRAME_CRC_B,
+            check: 0x1F_23B8,
+            residue: 0x00_0000,
+            max_input_bits: Some(FRAME_CRC_MAX_BITS),
+        },
+        CrcAlgorithm {
+            name: "CRC-16/MODBUS",
+            aliases: &["modbus"],
+            config: CrcConfig::MODBUS,
+            check: 0x4B37,
+            residue: 0x0000,
+            max_input_bits: Some(MODBUS_MAX_BITS),
+        },
+        CrcAlgorithm {
+            name: "CRC-16/IBM-3740",
+            aliases: &["ccitt-false", "crc-16/ccitt-false", "autosar-16"],
+            config: preset(16, 0x1021, 0xFFFF, false, 0),
+            check: 0x29B1,
+            residue: 0x0000,
+            max_input_bits: None,
+        },
+        CrcAlgorithm {
+            name: "CRC-16/XMODEM",
+            aliases: &["xmodem", "crc-16/acorn", "zmodem", "canopen", "sdo-block"],
+            config: SDO_BLOCK_CRC,
+            check: 0x31C3,
+            residue: 0x0000,
+            max_input_bits: None,
+        },
+        CrcAlgorithm {
+            name: "CRC-16/KERMIT",
+            aliases: &["kermit", "crc-16/ccitt-true"],
+            config: preset(16, 0x1021, 0, true, 0),
+            check: 0x2189,
+            residue: 0x0000,
+            max_input_bits: None,
+        },
+        CrcAlgorithm {
+            name: "CRC-16/IBM-SDLC",
+            aliases: &["x-25", "crc-16/x-25", "crc-16/iso-hdlc", "hdlc"],
+            config: preset(16, 0x1021, 0xFFFF, true, 0xFFFF),
+            check: 0x906E,
+            residue: 0xF0B8,
+            max_input_bits: None,
+        },
+        CrcAlgorithm {
+            name: "CRC-16/DNP",
+            aliases: &["dnp", "dnp3"],
+            config: DNP3_CRC,
+            check: 0xEA82,
+            residue: 0x66C5,
+            max_input_bits: None,
+        },
+        CrcAlgorithm {
+            name: "CRC-16/ARC",
+            aliases: &["arc", "crc-16/ibm", "crc-16/lha"],
+            config: preset(16, 0x8005, 0, true, 0),
+            check: 0xBB3D,
+            residue: 0x0000,
+            max_input_bits: None,
+        },
+        CrcAlgorithm {
+            name: "CRC-32/ISO-HDLC",
+            aliases: &["crc-32", "crc32", "ethernet", "zip"],
+            config: CrcConfig::CRC32,
+            check: 0xCBF4_3926,
+            residue: 0xDEBB_20E3,
+            max_input_bits: None,
+        },
+        CrcAlgorithm {
+            name: "CRC-32/ISCSI",
+            aliases: &["crc-32c", "castagnoli"],
+            config: preset(32, 0x1EDC_6F41, 0xFFFF_FFFF, true, 0xFFFF_FFFF),
+            check: 0xE306_9283,
+            residue: 0xB798_B438,
+            max_input_bits: None,
+        },
+        CrcAlgorithm {
+            name: "CRC-8/SAE-J1850",
+            aliases: &["j1850", "sae-j1850"],
+            config: CRC8_SAE_J1850,
+            check: 0x4B,
+            residue: 0xC4,
+            max_input_bits: None,
+        },
+        CrcAlgorithm {
+            name: "CRC-8/AUTOSAR",
+            aliases: &["crc-8h2f", "h2f", "autosar-8"],
+            config: CRC8_H2F,
+            check: 0xDF,
+            residue: 0x42,
+            max_input_bits: None,
+        },
+        CrcAlgorithm {
+            name: "CRC-8/SMBUS",
+            aliases: &["crc-8", "smbus"],
+            config: preset(8, 0x07, 0, false, 0),
+            check: 0xF4,
+            residue: 0x00,
+            max_input_bits: None,
+        },
+    ];
+
+    pub fn all() -> &'static [CrcAlgorithm] {
+        Self::ALGORITHMS
+    }
+
+    pub fn find(name: &str) -> Option<&'static CrcAlgorithm> {
+        Self::ALGORITHMS.iter().find(|algorithm| algorithm.matches(name))
+    }
+
+    pub fn find_config(config: &CrcConfig) -> Option<&'static CrcAlgorithm> {
+        Self::ALGORITHMS.iter().find(|algorithm| algorithm.config == *config)
+    }
+
+    pub fn max_input_bits(config: &CrcConfig) -> Option<usize> {
+        Self::find_config(config).and_then(|algorithm| algorithm.max_input_bits)
+    }
+
+    pub fn can() -> &'static CrcAlgorithm {
+        &Self::ALGORITHMS[0]
+    }
+}
+
+pub(crate) fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
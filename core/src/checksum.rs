@@ -0,0 +1,106 @@
+use crate::catalog::normalize;
+use crate::{bits_to_bytes_with, BitPadding, ParseError};
+use std::fmt;
+
+pub const ADLER_MODULUS: u32 = 65_521;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Checksum {
+    Xor8,
+    Sum8,
+    Lrc8,
+    Fletcher16,
+    Adler32,
+}
+
+impl Checksum {
+    pub const ALL: [Checksum; 5] = [Checksum::Xor8, Checksum::Sum8, Checksum::Lrc8, Checksum::Fletcher16, Checksum::Adler32];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Checksum::Xor8 => "XOR-8",
+            Checksum::Sum8 => "SUM-8",
+            Checksum::Lrc8 => "LRC-8",
+            Checksum::Fletcher16 => "FLETCHER-16",
+            Checksum::Adler32 => "ADLER-32",
+        }
+    }
+
+    pub fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Checksum::Xor8 => &["xor", "bcc"],
+            Checksum::Sum8 => &["sum", "checksum-8", "add-8"],
+            Checksum::Lrc8 => &["lrc", "modbus-ascii"],
+            Checksum::Fletcher16 => &["fletcher"],
+            Checksum::Adler32 => &["adler", "zlib-adler"],
+        }
+    }
+
+    pub fn width(self) -> u8 {
+        match self {
+            Checksum::Xor8 | Checksum::Sum8 | Checksum::Lrc8 => 8,
+            Checksum::Fletcher16 => 16,
+            Checksum::Adler32 => 32,
+        }
+    }
+
+    pub fn check(self) -> u64 {
+        match self {
+            Checksum::Xor8 => 0x31,
+            Checksum::Sum8 => 0xDD,
+            Checksum::Lrc8 => 0x23,
+            Checksum::Fletcher16 => 0x1EDE,
+            Checksum::Adler32 => 0x091E_01DE,
+        }
+    }
+
+    pub fn find(name: &str) -> Option<Self> {
+        let wanted = normalize(name);
+        Self::ALL.into_iter().find(|checksum| {
+            normalize(checksum.name()) == wanted || checksum.aliases().iter().any(|alias| normalize(alias) == wanted)
+        })
+    }
+
+    pub fn compute(self, bytes: &[u8]) -> u64 {
+        match self {
+            Checksum::Xor8 => bytes.iter().fold(0u8, |acc, byte| acc ^ byte) as u64,
+            Checksum::Sum8 => bytes.iter().fold(0u8, |acc, &byte| acc.wrapping_add(byte)) as u64,
+            Checksum::Lrc8 => bytes.iter().fold(0u8, |acc, &byte| acc.wrapping_add(byte)).wrapping_neg() as u64,
+            Checksum::Fletcher16 => {
+                let (low, high) = bytes.iter().fold((0u16, 0u16), |(low, high), &byte| {
+                    let low = (low + byte as u16) % 255;
+                    (low, (high + low) % 255)
+                });
+                ((high << 8) | low) as u64
+            }
+            Checksum::Adler32 => {
+                let (low, high) = bytes.iter().fold((1u32, 0u32), |(low, high), &byte| {
+                    let low = (low + byte as u32) % ADLER_MODULUS;
+                    (low, (high + low) % ADLER_MODULUS)
+                });
+                ((high as u64) << 16) | low as u64
+            }
+        }
+    }
+
+    pub fn compute_bits(self, bits: &[bool]) -> Result<u64, ParseError> {
+        Ok(self.compute(&bits_to_bytes_with(bits, BitPadding::Reject)?))
+    }
+
+    pub fn field_len(self) -> usize {
+        self.width() as usize / 8
+    }
+
+    pub fn check_codeword(self, bytes: &[u8]) -> Option<(u64, u64)> {
+        let (data, field) = bytes.split_at(bytes.len().checked_sub(self.field_len())?);
+        let received = field.iter().fold(0u64, |acc, &byte| (acc << 8) | byte as u64);
+        Some((self.compute(data), received))
+    }
+}
+
+impl fmt::Display for Checksum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
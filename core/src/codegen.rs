@@ -0,0 +1,209 @@
+use crate::engine::CrcConfig;
+use crate::ConfigError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableLanguage {
+    C,
+    Rust,
+    Python,
+}
+
+pub fn format_table(config: &CrcConfig, language: TableLanguage, name: &str) -> Result<String, ConfigError> {
+    config.validate()?;
+
+    let table = config.table();
+    let (c_type, rust_type, digits) = match config.width {
+        1..=8 => ("uint8_t", "u8", 2),
+        9..=16 => ("uint16_t", "u16", 4),
+        17..=32 => ("uint32_t", "u32", 8),
+        _ => ("uint64_t", "u64", 16),
+    };
+    let per_line = if digits > 8 { 4 } else { 8 };
+
+    let header = format!(
+        "CRC-{} lookup table: poly=0x{:X} refin={}",
+        config.width, config.poly, config.refin
+    );
+    let rows: Vec<String> = table
+        .chunks(per_line)
+        .map(|row| {
+            row.iter()
+                .map(|v| format!("0x{:0width$X}", v, width = digits))
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .collect();
+
+    let mut out = String::new();
+    match language {
+        TableLanguage::C => {
+            out.push_str(&format!("/* {} */\n", header));
+            out.push_str("#include <stdint.h>\n\n");
+            out.push_str(&format!("static const {} {}[256] = {{\n", c_type, name));
+            for row in &rows {
+                out.push_str(&format!("    {},\n", row));
+            }
+            out.push_str("};\n");
+        }
+        TableLanguage::Rust => {
+            out.push_str(&format!("// {}\n", header));
+            out.push_str(&format!("pub static {}: [{}; 256] = [\n", name.to_uppercase(), rust_type));
+            for row in &rows {
+                out.push_str(&format!("    {},\n", row));
+            }
+            out.push_str("];\n");
+        }
+        TableLanguage::Python => {
+            out.push_str(&format!("# {}\n", header));
+            out.push_str(&format!("{} = [\n", name.to_lowercase()));
+            for row in &rows {
+                out.push_str(&format!("    {},\n", row));
+            }
+            out.push_str("]\n");
+        }
+    }
+
+    Ok(out)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HdlLanguage {
+    Verilog,
+    Vhdl,
+}
+
+pub fn parallel_crc_equations(config: &CrcConfig, data_width: u8) -> Result<Vec<Vec<String>>, ConfigError> {
+    config.validate()?;
+    if data_width == 0 || data_width > 64 {
+        return Err(ConfigError::InvalidDataWidth { width: data_width });
+    }
+
+    let width = config.width as usize;
+    let data_width = data_width as usize;
+    let mut state: Vec<u128> = (0..width).map(|i| 1u128 << i).collect();
+
+    for k in (0..data_width).rev() {
+        let feedback = state[width - 1] ^ (1u128 << (width + k));
+        for i in (1..width).rev() {
+            state[i] = state[i - 1];
+        }
+        state[0] = 0;
+        for (i, term) in state.iter_mut().enumerate() {
+            if (config.poly >> i) & 1 == 1 {
+                *term ^= feedback;
+            }
+        }
+    }
+
+    Ok(state
+        .iter()
+        .map(|&terms| {
+            let mut vars: Vec<String> = (0..width)
+                .filter(|&j| (terms >> j) & 1 == 1)
+                .map(|j| format!("c[{}]", j))
+                .collect();
+            vars.extend(
+                (0..data_width)
+                    .filter(|&k| (terms >> (width + k)) & 1 == 1)
+                    .map(|k| format!("d[{}]", k)),
+            );
+            vars
+        })
+        .collect())
+}
+
+pub fn generate_hdl(
+    config: &CrcConfig,
+    data_width: u8,
+    language: HdlLanguage,
+    module_name: &str,
+) -> Result<String, ConfigError> {
+    let equations = parallel_crc_equations(config, data_width)?;
+    let width = config.width as usize;
+    let init_digits = width.div_ceil(4);
+    let header = format!(
+        "CRC-{} poly=0x{:X} init=0x{:X}, {} data bits per clock, MSB (d[{}]) first",
+        config.width, config.poly, config.init, data_width, data_width - 1
+    );
+
+    let mut out = String::new();
+    match language {
+        HdlLanguage::Verilog => {
+            out.push_str(&format!("// {}\n", header));
+            out.push_str(&format!("module {} (\n", module_name));
+            out.push_str("    input  wire clk,\n");
+            out.push_str("    input  wire rst,\n");
+            out.push_str("    input  wire en,\n");
+            out.push_str(&format!("    input  wire [{}:0] data_in,\n", data_width - 1));
+            out.push_str(&format!("    output reg  [{}:0] crc_out\n", width - 1));
+            out.push_str(");\n\n");
+            out.push_str(&format!("    wire [{}:0] c = crc_out;\n", width - 1));
+            out.push_str(&format!("    wire [{}:0] d = data_in;\n", data_width - 1));
+            out.push_str(&format!("    wire [{}:0] crc_next;\n\n", width - 1));
+            for (i, terms) in equations.iter().enumerate() {
+                let expr = if terms.is_empty() { "1'b0".to_string() } else { terms.join(" ^ ") };
+                out.push_str(&format!("    assign crc_next[{}] = {};\n", i, expr));
+            }
+            out.push_str("\n    always @(posedge clk) begin\n");
+            out.push_str("        if (rst)\n");
+            out.push_str(&format!(
+                "            crc_out <= {}'h{:0digits$X};\n",
+                width, config.init, digits = init_digits
+            ));
+            out.push_str("        else if (en)\n");
+            out.push_str("            crc_out <= crc_next;\n");
+            out.push_str("    end\n\n");
+            out.push_str("endmodule\n");
+        }
+        HdlLanguage::Vhdl => {
+            let init_bits: String = (0..width)
+                .rev()
+                .map(|i| if (config.init >> i) & 1 == 1 { '1' } else { '0' })
+                .collect();
+            out.push_str(&format!("-- {}\n", header));
+            out.push_str("library ieee;\n");
+            out.push_str("use ieee.std_logic_1164.all;\n\n");
+            out.push_str(&format!("entity {} is\n", module_name));
+            out.push_str("    port (\n");
+            out.push_str("        clk     : in  std_logic;\n");
+            out.push_str("        rst     : in  std_logic;\n");
+            out.push_str("        en      : in  std_logic;\n");
+            out.push_str(&format!("        data_in : in  std_logic_vector({} downto 0);\n", data_width - 1));
+            out.push_str(&format!("        crc_out : out std_logic_vector({} downto 0)\n", width - 1));
+            out.push_str("    );\n");
+            out.push_str(&format!("end entity {};\n\n", module_name));
+            out.push_str(&format!("architecture rtl of {} is\n", module_name));
+            out.push_str(&format!("    signal c        : std_logic_vector({} downto 0);\n", width - 1));
+            out.push_str(&format!("    signal d        : std_logic_vector({} downto 0);\n", data_width - 1));
+            out.push_str(&format!("    signal crc_next : std_logic_vector({} downto 0);\n", width - 1));
+            out.push_str("begin\n");
+            out.push_str("    d <= data_in;\n");
+            out.push_str("    crc_out <= c;\n\n");
+            for (i, terms) in equations.iter().enumerate() {
+                let expr = if terms.is_empty() {
+                    "'0'".to_string()
+                } else {
+                    terms
+                        .iter()
+                        .map(|t| t.replace('[', "(").replace(']', ")"))
+                        .collect::<Vec<_>>()
+                        .join(" xor ")
+                };
+                out.push_str(&format!("    crc_next({}) <= {};\n", i, expr));
+            }
+            out.push_str("\n    process (clk)\n");
+            out.push_str("    begin\n");
+            out.push_str("        if rising_edge(clk) then\n");
+            out.push_str("            if rst = '1' then\n");
+            out.push_str(&format!("                c <= \"{}\";\n", init_bits));
+            out.push_str("            elsif en = '1' then\n");
+            out.push_str("                c <= crc_next;\n");
+            out.push_str("            end if;\n");
+            out.push_str("        end if;\n");
+            out.push_str("    end process;\n");
+            out.push_str("end architecture rtl;\n");
+        }
+    }
+
+    Ok(out)
+}
@@ -0,0 +1,55 @@
+use crate::{
+    bits_to_bytes, calculate_can_crc, calculate_can_crc_bytes, calculate_can_crc_optimized, CrcConfig, CrcHasher,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Implementation {
+    pub name: &'static str,
+    pub crc: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrossCheck {
+    pub results: Vec<Implementation>,
+}
+
+impl CrossCheck {
+    pub fn agrees(&self) -> bool {
+        self.results.windows(2).all(|pair| pair[0].crc == pair[1].crc)
+    }
+
+    pub fn value(&self) -> Option<u64> {
+        self.results.first().filter(|_| self.agrees()).map(|result| result.crc)
+    }
+}
+
+pub fn cross_check(config: &CrcConfig, bits: &[bool]) -> CrossCheck {
+    let mut results = Vec::new();
+    let mut run = |name, crc| results.push(Implementation { name, crc });
+
+    if bits.len().is_multiple_of(8) {
+        let bytes = bits_to_bytes(bits);
+        run("bitwise", config.checksum_bitwise(&bytes));
+        run("table", config.checksum(&bytes));
+        run("slice-by-8", config.finalize(config.update_slice8(&config.slice8_table(), config.start(), &bytes)));
+
+        let mut hasher = CrcHasher::new(*config);
+        for chunk in bytes.chunks(3) {
+            hasher.update(chunk);
+        }
+        run("streaming", hasher.value());
+
+        if *config == CrcConfig::CAN {
+            run("can-bytes", calculate_can_crc_bytes(&bytes) as u64);
+        }
+    } else {
+        run("bitwise", config.checksum_bits(bits));
+    }
+
+    if *config == CrcConfig::CAN {
+        run("can-bitwise", calculate_can_crc(bits) as u64);
+        run("can-table", calculate_can_crc_optimized(bits) as u64);
+    }
+
+    CrossCheck { results }
+}
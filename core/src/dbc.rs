@@ -0,0 +1,200 @@
+use crate::frame::CanFrame;
+use crate::DbcError;
+
+const EXTENDED_ID_FLAG: u32 = 0x8000_0000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbcSignal {
+    pub name: String,
+    pub start_bit: u16,
+    pub length: u16,
+    pub little_endian: bool,
+    pub signed: bool,
+    pub factor: f64,
+    pub offset: f64,
+    pub min: f64,
+    pub max: f64,
+    pub unit: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbcMessage {
+    pub id: u32,
+    pub extended: bool,
+    pub name: String,
+    pub dlc: u8,
+    pub signals: Vec<DbcSignal>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dbc {
+    pub messages: Vec<DbcMessage>,
+}
+
+impl Dbc {
+    pub fn parse(text: &str) -> Result<Self, DbcError> {
+        let mut messages: Vec<DbcMessage> = Vec::new();
+
+        for (index, raw_line) in text.lines().enumerate() {
+            let line = raw_line.trim();
+            let syntax_error = || DbcError::Syntax {
+                line: index + 1,
+                content: line.to_string(),
+            };
+
+            if let Some(rest) = line.strip_prefix("BO_ ") {
+                messages.push(parse_message(rest).ok_or_else(syntax_error)?);
+            } else if let Some(rest) = line.strip_prefix("SG_ ") {
+                let signal = parse_signal(rest).ok_or_else(syntax_error)?;
+                messages.last_mut().ok_or_else(syntax_error)?.signals.push(signal);
+            }
+        }
+
+        Ok(Self { messages })
+    }
+
+    pub fn message(&self, name: &str) -> Result<&DbcMessage, DbcError> {
+        self.messages
+            .iter()
+            .find(|m| m.name == name)
+            .ok_or_else(|| DbcError::UnknownMessage { name: name.to_string() })
+    }
+}
+
+impl DbcMessage {
+    pub fn signal(&self, name: &str) -> Result<&DbcSignal, DbcError> {
+        self.signals
+            .iter()
+            .find(|s| s.name == name)
+            .ok_or_else(|| DbcError::UnknownSignal { name: name.to_string() })
+    }
+
+    pub fn encode(&self, values: &[(String, f64)]) -> Result<Vec<u8>, DbcError> {
+        let mut data = vec![0u8; self.dlc as usize];
+
+        for (name, value) in values {
+            let signal = self.signal(name)?;
+            let raw = signal.to_raw(*value)?;
+            signal.insert(&mut data, raw);
+        }
+
+        Ok(data)
+    }
+
+    pub fn to_frame(&self, data: &[u8]) -> Result<CanFrame, DbcError> {
+        let frame = if self.extended {
+            CanFrame::new_extended(self.id, data)?
+        } else {
+            CanFrame::new(self.id, data)?
+        };
+        Ok(frame)
+    }
+}
+
+impl DbcSignal {
+    pub fn to_raw(&self, value: f64) -> Result<u64, DbcError> {
+        let out_of_range = || DbcError::ValueOutOfRange {
+            signal: self.name.clone(),
+            value,
+        };
+
+        let factor = if self.factor == 0.0 { 1.0 } else { self.factor };
+        let raw = ((value - self.offset) / factor).round();
+        let bits = self.length.min(64) as u32;
+
+        let (min_raw, max_raw) = if self.signed {
+            let half = 2f64.powi(bits as i32 - 1);
+            (-half, half - 1.0)
+        } else {
+            (0.0, 2f64.powi(bits as i32) - 1.0)
+        };
+        if !raw.is_finite() || raw < min_raw || raw > max_raw {
+            return Err(out_of_range());
+        }
+
+        let mask = if bits >= 64 { u64::MAX } else { (1u64 << bits) - 1 };
+        Ok((raw as i64 as u64) & mask)
+    }
+
+    fn insert(&self, data: &mut [u8], raw: u64) {
+        let total_bits = data.len() * 8;
+
+        if self.little_endian {
+            for i in 0..self.length as usize {
+                let pos = self.start_bit as usize + i;
+                if pos < total_bits {
+                    set_bit(data, pos, (raw >> i) & 1 == 1);
+                }
+            }
+        } else {
+            let mut pos = self.start_bit as usize;
+            for i in (0..self.length as usize).rev() {
+                if pos < total_bits {
+                    set_bit(data, pos, (raw >> i) & 1 == 1);
+                }
+                pos = if pos.is_multiple_of(8) { pos + 15 } else { pos - 1 };
+            }
+        }
+    }
+}
+
+fn set_bit(data: &mut [u8], pos: usize, value: bool) {
+    let mask = 1u8 << (pos % 8);
+    if value {
+        data[pos / 8] |= mask;
+    } else {
+        data[pos / 8] &= !mask;
+    }
+}
+
+fn parse_message(rest: &str) -> Option<DbcMessage> {
+    let mut parts = rest.split_whitespace();
+    let raw_id: u32 = parts.next()?.parse().ok()?;
+    let name = parts.next()?.trim_end_matches(':').to_string();
+    let dlc: u8 = parts.next()?.parse().ok()?;
+
+    Some(DbcMessage {
+        id: raw_id & !EXTENDED_ID_FLAG,
+        extended: raw_id & EXTENDED_ID_FLAG != 0,
+        name,
+        dlc,
+        signals: Vec::new(),
+    })
+}
+
+fn parse_signal(rest: &str) -> Option<DbcSignal> {
+    let (head, layout) = rest.split_once(':')?;
+    let name = head.split_whitespace().next()?.to_string();
+
+    let layout = layout.trim();
+    let (bits, rest) = layout.split_once(' ')?;
+    let (start, rest_bits) = bits.split_once('|')?;
+    let (length, order) = rest_bits.split_once('@')?;
+    let mut order_chars = order.chars();
+    let little_endian = order_chars.next()? == '1';
+    let signed = order_chars.next()? == '-';
+
+    let (scale, rest) = rest.trim().strip_prefix('(')?.split_once(')')?;
+    let (factor, offset) = scale.split_once(',')?;
+    let (range, rest) = rest.trim().strip_prefix('[')?.split_once(']')?;
+    let (min, max) = range.split_once('|')?;
+    let unit = rest
+        .trim()
+        .strip_prefix('"')
+        .and_then(|r| r.split_once('"'))
+        .map(|(unit, _)| unit.to_string())
+        .unwrap_or_default();
+
+    Some(DbcSignal {
+        name,
+        start_bit: start.trim().parse().ok()?,
+        length: length.trim().parse().ok()?,
+        little_endian,
+        signed,
+        factor: factor.trim().parse().ok()?,
+        offset: offset.trim().parse().ok()?,
+        min: min.trim().parse().ok()?,
+        max: max.trim().parse().ok()?,
+        unit,
+    })
+}
@@ -0,0 +1,136 @@
+use crate::engine::CrcConfig;
+use crate::par::*;
+use crate::rng::SplitMix64;
+
+pub const DEFAULT_BUCKET_BITS: u8 = 8;
+pub const MAX_BUCKET_BITS: u8 = 20;
+pub const DEFAULT_SAMPLES: u64 = 1 << 20;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    pub data_bits: usize,
+    pub bucket_bits: u8,
+    pub exhaustive: bool,
+    pub inputs: u64,
+    pub buckets: Vec<u64>,
+}
+
+impl Distribution {
+    pub fn expected(&self) -> f64 {
+        self.inputs as f64 / self.buckets.len() as f64
+    }
+
+    pub fn collisions(&self) -> u64 {
+        self.buckets.iter().map(|&count| count.saturating_sub(1)).sum()
+    }
+
+    pub fn empty_buckets(&self) -> usize {
+        self.buckets.iter().filter(|&&count| count == 0).count()
+    }
+
+    pub fn min(&self) -> u64 {
+        self.buckets.iter().copied().min().unwrap_or(0)
+    }
+
+    pub fn max(&self) -> u64 {
+        self.buckets.iter().copied().max().unwrap_or(0)
+    }
+
+    pub fn chi_square(&self) -> f64 {
+        let expected = self.expected();
+        if expected == 0.0 {
+            return 0.0;
+        }
+        self.buckets.iter().map(|&count| (count as f64 - expected).powi(2) / expected).sum()
+    }
+
+    pub fn degrees_of_freedom(&self) -> usize {
+        self.buckets.len().saturating_sub(1)
+    }
+
+    pub fn p_value(&self) -> f64 {
+        chi_square_upper_tail(self.chi_square(), self.degrees_of_freedom())
+    }
+}
+
+pub fn crc_distribution(config: &CrcConfig, data_bits: usize, bucket_bits: u8, samples: u64, seed: u64) -> Distribution {
+    let bucket_bits = bucket_bits.clamp(1, config.width.min(MAX_BUCKET_BITS));
+    let shift = config.width - bucket_bits;
+    let base = config.checksum_bits(&vec![false; data_bits]);
+    let syndromes: Vec<u64> = (0..data_bits)
+        .map(|position| {
+            let mut bits = vec![false; data_bits];
+            bits[position] = true;
+            config.checksum_bits(&bits) ^ base
+        })
+        .collect();
+    let exhaustive = data_bits < 64 && 1u64 << data_bits <= samples;
+
+    let buckets = if exhaustive {
+        let mut buckets = vec![0u64; 1 << bucket_bits];
+        let mut crc = base;
+        buckets[(crc >> shift) as usize] += 1;
+        for index in 1..1u64 << data_bits {
+            crc ^= syndromes[index.trailing_zeros() as usize];
+            buckets[(crc >> shift) as usize] += 1;
+        }
+        buckets
+    } else {
+        let threads = current_num_threads().max(1) as u64;
+        let per_thread = samples / threads;
+        (0..threads)
+            .into_par_iter()
+            .map(|thread| {
+                let count = if thread == threads - 1 { samples - per_thread * (threads - 1) } else { per_thread };
+                let mut rng = SplitMix64::new(seed ^ thread.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+                let mut buckets = vec![0u64; 1 << bucket_bits];
+                for _ in 0..count {
+                    let mut crc = base;
+                    for chunk in syndromes.chunks(64) {
+                        let word = rng.next_u64();
+                        for (bit, syndrome) in chunk.iter().enumerate() {
+                            if (word >> bit) & 1 == 1 {
+                                crc ^= syndrome;
+                            }
+                        }
+                    }
+                    buckets[(crc >> shift) as usize] += 1;
+                }
+                buckets
+            })
+            .reduce_with(|mut total, buckets| {
+                total.iter_mut().zip(buckets).for_each(|(sum, count)| *sum += count);
+                total
+            })
+            .unwrap_or_default()
+    };
+
+    Distribution {
+        data_bits,
+        bucket_bits,
+        exhaustive,
+        inputs: buckets.iter().sum(),
+        buckets,
+    }
+}
+
+fn chi_square_upper_tail(chi_square: f64, degrees_of_freedom: usize) -> f64 {
+    if degrees_of_freedom == 0 {
+        return 1.0;
+    }
+    let k = degrees_of_freedom as f64;
+    let spread = 2.0 / (9.0 * k);
+    let z = ((chi_square / k).cbrt() - (1.0 - spread)) / spread.sqrt();
+    0.5 * erfc(z / std::f64::consts::SQRT_2)
+}
+
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = [-1.265_512_23, 1.000_023_68, 0.374_091_96, 0.096_784_18, -0.186_288_06, 0.278_868_07, -1.135_203_98, 1.488_515_87, -0.822_152_23, 0.170_872_77]
+        .iter()
+        .rev()
+        .fold(0.0, |acc, &c| acc * t + c);
+    let result = t * (-z * z + poly).exp();
+    if x >= 0.0 { result } else { 2.0 - result }
+}
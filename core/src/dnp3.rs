@@ -0,0 +1,122 @@
+use crate::engine::CrcConfig;
+use crate::{format_hex, FrameError};
+
+pub const DNP3_CRC: CrcConfig = CrcConfig {
+    width: 16,
+    poly: 0x3D65,
+    init: 0x0000,
+    refin: true,
+    refout: true,
+    xorout: 0xFFFF,
+};
+
+pub const START: [u8; 2] = [0x05, 0x64];
+pub const HEADER_LEN: usize = 8;
+pub const BLOCK_LEN: usize = 16;
+pub const CRC_LEN: usize = 2;
+pub const MAX_USER_DATA_LEN: usize = 250;
+pub const MAX_FRAME_LEN: usize = HEADER_LEN + CRC_LEN + MAX_USER_DATA_LEN + MAX_USER_DATA_LEN.div_ceil(BLOCK_LEN) * CRC_LEN;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockCheck {
+    pub offset: usize,
+    pub len: usize,
+    pub received: u16,
+    pub computed: u16,
+}
+
+impl BlockCheck {
+    pub fn is_valid(&self) -> bool {
+        self.received == self.computed
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinkFrame {
+    pub header: [u8; HEADER_LEN],
+    pub user_data: Vec<u8>,
+    pub blocks: Vec<BlockCheck>,
+}
+
+impl LinkFrame {
+    pub fn is_valid(&self) -> bool {
+        self.blocks.iter().all(BlockCheck::is_valid)
+    }
+
+    pub fn control(&self) -> u8 {
+        self.header[3]
+    }
+
+    pub fn destination(&self) -> u16 {
+        u16::from_le_bytes([self.header[4], self.header[5]])
+    }
+
+    pub fn source(&self) -> u16 {
+        u16::from_le_bytes([self.header[6], self.header[7]])
+    }
+}
+
+pub fn crc(bytes: &[u8]) -> u16 {
+    DNP3_CRC.checksum(bytes) as u16
+}
+
+pub fn add_block_crcs(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + data.len().div_ceil(BLOCK_LEN) * CRC_LEN);
+    for block in data.chunks(BLOCK_LEN) {
+        out.extend_from_slice(block);
+        out.extend_from_slice(&crc(block).to_le_bytes());
+    }
+    out
+}
+
+pub fn link_frame(control: u8, destination: u16, source: u16, user_data: &[u8]) -> Result<Vec<u8>, FrameError> {
+    if user_data.len() > MAX_USER_DATA_LEN {
+        return Err(FrameError::DataTooLong { len: user_data.len(), max_len: MAX_USER_DATA_LEN });
+    }
+    let mut frame = Vec::with_capacity(MAX_FRAME_LEN);
+    frame.extend_from_slice(&START);
+    frame.push((user_data.len() + 5) as u8);
+    frame.push(control);
+    frame.extend_from_slice(&destination.to_le_bytes());
+    frame.extend_from_slice(&source.to_le_bytes());
+    frame.extend_from_slice(&crc(&frame).to_le_bytes());
+    frame.extend(add_block_crcs(user_data));
+    Ok(frame)
+}
+
+pub fn check_link_frame(frame: &[u8]) -> Result<LinkFrame, FrameError> {
+    let min_len = HEADER_LEN + CRC_LEN;
+    if frame.len() < min_len {
+        return Err(FrameError::TooShort { len: frame.len(), min_len });
+    }
+    if frame[..2] != START || frame[2] < 5 {
+        return Err(FrameError::Syntax { content: format_hex(&frame[..HEADER_LEN]) });
+    }
+    let user_len = frame[2] as usize - 5;
+    let expected = min_len + user_len + user_len.div_ceil(BLOCK_LEN) * CRC_LEN;
+    if frame.len() < expected {
+        return Err(FrameError::TooShort { len: frame.len(), min_len: expected });
+    }
+    if frame.len() > expected {
+        return Err(FrameError::DataTooLong { len: frame.len(), max_len: expected });
+    }
+
+    let mut blocks = vec![check_block(frame, 0, HEADER_LEN)];
+    let mut user_data = Vec::with_capacity(user_len);
+    let mut offset = min_len;
+    while offset < frame.len() {
+        let len = (frame.len() - offset - CRC_LEN).min(BLOCK_LEN);
+        blocks.push(check_block(frame, offset, len));
+        user_data.extend_from_slice(&frame[offset..offset + len]);
+        offset += len + CRC_LEN;
+    }
+    let header = frame[..HEADER_LEN].try_into().expect("header length was checked");
+    Ok(LinkFrame { header, user_data, blocks })
+}
+
+fn check_block(frame: &[u8], offset: usize, len: usize) -> BlockCheck {
+    let (block, field) = frame[offset..offset + len + CRC_LEN].split_at(len);
+    BlockCheck { offset, len, received: u16::from_le_bytes([field[0], field[1]]), computed: crc(block) }
+}
@@ -0,0 +1,110 @@
+use crate::engine::CrcConfig;
+use crate::hasher::CrcHasher;
+
+pub const CRC8_SAE_J1850: CrcConfig = CrcConfig {
+    width: 8,
+    poly: 0x1D,
+    init: 0xFF,
+    refin: false,
+    refout: false,
+    xorout: 0xFF,
+};
+
+pub const CRC8_H2F: CrcConfig = CrcConfig {
+    width: 8,
+    poly: 0x2F,
+    init: 0xFF,
+    refin: false,
+    refout: false,
+    xorout: 0xFF,
+};
+
+pub const CRC_BYTE: usize = 0;
+pub const COUNTER_BYTE: usize = 1;
+pub const MAX_COUNTER_P1: u8 = 14;
+pub const MAX_COUNTER_P2: u8 = 15;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataIdMode {
+    #[default]
+    Both,
+    Alt,
+    Low,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum E2eProfile {
+    Profile1 { data_id: u16, mode: DataIdMode },
+    Profile2 { data_id_list: [u8; 16] },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct E2eCheck {
+    pub counter: u8,
+    pub received_crc: u8,
+    pub expected_crc: u8,
+}
+
+impl E2eCheck {
+    pub fn valid(&self) -> bool {
+        self.received_crc == self.expected_crc
+    }
+}
+
+impl E2eProfile {
+    pub fn max_counter(&self) -> u8 {
+        match self {
+            E2eProfile::Profile1 { .. } => MAX_COUNTER_P1,
+            E2eProfile::Profile2 { .. } => MAX_COUNTER_P2,
+        }
+    }
+
+    pub fn crc(&self, data: &[u8], counter: u8) -> u8 {
+        let payload = data.get(CRC_BYTE + 1..).unwrap_or_default();
+
+        match *self {
+            E2eProfile::Profile1 { data_id, mode } => {
+                let [low, high] = data_id.to_le_bytes();
+                let mut hasher = CrcHasher::new(CRC8_SAE_J1850);
+                match mode {
+                    DataIdMode::Both => hasher.update(&[low, high]),
+                    DataIdMode::Alt if counter.is_multiple_of(2) => hasher.update(&[low]),
+                    DataIdMode::Alt => hasher.update(&[high]),
+                    DataIdMode::Low => hasher.update(&[low]),
+                }
+                hasher.update(payload);
+                hasher.value() as u8
+            }
+            E2eProfile::Profile2 { data_id_list } => {
+                let mut hasher = CrcHasher::new(CRC8_H2F);
+                hasher.update(payload);
+                hasher.update(&[data_id_list[(counter & 0x0F) as usize]]);
+                hasher.value() as u8
+            }
+        }
+    }
+
+    pub fn protect(&self, data: &mut [u8], counter: u8) {
+        if data.len() <= COUNTER_BYTE {
+            return;
+        }
+        let counter = counter % (self.max_counter() + 1);
+        data[COUNTER_BYTE] = (data[COUNTER_BYTE] & 0xF0) | counter;
+        data[CRC_BYTE] = self.crc(data, counter);
+    }
+
+    pub fn check(&self, data: &[u8]) -> Option<E2eCheck> {
+        if data.len() <= COUNTER_BYTE {
+            return None;
+        }
+        let counter = data[COUNTER_BYTE] & 0x0F;
+        Some(E2eCheck {
+            counter,
+            received_crc: data[CRC_BYTE],
+            expected_crc: self.crc(data, counter),
+        })
+    }
+}
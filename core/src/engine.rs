@@ -0,0 +1,460 @@
+use crate::ConfigError;
+#[cfg(feature = "alloc")]
+use crate::PackedBits;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrcConfig {
+    pub width: u8,
+    pub poly: u64,
+    pub init: u64,
+    pub refin: bool,
+    pub refout: bool,
+    pub xorout: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrcStep {
+    pub index: usize,
+    pub bit: bool,
+    pub feedback: bool,
+    pub register: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Endianness {
+    Big,
+    Little,
+}
+
+impl Endianness {
+    pub fn for_config(config: &CrcConfig) -> Self {
+        if config.refout { Endianness::Little } else { Endianness::Big }
+    }
+}
+
+impl CrcConfig {
+    pub const CAN: CrcConfig = CrcConfig::new(15, 0x4599);
+    pub const CRC32: CrcConfig = CrcConfig {
+        width: 32,
+        poly: 0x04C1_1DB7,
+        init: 0xFFFF_FFFF,
+        refin: true,
+        refout: true,
+        xorout: 0xFFFF_FFFF,
+    };
+    pub const MODBUS: CrcConfig = CrcConfig {
+        width: 16,
+        poly: 0x8005,
+        init: 0xFFFF,
+        refin: true,
+        refout: true,
+        xorout: 0,
+    };
+
+    pub const fn new(width: u8, poly: u64) -> Self {
+        Self {
+            width,
+            poly,
+            init: 0,
+            refin: false,
+            refout: false,
+            xorout: 0,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.width == 0 || self.width > 64 {
+            return Err(ConfigError::InvalidWidth { width: self.width });
+        }
+        if self.poly & !self.mask() != 0 {
+            return Err(ConfigError::PolyTooWide { poly: self.poly, width: self.width });
+        }
+        if self.poly & 1 == 0 {
+            return Err(ConfigError::EvenPoly { poly: self.poly });
+        }
+        Ok(())
+    }
+
+    pub fn mask(&self) -> u64 {
+        if self.width >= 64 {
+            u64::MAX
+        } else {
+            (1u64 << self.width) - 1
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self), fields(width = self.width, poly = %format_args!("0x{:X}", self.poly))))]
+    pub fn table(&self) -> Vec<u64> {
+        let mask = self.mask();
+        let width = self.width as u32;
+
+        (0..256u64)
+            .map(|i| {
+                if self.refin {
+                    let poly = reflect(self.poly, width);
+                    let mut crc = i;
+                    for _ in 0..8 {
+                        crc = if crc & 1 != 0 { (crc >> 1) ^ poly } else { crc >> 1 };
+                    }
+                    crc & mask
+                } else if width >= 8 {
+                    let top = 1u64 << (width - 1);
+                    let mut crc = i << (width - 8);
+                    for _ in 0..8 {
+                        crc = if crc & top != 0 { (crc << 1) ^ self.poly } else { crc << 1 };
+                    }
+                    crc & mask
+                } else {
+                    let shift = 8 - width;
+                    let poly = self.poly << shift;
+                    let mut crc = i;
+                    for _ in 0..8 {
+                        crc = if crc & 0x80 != 0 { (crc << 1) ^ poly } else { crc << 1 };
+                    }
+                    (crc & 0xFF) >> shift
+                }
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn checksum(&self, bytes: &[u8]) -> u64 {
+        let table = self.table();
+        self.finalize(self.update_with_table(&table, self.start(), bytes))
+    }
+
+    pub fn checksum_bitwise(&self, bytes: &[u8]) -> u64 {
+        let mask = self.mask();
+        let top_shift = self.width as u32 - 1;
+        let mut crc = self.init & mask;
+
+        for &byte in bytes {
+            let byte = if self.refin { byte.reverse_bits() } else { byte };
+            for i in (0..8).rev() {
+                let crcnxt = ((byte >> i) & 1 == 1) ^ ((crc >> top_shift) & 1 == 1);
+                crc = (crc << 1) & mask;
+                if crcnxt {
+                    crc ^= self.poly;
+                }
+            }
+        }
+
+        let crc = if self.refout { reflect(crc, self.width as u32) } else { crc };
+        (crc ^ self.xorout) & mask
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn checksum_message(&self, bits: &[bool]) -> u64 {
+        self.checksum_packed(&PackedBits::from_bools(bits))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn checksum_packed(&self, bits: &PackedBits) -> u64 {
+        self.checksum_packed_with_table(&self.table(), bits)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn checksum_packed_with_table(&self, table: &[u64], bits: &PackedBits) -> u64 {
+        if bits.is_byte_aligned() {
+            return self.finalize(self.update_with_table(table, self.start(), bits.as_bytes()));
+        }
+        if self.refin {
+            return self.checksum_bits(&bits.to_bools());
+        }
+
+        self.finish_tail(self.update_with_table(table, self.start(), bits.whole_bytes()), bits.tail())
+    }
+
+    pub(crate) fn finish_tail(&self, crc: u64, tail: impl Iterator<Item = bool>) -> u64 {
+        let mask = self.mask();
+        let top_shift = self.width as u32 - 1;
+        let mut crc = crc;
+        for bit in tail {
+            let crcnxt = bit ^ ((crc >> top_shift) & 1 == 1);
+            crc = (crc << 1) & mask;
+            if crcnxt {
+                crc ^= self.poly;
+            }
+        }
+
+        let crc = if self.refout { reflect(crc, self.width as u32) } else { crc };
+        (crc ^ self.xorout) & mask
+    }
+
+    pub fn checksum_u64(&self, value: u64, bit_len: usize) -> u64 {
+        self.finish_tail(self.init & self.mask(), (0..bit_len.min(64)).rev().map(|bit| (value >> bit) & 1 == 1))
+    }
+
+    pub fn checksum_bits(&self, bits: &[bool]) -> u64 {
+        let mask = self.mask();
+        let top_shift = self.width as u32 - 1;
+        let mut crc = self.init & mask;
+
+        for &bit in bits {
+            let crcnxt = bit ^ ((crc >> top_shift) & 1 == 1);
+            crc = (crc << 1) & mask;
+            if crcnxt {
+                crc ^= self.poly;
+            }
+        }
+
+        let crc = if self.refout { reflect(crc, self.width as u32) } else { crc };
+        (crc ^ self.xorout) & mask
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn trace(&self, bits: &[bool]) -> Vec<CrcStep> {
+        let mask = self.mask();
+        let top_shift = self.width as u32 - 1;
+        let reflect_input = self.refin && bits.len().is_multiple_of(8);
+        let mut crc = self.init & mask;
+
+        (0..bits.len())
+            .map(|position| {
+                let index = if reflect_input { position / 8 * 8 + 7 - position % 8 } else { position };
+                let bit = bits[index];
+                let feedback = bit ^ ((crc >> top_shift) & 1 == 1);
+                crc = (crc << 1) & mask;
+                if feedback {
+                    crc ^= self.poly;
+                }
+                CrcStep { index, bit, feedback, register: crc }
+            })
+            .collect()
+    }
+
+    pub fn finish_trace(&self, steps: &[CrcStep]) -> u64 {
+        let crc = steps.last().map_or(self.init & self.mask(), |step| step.register);
+        let crc = if self.refout { reflect(crc, self.width as u32) } else { crc };
+        (crc ^ self.xorout) & self.mask()
+    }
+
+    pub fn residue(&self) -> u64 {
+        let width = self.width as u32;
+        let xorout = if self.refout { reflect(self.xorout & self.mask(), width) } else { self.xorout & self.mask() };
+        let crc = CrcConfig::new(self.width, self.poly).checksum_u64(xorout, width as usize);
+        if self.refout { reflect(crc, width) } else { crc }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn crc_to_bits(&self, crc: u64) -> Vec<bool> {
+        self.crc_to_bits_in(crc, Endianness::for_config(self))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn crc_to_bits_in(&self, crc: u64, endianness: Endianness) -> Vec<bool> {
+        match endianness {
+            Endianness::Little => crate::bytes_to_bits(&self.crc_bytes(crc, endianness)),
+            Endianness::Big => (0..self.width).rev().map(|i| (crc >> i) & 1 == 1).collect(),
+        }
+    }
+
+    pub fn crc_field_len(&self, endianness: Endianness) -> usize {
+        match endianness {
+            Endianness::Little => (self.width as usize).div_ceil(8) * 8,
+            Endianness::Big => self.width as usize,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn crc_bytes(&self, crc: u64, endianness: Endianness) -> Vec<u8> {
+        let len = (self.width as usize).div_ceil(8);
+        let crc = crc & self.mask();
+        match endianness {
+            Endianness::Little => crc.to_le_bytes()[..len].to_vec(),
+            Endianness::Big => crc.to_be_bytes()[8 - len..].to_vec(),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn crc_from_bits(&self, bits: &[bool]) -> u64 {
+        self.crc_from_bits_in(bits, Endianness::for_config(self))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn crc_from_bits_in(&self, bits: &[bool], endianness: Endianness) -> u64 {
+        match endianness {
+            Endianness::Little => {
+                let mut bytes = [0u8; 8];
+                for (byte, packed) in bytes.iter_mut().zip(crate::bits_to_bytes(bits)) {
+                    *byte = packed;
+                }
+                u64::from_le_bytes(bytes) & self.mask()
+            }
+            Endianness::Big => bits.iter().fold(0, |crc, &bit| (crc << 1) | bit as u64) & self.mask(),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn append_crc(&self, bits: &[bool]) -> Vec<bool> {
+        self.append_crc_in(bits, Endianness::for_config(self))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn append_crc_in(&self, bits: &[bool], endianness: Endianness) -> Vec<bool> {
+        let mut codeword = bits.to_vec();
+        codeword.extend(self.crc_to_bits_in(self.checksum_message(bits), endianness));
+        codeword
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn codeword_residue(&self, codeword: &[bool]) -> u64 {
+        (self.checksum_message(codeword) ^ self.xorout) & self.mask()
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn check_codeword(&self, codeword: &[bool]) -> bool {
+        self.codeword_residue(codeword) == self.residue()
+    }
+
+    pub fn start(&self) -> u64 {
+        let init = self.init & self.mask();
+        if self.refin {
+            reflect(init, self.width as u32)
+        } else {
+            init
+        }
+    }
+
+    pub fn update_with_table(&self, table: &[u64], crc: u64, bytes: &[u8]) -> u64 {
+        let mask = self.mask();
+        let width = self.width as u32;
+        let mut crc = crc;
+
+        for &byte in bytes {
+            crc = if self.refin {
+                (crc >> 8) ^ table[((crc ^ byte as u64) & 0xFF) as usize]
+            } else if width >= 8 {
+                (crc << 8) ^ table[(((crc >> (width - 8)) ^ byte as u64) & 0xFF) as usize]
+            } else {
+                let shift = 8 - width;
+                table[(((crc << shift) ^ byte as u64) & 0xFF) as usize]
+            } & mask;
+        }
+
+        crc
+    }
+
+    pub fn update_bitwise(&self, crc: u64, bytes: &[u8]) -> u64 {
+        let mask = self.mask();
+        let mut crc = crc;
+        if self.refin {
+            let poly = reflect(self.poly, self.width as u32);
+            for &byte in bytes {
+                crc ^= byte as u64;
+                for _ in 0..8 {
+                    crc = if crc & 1 != 0 { (crc >> 1) ^ poly } else { crc >> 1 };
+                }
+            }
+            return crc & mask;
+        }
+
+        let top_shift = self.width as u32 - 1;
+        for &byte in bytes {
+            for i in (0..8).rev() {
+                let crcnxt = ((byte >> i) & 1 == 1) ^ ((crc >> top_shift) & 1 == 1);
+                crc = (crc << 1) & mask;
+                if crcnxt {
+                    crc ^= self.poly;
+                }
+            }
+        }
+        crc
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn slice8_table(&self) -> Box<[[u64; 256]; 8]> {
+        let mut tables = Box::new([[0u64; 256]; 8]);
+        if self.refin {
+            tables[0].copy_from_slice(&self.table());
+        } else {
+            let poly = self.poly << (64 - self.width as u32);
+            for (i, entry) in tables[0].iter_mut().enumerate() {
+                let mut crc = (i as u64) << 56;
+                for _ in 0..8 {
+                    crc = if crc >> 63 != 0 { (crc << 1) ^ poly } else { crc << 1 };
+                }
+                *entry = crc;
+            }
+        }
+
+        for k in 1..8 {
+            let (done, rest) = tables.split_at_mut(k);
+            let (first, previous) = (&done[0], &done[k - 1]);
+            for (entry, &prev) in rest[0].iter_mut().zip(previous.iter()) {
+                *entry = if self.refin {
+                    (prev >> 8) ^ first[(prev & 0xFF) as usize]
+                } else {
+                    (prev << 8) ^ first[(prev >> 56) as usize]
+                };
+            }
+        }
+        tables
+    }
+
+    pub fn update_slice8(&self, tables: &[[u64; 256]; 8], crc: u64, bytes: &[u8]) -> u64 {
+        let mut chunks = bytes.chunks_exact(8);
+        if self.refin {
+            let mut crc = crc;
+            for chunk in &mut chunks {
+                let value = crc ^ u64::from_le_bytes(chunk.try_into().expect("8-byte chunk"));
+                crc = (0..8).fold(0, |acc, i| acc ^ tables[7 - i][((value >> (8 * i)) & 0xFF) as usize]);
+            }
+            return self.update_with_table(&tables[0], crc, chunks.remainder());
+        }
+
+        let shift = 64 - self.width as u32;
+        let mut crc = crc << shift;
+        for chunk in &mut chunks {
+            let value = crc ^ u64::from_be_bytes(chunk.try_into().expect("8-byte chunk"));
+            crc = (0..8).fold(0, |acc, i| acc ^ tables[7 - i][((value >> (56 - 8 * i)) & 0xFF) as usize]);
+        }
+        for &byte in chunks.remainder() {
+            crc = (crc << 8) ^ tables[0][((crc >> 56) ^ byte as u64) as usize];
+        }
+        crc >> shift
+    }
+
+    pub fn finalize(&self, crc: u64) -> u64 {
+        let width = self.width as u32;
+        let crc = if self.refin != self.refout { reflect(crc, width) } else { crc };
+        (crc ^ self.xorout) & self.mask()
+    }
+}
+
+impl Default for CrcConfig {
+    fn default() -> Self {
+        CrcConfig::CAN
+    }
+}
+
+pub fn reflect(value: u64, width: u32) -> u64 {
+    let mut result = 0u64;
+    for i in 0..width {
+        if (value >> i) & 1 == 1 {
+            result |= 1 << (width - 1 - i);
+        }
+    }
+    result
+}
+
+#[cfg(feature = "alloc")]
+pub fn parse_param(value: &str) -> Result<u64, ConfigError> {
+    let trimmed = value.trim().replace('_', "");
+    let parsed = if let Some(hex) = trimmed.strip_prefix("0x").or_else(|| trimmed.strip_prefix("0X")) {
+        u64::from_str_radix(hex, 16)
+    } else {
+        trimmed.parse()
+    };
+    parsed.map_err(|_| ConfigError::InvalidNumber { value: value.to_string() })
+}
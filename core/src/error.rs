@@ -0,0 +1,460 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParseError {
+    Empty,
+    InvalidBinaryChars { chars: String, index: usize },
+    NoBinaryData,
+    BinaryTooLong { bits: usize, max_bits: usize },
+    InvalidHexChars { chars: String, index: usize },
+    NoHexData,
+    OddHexLength { digits: usize },
+    HexTooLong { bytes: usize, max_bytes: usize },
+    InvalidHexFormat,
+    InvalidBase64Chars { chars: String, index: usize },
+    NoBase64Data,
+    InvalidBase64Length { chars: usize },
+    PartialByte { bits: usize },
+    InvalidArrayElement { element: String },
+    InvalidEscape { sequence: String, index: usize },
+    UnterminatedString { index: usize },
+}
+
+#[cfg(feature = "alloc")]
+impl ParseError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParseError::Empty => "E001",
+            ParseError::InvalidBinaryChars { .. } => "E002",
+            ParseError::NoBinaryData => "E003",
+            ParseError::BinaryTooLong { .. } => "E004",
+            ParseError::InvalidHexChars { .. } => "E005",
+            ParseError::NoHexData => "E006",
+            ParseError::OddHexLength { .. } => "E007",
+            ParseError::HexTooLong { .. } => "E008",
+            ParseError::InvalidHexFormat => "E009",
+            ParseError::InvalidBase64Chars { .. } => "E010",
+            ParseError::NoBase64Data => "E011",
+            ParseError::InvalidBase64Length { .. } => "E012",
+            ParseError::PartialByte { .. } => "E013",
+            ParseError::InvalidArrayElement { .. } => "E014",
+            ParseError::InvalidEscape { .. } => "E015",
+            ParseError::UnterminatedString { .. } => "E016",
+        }
+    }
+
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            ParseError::InvalidBinaryChars { index, .. }
+            | ParseError::InvalidHexChars { index, .. }
+            | ParseError::InvalidBase64Chars { index, .. }
+            | ParseError::InvalidEscape { index, .. }
+            | ParseError::UnterminatedString { index } => Some(*index),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "input is empty"),
+            ParseError::InvalidBinaryChars { chars, index } => {
+                write!(f, "invalid binary characters: '{}' (first at index {})", chars, index)
+            }
+            ParseError::NoBinaryData => write!(f, "no binary digits in input"),
+            ParseError::BinaryTooLong { bits, max_bits } => {
+                write!(f, "input too long: {} bits (max {})", bits, max_bits)
+            }
+            ParseError::InvalidHexChars { chars, index } => {
+                write!(f, "invalid hex characters: '{}' (first at index {})", chars, index)
+            }
+            ParseError::NoHexData => write!(f, "no hex digits in input"),
+            ParseError::OddHexLength { digits } => {
+                write!(f, "odd number of hex digits: {}", digits)
+            }
+            ParseError::HexTooLong { bytes, max_bytes } => {
+                write!(f, "input too long: {} bytes (max {})", bytes, max_bytes)
+            }
+            ParseError::InvalidHexFormat => write!(f, "invalid hex format"),
+            ParseError::InvalidBase64Chars { chars, index } => {
+                write!(f, "invalid base64 characters: '{}' (first at index {})", chars, index)
+            }
+            ParseError::NoBase64Data => write!(f, "no base64 characters in input"),
+            ParseError::InvalidBase64Length { chars } => {
+                write!(f, "invalid base64 length: {} characters", chars)
+            }
+            ParseError::PartialByte { bits } => {
+                write!(f, "input of {} bits is not a whole number of bytes", bits)
+            }
+            ParseError::InvalidArrayElement { element } => {
+                write!(f, "array element is not a byte value: '{}'", element)
+            }
+            ParseError::InvalidEscape { sequence, index } => {
+                write!(f, "invalid escape sequence '{}' at index {}", sequence, index)
+            }
+            ParseError::UnterminatedString { index } => {
+                write!(f, "string opened at index {} is not closed", index)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConfigError {
+    InvalidWidth { width: u8 },
+    PolyTooWide { poly: u64, width: u8 },
+    EvenPoly { poly: u64 },
+    #[cfg(feature = "alloc")]
+    InvalidNumber { value: String },
+    InvalidDataWidth { width: u8 },
+}
+
+impl ConfigError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ConfigError::InvalidWidth { .. } => "E101",
+            ConfigError::PolyTooWide { .. } => "E102",
+            ConfigError::EvenPoly { .. } => "E103",
+            #[cfg(feature = "alloc")]
+            ConfigError::InvalidNumber { .. } => "E104",
+            ConfigError::InvalidDataWidth { .. } => "E105",
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::InvalidWidth { width } => {
+                write!(f, "unsupported CRC width: {} (expected 1..=64)", width)
+            }
+            ConfigError::PolyTooWide { poly, width } => {
+                write!(f, "polynomial 0x{:X} does not fit in {} bits", poly, width)
+            }
+            ConfigError::EvenPoly { poly } => {
+                write!(f, "polynomial 0x{:X} has no x^0 term", poly)
+            }
+            #[cfg(feature = "alloc")]
+            ConfigError::InvalidNumber { value } => write!(f, "invalid number: '{}'", value),
+            ConfigError::InvalidDataWidth { width } => {
+                write!(f, "unsupported data width: {} (expected 1..=64)", width)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ConfigError {}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrameError {
+    IdOutOfRange { id: u32, extended: bool },
+    DataTooLong { len: usize, max_len: usize },
+    Syntax { content: String },
+    TooShort { len: usize, min_len: usize },
+    MissingCrc { bits: usize, crc_bits: usize },
+    SegmentSequence { index: usize, sequence: u8 },
+    CapacityExceeded { capacity: usize },
+}
+
+#[cfg(feature = "alloc")]
+impl FrameError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            FrameError::IdOutOfRange { .. } => "E201",
+            FrameError::DataTooLong { .. } => "E202",
+            FrameError::Syntax { .. } => "E203",
+            FrameError::TooShort { .. } => "E204",
+            FrameError::MissingCrc { .. } => "E205",
+            FrameError::SegmentSequence { .. } => "E206",
+            FrameError::CapacityExceeded { .. } => "E207",
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameError::IdOutOfRange { id, extended } => FixedError::IdOutOfRange { id: *id, extended: *extended }.fmt(f),
+            FrameError::DataTooLong { len, max_len } => FixedError::DataTooLong { len: *len, max_len: *max_len }.fmt(f),
+            FrameError::Syntax { content } => write!(f, "unrecognized CAN frame: '{}'", content),
+            FrameError::TooShort { len, min_len } => {
+                write!(f, "frame too short: {} bytes (min {})", len, min_len)
+            }
+            FrameError::MissingCrc { bits, crc_bits } => {
+                write!(f, "frame of {} bits has no room for a {}-bit CRC field", bits, crc_bits)
+            }
+            FrameError::SegmentSequence { index, sequence } => {
+                write!(f, "segment {} is out of sequence (sequence number {})", index + 1, sequence)
+            }
+            FrameError::CapacityExceeded { capacity } => FixedError::CapacityExceeded { capacity: *capacity }.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for FrameError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FixedError {
+    IdOutOfRange { id: u32, extended: bool },
+    DataTooLong { len: usize, max_len: usize },
+    CapacityExceeded { capacity: usize },
+}
+
+impl FixedError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            FixedError::IdOutOfRange { .. } => "E201",
+            FixedError::DataTooLong { .. } => "E202",
+            FixedError::CapacityExceeded { .. } => "E207",
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<FixedError> for FrameError {
+    fn from(error: FixedError) -> Self {
+        match error {
+            FixedError::IdOutOfRange { id, extended } => FrameError::IdOutOfRange { id, extended },
+            FixedError::DataTooLong { len, max_len } => FrameError::DataTooLong { len, max_len },
+            FixedError::CapacityExceeded { capacity } => FrameError::CapacityExceeded { capacity },
+        }
+    }
+}
+
+impl fmt::Display for FixedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixedError::IdOutOfRange { id, extended } => {
+                let kind = if *extended { "29-bit" } else { "11-bit" };
+                write!(f, "identifier 0x{:X} does not fit in a {} ID", id, kind)
+            }
+            FixedError::DataTooLong { len, max_len } => {
+                write!(f, "payload too long: {} bytes (max {})", len, max_len)
+            }
+            FixedError::CapacityExceeded { capacity } => {
+                write!(f, "fixed-capacity buffer of {} bits is full", capacity)
+            }
+        }
+    }
+}
+
+impl core::error::Error for FixedError {}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DbcError {
+    Syntax { line: usize, content: String },
+    UnknownMessage { name: String },
+    UnknownSignal { name: String },
+    ValueOutOfRange { signal: String, value: f64 },
+    Frame(FrameError),
+}
+
+#[cfg(feature = "alloc")]
+impl DbcError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            DbcError::Syntax { .. } => "E301",
+            DbcError::UnknownMessage { .. } => "E302",
+            DbcError::UnknownSignal { .. } => "E303",
+            DbcError::ValueOutOfRange { .. } => "E304",
+            DbcError::Frame(e) => e.code(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for DbcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DbcError::Syntax { line, content } => {
+                write!(f, "DBC syntax error on line {}: '{}'", line, content)
+            }
+            DbcError::UnknownMessage { name } => write!(f, "unknown DBC message: '{}'", name),
+            DbcError::UnknownSignal { name } => write!(f, "unknown DBC signal: '{}'", name),
+            DbcError::ValueOutOfRange { signal, value } => {
+                write!(f, "value {} does not fit in signal '{}'", value, signal)
+            }
+            DbcError::Frame(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for DbcError {}
+
+#[cfg(feature = "alloc")]
+impl From<FrameError> for DbcError {
+    fn from(e: FrameError) -> Self {
+        DbcError::Frame(e)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ImageError {
+    Syntax { line: usize },
+    Checksum { line: usize, expected: u8, actual: u8 },
+    UnsupportedRecord { line: usize, kind: String },
+    Overlap { address: u64 },
+    AddressOverflow { address: u64 },
+    MissingEndRecord,
+}
+
+#[cfg(feature = "alloc")]
+impl ImageError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ImageError::Syntax { .. } => "E401",
+            ImageError::Checksum { .. } => "E402",
+            ImageError::UnsupportedRecord { .. } => "E403",
+            ImageError::Overlap { .. } => "E404",
+            ImageError::AddressOverflow { .. } => "E405",
+            ImageError::MissingEndRecord => "E406",
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::Syntax { line } => write!(f, "malformed record on line {}", line),
+            ImageError::Checksum { line, expected, actual } => {
+                write!(f, "record checksum mismatch on line {}: expected {:02X}, found {:02X}", line, expected, actual)
+            }
+            ImageError::UnsupportedRecord { line, kind } => {
+                write!(f, "unsupported record type {} on line {}", kind, line)
+            }
+            ImageError::Overlap { address } => {
+                write!(f, "data records overlap at address 0x{:08X}", address)
+            }
+            ImageError::AddressOverflow { address } => {
+                write!(f, "data at address 0x{:X} exceeds the 32-bit address space", address)
+            }
+            ImageError::MissingEndRecord => write!(f, "missing end-of-file record"),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for ImageError {}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MqttError {
+    Address { broker: String },
+    Connection { broker: String, message: String },
+    Publish { message: String },
+}
+
+#[cfg(feature = "alloc")]
+impl MqttError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            MqttError::Address { .. } => "E601",
+            MqttError::Connection { .. } => "E602",
+            MqttError::Publish { .. } => "E603",
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for MqttError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MqttError::Address { broker } => write!(f, "invalid MQTT broker address '{}' (expected host[:port])", broker),
+            MqttError::Connection { broker, message } => write!(f, "cannot connect to MQTT broker {}: {}", broker, message),
+            MqttError::Publish { message } => write!(f, "cannot publish MQTT message: {}", message),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for MqttError {}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ManifestError {
+    Syntax { line: usize, content: String },
+    UnknownAlgorithm { name: String },
+}
+
+#[cfg(feature = "alloc")]
+impl ManifestError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ManifestError::Syntax { .. } => "E701",
+            ManifestError::UnknownAlgorithm { .. } => "E702",
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestError::Syntax { line, content } => write!(f, "malformed manifest entry on line {}: '{}'", line, content),
+            ManifestError::UnknownAlgorithm { name } => write!(f, "unknown manifest algorithm '{}'", name),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for ManifestError {}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GpuError {
+    NoAdapter,
+    Device { message: String },
+    UnsupportedWidth { width: u8, max_width: u8 },
+    BatchLength { len: usize, message_len: usize },
+}
+
+#[cfg(feature = "alloc")]
+impl GpuError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            GpuError::NoAdapter => "E801",
+            GpuError::Device { .. } => "E802",
+            GpuError::UnsupportedWidth { .. } => "E803",
+            GpuError::BatchLength { .. } => "E804",
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for GpuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GpuError::NoAdapter => write!(f, "no GPU adapter available"),
+            GpuError::Device { message } => write!(f, "GPU error: {}", message),
+            GpuError::UnsupportedWidth { width, max_width } => write!(f, "{}-bit CRCs are not supported on the GPU (max {} bits)", width, max_width),
+            GpuError::BatchLength { len, message_len } => write!(f, "batch of {} bytes cannot be split into messages of {} bytes", len, message_len),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for GpuError {}
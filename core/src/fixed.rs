@@ -0,0 +1,198 @@
+#[cfg(feature = "alloc")]
+use crate::frame::CanFrame;
+use crate::frame::{CRC_DELIMITER, EOF_BITS, MAX_CLASSIC_DATA_LEN, MAX_EXTENDED_ID, MAX_STANDARD_ID, STUFF_RUN};
+use crate::{calculate_can_crc_iter, FixedError};
+
+pub const FRAME_CRC_INPUT_BYTES: usize = 13;
+pub const FRAME_UNSTUFFED_BYTES: usize = 15;
+pub const FRAME_WIRE_BYTES: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedBits<const BYTES: usize> {
+    bytes: [u8; BYTES],
+    len: usize,
+}
+
+impl<const BYTES: usize> FixedBits<BYTES> {
+    pub const CAPACITY: usize = BYTES * 8;
+
+    pub const fn new() -> Self {
+        Self { bytes: [0; BYTES], len: 0 }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FixedError> {
+        if bytes.len() > BYTES {
+            return Err(FixedError::CapacityExceeded { capacity: Self::CAPACITY });
+        }
+        let mut bits = Self::new();
+        bits.bytes[..bytes.len()].copy_from_slice(bytes);
+        bits.len = bytes.len() * 8;
+        Ok(bits)
+    }
+
+    pub fn from_bits(bits: impl IntoIterator<Item = bool>) -> Result<Self, FixedError> {
+        let mut fixed = Self::new();
+        for bit in bits {
+            fixed.push(bit)?;
+        }
+        Ok(fixed)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, bit: bool) -> Result<(), FixedError> {
+        if self.len == Self::CAPACITY {
+            return Err(FixedError::CapacityExceeded { capacity: Self::CAPACITY });
+        }
+        if bit {
+            self.bytes[self.len / 8] |= 0x80 >> (self.len % 8);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn push_value(&mut self, value: u64, count: u32) -> Result<(), FixedError> {
+        (0..count).rev().try_for_each(|i| self.push((value >> i) & 1 == 1))
+    }
+
+    pub fn get(&self, index: usize) -> Option<bool> {
+        (index < self.len).then(|| self.bytes[index / 8] & (0x80 >> (index % 8)) != 0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|index| self.bytes[index / 8] & (0x80 >> (index % 8)) != 0)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len.div_ceil(8)]
+    }
+
+    pub fn can_crc(&self) -> u16 {
+        calculate_can_crc_iter(self.iter())
+    }
+}
+
+impl<const BYTES: usize> Default for FixedBits<BYTES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedCanFrame {
+    pub id: u32,
+    pub extended: bool,
+    pub rtr: bool,
+    len: u8,
+    data: [u8; MAX_CLASSIC_DATA_LEN],
+}
+
+impl FixedCanFrame {
+    pub fn new(id: u32, data: &[u8]) -> Result<Self, FixedError> {
+        Self::build(id, false, false, data)
+    }
+
+    pub fn new_extended(id: u32, data: &[u8]) -> Result<Self, FixedError> {
+        Self::build(id, true, false, data)
+    }
+
+    fn build(id: u32, extended: bool, rtr: bool, data: &[u8]) -> Result<Self, FixedError> {
+        let max_id = if extended { MAX_EXTENDED_ID } else { MAX_STANDARD_ID };
+        if id > max_id {
+            return Err(FixedError::IdOutOfRange { id, extended });
+        }
+        if data.len() > MAX_CLASSIC_DATA_LEN {
+            return Err(FixedError::DataTooLong { len: data.len(), max_len: MAX_CLASSIC_DATA_LEN });
+        }
+        let mut bytes = [0; MAX_CLASSIC_DATA_LEN];
+        bytes[..data.len()].copy_from_slice(data);
+        Ok(Self { id, extended, rtr, len: data.len() as u8, data: bytes })
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data[..self.len as usize]
+    }
+
+    pub fn dlc(&self) -> u8 {
+        self.len
+    }
+
+    pub fn crc_bits(&self) -> FixedBits<FRAME_CRC_INPUT_BYTES> {
+        let mut bits = FixedBits::new();
+        self.write_crc_bits(&mut bits).expect("a classic CAN frame fits in FRAME_CRC_INPUT_BYTES");
+        bits
+    }
+
+    fn write_crc_bits<const BYTES: usize>(&self, bits: &mut FixedBits<BYTES>) -> Result<(), FixedError> {
+        bits.push(false)?;
+        if self.extended {
+            bits.push_value((self.id >> 18) as u64, 11)?;
+            bits.push_value(0b11, 2)?;
+            bits.push_value((self.id & 0x3FFFF) as u64, 18)?;
+        } else {
+            bits.push_value(self.id as u64, 11)?;
+        }
+        bits.push(self.rtr)?;
+        bits.push_value(0, 2)?;
+        bits.push_value(self.len as u64, 4)?;
+        if !self.rtr {
+            self.data().iter().try_for_each(|&byte| bits.push_value(byte as u64, 8))?;
+        }
+        Ok(())
+    }
+
+    pub fn crc(&self) -> u16 {
+        self.crc_bits().can_crc()
+    }
+
+    pub fn verify_crc(&self, received: u16) -> bool {
+        received & 0x7FFF == self.crc()
+    }
+
+    pub fn wire_bits(&self) -> FixedBits<FRAME_WIRE_BYTES> {
+        let mut unstuffed = FixedBits::<FRAME_UNSTUFFED_BYTES>::new();
+        self.write_crc_bits(&mut unstuffed)
+            .and_then(|()| unstuffed.push_value(self.crc() as u64, 15))
+            .expect("a classic CAN frame and its CRC fit in FRAME_UNSTUFFED_BYTES");
+
+        let mut wire = FixedBits::new();
+        let mut run = 0;
+        let mut last = None;
+        for value in unstuffed.iter() {
+            wire.push(value).expect("a stuffed classic CAN frame fits in FRAME_WIRE_BYTES");
+            run = if last == Some(value) { run + 1 } else { 1 };
+            last = Some(value);
+            if run == STUFF_RUN {
+                wire.push(!value).expect("a stuffed classic CAN frame fits in FRAME_WIRE_BYTES");
+                run = 1;
+                last = Some(!value);
+            }
+        }
+        for value in [CRC_DELIMITER, false, true].into_iter().chain(core::iter::repeat_n(true, EOF_BITS)) {
+            wire.push(value).expect("a stuffed classic CAN frame fits in FRAME_WIRE_BYTES");
+        }
+        wire
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<FixedCanFrame> for CanFrame {
+    fn from(frame: FixedCanFrame) -> Self {
+        CanFrame { id: frame.id, extended: frame.extended, rtr: frame.rtr, data: frame.data().to_vec() }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&CanFrame> for FixedCanFrame {
+    type Error = FixedError;
+
+    fn try_from(frame: &CanFrame) -> Result<Self, FixedError> {
+        Self::build(frame.id, frame.extended, frame.rtr, &frame.data)
+    }
+}
@@ -0,0 +1,62 @@
+use crate::engine::CrcConfig;
+
+pub const HEADER_CRC: CrcConfig = CrcConfig {
+    width: 11,
+    poly: 0x385,
+    init: 0x01A,
+    refin: false,
+    refout: false,
+    xorout: 0x000,
+};
+
+pub const FRAME_CRC_A: CrcConfig = CrcConfig {
+    width: 24,
+    poly: 0x5D_6DCB,
+    init: 0xFE_DCBA,
+    refin: false,
+    refout: false,
+    xorout: 0x00_0000,
+};
+
+pub const FRAME_CRC_B: CrcConfig = CrcConfig {
+    init: 0xAB_CDEF,
+    ..FRAME_CRC_A
+};
+
+pub const HEADER_CRC_BITS: usize = 20;
+pub const HEADER_BYTES: usize = 5;
+pub const MAX_FRAME_ID: u16 = 0x7FF;
+pub const MAX_PAYLOAD_WORDS: u8 = 127;
+pub const FRAME_CRC_MAX_BITS: usize = (HEADER_BYTES + 2 * MAX_PAYLOAD_WORDS as usize) * 8;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Channel {
+    #[default]
+    A,
+    B,
+}
+
+impl Channel {
+    pub fn frame_crc_config(self) -> CrcConfig {
+        match self {
+            Channel::A => FRAME_CRC_A,
+            Channel::B => FRAME_CRC_B,
+        }
+    }
+}
+
+pub fn header_crc_bits(sync: bool, startup: bool, frame_id: u16, payload_words: u8) -> Vec<bool> {
+    let mut bits = vec![sync, startup];
+    bits.extend((0..11).rev().map(|bit| (frame_id >> bit) & 1 == 1));
+    bits.extend((0..7).rev().map(|bit| (payload_words >> bit) & 1 == 1));
+    bits
+}
+
+pub fn header_crc(sync: bool, startup: bool, frame_id: u16, payload_words: u8) -> u16 {
+    HEADER_CRC.checksum_bits(&header_crc_bits(sync, startup, frame_id, payload_words)) as u16
+}
+
+pub fn frame_crc(header_and_payload: &[u8], channel: Channel) -> u32 {
+    channel.frame_crc_config().checksum(header_and_payload) as u32
+}
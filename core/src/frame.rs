@@ -0,0 +1,442 @@
+use crate::Endianness;
+#[cfg(feature = "alloc")]
+use crate::{calculate_can_crc, CrcConfig, FrameError};
+#[cfg(feature = "std")]
+use crate::{CrcAlgorithm, CrcCatalog};
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+pub const MAX_STANDARD_ID: u32 = 0x7FF;
+pub const MAX_EXTENDED_ID: u32 = 0x1FFF_FFFF;
+pub const MAX_CLASSIC_DATA_LEN: usize = 8;
+pub const MAX_FD_DATA_LEN: usize = 64;
+pub const FD_DATA_LENGTHS: [usize; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 16, 20, 24, 32, 48, 64];
+pub const FD_PADDING_BYTE: u8 = 0x00;
+pub const FD_CRC17_MAX_DATA_LEN: usize = 16;
+pub const FD_FIXED_STUFF_INTERVAL: usize = 4;
+pub const FD_STUFF_COUNT_BITS: usize = 4;
+pub const CRC_DELIMITER: bool = true;
+pub const CRC_ENDIANNESS: Endianness = Endianness::Big;
+pub const STUFF_RUN: usize = 5;
+pub const EOF_BITS: usize = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WireBit {
+    pub value: bool,
+    pub stuff: bool,
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CanFrame {
+    pub id: u32,
+    pub extended: bool,
+    pub rtr: bool,
+    pub data: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl CanFrame {
+    pub fn new(id: u32, data: &[u8]) -> Result<Self, FrameError> {
+        Self::build(id, false, data)
+    }
+
+    pub fn new_extended(id: u32, data: &[u8]) -> Result<Self, FrameError> {
+        Self::build(id, true, data)
+    }
+
+    fn build(id: u32, extended: bool, data: &[u8]) -> Result<Self, FrameError> {
+        let max_id = if extended { MAX_EXTENDED_ID } else { MAX_STANDARD_ID };
+        if id > max_id {
+            return Err(FrameError::IdOutOfRange { id, extended });
+        }
+        if data.len() > MAX_CLASSIC_DATA_LEN {
+            return Err(FrameError::DataTooLong {
+                len: data.len(),
+                max_len: MAX_CLASSIC_DATA_LEN,
+            });
+        }
+        Ok(Self {
+            id,
+            extended,
+            rtr: false,
+            data: data.to_vec(),
+        })
+    }
+
+    pub fn parse_candump(line: &str) -> Result<Self, FrameError> {
+        let syntax = || FrameError::Syntax { content: line.trim().to_string() };
+        let tokens: Vec<&str> = line.split_whitespace()
+            .filter(|token| !token.starts_with('('))
+            .collect();
+
+        let (id, data) = match tokens.iter().find_map(|token| token.split_once('#')) {
+            Some((id, data)) => (id, data.replace('.', "")),
+            None => {
+                let dlc = tokens.iter()
+                    .position(|token| token.starts_with('[') && token.ends_with(']'))
+                    .filter(|&dlc| dlc > 0)
+                    .ok_or_else(syntax)?;
+                (tokens[dlc - 1], tokens[dlc + 1..].concat())
+            }
+        };
+
+        let raw_id = u32::from_str_radix(id, 16).map_err(|_| syntax())?;
+        let extended = id.len() > 3;
+        if data.starts_with(['R', 'r']) {
+            let mut frame = Self::build(raw_id, extended, &[])?;
+            frame.rtr = true;
+            return Ok(frame);
+        }
+        if data.starts_with('#') || !data.is_ascii() || !data.len().is_multiple_of(2) {
+            return Err(syntax());
+        }
+        let bytes = (0..data.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&data[i..i + 2], 16).ok())
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(syntax)?;
+        Self::build(raw_id, extended, &bytes)
+    }
+
+    pub fn to_candump(&self) -> String {
+        let id = if self.extended { format!("{:08X}", self.id) } else { format!("{:03X}", self.id) };
+        if self.rtr {
+            return format!("{}#R", id);
+        }
+        let data: String = self.data.iter().map(|byte| format!("{:02X}", byte)).collect();
+        format!("{}#{}", id, data)
+    }
+
+    pub fn dlc(&self) -> u8 {
+        self.data.len() as u8
+    }
+
+    pub fn crc_bits(&self) -> Vec<bool> {
+        let mut bits = Vec::with_capacity(40 + self.data.len() * 8);
+        bits.push(false);
+
+        if self.extended {
+            push_bits(&mut bits, (self.id >> 18) as u64, 11);
+            bits.push(true);
+            bits.push(true);
+            push_bits(&mut bits, (self.id & 0x3FFFF) as u64, 18);
+            bits.push(self.rtr);
+            bits.push(false);
+            bits.push(false);
+        } else {
+            push_bits(&mut bits, self.id as u64, 11);
+            bits.push(self.rtr);
+            bits.push(false);
+            bits.push(false);
+        }
+
+        push_bits(&mut bits, self.dlc() as u64, 4);
+        if !self.rtr {
+            for &byte in &self.data {
+                push_bits(&mut bits, byte as u64, 8);
+            }
+        }
+
+        bits
+    }
+
+    pub fn crc(&self) -> u16 {
+        calculate_can_crc(&self.crc_bits())
+    }
+
+    pub fn wire_bits(&self) -> Vec<WireBit> {
+        let mut bits = self.crc_bits();
+        push_bits(&mut bits, self.crc() as u64, 15);
+
+        let mut wire = stuff_bits(&bits);
+        let tail = [CRC_DELIMITER, false, true].into_iter().chain(core::iter::repeat_n(true, EOF_BITS));
+        wire.extend(tail.map(|value| WireBit { value, stuff: false }));
+        wire
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CanFdFrame {
+    pub id: u32,
+    pub extended: bool,
+    pub brs: bool,
+    pub esi: bool,
+    pub data: Vec<u8>,
+}
+
+#[cfg(feature = "std")]
+impl CanFdFrame {
+    pub fn new(id: u32, extended: bool, data: &[u8]) -> Result<Self, FrameError> {
+        let max_id = if extended { MAX_EXTENDED_ID } else { MAX_STANDARD_ID };
+        if id > max_id {
+            return Err(FrameError::IdOutOfRange { id, extended });
+        }
+        let len = fd_padded_len(data.len()).ok_or(FrameError::DataTooLong {
+            len: data.len(),
+            max_len: MAX_FD_DATA_LEN,
+        })?;
+        let mut data = data.to_vec();
+        data.resize(len, FD_PADDING_BYTE);
+        Ok(Self {
+            id,
+            extended,
+            brs: false,
+            esi: false,
+            data,
+        })
+    }
+
+    pub fn dlc(&self) -> u8 {
+        fd_dlc(self.data.len()).unwrap_or(15)
+    }
+
+    pub fn crc_algorithm(&self) -> &'static CrcAlgorithm {
+        let name = if self.data.len() <= FD_CRC17_MAX_DATA_LEN { "CRC-17/CAN-FD" } else { "CRC-21/CAN-FD" };
+        CrcCatalog::find(name).unwrap_or(CrcCatalog::can())
+    }
+
+    pub fn crc_bits(&self) -> Vec<bool> {
+        let mut bits = Vec::with_capacity(48 + self.data.len() * 8);
+        bits.push(false);
+
+        if self.extended {
+            push_bits(&mut bits, (self.id >> 18) as u64, 11);
+            bits.push(true);
+            bits.push(true);
+            push_bits(&mut bits, (self.id & 0x3FFFF) as u64, 18);
+        } else {
+            push_bits(&mut bits, self.id as u64, 11);
+        }
+        bits.push(false);
+        if !self.extended {
+            bits.push(false);
+        }
+        bits.push(true);
+        bits.push(false);
+        bits.push(self.brs);
+        bits.push(self.esi);
+
+        push_bits(&mut bits, self.dlc() as u64, 4);
+        for &byte in &self.data {
+            push_bits(&mut bits, byte as u64, 8);
+        }
+
+        bits
+    }
+
+    pub fn stuffed_bits(&self) -> Vec<WireBit> {
+        let mut wire = stuff_bits(&self.crc_bits());
+        if wire.last().is_some_and(|bit| bit.stuff) {
+            wire.pop();
+        }
+        wire
+    }
+
+    pub fn stuff_count(&self) -> usize {
+        self.stuffed_bits().iter().filter(|bit| bit.stuff).count()
+    }
+
+    pub fn iso_crc_config(&self) -> CrcConfig {
+        let config = self.crc_algorithm().config;
+        CrcConfig { init: 1 << (config.width - 1), ..config }
+    }
+
+    pub fn iso_crc_bits(&self) -> Vec<bool> {
+        let wire = self.stuffed_bits();
+        let stuffed = wire.iter().filter(|bit| bit.stuff).count();
+        let mut bits: Vec<bool> = wire.iter().map(|bit| bit.value).collect();
+        bits.extend(stuff_count_bits(stuffed));
+        bits
+    }
+
+    pub fn crc(&self) -> u32 {
+        self.iso_crc_config().checksum_bits(&self.iso_crc_bits()) as u32
+    }
+
+    pub fn non_iso_crc(&self) -> u32 {
+        self.crc_algorithm().config.checksum_bits(&self.crc_bits()) as u32
+    }
+
+    pub fn wire_bits(&self) -> Vec<WireBit> {
+        let mut wire = self.stuffed_bits();
+        let stuffed = wire.iter().filter(|bit| bit.stuff).count();
+        let mut crc_field = stuff_count_bits(stuffed).to_vec();
+        push_bits(&mut crc_field, self.crc() as u64, self.crc_algorithm().config.width as u32);
+
+        for (index, value) in crc_field.into_iter().enumerate() {
+            if index % FD_FIXED_STUFF_INTERVAL == 0 {
+                let previous = wire.last().is_some_and(|bit| bit.value);
+                wire.push(WireBit { value: !previous, stuff: true });
+            }
+            wire.push(WireBit { value, stuff: false });
+        }
+        let tail = [CRC_DELIMITER, false, true].into_iter().chain(core::iter::repeat_n(true, EOF_BITS));
+        wire.extend(tail.map(|value| WireBit { value, stuff: false }));
+        wire
+    }
+}
+
+pub fn stuff_count_bits(count: usize) -> [bool; FD_STUFF_COUNT_BITS] {
+    let count = (count % 8) as u8;
+    let gray = count ^ (count >> 1);
+    [gray & 0b100 != 0, gray & 0b010 != 0, gray & 0b001 != 0, gray.count_ones() % 2 == 1]
+}
+
+pub fn fd_dlc(len: usize) -> Option<u8> {
+    FD_DATA_LENGTHS.iter().position(|&valid| valid == len).map(|dlc| dlc as u8)
+}
+
+pub fn fd_padded_len(len: usize) -> Option<usize> {
+    FD_DATA_LENGTHS.iter().copied().find(|&valid| valid >= len)
+}
+
+#[cfg(feature = "alloc")]
+pub fn emit_frame(config: &CrcConfig, bits: &[bool], endianness: Endianness) -> Vec<bool> {
+    let mut frame = config.append_crc_in(bits, endianness);
+    if *config == CrcConfig::CAN {
+        frame.push(CRC_DELIMITER);
+    }
+    frame
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrcFieldCheck {
+    pub width: u8,
+    pub received: u64,
+    pub computed: u64,
+}
+
+impl CrcFieldCheck {
+    pub fn passed(&self) -> bool {
+        self.received == self.computed
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn differing_bits(&self) -> Vec<u8> {
+        let diff = self.received ^ self.computed;
+        (0..self.width).rev().filter(|&bit| (diff >> bit) & 1 == 1).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn check_crc_field(config: &CrcConfig, frame: &[bool], endianness: Endianness, delimiter: bool) -> Result<CrcFieldCheck, FrameError> {
+    let crc_bits = config.crc_field_len(endianness);
+    let frame = if delimiter { &frame[..frame.len().saturating_sub(1)] } else { frame };
+    let Some(split) = frame.len().checked_sub(crc_bits) else {
+        return Err(FrameError::MissingCrc { bits: frame.len(), crc_bits });
+    };
+    let (message, crc_field) = frame.split_at(split);
+    Ok(CrcFieldCheck {
+        width: config.width,
+        received: config.crc_from_bits_in(crc_field, endianness),
+        computed: config.checksum_message(message),
+    })
+}
+
+#[cfg(feature = "alloc")]
+pub fn stuff_bits(bits: &[bool]) -> Vec<WireBit> {
+    let mut wire = Vec::with_capacity(bits.len() + bits.len() / (STUFF_RUN - 1));
+    let mut run = 0;
+    let mut last = None;
+
+    for &value in bits {
+        wire.push(WireBit { value, stuff: false });
+        if last == Some(value) {
+            run += 1;
+        } else {
+            run = 1;
+            last = Some(value);
+        }
+        if run == STUFF_RUN {
+            wire.push(WireBit { value: !value, stuff: true });
+            run = 1;
+            last = Some(!value);
+        }
+    }
+
+    wire
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StuffError {
+    pub position: usize,
+    pub value: bool,
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Destuffed {
+    pub bits: Vec<bool>,
+    pub stuff_positions: Vec<usize>,
+    pub errors: Vec<StuffError>,
+}
+
+#[cfg(feature = "alloc")]
+impl Destuffed {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn destuff_bits(wire: &[bool]) -> Destuffed {
+    let mut destuffed = Destuffed {
+        bits: Vec::with_capacity(wire.len()),
+        stuff_positions: Vec::new(),
+        errors: Vec::new(),
+    };
+    let mut run = 0;
+    let mut last = None;
+
+    for (position, &value) in wire.iter().enumerate() {
+        if run == STUFF_RUN {
+            if last == Some(value) {
+                destuffed.errors.push(StuffError { position, value });
+                destuffed.bits.push(value);
+            } else {
+                destuffed.stuff_positions.push(position);
+            }
+            run = 1;
+            last = Some(value);
+            continue;
+        }
+        destuffed.bits.push(value);
+        if last == Some(value) {
+            run += 1;
+        } else {
+            run = 1;
+            last = Some(value);
+        }
+    }
+
+    destuffed
+}
+
+#[cfg(feature = "alloc")]
+pub fn format_wire_bits(bits: &[WireBit]) -> String {
+    bits.iter()
+        .map(|bit| match (bit.stuff, bit.value) {
+            (false, false) => "0",
+            (false, true) => "1",
+            (true, false) => "[0]",
+            (true, true) => "[1]",
+        })
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+fn push_bits(bits: &mut Vec<bool>, value: u64, count: u32) {
+    for i in (0..count).rev() {
+        bits.push((value >> i) & 1 == 1);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::frame::{CanFrame, MAX_CLASSIC_DATA_LEN, MAX_EXTENDED_ID, MAX_STANDARD_ID};
+use crate::rng::SplitMix64;
+use crate::{bytes_to_bits, parse_hex_bytes, FrameError};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdMode {
+    Random,
+    Increment,
+    Fixed(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataMode {
+    Random,
+    Increment,
+    Fixed(Vec<u8>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthMode {
+    Random,
+    Fixed(usize),
+}
+
+impl FromStr for IdMode {
+    type Err = FrameError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "r" => Ok(IdMode::Random),
+            "i" => Ok(IdMode::Increment),
+            id => u32::from_str_radix(id.trim_start_matches("0x"), 16)
+                .map(IdMode::Fixed)
+                .map_err(|_| FrameError::Syntax { content: value.to_string() }),
+        }
+    }
+}
+
+impl FromStr for DataMode {
+    type Err = FrameError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "r" => Ok(DataMode::Random),
+            "i" => Ok(DataMode::Increment),
+            data => {
+                let bytes = parse_hex_bytes(&data.replace('.', " "))
+                    .map_err(|_| FrameError::Syntax { content: value.to_string() })?;
+                if bytes.len() > MAX_CLASSIC_DATA_LEN {
+                    return Err(FrameError::DataTooLong { len: bytes.len(), max_len: MAX_CLASSIC_DATA_LEN });
+                }
+                Ok(DataMode::Fixed(bytes))
+            }
+        }
+    }
+}
+
+impl FromStr for LengthMode {
+    type Err = FrameError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "r" => Ok(LengthMode::Random),
+            len => match len.parse() {
+                Ok(len) if len <= MAX_CLASSIC_DATA_LEN => Ok(LengthMode::Fixed(len)),
+                Ok(len) => Err(FrameError::DataTooLong { len, max_len: MAX_CLASSIC_DATA_LEN }),
+                Err(_) => Err(FrameError::Syntax { content: value.to_string() }),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FrameGenerator {
+    id: IdMode,
+    data: DataMode,
+    len: LengthMode,
+    extended: bool,
+    rng: SplitMix64,
+    counter: u64,
+}
+
+impl FrameGenerator {
+    pub fn new(id: IdMode, data: DataMode, len: LengthMode, extended: bool, seed: u64) -> Result<Self, FrameError> {
+        if let IdMode::Fixed(fixed) = id {
+            let max_id = if extended { MAX_EXTENDED_ID } else { MAX_STANDARD_ID };
+            if fixed > max_id {
+                return Err(FrameError::IdOutOfRange { id: fixed, extended });
+            }
+        }
+        Ok(Self {
+            id,
+            data,
+            len,
+            extended,
+            rng: SplitMix64::new(seed),
+            counter: 0,
+        })
+    }
+
+    pub fn next_frame(&mut self) -> CanFrame {
+        let max_id = if self.extended { MAX_EXTENDED_ID } else { MAX_STANDARD_ID };
+        let id = match self.id {
+            IdMode::Random => self.rng.below(max_id as u64 + 1) as u32,
+            IdMode::Increment => (self.counter % (max_id as u64 + 1)) as u32,
+            IdMode::Fixed(id) => id,
+        };
+        let len = match self.len {
+            LengthMode::Random => self.rng.below(MAX_CLASSIC_DATA_LEN as u64 + 1) as usize,
+            LengthMode::Fixed(len) => len,
+        };
+        let data = match &self.data {
+            DataMode::Random => (0..len).map(|_| self.rng.next_u64() as u8).collect(),
+            DataMode::Increment => self.counter.to_le_bytes()[..len].to_vec(),
+            DataMode::Fixed(bytes) => bytes.clone(),
+        };
+        self.counter += 1;
+        CanFrame { id, extended: self.extended, rtr: false, data }
+    }
+}
+
+impl Iterator for FrameGenerator {
+    type Item = CanFrame;
+
+    fn next(&mut self) -> Option<CanFrame> {
+        Some(self.next_frame())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PayloadGenerator {
+    bits: usize,
+    rng: SplitMix64,
+}
+
+impl PayloadGenerator {
+    pub fn new(bits: usize, seed: u64) -> Self {
+        Self { bits, rng: SplitMix64::new(seed) }
+    }
+
+    pub fn next_payload(&mut self) -> Vec<bool> {
+        let bytes: Vec<u8> = (0..self.bits.div_ceil(64)).flat_map(|_| self.rng.next_u64().to_be_bytes()).collect();
+        let mut bits = bytes_to_bits(&bytes);
+        bits.truncate(self.bits);
+        bits
+    }
+}
+
+impl Iterator for PayloadGenerator {
+    type Item = Vec<bool>;
+
+    fn next(&mut self) -> Option<Vec<bool>> {
+        Some(self.next_payload())
+    }
+}
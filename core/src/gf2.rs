@@ -0,0 +1,52 @@
+pub type Equation = (u128, bool);
+pub type Pivot = (usize, u128, bool);
+
+pub fn eliminate(rows: &mut [Equation], unknowns: usize) -> Option<(Vec<Pivot>, Vec<usize>)> {
+    let mut pivot_row = 0;
+    let mut pivot_columns = Vec::new();
+    let mut free = Vec::new();
+
+    for column in 0..unknowns {
+        let bit = 1u128 << column;
+        let Some(found) = (pivot_row..rows.len()).find(|&r| rows[r].0 & bit != 0) else {
+            free.push(column);
+            continue;
+        };
+        rows.swap(pivot_row, found);
+        let (pivot, rhs) = rows[pivot_row];
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != pivot_row && row.0 & bit != 0 {
+                row.0 ^= pivot;
+                row.1 ^= rhs;
+            }
+        }
+        pivot_columns.push(column);
+        pivot_row += 1;
+    }
+
+    if rows[pivot_row..].iter().any(|&(_, rhs)| rhs) {
+        return None;
+    }
+
+    let pivots = pivot_columns
+        .into_iter()
+        .zip(rows.iter())
+        .map(|(column, &(row, rhs))| (column, row & !(1u128 << column), rhs))
+        .collect();
+    Some((pivots, free))
+}
+
+pub fn solve(pivots: &[Pivot], free: &[usize], free_values: u64) -> u128 {
+    let mut solution = 0u128;
+    for (i, &column) in free.iter().enumerate() {
+        if free_values.checked_shr(i as u32).is_some_and(|values| values & 1 == 1) {
+            solution |= 1 << column;
+        }
+    }
+    for &(column, row, rhs) in pivots {
+        if rhs ^ ((row & solution).count_ones() % 2 == 1) {
+            solution |= 1 << column;
+        }
+    }
+    solution
+}
@@ -0,0 +1,257 @@
+use crate::engine::CrcConfig;
+use crate::rng::SplitMix64;
+use crate::GpuError;
+use rayon::prelude::*;
+use std::sync::mpsc;
+use std::time::Instant;
+use wgpu::util::DeviceExt;
+
+pub const MAX_GPU_WIDTH: u8 = 32;
+pub const WORKGROUP_SIZE: u32 = 64;
+pub const DEFAULT_GPU_MESSAGES: usize = 1_000_000;
+pub const DEFAULT_GPU_MESSAGE_LEN: usize = 8;
+
+const SHADER: &str = r#"
+struct Params {
+    count: u32,
+    message_len: u32,
+    stride: u32,
+    width: u32,
+    mode: u32,
+    start: u32,
+    mask: u32,
+    padding: u32,
+}
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read> table: array<u32, 256>;
+@group(0) @binding(2) var<storage, read> data: array<u32>;
+@group(0) @binding(3) var<storage, read_write> crcs: array<u32>;
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let index = id.x;
+    if (index >= params.count) {
+        return;
+    }
+    let base = index * params.stride;
+    var crc = params.start;
+    for (var i = 0u; i < params.message_len; i = i + 1u) {
+        let byte = (data[base + i / 4u] >> (8u * (i % 4u))) & 0xFFu;
+        if (params.mode == 0u) {
+            crc = (crc >> 8u) ^ table[(crc ^ byte) & 0xFFu];
+        } else if (params.mode == 1u) {
+            crc = (crc << 8u) ^ table[((crc >> (params.width - 8u)) ^ byte) & 0xFFu];
+        } else {
+            crc = table[((crc << (8u - params.width)) ^ byte) & 0xFFu];
+        }
+        crc = crc & params.mask;
+    }
+    crcs[index] = crc;
+}
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GpuComparison {
+    pub messages: usize,
+    pub bytes: usize,
+    pub cpu_ms: f64,
+    pub gpu_ms: f64,
+    pub matches: bool,
+}
+
+impl GpuComparison {
+    pub fn speedup(&self) -> f64 {
+        self.cpu_ms / self.gpu_ms
+    }
+
+    pub fn cpu_messages_per_second(&self) -> f64 {
+        self.messages as f64 / (self.cpu_ms / 1000.0)
+    }
+
+    pub fn gpu_messages_per_second(&self) -> f64 {
+        self.messages as f64 / (self.gpu_ms / 1000.0)
+    }
+}
+
+pub struct GpuCrc {
+    adapter_name: String,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    max_binding_size: u64,
+}
+
+impl GpuCrc {
+    pub fn new() -> Result<Self, GpuError> {
+        pollster::block_on(Self::request())
+    }
+
+    async fn request() -> Result<Self, GpuError> {
+        let instance = wgpu::Instance::default();
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                compatible_surface: None,
+                force_fallback_adapter: false,
+            })
+            .await
+            .ok_or(GpuError::NoAdapter)?;
+        let (device, queue) = adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    label: Some("can-crc"),
+                    required_features: wgpu::Features::empty(),
+                    required_limits: wgpu::Limits::downlevel_defaults().using_resolution(adapter.limits()),
+                },
+                None,
+            )
+            .await
+            .map_err(|e| GpuError::Device { message: e.to_string() })?;
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("crc"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("crc"),
+            layout: None,
+            module: &module,
+            entry_point: "main",
+            compilation_options: Default::default(),
+        });
+        let max_binding_size = device.limits().max_storage_buffer_binding_size as u64;
+        Ok(Self { adapter_name: adapter.get_info().name, device, queue, pipeline, max_binding_size })
+    }
+
+    pub fn adapter_name(&self) -> &str {
+        &self.adapter_name
+    }
+
+    pub fn checksums(&self, config: &CrcConfig, messages: &[u8], message_len: usize) -> Result<Vec<u64>, GpuError> {
+        check_batch(config, messages, message_len)?;
+        let stride = message_len.div_ceil(4);
+        let max_per_dispatch = (self.device.limits().max_compute_workgroups_per_dimension as usize * WORKGROUP_SIZE as usize)
+            .min((self.max_binding_size as usize / (stride * 4)).max(1));
+        let table: Vec<u32> = config.table().iter().map(|&entry| entry as u32).collect();
+        let table = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("crc table"),
+            contents: &words_to_bytes(&table),
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+
+        let mut crcs = Vec::with_capacity(messages.len() / message_len);
+        for chunk in messages.chunks(max_per_dispatch * message_len) {
+            let raw = self.dispatch(config, &table, chunk, message_len, stride)?;
+            crcs.extend(raw.into_iter().map(|crc| config.finalize(crc as u64)));
+        }
+        Ok(crcs)
+    }
+
+    fn dispatch(&self, config: &CrcConfig, table: &wgpu::Buffer, messages: &[u8], message_len: usize, stride: usize) -> Result<Vec<u32>, GpuError> {
+        let count = messages.len() / message_len;
+        let mut words = vec![0u32; count * stride];
+        for (message, padded) in messages.chunks(message_len).zip(words.chunks_mut(stride)) {
+            for (bytes, word) in message.chunks(4).zip(padded.iter_mut()) {
+                *word = bytes.iter().rev().fold(0, |word, &byte| (word << 8) | byte as u32);
+            }
+        }
+        let mode = if config.refin { 0 } else if config.width >= 8 { 1 } else { 2 };
+        let params = [count as u32, message_len as u32, stride as u32, config.width as u32, mode, config.start() as u32, config.mask() as u32, 0];
+
+        let params = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("params"),
+            contents: &words_to_bytes(&params),
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+        let data = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("messages"),
+            contents: &words_to_bytes(&words),
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+        let size = (count * 4) as u64;
+        let output = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("crcs"),
+            size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback"),
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("crc"),
+            layout: &self.pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: params.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: table.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 2, resource: data.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 3, resource: output.as_entire_binding() },
+            ],
+        });
+
+        let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some("crc") });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor { label: Some("crc"), timestamp_writes: None });
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups((count as u32).div_ceil(WORKGROUP_SIZE), 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&output, 0, &readback, 0, size);
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = readback.slice(..);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        receiver
+            .recv()
+            .map_err(|e| GpuError::Device { message: e.to_string() })?
+            .map_err(|e| GpuError::Device { message: e.to_string() })?;
+        let crcs = slice.get_mapped_range().chunks_exact(4).map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])).collect();
+        readback.unmap();
+        Ok(crcs)
+    }
+}
+
+pub fn cpu_checksums(config: &CrcConfig, messages: &[u8], message_len: usize) -> Result<Vec<u64>, GpuError> {
+    check_batch(config, messages, message_len)?;
+    let table = config.table();
+    Ok(messages
+        .par_chunks(message_len)
+        .map(|message| config.finalize(config.update_with_table(&table, config.start(), message)))
+        .collect())
+}
+
+pub fn compare(gpu: &GpuCrc, config: &CrcConfig, messages: &[u8], message_len: usize) -> Result<GpuComparison, GpuError> {
+    let started = Instant::now();
+    let cpu = cpu_checksums(config, messages, message_len)?;
+    let cpu_ms = started.elapsed().as_secs_f64() * 1000.0;
+    let started = Instant::now();
+    let gpu_crcs = gpu.checksums(config, messages, message_len)?;
+    let gpu_ms = started.elapsed().as_secs_f64() * 1000.0;
+    Ok(GpuComparison { messages: cpu.len(), bytes: messages.len(), cpu_ms, gpu_ms, matches: cpu == gpu_crcs })
+}
+
+pub fn random_batch(count: usize, message_len: usize, seed: u64) -> Vec<u8> {
+    let mut rng = SplitMix64::new(seed);
+    (0..count * message_len).map(|_| rng.next_u64() as u8).collect()
+}
+
+fn check_batch(config: &CrcConfig, messages: &[u8], message_len: usize) -> Result<(), GpuError> {
+    if config.width > MAX_GPU_WIDTH {
+        return Err(GpuError::UnsupportedWidth { width: config.width, max_width: MAX_GPU_WIDTH });
+    }
+    if message_len == 0 || !messages.len().is_multiple_of(message_len) {
+        return Err(GpuError::BatchLength { len: messages.len(), message_len });
+    }
+    Ok(())
+}
+
+fn words_to_bytes(words: &[u32]) -> Vec<u8> {
+    words.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
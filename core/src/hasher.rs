@@ -0,0 +1,136 @@
+use crate::auto::AutoCrc;
+use crate::engine::CrcConfig;
+use std::hash::Hasher;
+use std::io::{self, Read};
+
+pub const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct CrcHasher {
+    config: CrcConfig,
+    table: Vec<u64>,
+    crc: u64,
+}
+
+impl CrcHasher {
+    pub fn new(config: CrcConfig) -> Self {
+        Self {
+            table: config.table(),
+            crc: config.start(),
+            config,
+        }
+    }
+
+    pub fn config(&self) -> &CrcConfig {
+        &self.config
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.crc = self.config.update_with_table(&self.table, self.crc, bytes);
+    }
+
+    pub fn value(&self) -> u64 {
+        self.config.finalize(self.crc)
+    }
+
+    pub fn reset(&mut self) {
+        self.crc = self.config.start();
+    }
+
+    pub fn update_reader<R: Read>(&mut self, mut reader: R) -> io::Result<u64> {
+        let mut buffer = vec![0u8; STREAM_CHUNK_SIZE];
+        let mut total = 0u64;
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) => return Ok(total),
+                Ok(n) => {
+                    self.update(&buffer[..n]);
+                    total += n as u64;
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+pub fn crc_from_reader<R: Read>(reader: R, config: &CrcConfig) -> io::Result<u64> {
+    let mut hasher = CrcHasher::new(*config);
+    hasher.update_reader(reader)?;
+    Ok(hasher.value())
+}
+
+pub fn checksum_reader(config: &CrcConfig, reader: &mut dyn Read) -> io::Result<(u64, u64)> {
+    let auto = AutoCrc::new(*config);
+    let mut crc = config.start();
+    let mut buffer = vec![0u8; STREAM_CHUNK_SIZE];
+    let mut total = 0u64;
+
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(n) => {
+                crc = auto.update(crc, &buffer[..n]);
+                total += n as u64;
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok((config.finalize(crc), total))
+}
+
+impl Default for CrcHasher {
+    fn default() -> Self {
+        Self::new(CrcConfig::CAN)
+    }
+}
+
+impl Hasher for CrcHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.value()
+    }
+}
+
+#[cfg(feature = "digest")]
+mod digest_impl {
+    use super::CrcHasher;
+    use digest::consts::U8;
+    use digest::{FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
+
+    impl HashMarker for CrcHasher {}
+
+    impl Update for CrcHasher {
+        fn update(&mut self, data: &[u8]) {
+            CrcHasher::update(self, data);
+        }
+    }
+
+    impl OutputSizeUser for CrcHasher {
+        type OutputSize = U8;
+    }
+
+    impl FixedOutput for CrcHasher {
+        fn finalize_into(self, out: &mut Output<Self>) {
+            out.copy_from_slice(&self.value().to_be_bytes());
+        }
+    }
+
+    impl Reset for CrcHasher {
+        fn reset(&mut self) {
+            CrcHasher::reset(self);
+        }
+    }
+
+    impl FixedOutputReset for CrcHasher {
+        fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+            out.copy_from_slice(&self.value().to_be_bytes());
+            CrcHasher::reset(self);
+        }
+    }
+}
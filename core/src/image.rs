@@ -0,0 +1,274 @@
+use crate::ImageError;
+use std::collections::BTreeMap;
+use std::io::{self, Read};
+
+pub const DEFAULT_FILL: u8 = 0xFF;
+const ADDRESS_SPACE: u64 = 1 << 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    IntelHex,
+    SRecord,
+}
+
+impl ImageFormat {
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_lowercase().as_str() {
+            "hex" | "ihx" | "ihex" => Some(ImageFormat::IntelHex),
+            "s19" | "s28" | "s37" | "srec" | "mot" => Some(ImageFormat::SRecord),
+            _ => None,
+        }
+    }
+
+    pub fn parse(self, text: &str) -> Result<MemoryImage, ImageError> {
+        match self {
+            ImageFormat::IntelHex => parse_intel_hex(text),
+            ImageFormat::SRecord => parse_srecord(text),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryImage {
+    blocks: BTreeMap<u32, Vec<u8>>,
+}
+
+impl MemoryImage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.blocks.values().map(Vec::len).sum()
+    }
+
+    pub fn start(&self) -> Option<u64> {
+        self.blocks.keys().next().map(|&address| address as u64)
+    }
+
+    pub fn end(&self) -> Option<u64> {
+        self.blocks
+            .iter()
+            .next_back()
+            .map(|(&address, data)| address as u64 + data.len() as u64)
+    }
+
+    pub fn blocks(&self) -> impl Iterator<Item = (u32, &[u8])> {
+        self.blocks.iter().map(|(&address, data)| (address, data.as_slice()))
+    }
+
+    pub fn write(&mut self, address: u64, data: &[u8]) -> Result<(), ImageError> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let end = address + data.len() as u64;
+        if end > ADDRESS_SPACE {
+            return Err(ImageError::AddressOverflow { address });
+        }
+        let address = address as u32;
+
+        if let Some((&next, _)) = self.blocks.range(address..).next() {
+            if (next as u64) < end {
+                return Err(ImageError::Overlap { address: next as u64 });
+            }
+        }
+
+        let previous = self.blocks.range_mut(..address).next_back();
+        let start = match previous {
+            Some((&start, block)) if start as u64 + block.len() as u64 > address as u64 => {
+                return Err(ImageError::Overlap { address: address as u64 });
+            }
+            Some((&start, block)) if start as u64 + block.len() as u64 == address as u64 => {
+                block.extend_from_slice(data);
+                start
+            }
+            _ => {
+                self.blocks.insert(address, data.to_vec());
+                address
+            }
+        };
+
+        if end < ADDRESS_SPACE {
+            if let Some(following) = self.blocks.remove(&(end as u32)) {
+                self.blocks.entry(start).or_default().extend(following);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn read(&self, start: u64, end: u64, fill: u8) -> Vec<u8> {
+        let mut bytes = vec![fill; end.saturating_sub(start) as usize];
+
+        for (&address, data) in &self.blocks {
+            let block_start = address as u64;
+            let block_end = block_start + data.len() as u64;
+            let from = block_start.max(start);
+            let to = block_end.min(end);
+            if from < to {
+                bytes[(from - start) as usize..(to - start) as usize]
+                    .copy_from_slice(&data[(from - block_start) as usize..(to - block_start) as usize]);
+            }
+        }
+
+        bytes
+    }
+
+    pub fn reader(&self, start: u64, end: u64, fill: u8) -> ImageReader<'_> {
+        ImageReader { image: self, position: start, end: end.max(start), fill }
+    }
+
+    pub fn to_bytes(&self, fill: u8) -> Vec<u8> {
+        match (self.start(), self.end()) {
+            (Some(start), Some(end)) => self.read(start, end, fill),
+            _ => Vec::new(),
+        }
+    }
+}
+
+pub struct ImageReader<'a> {
+    image: &'a MemoryImage,
+    position: u64,
+    end: u64,
+    fill: u8,
+}
+
+impl ImageReader<'_> {
+    pub fn remaining(&self) -> u64 {
+        self.end - self.position
+    }
+}
+
+impl Read for ImageReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position >= self.end || buf.is_empty() {
+            return Ok(0);
+        }
+        let limit = self.remaining().min(buf.len() as u64) as usize;
+        let key = self.position.min(u32::MAX as u64) as u32;
+        let inside = self.image.blocks.range(..=key).next_back().and_then(|(&address, data)| {
+            let offset = self.position - address as u64;
+            (offset < data.len() as u64).then(|| &data[offset as usize..])
+        });
+
+        let n = match inside {
+            Some(data) => {
+                let n = limit.min(data.len());
+                buf[..n].copy_from_slice(&data[..n]);
+                n
+            }
+            None => {
+                let next = self.image.blocks
+                    .range(key..)
+                    .map(|(&address, _)| address as u64)
+                    .find(|&address| address > self.position)
+                    .unwrap_or(self.end);
+                let n = (next - self.position).min(limit as u64) as usize;
+                buf[..n].fill(self.fill);
+                n
+            }
+        };
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+fn decode_record(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+fn record_sum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |sum, &byte| sum.wrapping_add(byte))
+}
+
+pub fn parse_intel_hex(text: &str) -> Result<MemoryImage, ImageError> {
+    let mut image = MemoryImage::new();
+    let mut base = 0u64;
+
+    for (index, raw_line) in text.lines().enumerate() {
+        let line = index + 1;
+        let record = raw_line.trim();
+        if record.is_empty() {
+            continue;
+        }
+
+        let bytes = record
+            .strip_prefix(':')
+            .and_then(decode_record)
+            .filter(|bytes| bytes.len() >= 5 && bytes.len() == bytes[0] as usize + 5)
+            .ok_or(ImageError::Syntax { line })?;
+
+        let (body, checksum) = bytes.split_at(bytes.len() - 1);
+        let expected = record_sum(body).wrapping_neg();
+        if expected != checksum[0] {
+            return Err(ImageError::Checksum { line, expected, actual: checksum[0] });
+        }
+
+        let offset = u16::from_be_bytes([body[1], body[2]]) as u64;
+        let data = &body[4..];
+        match body[3] {
+            0x00 => image.write(base + offset, data)?,
+            0x01 => return Ok(image),
+            0x02 if data.len() == 2 => base = (u16::from_be_bytes([data[0], data[1]]) as u64) << 4,
+            0x04 if data.len() == 2 => base = (u16::from_be_bytes([data[0], data[1]]) as u64) << 16,
+            0x03 | 0x05 => {}
+            0x02 | 0x04 => return Err(ImageError::Syntax { line }),
+            kind => return Err(ImageError::UnsupportedRecord { line, kind: format!("{:02X}", kind) }),
+        }
+    }
+
+    Err(ImageError::MissingEndRecord)
+}
+
+pub fn parse_srecord(text: &str) -> Result<MemoryImage, ImageError> {
+    let mut image = MemoryImage::new();
+
+    for (index, raw_line) in text.lines().enumerate() {
+        let line = index + 1;
+        let record = raw_line.trim();
+        if record.is_empty() {
+            continue;
+        }
+
+        let kind = record
+            .strip_prefix(['S', 's'])
+            .and_then(|rest| rest.chars().next())
+            .ok_or(ImageError::Syntax { line })?;
+        let address_len = match kind {
+            '0' | '1' | '5' | '9' => 2,
+            '2' | '6' | '8' => 3,
+            '3' | '7' => 4,
+            _ => return Err(ImageError::UnsupportedRecord { line, kind: format!("S{}", kind) }),
+        };
+
+        let bytes = decode_record(&record[2..])
+            .filter(|bytes| bytes.len() >= address_len + 2 && bytes.len() == bytes[0] as usize + 1)
+            .ok_or(ImageError::Syntax { line })?;
+
+        let (body, checksum) = bytes.split_at(bytes.len() - 1);
+        let expected = !record_sum(body);
+        if expected != checksum[0] {
+            return Err(ImageError::Checksum { line, expected, actual: checksum[0] });
+        }
+
+        let address = body[1..=address_len]
+            .iter()
+            .fold(0u64, |address, &byte| (address << 8) | byte as u64);
+        match kind {
+            '1' | '2' | '3' => image.write(address, &body[address_len + 1..])?,
+            '7' | '8' | '9' => return Ok(image),
+            _ => {}
+        }
+    }
+
+    Ok(image)
+}
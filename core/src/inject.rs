@@ -0,0 +1,160 @@
+use crate::engine::CrcConfig;
+use crate::par::*;
+use crate::rng::SplitMix64;
+use crate::{calculate_can_crc, PackedBits};
+
+pub const CRC_BITS: usize = 15;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorPattern {
+    Bits(Vec<usize>),
+    Burst { start: usize, len: usize },
+    Random { flips: usize, seed: u64 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InjectionResult {
+    pub flipped: Vec<usize>,
+    pub original_crc: u16,
+    pub received_crc: u16,
+    pub recomputed_crc: u16,
+    pub detected: bool,
+}
+
+pub fn codeword(bits: &[bool]) -> Vec<bool> {
+    let crc = calculate_can_crc(bits);
+    let mut codeword = bits.to_vec();
+    codeword.extend((0..CRC_BITS).rev().map(|i| (crc >> i) & 1 == 1));
+    codeword
+}
+
+pub fn error_positions(pattern: &ErrorPattern, codeword_len: usize) -> Vec<usize> {
+    let mut positions: Vec<usize> = match pattern {
+        ErrorPattern::Bits(bits) => bits.clone(),
+        ErrorPattern::Burst { start, len } => (*start..start + len).collect(),
+        ErrorPattern::Random { flips, seed } => {
+            let mut rng = SplitMix64::new(*seed);
+            let mut chosen = Vec::with_capacity(*flips);
+            let flips = (*flips).min(codeword_len);
+            while chosen.len() < flips {
+                let pos = rng.below(codeword_len as u64) as usize;
+                if !chosen.contains(&pos) {
+                    chosen.push(pos);
+                }
+            }
+            chosen
+        }
+    };
+
+    positions.retain(|&p| p < codeword_len);
+    positions.sort_unstable();
+    positions.dedup();
+    positions
+}
+
+pub fn inject_errors(bits: &[bool], pattern: &ErrorPattern) -> InjectionResult {
+    let original_crc = calculate_can_crc(bits);
+    let mut received = codeword(bits);
+    let flipped = error_positions(pattern, received.len());
+
+    for &pos in &flipped {
+        received[pos] = !received[pos];
+    }
+
+    let (data, crc_field) = received.split_at(bits.len());
+    let received_crc = crc_field.iter().fold(0u16, |acc, &b| (acc << 1) | b as u16);
+    let recomputed_crc = calculate_can_crc(data);
+
+    InjectionResult {
+        flipped,
+        original_crc,
+        received_crc,
+        recomputed_crc,
+        detected: recomputed_crc != received_crc,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlipCheck {
+    pub original_crc: u64,
+    pub received_crc: u64,
+    pub recomputed_crc: u64,
+    pub detected: bool,
+}
+
+pub fn check_flips(config: &CrcConfig, bits: &[bool], flipped: &[usize]) -> FlipCheck {
+    let mut received = config.append_crc(bits);
+    for &pos in flipped {
+        if let Some(bit) = received.get_mut(pos) {
+            *bit = !*bit;
+        }
+    }
+
+    let (data, crc_field) = received.split_at(bits.len());
+    let received_crc = config.crc_from_bits(crc_field);
+    let recomputed_crc = config.checksum_message(data);
+    FlipCheck {
+        original_crc: config.checksum_message(bits),
+        received_crc,
+        recomputed_crc,
+        detected: recomputed_crc != received_crc,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlipReport {
+    pub flips: usize,
+    pub tested: u64,
+    pub undetected: Vec<Vec<usize>>,
+}
+
+impl FlipReport {
+    pub fn all_detected(&self) -> bool {
+        self.undetected.is_empty()
+    }
+}
+
+pub fn verify_single_flips(config: &CrcConfig, bits: &[bool]) -> FlipReport {
+    let table = config.table();
+    let mut message = PackedBits::from_bools(bits);
+    let crc = config.checksum_packed_with_table(&table, &message);
+    let mut undetected = Vec::new();
+    for (i, &bit) in bits.iter().enumerate() {
+        message.set(i, !bit);
+        if config.checksum_packed_with_table(&table, &message) == crc {
+            undetected.push(vec![i]);
+        }
+        message.set(i, bit);
+    }
+    FlipReport { flips: 1, tested: bits.len() as u64, undetected }
+}
+
+pub fn verify_double_flips(config: &CrcConfig, bits: &[bool]) -> FlipReport {
+    let table = config.table();
+    let original = PackedBits::from_bools(bits);
+    let crc = config.checksum_packed_with_table(&table, &original);
+    let n = bits.len();
+
+    let undetected: Vec<Vec<Vec<usize>>> = (0..n)
+        .into_par_iter()
+        .map(|i| {
+            let mut message = original.clone();
+            message.set(i, !bits[i]);
+            let mut found = Vec::new();
+            for (j, &bit) in bits.iter().enumerate().skip(i + 1) {
+                message.set(j, !bit);
+                if config.checksum_packed_with_table(&table, &message) == crc {
+                    found.push(vec![i, j]);
+                }
+                message.set(j, bit);
+            }
+            found
+        })
+        .collect();
+
+    FlipReport {
+        flips: 2,
+        tested: (n * n.saturating_sub(1) / 2) as u64,
+        undetected: undetected.into_iter().flatten().collect(),
+    }
+}
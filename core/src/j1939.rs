@@ -0,0 +1,83 @@
+use crate::frame::MAX_EXTENDED_ID;
+use std::fmt;
+
+pub const PDU2_THRESHOLD: u8 = 240;
+pub const GLOBAL_ADDRESS: u8 = 0xFF;
+pub const MAX_PRIORITY: u8 = 7;
+
+pub const KNOWN_PGNS: &[(u32, &str)] = &[
+    (0x00_0000, "TSC1"),
+    (0x00_EA00, "RQST"),
+    (0x00_EB00, "TP.DT"),
+    (0x00_EC00, "TP.CM"),
+    (0x00_EE00, "ACL"),
+    (0x00_F003, "EEC2"),
+    (0x00_F004, "EEC1"),
+    (0x00_FECA, "DM1"),
+    (0x00_FECB, "DM2"),
+    (0x00_FEE5, "HOURS"),
+    (0x00_FEE9, "LFC"),
+    (0x00_FEEE, "ET1"),
+    (0x00_FEEF, "EFL/P1"),
+    (0x00_FEF1, "CCVS"),
+    (0x00_FEF2, "LFE"),
+    (0x00_FEF5, "AMB"),
+    (0x00_FEF6, "IC1"),
+    (0x00_FEF7, "VEP1"),
+    (0x00_FEFC, "DD"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct J1939Id {
+    pub priority: u8,
+    pub pgn: u32,
+    pub source: u8,
+    pub destination: Option<u8>,
+}
+
+impl J1939Id {
+    pub fn from_can_id(id: u32) -> Self {
+        let id = id & MAX_EXTENDED_ID;
+        let pdu_format = (id >> 16) as u8;
+        let pdu_specific = (id >> 8) as u8;
+        let page = (id >> 8) & 0x3_FF00;
+        let (pgn, destination) = if pdu_format < PDU2_THRESHOLD {
+            (page, Some(pdu_specific))
+        } else {
+            (page | pdu_specific as u32, None)
+        };
+        Self { priority: (id >> 26) as u8, pgn, source: id as u8, destination }
+    }
+
+    pub fn can_id(&self) -> u32 {
+        let pdu_specific = self.destination.map_or(0, u32::from);
+        ((self.priority as u32 & 0x7) << 26) | ((self.pgn & 0x3_FFFF) << 8) | (pdu_specific << 8) | self.source as u32
+    }
+
+    pub fn pdu_format(&self) -> u8 {
+        (self.pgn >> 8) as u8
+    }
+
+    pub fn is_broadcast(&self) -> bool {
+        self.destination.is_none_or(|destination| destination == GLOBAL_ADDRESS)
+    }
+
+    pub fn name(&self) -> Option<&'static str> {
+        pgn_name(self.pgn)
+    }
+}
+
+impl fmt::Display for J1939Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PGN {} (0x{:04X}) P{} SA {:02X}", self.pgn, self.pgn, self.priority, self.source)?;
+        if let Some(destination) = self.destination {
+            write!(f, " DA {:02X}", destination)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn pgn_name(pgn: u32) -> Option<&'static str> {
+    KNOWN_PGNS.iter().find(|(known, _)| *known == pgn).map(|(_, name)| *name)
+}
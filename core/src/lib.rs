@@ -0,0 +1,628 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod analysis;
+#[cfg(feature = "async")]
+pub mod async_io;
+#[cfg(feature = "std")]
+pub mod auto;
+#[cfg(feature = "alloc")]
+pub mod bits;
+#[cfg(feature = "alloc")]
+pub mod bitstring;
+#[cfg(feature = "std")]
+pub mod canopen;
+#[cfg(feature = "std")]
+pub mod catalog;
+#[cfg(feature = "std")]
+pub mod checksum;
+#[cfg(feature = "std")]
+pub mod codegen;
+#[cfg(feature = "std")]
+pub mod crosscheck;
+#[cfg(feature = "std")]
+pub mod dbc;
+#[cfg(feature = "std")]
+pub mod distribution;
+#[cfg(feature = "std")]
+pub mod dnp3;
+#[cfg(feature = "std")]
+pub mod e2e;
+pub mod engine;
+pub mod error;
+pub mod fixed;
+#[cfg(feature = "std")]
+pub mod flexray;
+pub mod frame;
+#[cfg(feature = "std")]
+pub mod generator;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+#[cfg(feature = "std")]
+mod gf2;
+#[cfg(feature = "std")]
+pub mod hasher;
+#[cfg(feature = "std")]
+pub mod image;
+#[cfg(feature = "std")]
+pub mod inject;
+#[cfg(feature = "std")]
+pub mod j1939;
+#[cfg(feature = "std")]
+pub mod manifest;
+#[cfg(feature = "std")]
+pub mod master;
+#[cfg(feature = "std")]
+pub mod modbus;
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+#[cfg(feature = "std")]
+mod par;
+#[cfg(feature = "std")]
+pub mod preimage;
+#[cfg(feature = "std")]
+pub mod prelude;
+#[cfg(feature = "std")]
+pub mod protocol;
+#[cfg(feature = "std")]
+pub mod rawframe;
+#[cfg(feature = "std")]
+pub mod recover;
+#[cfg(feature = "std")]
+pub mod replay;
+#[cfg(feature = "std")]
+pub mod rng;
+#[cfg(feature = "parallel")]
+pub mod scaling;
+#[cfg(all(feature = "serial", unix))]
+pub mod serial;
+#[cfg(feature = "std")]
+pub mod slave;
+#[cfg(feature = "std")]
+pub mod sweep;
+#[cfg(all(feature = "socketcan", target_os = "linux"))]
+pub mod socketcan;
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
+#[cfg(feature = "alloc")]
+mod text;
+#[cfg(feature = "std")]
+pub mod timing;
+#[cfg(feature = "watch")]
+pub mod watch;
+
+#[cfg(feature = "alloc")]
+pub use bits::PackedBits;
+#[cfg(feature = "alloc")]
+pub use bitstring::BitString;
+#[cfg(feature = "std")]
+pub use catalog::{CrcAlgorithm, CrcCatalog};
+pub use engine::{CrcConfig, CrcStep, Endianness};
+pub use error::{ConfigError, FixedError};
+#[cfg(feature = "alloc")]
+pub use error::{DbcError, FrameError, GpuError, ImageError, ManifestError, MqttError, ParseError};
+#[cfg(feature = "alloc")]
+pub use frame::CanFrame;
+#[cfg(feature = "std")]
+pub use hasher::{checksum_reader, crc_from_reader, CrcHasher};
+#[cfg(feature = "std")]
+pub use image::MemoryImage;
+#[cfg(feature = "std")]
+pub use par::current_num_threads;
+#[cfg(feature = "alloc")]
+pub use text::*;
+#[cfg(feature = "std")]
+pub use timing::TimingStats;
+#[cfg(feature = "parallel")]
+pub use rayon::{ThreadPool, ThreadPoolBuildError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "parallel")]
+use std::sync::atomic::AtomicU16;
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const CAN_POLY: u16 = 0x4599;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Implementation {
+    #[default]
+    Sequential,
+    Parallel,
+}
+
+impl Implementation {
+    pub fn for_iterations(iterations: u64) -> Self {
+        if cfg!(feature = "parallel") && iterations >= PARALLEL_THRESHOLD {
+            Implementation::Parallel
+        } else {
+            Implementation::Sequential
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Implementation::Sequential => "sequential",
+            Implementation::Parallel => "parallel",
+        }
+    }
+}
+
+impl fmt::Display for Implementation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrcResult {
+    pub algorithm: String,
+    pub poly: u64,
+    pub width: u8,
+    pub crc_value: u64,
+    pub crc_hex: String,
+    pub bits: usize,
+    pub iterations: u64,
+    pub implementation: Implementation,
+    pub duration_ms: f64,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub timing: Option<TimingStats>,
+}
+
+#[cfg(feature = "std")]
+impl CrcResult {
+    pub fn new(crc_value: u16, duration_ms: f64) -> Self {
+        Self::for_algorithm(CrcCatalog::can(), crc_value as u64, duration_ms)
+    }
+
+    pub fn for_algorithm(algorithm: &CrcAlgorithm, crc_value: u64, duration_ms: f64) -> Self {
+        Self::named(algorithm.name, &algorithm.config, crc_value, duration_ms)
+    }
+
+    pub fn for_config(config: &CrcConfig, crc_value: u64, duration_ms: f64) -> Self {
+        let name = CrcCatalog::find_config(config).map_or(CUSTOM_ALGORITHM, |algorithm| algorithm.name);
+        Self::named(name, config, crc_value, duration_ms)
+    }
+
+    fn named(name: &str, config: &CrcConfig, crc_value: u64, duration_ms: f64) -> Self {
+        let digits = (config.width as usize).div_ceil(4).max(4);
+        Self {
+            algorithm: name.to_string(),
+            poly: config.poly,
+            width: config.width,
+            crc_value,
+            crc_hex: format!("{:0digits$X}", crc_value),
+            bits: 0,
+            iterations: 1,
+            implementation: Implementation::Sequential,
+            duration_ms,
+            timing: None,
+        }
+    }
+
+    pub fn with_input(self, bits: usize, iterations: u64) -> Self {
+        Self { bits, iterations, implementation: Implementation::for_iterations(iterations), ..self }
+    }
+
+    pub fn with_implementation(self, implementation: Implementation) -> Self {
+        Self { implementation, ..self }
+    }
+
+    pub fn with_timing(self, timing: TimingStats) -> Self {
+        Self { duration_ms: timing.median_ms, timing: Some(timing), ..self }
+    }
+
+    pub fn crc_bin(&self) -> String {
+        format!("{:0width$b}", self.crc_value, width = self.width as usize)
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for CrcResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} = 0x{} (poly 0x{:X}, width {}, {} bits, {} iterations, {}, {:.3} ms)",
+            self.algorithm, self.crc_hex, self.poly, self.width, self.bits, self.iterations, self.implementation, self.duration_ms
+        )
+    }
+}
+
+pub const CUSTOM_ALGORITHM: &str = "custom";
+
+pub const MAX_INPUT_BITS: usize = 96;
+pub const SNIPPET_WIDTH: usize = 60;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CanCrcParams {
+    pub init: u16,
+    pub xorout: u16,
+}
+
+impl CanCrcParams {
+    pub fn config(&self) -> CrcConfig {
+        CrcConfig {
+            init: self.init as u64,
+            xorout: self.xorout as u64,
+            ..CrcConfig::CAN
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BitOrder {
+    #[default]
+    MsbFirst,
+    LsbFirst,
+}
+
+impl BitOrder {
+    pub fn apply(self, byte: u8) -> u8 {
+        match self {
+            BitOrder::MsbFirst => byte,
+            BitOrder::LsbFirst => byte.reverse_bits(),
+        }
+    }
+
+    pub fn reorder_bits(self, bits: &mut [bool]) {
+        if self == BitOrder::LsbFirst {
+            for chunk in bits.chunks_mut(8) {
+                chunk.reverse();
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BitPadding {
+    #[default]
+    Trailing,
+    Leading,
+    Reject,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub max_bits: Option<usize>,
+    pub bit_order: BitOrder,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_bits: Some(MAX_INPUT_BITS),
+            bit_order: BitOrder::MsbFirst,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ParseOptions {
+    pub fn for_config(config: &CrcConfig) -> Self {
+        Self {
+            max_bits: CrcCatalog::max_input_bits(config),
+            ..Self::default()
+        }
+    }
+}
+
+pub fn calculate_can_crc(bits: &[bool]) -> u16 {
+    calculate_can_crc_with_params(bits, CanCrcParams::default())
+}
+
+pub fn calculate_can_crc_with_params(bits: &[bool], params: CanCrcParams) -> u16 {
+    let mut crc_rg: u16 = params.init & 0x7FFF;
+    
+    for &nxtbit in bits {
+        let crcnxt = nxtbit ^ ((crc_rg >> 14) & 1 == 1);
+        
+        crc_rg = (crc_rg << 1) & 0x7FFF;
+        
+        if crcnxt {
+            crc_rg ^= CAN_POLY;
+        }
+    }
+    
+    (crc_rg ^ params.xorout) & 0x7FFF
+}
+
+pub fn calculate_can_crc_optimized(bits: &[bool]) -> u16 {
+    calculate_can_crc_optimized_with_params(bits, CanCrcParams::default())
+}
+
+pub fn calculate_can_crc_optimized_with_params(bits: &[bool], params: CanCrcParams) -> u16 {
+    static CRC_TABLE: [u16; 256] = generate_crc_table();
+    
+    let mut crc_rg: u16 = params.init & 0x7FFF;
+    
+    let full_bytes = bits.len() / 8;
+    for i in 0..full_bytes {
+        let mut byte = 0u8;
+        for j in 0..8 {
+            if bits[i * 8 + j] {
+                byte |= 1 << (7 - j);
+            }
+        }
+        
+        let tbl_idx = ((crc_rg >> 7) ^ (byte as u16)) as u8;
+        crc_rg = ((crc_rg << 8) ^ CRC_TABLE[tbl_idx as usize]) & 0x7FFF;
+    }
+    
+    for &nxtbit in &bits[full_bytes * 8..] {
+        let crcnxt = nxtbit ^ ((crc_rg >> 14) & 1 == 1);
+        crc_rg = (crc_rg << 1) & 0x7FFF;
+        if crcnxt {
+            crc_rg ^= CAN_POLY;
+        }
+    }
+    
+    (crc_rg ^ params.xorout) & 0x7FFF
+}
+
+#[cfg(feature = "alloc")]
+pub fn calculate_can_crc_trace(bits: &[bool]) -> Vec<CrcStep> {
+    CrcConfig::CAN.trace(bits)
+}
+
+pub fn calculate_can_crc_iter(bits: impl IntoIterator<Item = bool>) -> u16 {
+    let mut crc_rg: u16 = 0;
+    for nxtbit in bits {
+        let crcnxt = nxtbit ^ ((crc_rg >> 14) & 1 == 1);
+        crc_rg = (crc_rg << 1) & 0x7FFF;
+        if crcnxt {
+            crc_rg ^= CAN_POLY;
+        }
+    }
+    crc_rg
+}
+
+pub fn calculate_can_crc_bytes_iter(bytes: impl IntoIterator<Item = u8>) -> u16 {
+    static CRC_TABLE: [u16; 256] = generate_crc_table();
+    
+    let mut crc_rg: u16 = 0;
+    for byte in bytes {
+        let tbl_idx = ((crc_rg >> 7) ^ (byte as u16)) as u8;
+        crc_rg = ((crc_rg << 8) ^ CRC_TABLE[tbl_idx as usize]) & 0x7FFF;
+    }
+    crc_rg
+}
+
+#[cfg(feature = "alloc")]
+pub fn calculate_can_crc_packed(bits: &PackedBits) -> u16 {
+    let mut crc_rg = update_can_crc_bytes(0, bits.whole_bytes());
+    for nxtbit in bits.tail() {
+        let crcnxt = nxtbit ^ ((crc_rg >> 14) & 1 == 1);
+        crc_rg = (crc_rg << 1) & 0x7FFF;
+        if crcnxt {
+            crc_rg ^= CAN_POLY;
+        }
+    }
+    crc_rg
+}
+
+pub const fn crc15_bytes(bytes: &[u8]) -> u16 {
+    const CRC_TABLE: [u16; 256] = generate_crc_table();
+    
+    let mut crc_rg: u16 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let tbl_idx = ((crc_rg >> 7) ^ (bytes[i] as u16)) as u8;
+        crc_rg = ((crc_rg << 8) ^ CRC_TABLE[tbl_idx as usize]) & 0x7FFF;
+        i += 1;
+    }
+    crc_rg
+}
+
+pub const fn crc15_of_u64(value: u64, bit_len: usize) -> u16 {
+    let mut crc_rg: u16 = 0;
+    let mut bit = if bit_len < 64 { bit_len } else { 64 };
+    while bit > 0 {
+        bit -= 1;
+        let crcnxt = ((value >> bit) & 1 == 1) ^ ((crc_rg >> 14) & 1 == 1);
+        crc_rg = (crc_rg << 1) & 0x7FFF;
+        if crcnxt {
+            crc_rg ^= CAN_POLY;
+        }
+    }
+    crc_rg
+}
+
+pub fn calculate_can_crc_bytes(bytes: &[u8]) -> u16 {
+    update_can_crc_bytes(0, bytes)
+}
+
+pub fn calculate_can_crc_bytes_with_params(bytes: &[u8], params: CanCrcParams) -> u16 {
+    (update_can_crc_bytes(params.init, bytes) ^ params.xorout) & 0x7FFF
+}
+
+pub fn calculate_can_crc_bytes_with_order(bytes: &[u8], order: BitOrder) -> u16 {
+    update_can_crc_bytes_with_order(0, bytes, order)
+}
+
+pub fn update_can_crc_bytes_with_order(crc_rg: u16, bytes: &[u8], order: BitOrder) -> u16 {
+    match order {
+        BitOrder::MsbFirst => update_can_crc_bytes(crc_rg, bytes),
+        BitOrder::LsbFirst => bytes.iter().fold(crc_rg, |crc, &byte| update_can_crc_bytes(crc, &[order.apply(byte)])),
+    }
+}
+
+pub fn update_can_crc_bytes(crc_rg: u16, bytes: &[u8]) -> u16 {
+    static CRC_TABLE: [u16; 256] = generate_crc_table();
+    
+    let mut crc_rg = crc_rg & 0x7FFF;
+    for &byte in bytes {
+        let tbl_idx = ((crc_rg >> 7) ^ (byte as u16)) as u8;
+        crc_rg = ((crc_rg << 8) ^ CRC_TABLE[tbl_idx as usize]) & 0x7FFF;
+    }
+    
+    crc_rg
+}
+
+const fn generate_crc_table() -> [u16; 256] {
+    let mut table = [0u16; 256];
+    let mut i = 0;
+    
+    while i < 256 {
+        let mut crc = (i as u16) << 7;
+        let mut j = 0;
+        
+        while j < 8 {
+            if (crc & 0x4000) != 0 {
+                crc = ((crc << 1) ^ CAN_POLY) & 0x7FFF;
+            } else {
+                crc = (crc << 1) & 0x7FFF;
+            }
+            j += 1;
+        }
+        
+        table[i] = crc;
+        i += 1;
+    }
+    
+    table
+}
+
+pub const PARALLEL_THRESHOLD: u64 = 100_000;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(bits), fields(bits = bits.len())))]
+pub fn compute_batch_crcs_optimized(bits: &[bool], iterations: u64) -> u16 {
+    if iterations == 1 {
+        return calculate_can_crc_optimized(bits);
+    }
+    
+    #[cfg(feature = "parallel")]
+    if iterations >= PARALLEL_THRESHOLD {
+        #[cfg(feature = "tracing")]
+        tracing::info!(iterations, threads = rayon::current_num_threads(), "using parallel processing");
+        return compute_batch_crcs_parallel(bits, iterations);
+    }
+    
+    compute_batch_crcs_sequential(bits, iterations)
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(config, bits), fields(width = config.width, poly = %format_args!("0x{:X}", config.poly), bits = bits.len())))]
+pub fn compute_batch_checksums(config: &CrcConfig, bits: &[bool], iterations: u64) -> u64 {
+    if *config == CrcConfig::CAN {
+        return compute_batch_crcs_optimized(bits, iterations) as u64;
+    }
+    
+    compute_batch_checksums_with_progress(config, bits, iterations, |_| {})
+}
+
+pub const PROGRESS_INTERVAL: u64 = 65_536;
+
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(config, bits, progress), fields(width = config.width, poly = %format_args!("0x{:X}", config.poly), bits = bits.len())))]
+pub fn compute_batch_checksums_with_progress(
+    config: &CrcConfig,
+    bits: &[bool],
+    iterations: u64,
+    progress: impl Fn(u64) + Sync,
+) -> u64 {
+    let table = config.table();
+    let packed = PackedBits::from_bools(bits);
+    let can = *config == CrcConfig::CAN;
+    let once = || {
+        if can {
+            calculate_can_crc_packed(&packed) as u64
+        } else {
+            config.checksum_packed_with_table(&table, &packed)
+        }
+    };
+    
+    let done = AtomicU64::new(0);
+    let run_chunk = |chunk: u64| {
+        let count = PROGRESS_INTERVAL.min(iterations - chunk * PROGRESS_INTERVAL);
+        let mut crc = 0u64;
+        for _ in 0..count {
+            crc = once();
+        }
+        progress(done.fetch_add(count, Ordering::Relaxed) + count);
+        crc
+    };
+    let chunks = iterations.div_ceil(PROGRESS_INTERVAL);
+    
+    #[cfg(feature = "parallel")]
+    if iterations >= PARALLEL_THRESHOLD {
+        #[cfg(feature = "tracing")]
+        tracing::info!(iterations, threads = rayon::current_num_threads(), "using parallel processing");
+        return (0..chunks).into_par_iter().map(run_chunk).max().unwrap_or(0);
+    }
+    
+    (0..chunks).map(run_chunk).last().unwrap_or(0)
+}
+
+#[cfg(feature = "alloc")]
+pub fn compute_batch_crcs_sequential(bits: &[bool], iterations: u64) -> u16 {
+    let packed = PackedBits::from_bools(bits);
+    let mut crc = 0u16;
+    for _ in 0..iterations {
+        crc = calculate_can_crc_packed(&packed);
+    }
+    crc
+}
+
+#[cfg(feature = "parallel")]
+pub fn compute_batch_crcs_parallel(bits: &[bool], iterations: u64) -> u16 {
+    let packed = PackedBits::from_bools(bits);
+    let result = AtomicU16::new(0);
+    let num_threads = rayon::current_num_threads();
+    let chunk_size = (iterations as usize / num_threads).max(1);
+    
+    (0..num_threads)
+        .into_par_iter()
+        .for_each(|thread_idx| {
+            let start = thread_idx * chunk_size;
+            let end = if thread_idx == num_threads - 1 {
+                iterations as usize
+            } else {
+                ((thread_idx + 1) * chunk_size).min(iterations as usize)
+            };
+            
+            let mut local_crc = 0u16;
+            for _ in start.min(end)..end {
+                local_crc = calculate_can_crc_packed(&packed);
+            }
+            if end > start {
+                result.store(local_crc, Ordering::Relaxed);
+            }
+        });
+    
+    result.load(Ordering::Relaxed)
+}
+
+#[cfg(feature = "parallel")]
+pub fn thread_pool(threads: usize) -> Result<ThreadPool, ThreadPoolBuildError> {
+    rayon::ThreadPoolBuilder::new().num_threads(threads).build()
+}
+
+#[cfg(feature = "parallel")]
+pub fn compute_batch_checksums_in(pool: &ThreadPool, config: &CrcConfig, bits: &[bool], iterations: u64) -> u64 {
+    pool.install(|| compute_batch_checksums(config, bits, iterations))
+}
+
+#[cfg(feature = "parallel")]
+pub fn compute_batch_checksums_with_progress_in(
+    pool: &ThreadPool,
+    config: &CrcConfig,
+    bits: &[bool],
+    iterations: u64,
+    progress: impl Fn(u64) + Sync + Send,
+) -> u64 {
+    pool.install(|| compute_batch_checksums_with_progress(config, bits, iterations, progress))
+}
@@ -0,0 +1,100 @@
+use crate::catalog::{CrcAlgorithm, CrcCatalog};
+use crate::hasher::checksum_reader;
+use crate::ManifestError;
+use std::fmt;
+use std::path::Path;
+
+pub const DEFAULT_ALGORITHM: &str = "CRC-32/ISO-HDLC";
+const ALGORITHM_HEADER: &str = "algorithm:";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    pub path: String,
+    pub crc: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryStatus {
+    Ok,
+    Mismatch { computed: u64 },
+    Unreadable { message: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manifest {
+    pub algorithm: &'static CrcAlgorithm,
+    pub entries: Vec<ManifestEntry>,
+}
+
+impl Manifest {
+    pub fn new(algorithm: &'static CrcAlgorithm) -> Self {
+        Self { algorithm, entries: Vec::new() }
+    }
+
+    pub fn push(&mut self, path: &str, crc: u64) {
+        self.entries.push(ManifestEntry { path: path.replace('\\', "/"), crc });
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ManifestError> {
+        let mut algorithm = None;
+        let mut entries = Vec::new();
+
+        for (index, raw_line) in text.lines().enumerate() {
+            let line = raw_line.trim();
+            let syntax_error = || ManifestError::Syntax {
+                line: index + 1,
+                content: line.to_string(),
+            };
+
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(comment) = line.strip_prefix(';') {
+                if let Some(name) = comment.trim().strip_prefix(ALGORITHM_HEADER).map(str::trim) {
+                    let found = CrcCatalog::find(name).ok_or_else(|| ManifestError::UnknownAlgorithm { name: name.to_string() })?;
+                    algorithm.get_or_insert(found);
+                }
+                continue;
+            }
+
+            let (path, crc) = line.rsplit_once(char::is_whitespace).ok_or_else(syntax_error)?;
+            let crc = u64::from_str_radix(crc.trim_start_matches("0x"), 16).map_err(|_| syntax_error())?;
+            let path = path.trim_end();
+            if path.is_empty() {
+                return Err(syntax_error());
+            }
+            entries.push(ManifestEntry { path: path.to_string(), crc });
+        }
+
+        let algorithm = match algorithm {
+            Some(algorithm) => algorithm,
+            None => CrcCatalog::find(DEFAULT_ALGORITHM).ok_or_else(|| ManifestError::UnknownAlgorithm { name: DEFAULT_ALGORITHM.to_string() })?,
+        };
+        Ok(Self { algorithm, entries })
+    }
+
+    pub fn check_entry(&self, base: &Path, entry: &ManifestEntry) -> EntryStatus {
+        let checksum = std::fs::File::open(base.join(&entry.path))
+            .and_then(|mut file| checksum_reader(&self.algorithm.config, &mut file));
+        match checksum {
+            Ok((computed, _)) if computed == entry.crc => EntryStatus::Ok,
+            Ok((computed, _)) => EntryStatus::Mismatch { computed },
+            Err(e) => EntryStatus::Unreadable { message: e.to_string() },
+        }
+    }
+
+    pub fn verify<'a>(&'a self, base: &'a Path) -> impl Iterator<Item = (&'a ManifestEntry, EntryStatus)> + 'a {
+        self.entries.iter().map(move |entry| (entry, self.check_entry(base, entry)))
+    }
+}
+
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = (self.algorithm.config.width as usize).div_ceil(4);
+        writeln!(f, "; {} {}", ALGORITHM_HEADER, self.algorithm.name)?;
+        for entry in &self.entries {
+            writeln!(f, "{} {:0digits$X}", entry.path, entry.crc)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use crate::modbus::{check_rtu, rtu_frame, Direction, Pdu, RtuCheck, EXCEPTION_FLAG};
+use crate::FrameError;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+pub const READ_HOLDING_REGISTERS: u8 = 0x03;
+pub const TCP_FRAME_GAP: Duration = Duration::from_millis(20);
+
+pub trait RtuTransport {
+    fn send(&mut self, adu: &[u8]) -> io::Result<()>;
+    fn receive(&mut self, timeout: Duration) -> io::Result<Option<Vec<u8>>>;
+}
+
+#[cfg(all(feature = "serial", unix))]
+impl RtuTransport for crate::serial::SerialPort {
+    fn send(&mut self, adu: &[u8]) -> io::Result<()> {
+        self.write_frame(adu)
+    }
+
+    fn receive(&mut self, timeout: Duration) -> io::Result<Option<Vec<u8>>> {
+        self.read_frame(Some(timeout))
+    }
+}
+
+#[derive(Debug)]
+pub struct TcpTransport {
+    stream: TcpStream,
+}
+
+impl TcpTransport {
+    pub fn connect(address: &str) -> io::Result<Self> {
+        let stream = TcpStream::connect(address)?;
+        stream.set_nodelay(true)?;
+        Ok(Self { stream })
+    }
+}
+
+impl RtuTransport for TcpTransport {
+    fn send(&mut self, adu: &[u8]) -> io::Result<()> {
+        self.stream.write_all(adu)
+    }
+
+    fn receive(&mut self, timeout: Duration) -> io::Result<Option<Vec<u8>>> {
+        let mut frame = Vec::new();
+        let mut buffer = [0u8; 256];
+        let mut wait = timeout;
+        loop {
+            self.stream.set_read_timeout(Some(wait.max(Duration::from_millis(1))))?;
+            match self.stream.read(&mut buffer) {
+                Ok(0) if frame.is_empty() => return Err(io::ErrorKind::UnexpectedEof.into()),
+                Ok(0) => break,
+                Ok(read) => frame.extend_from_slice(&buffer[..read]),
+                Err(e) if matches!(e.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => break,
+                Err(e) => return Err(e),
+            }
+            wait = TCP_FRAME_GAP;
+        }
+        Ok((!frame.is_empty()).then_some(frame))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PollOutcome {
+    Registers(Vec<u16>),
+    Exception(u8),
+    CrcError(RtuCheck),
+    Unexpected(Pdu),
+    Invalid(FrameError),
+    Timeout,
+}
+
+impl PollOutcome {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, PollOutcome::Registers(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PollStats {
+    pub polls: u64,
+    pub ok: u64,
+    pub timeouts: u64,
+    pub crc_errors: u64,
+    pub exceptions: u64,
+    pub unexpected: u64,
+}
+
+impl PollStats {
+    pub fn failures(&self) -> u64 {
+        self.polls - self.ok
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Poller {
+    pub address: u8,
+    pub start: u16,
+    pub count: u16,
+    pub stats: PollStats,
+}
+
+impl Poller {
+    pub fn new(address: u8, start: u16, count: u16) -> Self {
+        Self { address, start, count, stats: PollStats::default() }
+    }
+
+    pub fn request(&self) -> Vec<u8> {
+        let data: Vec<u8> = self.start.to_be_bytes().into_iter().chain(self.count.to_be_bytes()).collect();
+        rtu_frame(self.address, READ_HOLDING_REGISTERS, &data)
+    }
+
+    pub fn evaluate(&mut self, response: Option<&[u8]>) -> PollOutcome {
+        let outcome = self.classify(response);
+        self.stats.polls += 1;
+        match outcome {
+            PollOutcome::Registers(_) => self.stats.ok += 1,
+            PollOutcome::Exception(_) => self.stats.exceptions += 1,
+            PollOutcome::CrcError(_) => self.stats.crc_errors += 1,
+            PollOutcome::Unexpected(_) | PollOutcome::Invalid(_) => self.stats.unexpected += 1,
+            PollOutcome::Timeout => self.stats.timeouts += 1,
+        }
+        outcome
+    }
+
+    pub fn poll<T: RtuTransport>(&mut self, transport: &mut T, timeout: Duration) -> io::Result<(Vec<u8>, PollOutcome)> {
+        transport.send(&self.request())?;
+        let response = transport.receive(timeout)?.unwrap_or_default();
+        let outcome = self.evaluate((!response.is_empty()).then_some(&response[..]));
+        Ok((response, outcome))
+    }
+
+    fn classify(&self, response: Option<&[u8]>) -> PollOutcome {
+        let Some(adu) = response else {
+            return PollOutcome::Timeout;
+        };
+        let check = match check_rtu(adu) {
+            Ok(check) => check,
+            Err(e) => return PollOutcome::Invalid(e),
+        };
+        if !check.is_valid() {
+            return PollOutcome::CrcError(check);
+        }
+        let (function, data) = (adu[1], &adu[2..adu.len() - 2]);
+        let pdu = Pdu::decode(function, data, Direction::Response);
+        if check.address != self.address || function & !EXCEPTION_FLAG != READ_HOLDING_REGISTERS {
+            return PollOutcome::Unexpected(pdu);
+        }
+        match pdu {
+            Pdu::Exception { code, .. } => PollOutcome::Exception(code),
+            Pdu::ReadRegistersResponse { registers, .. } if registers.len() == self.count as usize => PollOutcome::Registers(registers),
+            pdu => PollOutcome::Unexpected(pdu),
+        }
+    }
+}
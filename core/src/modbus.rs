@@ -0,0 +1,295 @@
+use crate::{CrcConfig, Endianness, FrameError};
+use std::time::Duration;
+
+pub const MIN_ADU_LEN: usize = 4;
+pub const MAX_ADU_LEN: usize = 256;
+pub const MAX_SLAVE_ADDRESS: u8 = 247;
+pub const CRC_ENDIANNESS: Endianness = Endianness::Little;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RtuCheck {
+    pub address: u8,
+    pub function: u8,
+    pub received: u16,
+    pub computed: u16,
+}
+
+impl RtuCheck {
+    pub fn is_valid(&self) -> bool {
+        self.received == self.computed
+    }
+}
+
+pub fn crc(bytes: &[u8]) -> u16 {
+    CrcConfig::MODBUS.checksum(bytes) as u16
+}
+
+pub fn rtu_frame(address: u8, function: u8, data: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(data.len() + 4);
+    frame.push(address);
+    frame.push(function);
+    frame.extend_from_slice(data);
+    let crc = crc(&frame);
+    frame.extend(CrcConfig::MODBUS.crc_bytes(crc as u64, CRC_ENDIANNESS));
+    frame
+}
+
+pub fn check_rtu(adu: &[u8]) -> Result<RtuCheck, FrameError> {
+    if adu.len() < MIN_ADU_LEN {
+        return Err(FrameError::TooShort { len: adu.len(), min_len: MIN_ADU_LEN });
+    }
+    if adu.len() > MAX_ADU_LEN {
+        return Err(FrameError::DataTooLong { len: adu.len(), max_len: MAX_ADU_LEN });
+    }
+    let (body, crc_bytes) = adu.split_at(adu.len() - 2);
+    Ok(RtuCheck {
+        address: body[0],
+        function: body[1],
+        received: u16::from_le_bytes([crc_bytes[0], crc_bytes[1]]),
+        computed: crc(body),
+    })
+}
+
+pub const EXCEPTION_FLAG: u8 = 0x80;
+pub const MAX_READ_BITS: u16 = 2000;
+pub const MAX_READ_REGISTERS: u16 = 125;
+pub const BROADCAST_ADDRESS: u8 = 0;
+pub const DEFAULT_RESPONSE_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Request,
+    Response,
+}
+
+impl Direction {
+    pub fn guess(function: u8, data: &[u8]) -> Self {
+        if function & EXCEPTION_FLAG != 0 {
+            return Direction::Response;
+        }
+        match function {
+            1..=4 => {
+                let count = data.get(2..4).map(|count| u16::from_be_bytes([count[0], count[1]]));
+                let max = if function <= 2 { MAX_READ_BITS } else { MAX_READ_REGISTERS };
+                if data.len() == 4 && count.is_some_and(|count| (1..=max).contains(&count)) {
+                    Direction::Request
+                } else {
+                    Direction::Response
+                }
+            }
+            15 | 16 if data.len() == 4 => Direction::Response,
+            _ => Direction::Request,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Pdu {
+    ReadRequest { function: u8, start: u16, count: u16 },
+    ReadBitsResponse { function: u8, bits: Vec<bool> },
+    ReadRegistersResponse { function: u8, registers: Vec<u16> },
+    WriteSingle { function: u8, address: u16, value: u16 },
+    WriteMultipleRequest { function: u8, start: u16, count: u16, data: Vec<u8> },
+    WriteMultipleResponse { function: u8, start: u16, count: u16 },
+    Exception { function: u8, code: u8 },
+    Malformed { function: u8, len: usize },
+    Other { function: u8, data: Vec<u8> },
+}
+
+impl Pdu {
+    pub fn decode(function: u8, data: &[u8], direction: Direction) -> Self {
+        let word = |index: usize| u16::from_be_bytes([data[index], data[index + 1]]);
+        let malformed = Pdu::Malformed { function, len: data.len() };
+
+        if function & EXCEPTION_FLAG != 0 {
+            return match data {
+                [code] => Pdu::Exception { function: function & !EXCEPTION_FLAG, code: *code },
+                _ => malformed,
+            };
+        }
+        match (function, direction) {
+            (1..=4, Direction::Request) | (15 | 16, Direction::Response) => {
+                if data.len() != 4 {
+                    return malformed;
+                }
+                let (start, count) = (word(0), word(2));
+                if function <= 4 {
+                    Pdu::ReadRequest { function, start, count }
+                } else {
+                    Pdu::WriteMultipleResponse { function, start, count }
+                }
+            }
+            (1 | 2, Direction::Response) => match data.split_first() {
+                Some((&len, bytes)) if len as usize == bytes.len() => Pdu::ReadBitsResponse {
+                    function,
+                    bits: bytes.iter().flat_map(|byte| (0..8).map(move |bit| (byte >> bit) & 1 == 1)).collect(),
+                },
+                _ => malformed,
+            },
+            (3 | 4, Direction::Response) => match data.split_first() {
+                Some((&len, bytes)) if len as usize == bytes.len() && bytes.len().is_multiple_of(2) => Pdu::ReadRegistersResponse {
+                    function,
+                    registers: bytes.chunks_exact(2).map(|pair| u16::from_be_bytes([pair[0], pair[1]])).collect(),
+                },
+                _ => malformed,
+            },
+            (5 | 6, _) => {
+                if data.len() != 4 {
+                    return malformed;
+                }
+                Pdu::WriteSingle { function, address: word(0), value: word(2) }
+            }
+            (15 | 16, Direction::Request) => {
+                if data.len() < 5 || data[4] as usize != data.len() - 5 {
+                    return malformed;
+                }
+                Pdu::WriteMultipleRequest { function, start: word(0), count: word(2), data: data[5..].to_vec() }
+            }
+            _ => Pdu::Other { function, data: data.to_vec() },
+        }
+    }
+
+    pub fn function(&self) -> u8 {
+        match self {
+            Pdu::ReadRequest { function, .. }
+            | Pdu::ReadBitsResponse { function, .. }
+            | Pdu::ReadRegistersResponse { function, .. }
+            | Pdu::WriteSingle { function, .. }
+            | Pdu::WriteMultipleRequest { function, .. }
+            | Pdu::WriteMultipleResponse { function, .. }
+            | Pdu::Exception { function, .. }
+            | Pdu::Malformed { function, .. }
+            | Pdu::Other { function, .. } => *function,
+        }
+    }
+}
+
+pub fn decode_adu(adu: &[u8]) -> Result<Pdu, FrameError> {
+    check_rtu(adu)?;
+    let (function, data) = (adu[1], &adu[2..adu.len() - 2]);
+    Ok(Pdu::decode(function, data, Direction::guess(function, data)))
+}
+
+pub fn split_timestamp(line: &str) -> (Option<Duration>, &str) {
+    let line = line.trim();
+    let Some((stamp, rest)) = line.strip_prefix('(').and_then(|rest| rest.split_once(')')) else {
+        return (None, line);
+    };
+    match stamp.trim().parse::<f64>() {
+        Ok(seconds) if seconds >= 0.0 && seconds.is_finite() => (Some(Duration::from_secs_f64(seconds)), rest.trim()),
+        _ => (None, line),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RtuFrame {
+    pub time: Duration,
+    pub check: RtuCheck,
+    pub pdu: Pdu,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transaction {
+    pub request: Option<RtuFrame>,
+    pub response: Option<RtuFrame>,
+}
+
+impl Transaction {
+    pub fn address(&self) -> u8 {
+        self.request.as_ref().or(self.response.as_ref()).map_or(BROADCAST_ADDRESS, |frame| frame.check.address)
+    }
+
+    pub fn function(&self) -> u8 {
+        self.request.as_ref().or(self.response.as_ref()).map_or(0, |frame| frame.pdu.function())
+    }
+
+    pub fn is_broadcast(&self) -> bool {
+        self.request.is_some() && self.address() == BROADCAST_ADDRESS
+    }
+
+    pub fn exception(&self) -> Option<u8> {
+        match self.response.as_ref().map(|frame| &frame.pdu) {
+            Some(Pdu::Exception { code, .. }) => Some(*code),
+            _ => None,
+        }
+    }
+
+    pub fn response_time(&self) -> Option<Duration> {
+        match (&self.request, &self.response) {
+            (Some(request), Some(response)) => Some(response.time.saturating_sub(request.time)),
+            _ => None,
+        }
+    }
+
+    pub fn crc_errors(&self) -> usize {
+        [&self.request, &self.response].into_iter().flatten().filter(|frame| !frame.check.is_valid()).count()
+    }
+
+    pub fn is_ok(&self) -> bool {
+        let answered = self.response.is_some() || self.is_broadcast();
+        self.request.is_some() && answered && self.crc_errors() == 0 && self.exception().is_none()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TransactionTracker {
+    timeout: Duration,
+    pending: Option<RtuFrame>,
+}
+
+impl TransactionTracker {
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout, pending: None }
+    }
+
+    pub fn push(&mut self, adu: &[u8], time: Duration) -> Result<Vec<Transaction>, FrameError> {
+        let check = check_rtu(adu)?;
+        let (function, data) = (adu[1], &adu[2..adu.len() - 2]);
+        let mut done = Vec::new();
+
+        if let Some(pending) = self.pending.take_if(|pending| time.saturating_sub(pending.time) > self.timeout) {
+            done.push(Transaction { request: Some(pending), response: None });
+        }
+        let answers_pending = self.pending.as_ref().is_some_and(|pending| {
+            pending.check.address == check.address && pending.pdu.function() == function & !EXCEPTION_FLAG
+        });
+        let direction = match Direction::guess(function, data) {
+            Direction::Request if answers_pending && !matches!(function, 1..=4 | 15 | 16) => Direction::Response,
+            guess => guess,
+        };
+        let frame = RtuFrame { time, check, pdu: Pdu::decode(function, data, direction) };
+
+        match direction {
+            Direction::Response if answers_pending => {
+                done.push(Transaction { request: self.pending.take(), response: Some(frame) });
+            }
+            Direction::Response => done.push(Transaction { request: None, response: Some(frame) }),
+            Direction::Request => {
+                if let Some(pending) = self.pending.take() {
+                    done.push(Transaction { request: Some(pending), response: None });
+                }
+                if check.address == BROADCAST_ADDRESS {
+                    done.push(Transaction { request: Some(frame), response: None });
+                } else {
+                    self.pending = Some(frame);
+                }
+            }
+        }
+        Ok(done)
+    }
+
+    pub fn finish(&mut self) -> Option<Transaction> {
+        self.pending.take().map(|request| Transaction { request: Some(request), response: None })
+    }
+}
+
+impl Default for TransactionTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_RESPONSE_TIMEOUT)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::frame::CanFrame;
+use crate::modbus::RtuCheck;
+use crate::MqttError;
+use rumqttc::{Client, Connection, ConnectionError, Event, MqttOptions, Outgoing, Packet, QoS};
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+pub const DEFAULT_PORT: u16 = 1883;
+pub const DEFAULT_TOPIC_PREFIX: &str = "can-crc";
+pub const DEFAULT_CLIENT_ID: &str = "can-crc";
+const KEEP_ALIVE: Duration = Duration::from_secs(30);
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+const QUEUE_CAPACITY: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FrameReport {
+    pub protocol: &'static str,
+    pub id: u32,
+    pub crc: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub received: Option<u32>,
+    pub valid: bool,
+}
+
+impl FrameReport {
+    pub fn can(frame: &CanFrame) -> Self {
+        Self {
+            protocol: "can",
+            id: frame.id,
+            crc: frame.crc() as u32,
+            received: None,
+            valid: true,
+        }
+    }
+
+    pub fn modbus(check: &RtuCheck) -> Self {
+        Self {
+            protocol: "modbus",
+            id: check.address as u32,
+            crc: check.computed as u32,
+            received: Some(check.received as u32),
+            valid: check.is_valid(),
+        }
+    }
+}
+
+pub fn can_topic(prefix: &str, frame: &CanFrame) -> String {
+    if frame.extended {
+        format!("{}/can/{:08X}", prefix, frame.id)
+    } else {
+        format!("{}/can/{:03X}", prefix, frame.id)
+    }
+}
+
+pub fn modbus_topic(prefix: &str, address: u8) -> String {
+    format!("{}/modbus/{}", prefix, address)
+}
+
+pub fn parse_broker(broker: &str) -> Result<(String, u16), MqttError> {
+    let invalid = || MqttError::Address { broker: broker.to_string() };
+    let address = broker.trim().trim_start_matches("mqtt://");
+    let (host, port) = match address.rsplit_once(':') {
+        Some((host, port)) => (host, port.parse().map_err(|_| invalid())?),
+        None => (address, DEFAULT_PORT),
+    };
+    if host.is_empty() || host.contains('/') {
+        return Err(invalid());
+    }
+    Ok((host.to_string(), port))
+}
+
+pub struct Publisher {
+    client: Client,
+    closing: Arc<AtomicBool>,
+    events: Option<JoinHandle<()>>,
+}
+
+impl Publisher {
+    pub fn connect(broker: &str, client_id: &str) -> Result<Self, MqttError> {
+        let (host, port) = parse_broker(broker)?;
+        let mut options = MqttOptions::new(client_id, host, port);
+        options.set_keep_alive(KEEP_ALIVE);
+        let (client, mut connection) = Client::new(options, QUEUE_CAPACITY);
+
+        wait_for_connack(&mut connection).map_err(|message| MqttError::Connection {
+            broker: broker.to_string(),
+            message,
+        })?;
+        #[cfg(feature = "tracing")]
+        tracing::info!(broker, "connected to MQTT broker");
+
+        let closing = Arc::new(AtomicBool::new(false));
+        let events = {
+            let closing = Arc::clone(&closing);
+            std::thread::spawn(move || drive(connection, &closing))
+        };
+        Ok(Self { client, closing, events: Some(events) })
+    }
+
+    pub fn publish(&self, topic: &str, report: &FrameReport) -> Result<(), MqttError> {
+        let payload = serde_json::to_vec(report).map_err(|e| MqttError::Publish { message: e.to_string() })?;
+        self.client
+            .publish(topic, QoS::AtLeastOnce, false, payload)
+            .map_err(|e| MqttError::Publish { message: e.to_string() })
+    }
+}
+
+impl Drop for Publisher {
+    fn drop(&mut self) {
+        self.closing.store(true, Ordering::Relaxed);
+        let _ = self.client.disconnect();
+        if let Some(events) = self.events.take() {
+            let _ = events.join();
+        }
+    }
+}
+
+fn wait_for_connack(connection: &mut Connection) -> Result<(), String> {
+    for event in connection.iter() {
+        match event {
+            Ok(Event::Incoming(Packet::ConnAck(_))) => return Ok(()),
+            Ok(_) => {}
+            Err(e) => return Err(e.to_string()),
+        }
+    }
+    Err(ConnectionError::RequestsDone.to_string())
+}
+
+fn drive(mut connection: Connection, closing: &AtomicBool) {
+    for event in connection.iter() {
+        match event {
+            Ok(Event::Outgoing(Outgoing::Disconnect)) => break,
+            Ok(_) => {}
+            Err(ConnectionError::RequestsDone) => break,
+            Err(_) if closing.load(Ordering::Relaxed) => break,
+            Err(_e) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %_e, "MQTT connection lost, reconnecting");
+                std::thread::sleep(RECONNECT_DELAY);
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(feature = "parallel")]
+pub use rayon::prelude::*;
+
+#[cfg(feature = "parallel")]
+pub fn current_num_threads() -> usize {
+    rayon::current_num_threads()
+}
+
+#[cfg(not(feature = "parallel"))]
+pub use sequential::*;
+
+#[cfg(not(feature = "parallel"))]
+mod sequential {
+    pub fn current_num_threads() -> usize {
+        1
+    }
+
+    pub trait IntoParallelIterator: IntoIterator + Sized {
+        fn into_par_iter(self) -> Self::IntoIter {
+            self.into_iter()
+        }
+    }
+
+    impl<T: IntoIterator> IntoParallelIterator for T {}
+
+    pub trait IntoParallelRefIterator<T> {
+        fn par_iter(&self) -> std::slice::Iter<'_, T>;
+    }
+
+    impl<T> IntoParallelRefIterator<T> for [T] {
+        fn par_iter(&self) -> std::slice::Iter<'_, T> {
+            self.iter()
+        }
+    }
+
+    pub trait ParallelIterator: Iterator + Sized {
+        fn flat_map_iter<U: IntoIterator, F: FnMut(Self::Item) -> U>(self, f: F) -> std::iter::FlatMap<Self, U, F> {
+            self.flat_map(f)
+        }
+
+        fn reduce_with<F: FnMut(Self::Item, Self::Item) -> Self::Item>(self, f: F) -> Option<Self::Item> {
+            self.reduce(f)
+        }
+    }
+
+    impl<I: Iterator> ParallelIterator for I {}
+
+    pub trait ParallelSliceMut<T: Ord> {
+        fn par_sort_unstable(&mut self);
+    }
+
+    impl<T: Ord> ParallelSliceMut<T> for [T] {
+        fn par_sort_unstable(&mut self) {
+            self.sort_unstable();
+        }
+    }
+}
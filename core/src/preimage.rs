@@ -0,0 +1,86 @@
+use crate::engine::CrcConfig;
+use crate::gf2::{eliminate, solve, Equation};
+use crate::par::*;
+use crate::rng::SplitMix64;
+
+pub const MAX_ADJUSTABLE_BITS: usize = 128;
+
+pub fn find_preimages(
+    config: &CrcConfig,
+    prefix: &[bool],
+    total_bits: usize,
+    target: u64,
+    count: usize,
+    seed: u64,
+) -> Vec<Vec<bool>> {
+    if prefix.len() > total_bits {
+        return Vec::new();
+    }
+
+    let linear = CrcConfig { init: 0, xorout: 0, ..*config };
+    let first_adjustable = prefix.len().max(total_bits.saturating_sub(MAX_ADJUSTABLE_BITS));
+    let syndromes: Vec<u64> = (first_adjustable..total_bits)
+        .map(|position| {
+            let mut unit = vec![false; total_bits];
+            unit[position] = true;
+            linear.checksum_message(&unit)
+        })
+        .collect();
+    let coefficients: Vec<u128> = (0..config.width as usize)
+        .map(|bit| {
+            syndromes
+                .iter()
+                .enumerate()
+                .filter(|(_, syndrome)| (*syndrome >> bit) & 1 == 1)
+                .fold(0u128, |row, (k, _)| row | (1 << k))
+        })
+        .collect();
+
+    let candidates: Vec<Vec<bool>> = (0..count as u64)
+        .into_par_iter()
+        .filter_map(|attempt| {
+            let mut rng = SplitMix64::new(seed ^ attempt.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+            let mut message = prefix.to_vec();
+            message.extend((prefix.len()..total_bits).map(|_| rng.next_bool()));
+
+            let residual = config.checksum_message(&message) ^ target;
+            let mut rows: Vec<Equation> = coefficients
+                .iter()
+                .enumerate()
+                .map(|(bit, &row)| (row, (residual >> bit) & 1 == 1))
+                .collect();
+            let (pivots, free) = eliminate(&mut rows, syndromes.len())?;
+            let flips = solve(&pivots, &free, 0);
+
+            for k in 0..syndromes.len() {
+                if (flips >> k) & 1 == 1 {
+                    message[first_adjustable + k] ^= true;
+                }
+            }
+            (config.checksum_message(&message) == target).then_some(message)
+        })
+        .collect();
+
+    let mut found: Vec<Vec<bool>> = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        if !found.contains(&candidate) {
+            found.push(candidate);
+        }
+    }
+    found
+}
+
+pub fn find_collisions(
+    config: &CrcConfig,
+    data: &[bool],
+    fixed_prefix: usize,
+    count: usize,
+    seed: u64,
+) -> Vec<Vec<bool>> {
+    let target = config.checksum_message(data);
+    let prefix = &data[..fixed_prefix.min(data.len())];
+    find_preimages(config, prefix, data.len(), target, count, seed)
+        .into_iter()
+        .filter(|candidate| candidate != data)
+        .collect()
+}
@@ -0,0 +1,8 @@
+pub use crate::catalog::{CrcAlgorithm, CrcCatalog};
+pub use crate::checksum::Checksum;
+pub use crate::engine::{CrcConfig, Endianness};
+pub use crate::error::{ConfigError, FrameError, ParseError};
+pub use crate::frame::{emit_frame, CanFdFrame, CanFrame};
+pub use crate::hasher::{checksum_reader, crc_from_reader, CrcHasher};
+pub use crate::protocol::Protocol;
+pub use crate::{bits_to_bytes, bits_to_bytes_with, bytes_to_bits, format_binary_grouped, format_hex_grouped, BitOrder, BitPadding, BitString, CrcResult, PackedBits, ParseOptions};
@@ -0,0 +1,83 @@
+use crate::catalog::{CrcAlgorithm, CrcCatalog, CAN_FD_17_MAX_BITS};
+use crate::checksum::Checksum;
+use crate::frame::emit_frame;
+use crate::{bits_to_bytes, format_binary, format_hex, Endianness, ParseError};
+use std::fmt;
+
+pub const ASCII_START: char = ':';
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Protocol {
+    Can,
+    CanFd,
+    ModbusRtu,
+    ModbusAscii,
+    #[default]
+    Generic,
+}
+
+impl Protocol {
+    pub fn name(self) -> &'static str {
+        match self {
+            Protocol::Can => "can",
+            Protocol::CanFd => "canfd",
+            Protocol::ModbusRtu => "modbus-rtu",
+            Protocol::ModbusAscii => "modbus-ascii",
+            Protocol::Generic => "generic",
+        }
+    }
+
+    pub fn algorithm_name(self) -> Option<&'static str> {
+        match self {
+            Protocol::Can => Some("CRC-15/CAN"),
+            Protocol::CanFd => Some("CRC-21/CAN-FD"),
+            Protocol::ModbusRtu => Some("CRC-16/MODBUS"),
+            Protocol::ModbusAscii => Some(Checksum::Lrc8.name()),
+            Protocol::Generic => None,
+        }
+    }
+
+    pub fn algorithm(self, data_bits: usize) -> Option<&'static CrcAlgorithm> {
+        match self {
+            Protocol::CanFd if data_bits <= CAN_FD_17_MAX_BITS => CrcCatalog::find("CRC-17/CAN-FD"),
+            _ => self.algorithm_name().and_then(CrcCatalog::find),
+        }
+    }
+
+    pub fn checksum(self) -> Option<Checksum> {
+        match self {
+            Protocol::ModbusAscii => Some(Checksum::Lrc8),
+            _ => None,
+        }
+    }
+
+    pub fn frame(self, data: &[bool]) -> Result<Option<String>, ParseError> {
+        if matches!(self, Protocol::ModbusRtu | Protocol::ModbusAscii) && !data.len().is_multiple_of(8) {
+            return Err(ParseError::PartialByte { bits: data.len() });
+        }
+        Ok(match self {
+            Protocol::Can | Protocol::CanFd => {
+                let algorithm = self.algorithm(data.len()).expect("CAN presets are in the catalog");
+                Some(format_binary(&emit_frame(&algorithm.config, data, Endianness::Big)))
+            }
+            Protocol::ModbusRtu => {
+                let algorithm = self.algorithm(data.len()).expect("Modbus preset is in the catalog");
+                Some(format_hex(&bits_to_bytes(&emit_frame(&algorithm.config, data, Endianness::Little))))
+            }
+            Protocol::ModbusAscii => {
+                let bytes = bits_to_bytes(data);
+                let lrc = Checksum::Lrc8.compute(&bytes);
+                let hex: String = bytes.iter().map(|byte| format!("{:02X}", byte)).collect();
+                Some(format!("{}{}{:02X}", ASCII_START, hex, lrc))
+            }
+            Protocol::Generic => None,
+        })
+    }
+}
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
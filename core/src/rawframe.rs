@@ -0,0 +1,153 @@
+use crate::engine::{CrcConfig, Endianness};
+use crate::frame::CrcFieldCheck;
+use crate::FrameError;
+use std::io::{self, Read, Write};
+
+pub const MAX_RAW_FRAME_LEN: usize = 1 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrefixSize {
+    One,
+    Two,
+    Four,
+}
+
+impl PrefixSize {
+    pub const ALL: [PrefixSize; 3] = [PrefixSize::One, PrefixSize::Two, PrefixSize::Four];
+
+    pub const fn from_bytes(bytes: u8) -> Option<Self> {
+        match bytes {
+            1 => Some(PrefixSize::One),
+            2 => Some(PrefixSize::Two),
+            4 => Some(PrefixSize::Four),
+            _ => None,
+        }
+    }
+
+    pub const fn bytes(self) -> usize {
+        match self {
+            PrefixSize::One => 1,
+            PrefixSize::Two => 2,
+            PrefixSize::Four => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthPrefix {
+    pub size: PrefixSize,
+    pub endianness: Endianness,
+}
+
+impl LengthPrefix {
+    pub const fn new(size: PrefixSize, endianness: Endianness) -> Self {
+        Self { size, endianness }
+    }
+
+    pub fn max_len(&self) -> usize {
+        match self.size {
+            PrefixSize::One => u8::MAX as usize,
+            PrefixSize::Two => u16::MAX as usize,
+            PrefixSize::Four => MAX_RAW_FRAME_LEN,
+        }
+    }
+
+    pub fn encode(&self, len: usize) -> Vec<u8> {
+        let len = len as u32;
+        let bytes = self.size.bytes();
+        match self.endianness {
+            Endianness::Big => len.to_be_bytes()[4 - bytes..].to_vec(),
+            Endianness::Little => len.to_le_bytes()[..bytes].to_vec(),
+        }
+    }
+
+    pub fn decode(&self, prefix: &[u8]) -> usize {
+        let fold = |len: usize, &byte: &u8| (len << 8) | byte as usize;
+        match self.endianness {
+            Endianness::Big => prefix.iter().fold(0, fold),
+            Endianness::Little => prefix.iter().rev().fold(0, fold),
+        }
+    }
+}
+
+pub fn read_frame<R: Read>(reader: &mut R, prefix: LengthPrefix) -> io::Result<Option<Vec<u8>>> {
+    let mut header = [0u8; 4];
+    let header = &mut header[..prefix.size.bytes()];
+    let mut filled = 0;
+    while filled < header.len() {
+        match reader.read(&mut header[filled..]) {
+            Ok(0) if filled == 0 => return Ok(None),
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    let len = prefix.decode(header);
+    if len > MAX_RAW_FRAME_LEN {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, format!("frame length {} exceeds {}", len, MAX_RAW_FRAME_LEN)));
+    }
+    let mut frame = vec![0u8; len];
+    reader.read_exact(&mut frame)?;
+    Ok(Some(frame))
+}
+
+pub fn write_frame<W: Write>(writer: &mut W, prefix: LengthPrefix, frame: &[u8]) -> io::Result<()> {
+    if frame.len() > prefix.max_len() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("frame length {} exceeds {}", frame.len(), prefix.max_len())));
+    }
+    writer.write_all(&prefix.encode(frame.len()))?;
+    writer.write_all(frame)
+}
+
+pub fn check_raw_frame(config: &CrcConfig, frame: &[u8], endianness: Endianness) -> Result<CrcFieldCheck, FrameError> {
+    let crc_len = (config.width as usize).div_ceil(8);
+    let Some(split) = frame.len().checked_sub(crc_len) else {
+        return Err(FrameError::TooShort { len: frame.len(), min_len: crc_len });
+    };
+    let (message, crc_field) = frame.split_at(split);
+    let fold = |crc: u64, &byte: &u8| (crc << 8) | byte as u64;
+    let received = match endianness {
+        Endianness::Big => crc_field.iter().fold(0, fold),
+        Endianness::Little => crc_field.iter().rev().fold(0, fold),
+    };
+    Ok(CrcFieldCheck {
+        width: config.width,
+        received: received & config.mask(),
+        computed: config.checksum(message),
+    })
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct FrameRecord<'a> {
+    peer: &'a str,
+    frame: u64,
+    len: usize,
+    #[serde(flatten)]
+    outcome: FrameOutcome,
+    valid: bool,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+#[serde(untagged)]
+enum FrameOutcome {
+    Checked { data: String, crc: u64, received: u64 },
+    Failed { error: &'static str, message: String },
+}
+
+#[cfg(feature = "serde")]
+pub fn frame_json(peer: &str, index: u64, frame: &[u8], result: &Result<CrcFieldCheck, FrameError>) -> String {
+    let (outcome, valid) = match result {
+        Ok(check) => {
+            let data = &frame[..frame.len() - (check.width as usize).div_ceil(8)];
+            let data = data.iter().map(|byte| format!("{:02X}", byte)).collect();
+            (FrameOutcome::Checked { data, crc: check.computed, received: check.received }, check.passed())
+        }
+        Err(e) => (FrameOutcome::Failed { error: e.code(), message: e.to_string() }, false),
+    };
+    let record = FrameRecord { peer, frame: index, len: frame.len(), outcome, valid };
+    serde_json::to_string(&record).expect("a raw frame record serializes to JSON")
+}
@@ -0,0 +1,112 @@
+use crate::engine::CrcConfig;
+use crate::gf2::{eliminate, solve, Equation};
+use crate::par::*;
+use std::collections::HashMap;
+
+pub const MAX_POLY_SEARCH_WIDTH: u8 = 24;
+pub const MAX_SOLUTIONS_PER_VARIANT: u64 = 16;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    pub data: Vec<u8>,
+    pub crc: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RecoverOptions {
+    pub poly: Option<u64>,
+    pub max_results: usize,
+}
+
+pub fn recover(width: u8, samples: &[Sample], options: &RecoverOptions) -> Vec<CrcConfig> {
+    if samples.is_empty() || width == 0 || width > 64 {
+        return Vec::new();
+    }
+    if options.poly.is_none() && width > MAX_POLY_SEARCH_WIDTH {
+        return Vec::new();
+    }
+
+    let mask = CrcConfig::new(width, 1).mask();
+    let differences = same_length_differences(samples);
+    let polys: Vec<u64> = match options.poly {
+        Some(poly) => vec![poly & mask],
+        None => (0..=mask >> 1).map(|p| (p << 1) | 1).collect(),
+    };
+    let max_results = if options.max_results == 0 { usize::MAX } else { options.max_results };
+
+    let mut found: Vec<CrcConfig> = polys
+        .par_iter()
+        .flat_map_iter(|&poly| {
+            let mut matches = Vec::new();
+            for (refin, refout) in [(false, false), (true, true), (false, true), (true, false)] {
+                let linear = CrcConfig { refin, refout, ..CrcConfig::new(width, poly) };
+                let consistent = differences
+                    .iter()
+                    .all(|d| linear.checksum_bitwise(&d.data) == d.crc);
+                if consistent {
+                    matches.extend(solve_init_xorout(&linear, samples));
+                }
+            }
+            matches
+        })
+        .collect();
+
+    found.sort_by_key(|c| (c.poly, c.refin, c.refout, c.init));
+    found.truncate(max_results);
+    found
+}
+
+fn same_length_differences(samples: &[Sample]) -> Vec<Sample> {
+    let mut differences = Vec::new();
+    for (i, a) in samples.iter().enumerate() {
+        if let Some(b) = samples[i + 1..].iter().find(|b| b.data.len() == a.data.len()) {
+            differences.push(Sample {
+                data: a.data.iter().zip(&b.data).map(|(x, y)| x ^ y).collect(),
+                crc: a.crc ^ b.crc,
+            });
+        }
+    }
+    differences
+}
+
+fn solve_init_xorout(linear: &CrcConfig, samples: &[Sample]) -> Vec<CrcConfig> {
+    let width = linear.width as usize;
+    let mut columns: HashMap<usize, Vec<u64>> = HashMap::new();
+    let mut rows: Vec<Equation> = Vec::with_capacity(samples.len() * width);
+
+    for sample in samples {
+        let init_columns = columns.entry(sample.data.len()).or_insert_with(|| {
+            let zeros = vec![0u8; sample.data.len()];
+            (0..width)
+                .map(|k| CrcConfig { init: 1 << k, ..*linear }.checksum_bitwise(&zeros))
+                .collect()
+        });
+        let residual = sample.crc ^ linear.checksum_bitwise(&sample.data);
+
+        for bit in 0..width {
+            let mut row = 1u128 << (width + bit);
+            for (k, column) in init_columns.iter().enumerate() {
+                if (column >> bit) & 1 == 1 {
+                    row |= 1 << k;
+                }
+            }
+            rows.push((row, (residual >> bit) & 1 == 1));
+        }
+    }
+
+    let Some((pivots, free)) = eliminate(&mut rows, 2 * width) else {
+        return Vec::new();
+    };
+
+    let combinations = 1u64.checked_shl(free.len() as u32).unwrap_or(u64::MAX).min(MAX_SOLUTIONS_PER_VARIANT);
+    (0..combinations)
+        .map(|combination| {
+            let solution = solve(&pivots, &free, combination);
+            CrcConfig {
+                init: (solution as u64) & linear.mask(),
+                xorout: ((solution >> width) as u64) & linear.mask(),
+                ..*linear
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,154 @@
+use crate::frame::{CanFrame, MAX_CLASSIC_DATA_LEN, MAX_STANDARD_ID};
+use crate::modbus::split_timestamp;
+use crate::{parse_hex_bytes, FrameError};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TraceRecord {
+    pub time: Option<Duration>,
+    pub frame: CanFrame,
+    pub crc: Option<u16>,
+}
+
+impl TraceRecord {
+    pub fn parse_candump(line: &str) -> Result<Self, FrameError> {
+        let (time, _) = split_timestamp(line);
+        Ok(Self { time, frame: CanFrame::parse_candump(line)?, crc: None })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvLayout {
+    pub time: Option<usize>,
+    pub id: usize,
+    pub extended: Option<usize>,
+    pub data: usize,
+    pub crc: Option<usize>,
+}
+
+impl Default for CsvLayout {
+    fn default() -> Self {
+        Self { time: Some(0), id: 1, extended: None, data: 2, crc: Some(3) }
+    }
+}
+
+impl CsvLayout {
+    pub fn from_header(line: &str) -> Option<Self> {
+        let columns: Vec<String> = line.split(',').map(|column| column.trim().to_ascii_lowercase()).collect();
+        let find = |names: &[&str]| columns.iter().position(|column| names.contains(&column.as_str()));
+        Some(Self {
+            time: find(&["time", "timestamp", "t"]),
+            id: find(&["id", "can_id", "identifier"])?,
+            extended: find(&["extended", "ext", "ide"]),
+            data: find(&["data", "payload"])?,
+            crc: find(&["crc", "crc15"]),
+        })
+    }
+
+    pub fn parse(&self, line: &str) -> Result<TraceRecord, FrameError> {
+        let syntax = || FrameError::Syntax { content: line.trim().to_string() };
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let field = |index: Option<usize>| index.and_then(|index| fields.get(index).copied()).filter(|field| !field.is_empty());
+
+        let id_text = field(Some(self.id)).ok_or_else(syntax)?;
+        let id_text = id_text.trim_start_matches("0x").trim_start_matches("0X");
+        let id = u32::from_str_radix(id_text, 16).map_err(|_| syntax())?;
+        let extended = match field(self.extended) {
+            Some(flag) => matches!(flag.to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "x"),
+            None => id_text.len() > 3 || id > MAX_STANDARD_ID,
+        };
+        let data = match field(Some(self.data)) {
+            Some(data) => parse_hex_bytes(&data.replace('.', " ")).map_err(|_| syntax())?,
+            None => Vec::new(),
+        };
+        let frame = if extended { CanFrame::new_extended(id, &data)? } else { CanFrame::new(id, &data)? };
+
+        let time = match field(self.time) {
+            Some(seconds) => match seconds.parse::<f64>() {
+                Ok(seconds) if seconds >= 0.0 && seconds.is_finite() => Some(Duration::from_secs_f64(seconds)),
+                _ => return Err(syntax()),
+            },
+            None => None,
+        };
+        let crc = match field(self.crc) {
+            Some(crc) => Some(u16::from_str_radix(crc.trim_start_matches("0x").trim_start_matches("0X"), 16).map_err(|_| syntax())?),
+            None => None,
+        };
+        Ok(TraceRecord { time, frame, crc })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdStats {
+    pub frames: u64,
+    pub mismatches: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayStats {
+    pub frames: u64,
+    pub errors: u64,
+    pub checked: u64,
+    pub mismatches: u64,
+    pub per_id: BTreeMap<(bool, u32), IdStats>,
+    pub lengths: [u64; MAX_CLASSIC_DATA_LEN + 1],
+    pub first_time: Option<Duration>,
+    pub last_time: Option<Duration>,
+}
+
+impl ReplayStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, record: &TraceRecord) -> Option<bool> {
+        let frame = &record.frame;
+        let matched = record.crc.map(|crc| crc == frame.crc());
+        self.frames += 1;
+        self.lengths[frame.data.len()] += 1;
+        let id = self.per_id.entry((frame.extended, frame.id)).or_default();
+        id.frames += 1;
+        if let Some(matched) = matched {
+            self.checked += 1;
+            if !matched {
+                self.mismatches += 1;
+                id.mismatches += 1;
+            }
+        }
+        if let Some(time) = record.time {
+            self.first_time.get_or_insert(time);
+            self.last_time = Some(time);
+        }
+        matched
+    }
+
+    pub fn record_error(&mut self) {
+        self.errors += 1;
+    }
+
+    pub fn trace_duration(&self) -> Option<Duration> {
+        Some(self.last_time?.saturating_sub(self.first_time?))
+    }
+
+    pub fn trace_rate(&self) -> Option<f64> {
+        let seconds = self.trace_duration()?.as_secs_f64();
+        (seconds > 0.0).then(|| self.frames.saturating_sub(1) as f64 / seconds)
+    }
+
+    pub fn busiest_ids(&self) -> Vec<((bool, u32), IdStats)> {
+        let mut ids: Vec<_> = self.per_id.iter().map(|(&id, &stats)| (id, stats)).collect();
+        ids.sort_by(|a, b| b.1.frames.cmp(&a.1.frames).then(a.0.cmp(&b.0)));
+        ids
+    }
+}
+
+pub fn replay_delay(previous: Option<Duration>, current: Option<Duration>, speed: f64) -> Duration {
+    match (previous, current) {
+        (Some(previous), Some(current)) if speed > 0.0 => current.saturating_sub(previous).div_f64(speed),
+        _ => Duration::ZERO,
+    }
+}
@@ -0,0 +1,56 @@
+use crate::engine::CrcConfig;
+use crate::timing::{measure, BenchPlan, TimingStats};
+use crate::{compute_batch_checksums_in, thread_pool, ThreadPoolBuildError};
+
+pub const MIN_USEFUL_SPEEDUP: f64 = 1.1;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScalingPoint {
+    pub threads: usize,
+    pub crc: u64,
+    pub timing: TimingStats,
+    pub speedup: f64,
+}
+
+impl ScalingPoint {
+    pub fn efficiency(&self) -> f64 {
+        self.speedup / self.threads as f64
+    }
+}
+
+pub fn thread_counts(max_threads: usize) -> Vec<usize> {
+    let max_threads = max_threads.max(1);
+    let mut counts: Vec<usize> = std::iter::successors(Some(1usize), |&threads| threads.checked_mul(2))
+        .take_while(|&threads| threads < max_threads)
+        .collect();
+    counts.push(max_threads);
+    counts
+}
+
+pub fn measure_scaling(config: &CrcConfig, bits: &[bool], plan: &BenchPlan, counts: &[usize]) -> Result<Vec<ScalingPoint>, ThreadPoolBuildError> {
+    measure_scaling_with_progress(config, bits, plan, counts, |_| {})
+}
+
+pub fn measure_scaling_with_progress(
+    config: &CrcConfig,
+    bits: &[bool],
+    plan: &BenchPlan,
+    counts: &[usize],
+    mut progress: impl FnMut(&ScalingPoint),
+) -> Result<Vec<ScalingPoint>, ThreadPoolBuildError> {
+    let mut points: Vec<ScalingPoint> = Vec::with_capacity(counts.len());
+    for &threads in counts {
+        let pool = thread_pool(threads)?;
+        let (crc, timing) = measure(plan, |iterations| compute_batch_checksums_in(&pool, config, bits, iterations));
+        let baseline = points.first().map_or(timing.median_ms, |first| first.timing.median_ms);
+        let speedup = if timing.median_ms > 0.0 { baseline / timing.median_ms } else { 1.0 };
+        let point = ScalingPoint { threads, crc, timing, speedup };
+        progress(&point);
+        points.push(point);
+    }
+    Ok(points)
+}
+
+pub fn best_point(points: &[ScalingPoint]) -> Option<&ScalingPoint> {
+    points.iter().max_by(|a, b| a.speedup.total_cmp(&b.speedup))
+}
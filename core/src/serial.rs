@@ -0,0 +1,122 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::mem;
+use std::os::fd::AsRawFd;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+use std::time::Duration;
+
+pub const DEFAULT_BAUD: u32 = 19_200;
+pub const CHARACTER_BITS: u32 = 11;
+pub const MIN_FRAME_GAP: Duration = Duration::from_micros(1750);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Parity {
+    None,
+    #[default]
+    Even,
+    Odd,
+}
+
+pub fn frame_gap(baud: u32) -> Duration {
+    if baud > 19_200 {
+        return MIN_FRAME_GAP;
+    }
+    Duration::from_micros(u64::from(CHARACTER_BITS) * 3_500_000 / u64::from(baud.max(1)) + 1)
+}
+
+#[derive(Debug)]
+pub struct SerialPort {
+    file: File,
+    gap: Duration,
+}
+
+impl SerialPort {
+    pub fn open(path: &Path, baud: u32, parity: Parity) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .custom_flags(libc::O_NOCTTY | libc::O_CLOEXEC)
+            .open(path)?;
+        let fd = file.as_raw_fd();
+
+        let mut termios: libc::termios = unsafe { mem::zeroed() };
+        if unsafe { libc::tcgetattr(fd, &mut termios) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        unsafe { libc::cfmakeraw(&mut termios) };
+        termios.c_cflag |= libc::CLOCAL | libc::CREAD;
+        termios.c_cflag &= !(libc::PARENB | libc::PARODD | libc::CSTOPB);
+        match parity {
+            Parity::None => termios.c_cflag |= libc::CSTOPB,
+            Parity::Even => termios.c_cflag |= libc::PARENB,
+            Parity::Odd => termios.c_cflag |= libc::PARENB | libc::PARODD,
+        }
+        termios.c_cc[libc::VMIN] = 1;
+        termios.c_cc[libc::VTIME] = 0;
+        let speed = baud_constant(baud)?;
+        if unsafe { libc::cfsetispeed(&mut termios, speed) } < 0 || unsafe { libc::cfsetospeed(&mut termios, speed) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &termios) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        unsafe { libc::tcflush(fd, libc::TCIOFLUSH) };
+        Ok(Self { file, gap: frame_gap(baud) })
+    }
+
+    pub fn gap(&self) -> Duration {
+        self.gap
+    }
+
+    pub fn read_frame(&mut self, timeout: Option<Duration>) -> io::Result<Option<Vec<u8>>> {
+        if !self.wait_readable(timeout)? {
+            return Ok(None);
+        }
+        let mut frame = Vec::new();
+        let mut buffer = [0u8; 256];
+        loop {
+            let read = self.file.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            frame.extend_from_slice(&buffer[..read]);
+            if !self.wait_readable(Some(self.gap))? {
+                break;
+            }
+        }
+        Ok(Some(frame))
+    }
+
+    pub fn write_frame(&mut self, adu: &[u8]) -> io::Result<()> {
+        self.file.write_all(adu)?;
+        if unsafe { libc::tcdrain(self.file.as_raw_fd()) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    fn wait_readable(&self, timeout: Option<Duration>) -> io::Result<bool> {
+        let mut poll = libc::pollfd { fd: self.file.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+        let timeout = timeout.map_or(-1, |timeout| timeout.as_micros().div_ceil(1000).min(i32::MAX as u128) as libc::c_int);
+        match unsafe { libc::poll(&mut poll, 1, timeout) } {
+            n if n < 0 => Err(io::Error::last_os_error()),
+            n => Ok(n > 0),
+        }
+    }
+}
+
+fn baud_constant(baud: u32) -> io::Result<libc::speed_t> {
+    Ok(match baud {
+        1200 => libc::B1200,
+        2400 => libc::B2400,
+        4800 => libc::B4800,
+        9600 => libc::B9600,
+        19_200 => libc::B19200,
+        38_400 => libc::B38400,
+        57_600 => libc::B57600,
+        115_200 => libc::B115200,
+        230_400 => libc::B230400,
+        _ => return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("unsupported baud rate {}", baud))),
+    })
+}
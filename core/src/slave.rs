@@ -0,0 +1,232 @@
+use crate::modbus::{check_rtu, rtu_frame, BROADCAST_ADDRESS, EXCEPTION_FLAG, MAX_READ_BITS, MAX_READ_REGISTERS};
+use crate::rng::SplitMix64;
+use crate::FrameError;
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+pub const ILLEGAL_FUNCTION: u8 = 0x01;
+pub const ILLEGAL_DATA_ADDRESS: u8 = 0x02;
+pub const ILLEGAL_DATA_VALUE: u8 = 0x03;
+pub const MAX_WRITE_COILS: u16 = 0x07B0;
+pub const MAX_WRITE_REGISTERS: u16 = 123;
+pub const COIL_ON: u16 = 0xFF00;
+pub const COIL_OFF: u16 = 0x0000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Table {
+    Coils,
+    DiscreteInputs,
+    HoldingRegisters,
+    InputRegisters,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisterBlock {
+    pub start: u16,
+    pub values: Vec<u16>,
+}
+
+impl FromStr for RegisterBlock {
+    type Err = FrameError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let syntax = || FrameError::Syntax { content: value.to_string() };
+        let (start, values) = value.split_once('=').ok_or_else(syntax)?;
+        let start = parse_word(start).ok_or_else(syntax)?;
+        let values = values.split(',').map(parse_word).collect::<Option<Vec<u16>>>().ok_or_else(syntax)?;
+        if start as usize + values.len() > u16::MAX as usize + 1 {
+            return Err(syntax());
+        }
+        Ok(Self { start, values })
+    }
+}
+
+fn parse_word(value: &str) -> Option<u16> {
+    let value = value.trim();
+    match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+        Some(hex) => u16::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegisterMap {
+    pub coils: BTreeMap<u16, bool>,
+    pub discrete_inputs: BTreeMap<u16, bool>,
+    pub holding_registers: BTreeMap<u16, u16>,
+    pub input_registers: BTreeMap<u16, u16>,
+}
+
+impl RegisterMap {
+    pub fn insert(&mut self, table: Table, block: &RegisterBlock) {
+        let addresses = (block.start..=u16::MAX).zip(block.values.iter().copied());
+        match table {
+            Table::Coils => self.coils.extend(addresses.map(|(address, value)| (address, value != 0))),
+            Table::DiscreteInputs => self.discrete_inputs.extend(addresses.map(|(address, value)| (address, value != 0))),
+            Table::HoldingRegisters => self.holding_registers.extend(addresses),
+            Table::InputRegisters => self.input_registers.extend(addresses),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.coils.is_empty() && self.discrete_inputs.is_empty() && self.holding_registers.is_empty() && self.input_registers.is_empty()
+    }
+
+    pub fn respond(&mut self, function: u8, data: &[u8]) -> Result<Vec<u8>, u8> {
+        let word = |index: usize| data.get(index..index + 2).map(|pair| u16::from_be_bytes([pair[0], pair[1]]));
+        match function {
+            1..=4 => {
+                let (Some(start), Some(count), 4) = (word(0), word(2), data.len()) else {
+                    return Err(ILLEGAL_DATA_VALUE);
+                };
+                let max = if function <= 2 { MAX_READ_BITS } else { MAX_READ_REGISTERS };
+                if !(1..=max).contains(&count) {
+                    return Err(ILLEGAL_DATA_VALUE);
+                }
+                let addresses = addresses(start, count)?;
+                match function {
+                    1 => read_bits(&self.coils, addresses),
+                    2 => read_bits(&self.discrete_inputs, addresses),
+                    3 => read_registers(&self.holding_registers, addresses),
+                    _ => read_registers(&self.input_registers, addresses),
+                }
+            }
+            5 | 6 => {
+                let (Some(address), Some(value), 4) = (word(0), word(2), data.len()) else {
+                    return Err(ILLEGAL_DATA_VALUE);
+                };
+                if function == 5 {
+                    let coil = self.coils.get_mut(&address).ok_or(ILLEGAL_DATA_ADDRESS)?;
+                    *coil = match value {
+                        COIL_ON => true,
+                        COIL_OFF => false,
+                        _ => return Err(ILLEGAL_DATA_VALUE),
+                    };
+                } else {
+                    *self.holding_registers.get_mut(&address).ok_or(ILLEGAL_DATA_ADDRESS)? = value;
+                }
+                Ok(data.to_vec())
+            }
+            15 | 16 => {
+                let (Some(start), Some(count), Some(&len)) = (word(0), word(2), data.get(4)) else {
+                    return Err(ILLEGAL_DATA_VALUE);
+                };
+                let (max, expected_len) = if function == 15 {
+                    (MAX_WRITE_COILS, count.div_ceil(8) as usize)
+                } else {
+                    (MAX_WRITE_REGISTERS, count as usize * 2)
+                };
+                if !(1..=max).contains(&count) || len as usize != expected_len || data.len() != 5 + len as usize {
+                    return Err(ILLEGAL_DATA_VALUE);
+                }
+                let addresses = addresses(start, count)?;
+                let values = &data[5..];
+                if function == 15 {
+                    if !addresses.clone().all(|address| self.coils.contains_key(&address)) {
+                        return Err(ILLEGAL_DATA_ADDRESS);
+                    }
+                    for (index, address) in addresses.enumerate() {
+                        self.coils.insert(address, (values[index / 8] >> (index % 8)) & 1 == 1);
+                    }
+                } else {
+                    if !addresses.clone().all(|address| self.holding_registers.contains_key(&address)) {
+                        return Err(ILLEGAL_DATA_ADDRESS);
+                    }
+                    for (address, pair) in addresses.zip(values.chunks_exact(2)) {
+                        self.holding_registers.insert(address, u16::from_be_bytes([pair[0], pair[1]]));
+                    }
+                }
+                Ok(data[..4].to_vec())
+            }
+            _ => Err(ILLEGAL_FUNCTION),
+        }
+    }
+}
+
+fn addresses(start: u16, count: u16) -> Result<RangeInclusive<u16>, u8> {
+    start.checked_add(count - 1).map(|end| start..=end).ok_or(ILLEGAL_DATA_ADDRESS)
+}
+
+fn read_bits(table: &BTreeMap<u16, bool>, addresses: RangeInclusive<u16>) -> Result<Vec<u8>, u8> {
+    let bits = addresses.map(|address| table.get(&address).copied()).collect::<Option<Vec<bool>>>().ok_or(ILLEGAL_DATA_ADDRESS)?;
+    let bytes: Vec<u8> = bits.chunks(8)
+        .map(|chunk| chunk.iter().enumerate().fold(0u8, |acc, (bit, &on)| acc | (u8::from(on) << bit)))
+        .collect();
+    Ok(std::iter::once(bytes.len() as u8).chain(bytes).collect())
+}
+
+fn read_registers(table: &BTreeMap<u16, u16>, addresses: RangeInclusive<u16>) -> Result<Vec<u8>, u8> {
+    let registers = addresses.map(|address| table.get(&address).copied()).collect::<Option<Vec<u16>>>().ok_or(ILLEGAL_DATA_ADDRESS)?;
+    let bytes = registers.iter().flat_map(|register| register.to_be_bytes());
+    Ok(std::iter::once(registers.len() as u8 * 2).chain(bytes).collect())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlaveReply {
+    pub adu: Vec<u8>,
+    pub exception: Option<u8>,
+    pub corrupted: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SlaveStats {
+    pub requests: u64,
+    pub request_crc_errors: u64,
+    pub ignored: u64,
+    pub replies: u64,
+    pub exceptions: u64,
+    pub corrupted: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SlaveSimulator {
+    pub address: u8,
+    pub map: RegisterMap,
+    pub stats: SlaveStats,
+    bad_crc_rate: f64,
+    rng: SplitMix64,
+}
+
+impl SlaveSimulator {
+    pub fn new(address: u8, map: RegisterMap) -> Self {
+        Self { address, map, stats: SlaveStats::default(), bad_crc_rate: 0.0, rng: SplitMix64::new(0) }
+    }
+
+    pub fn with_bad_crc_rate(self, rate: f64, seed: u64) -> Self {
+        Self { bad_crc_rate: rate.clamp(0.0, 1.0), rng: SplitMix64::new(seed), ..self }
+    }
+
+    pub fn handle(&mut self, adu: &[u8]) -> Result<Option<SlaveReply>, FrameError> {
+        let check = check_rtu(adu)?;
+        self.stats.requests += 1;
+        if !check.is_valid() {
+            self.stats.request_crc_errors += 1;
+            return Ok(None);
+        }
+        if check.address != self.address && check.address != BROADCAST_ADDRESS {
+            self.stats.ignored += 1;
+            return Ok(None);
+        }
+
+        let (function, data) = (adu[1], &adu[2..adu.len() - 2]);
+        let (mut reply, exception) = match self.map.respond(function, data) {
+            Ok(pdu) => (rtu_frame(self.address, function, &pdu), None),
+            Err(code) => (rtu_frame(self.address, function | EXCEPTION_FLAG, &[code]), Some(code)),
+        };
+        if check.address == BROADCAST_ADDRESS {
+            return Ok(None);
+        }
+
+        self.stats.replies += 1;
+        self.stats.exceptions += u64::from(exception.is_some());
+        let corrupted = self.bad_crc_rate > 0.0 && self.rng.next_f64() < self.bad_crc_rate;
+        if corrupted {
+            let last = reply.len() - 1 - self.rng.below(2) as usize;
+            reply[last] ^= 1 << self.rng.below(8);
+            self.stats.corrupted += 1;
+        }
+        Ok(Some(SlaveReply { adu: reply, exception, corrupted }))
+    }
+}
@@ -0,0 +1,70 @@
+use crate::CanFrame;
+use std::ffi::CString;
+use std::io;
+use std::mem;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+const CAN_EFF_FLAG: u32 = 0x8000_0000;
+const CAN_RTR_FLAG: u32 = 0x4000_0000;
+const CAN_MTU: usize = 16;
+
+#[derive(Debug)]
+pub struct CanSocket {
+    fd: OwnedFd,
+}
+
+impl CanSocket {
+    pub fn open(interface: &str) -> io::Result<Self> {
+        let name = CString::new(interface).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let index = unsafe { libc::if_nametoindex(name.as_ptr()) };
+        if index == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let raw = unsafe { libc::socket(libc::AF_CAN, libc::SOCK_RAW | libc::SOCK_CLOEXEC, libc::CAN_RAW) };
+        if raw < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let fd = unsafe { OwnedFd::from_raw_fd(raw) };
+
+        let mut address: libc::sockaddr_can = unsafe { mem::zeroed() };
+        address.can_family = libc::AF_CAN as libc::sa_family_t;
+        address.can_ifindex = index as libc::c_int;
+        let bound = unsafe {
+            libc::bind(
+                fd.as_raw_fd(),
+                &address as *const libc::sockaddr_can as *const libc::sockaddr,
+                mem::size_of::<libc::sockaddr_can>() as libc::socklen_t,
+            )
+        };
+        if bound < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self { fd })
+    }
+
+    pub fn send(&self, frame: &CanFrame) -> io::Result<()> {
+        let buffer = encode_frame(frame);
+        let written = unsafe { libc::write(self.fd.as_raw_fd(), buffer.as_ptr().cast(), buffer.len()) };
+        match written {
+            n if n < 0 => Err(io::Error::last_os_error()),
+            n if n as usize != buffer.len() => Err(io::Error::new(io::ErrorKind::WriteZero, "short write to CAN socket")),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn encode_frame(frame: &CanFrame) -> [u8; CAN_MTU] {
+    let mut can_id = frame.id;
+    if frame.extended {
+        can_id |= CAN_EFF_FLAG;
+    }
+    if frame.rtr {
+        can_id |= CAN_RTR_FLAG;
+    }
+    let mut buffer = [0u8; CAN_MTU];
+    buffer[..4].copy_from_slice(&can_id.to_ne_bytes());
+    buffer[4] = frame.dlc();
+    buffer[8..8 + frame.data.len()].copy_from_slice(&frame.data);
+    buffer
+}
@@ -0,0 +1,78 @@
+use crate::engine::CrcConfig;
+use crate::rng::SplitMix64;
+use crate::PackedBits;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub const SWEEP_SHORT_BITS: usize = 96;
+pub const SWEEP_MAX_BITS: usize = 64 * 1024 * 8;
+pub const DEFAULT_POINT_TIME: Duration = Duration::from_millis(10);
+const MAX_BATCH: u64 = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SweepPoint {
+    pub bits: usize,
+    pub bitwise: f64,
+    pub table: f64,
+}
+
+impl SweepPoint {
+    pub fn speedup(&self) -> f64 {
+        self.table / self.bitwise
+    }
+
+    pub fn table_wins(&self) -> bool {
+        self.table > self.bitwise
+    }
+}
+
+pub fn sweep_lengths(max_bits: Option<usize>) -> Vec<usize> {
+    let limit = max_bits.unwrap_or(SWEEP_MAX_BITS).min(SWEEP_MAX_BITS);
+    let mut lengths: Vec<usize> = (1..=SWEEP_SHORT_BITS.min(limit)).collect();
+    let mut bits = SWEEP_SHORT_BITS.next_power_of_two();
+    while bits < limit {
+        lengths.push(bits);
+        bits *= 2;
+    }
+    if limit > SWEEP_SHORT_BITS {
+        lengths.push(limit);
+    }
+    lengths
+}
+
+pub fn measure_point(config: &CrcConfig, table: &[u64], bits: usize, point_time: Duration) -> SweepPoint {
+    let mut rng = SplitMix64::new(bits as u64);
+    let message: Vec<bool> = (0..bits).map(|_| rng.next_bool()).collect();
+    let packed = PackedBits::from_bools(&message);
+    SweepPoint {
+        bits,
+        bitwise: throughput(point_time, || config.checksum_bits(black_box(&message))),
+        table: throughput(point_time, || config.checksum_packed_with_table(table, black_box(&packed))),
+    }
+}
+
+pub fn sweep(config: &CrcConfig, lengths: &[usize], point_time: Duration) -> Vec<SweepPoint> {
+    let table = config.table();
+    lengths.iter().map(|&bits| measure_point(config, &table, bits, point_time)).collect()
+}
+
+pub fn crossover(points: &[SweepPoint]) -> Option<usize> {
+    match points.iter().rposition(|point| !point.table_wins()) {
+        Some(last_loss) => points.get(last_loss + 1).map(|point| point.bits),
+        None => points.first().map(|point| point.bits),
+    }
+}
+
+pub(crate) fn throughput(point_time: Duration, mut run: impl FnMut() -> u64) -> f64 {
+    let start = Instant::now();
+    let mut count = 0;
+    let mut batch = 1;
+    while start.elapsed() < point_time {
+        for _ in 0..batch {
+            black_box(run());
+        }
+        count += batch;
+        batch = (batch * 2).min(MAX_BATCH);
+    }
+    count as f64 / start.elapsed().as_secs_f64()
+}
@@ -0,0 +1,149 @@
+use crate::engine::CrcConfig;
+use crate::frame::CanFrame;
+use crate::{calculate_can_crc, calculate_can_crc_bytes, calculate_can_crc_optimized};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorKind {
+    Can15,
+    CanFrame { id: u32, extended: bool },
+    Modbus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestVector {
+    pub name: &'static str,
+    pub source: &'static str,
+    pub kind: VectorKind,
+    pub input: &'static [u8],
+    pub expected: u64,
+}
+
+pub static VECTORS: &[TestVector] = &[
+    TestVector {
+        name: "CRC-15/CAN check",
+        source: "CRC catalogue check value (ASCII \"123456789\")",
+        kind: VectorKind::Can15,
+        input: b"123456789",
+        expected: 0x059E,
+    },
+    TestVector {
+        name: "CRC-15/CAN zeros",
+        source: "Property of the code (init 0, no final XOR)",
+        kind: VectorKind::Can15,
+        input: &[0x00, 0x00, 0x00, 0x00],
+        expected: 0x0000,
+    },
+    TestVector {
+        name: "CAN 0x123 8 B",
+        source: "Regression vector, recorded from this crate (CAN 2.0A frame)",
+        kind: VectorKind::CanFrame { id: 0x123, extended: false },
+        input: &[0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88],
+        expected: 0x4237,
+    },
+    TestVector {
+        name: "CAN 0x7FF 1 B",
+        source: "Regression vector, recorded from this crate (CAN 2.0A frame)",
+        kind: VectorKind::CanFrame { id: 0x7FF, extended: false },
+        input: &[0xFF],
+        expected: 0x7A15,
+    },
+    TestVector {
+        name: "CAN 0x555 2 B",
+        source: "Regression vector, recorded from this crate (CAN 2.0A frame)",
+        kind: VectorKind::CanFrame { id: 0x555, extended: false },
+        input: &[0xAA, 0x55],
+        expected: 0x33CE,
+    },
+    TestVector {
+        name: "CAN 0x12345678 4 B",
+        source: "Regression vector, recorded from this crate (CAN 2.0B frame)",
+        kind: VectorKind::CanFrame { id: 0x1234_5678, extended: true },
+        input: &[0x01, 0x02, 0x03, 0x04],
+        expected: 0x22AB,
+    },
+    TestVector {
+        name: "CRC-16/MODBUS check",
+        source: "CRC catalogue check value (ASCII \"123456789\")",
+        kind: VectorKind::Modbus,
+        input: b"123456789",
+        expected: 0x4B37,
+    },
+    TestVector {
+        name: "Modbus read holding registers",
+        source: "Modbus RTU read holding registers request (frame 11 03 00 6B 00 03 76 87)",
+        kind: VectorKind::Modbus,
+        input: &[0x11, 0x03, 0x00, 0x6B, 0x00, 0x03],
+        expected: 0x8776,
+    },
+    TestVector {
+        name: "Modbus read exception status",
+        source: "Modbus over Serial Line specification, CRC example (frame 02 07 41 12)",
+        kind: VectorKind::Modbus,
+        input: &[0x02, 0x07],
+        expected: 0x1241,
+    },
+    TestVector {
+        name: "Modbus read 10 registers",
+        source: "Modbus RTU read holding registers request (frame 01 03 00 00 00 0A C5 CD)",
+        kind: VectorKind::Modbus,
+        input: &[0x01, 0x03, 0x00, 0x00, 0x00, 0x0A],
+        expected: 0xCDC5,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VectorResult {
+    pub vector: &'static TestVector,
+    pub actual: u64,
+}
+
+impl VectorResult {
+    pub fn passed(&self) -> bool {
+        self.actual == self.vector.expected
+    }
+}
+
+impl TestVector {
+    pub fn compute(&self) -> u64 {
+        match self.kind {
+            VectorKind::Can15 => {
+                let bits: Vec<bool> = self.input
+                    .iter()
+                    .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+                    .collect();
+                let reference = calculate_can_crc(&bits);
+                let agree = reference == calculate_can_crc_optimized(&bits)
+                    && reference == calculate_can_crc_bytes(self.input)
+                    && reference as u64 == CrcConfig::CAN.checksum(self.input);
+                if agree {
+                    reference as u64
+                } else {
+                    u64::MAX
+                }
+            }
+            VectorKind::CanFrame { id, extended } => {
+                let frame = if extended {
+                    CanFrame::new_extended(id, self.input)
+                } else {
+                    CanFrame::new(id, self.input)
+                };
+                frame.map(|f| f.crc() as u64).unwrap_or(u64::MAX)
+            }
+            VectorKind::Modbus => {
+                let table_driven = CrcConfig::MODBUS.checksum(self.input);
+                if table_driven == CrcConfig::MODBUS.checksum_bitwise(self.input) {
+                    table_driven
+                } else {
+                    u64::MAX
+                }
+            }
+        }
+    }
+}
+
+pub fn self_test() -> Vec<VectorResult> {
+    VECTORS
+        .iter()
+        .map(|vector| VectorResult { vector, actual: vector.compute() })
+        .collect()
+}
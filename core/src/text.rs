@@ -0,0 +1,601 @@
+use crate::{BitOrder, BitPadding, BitString, PackedBits, ParseError, ParseOptions, SNIPPET_WIDTH};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+pub fn parse_binary_input(input: &str) -> Result<Vec<bool>, ParseError> {
+    parse_binary_input_with(input, &ParseOptions::default())
+}
+
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(len = input.len())))]
+pub fn parse_binary_input_with(input: &str, options: &ParseOptions) -> Result<Vec<bool>, ParseError> {
+    BitString::parse_binary(input, options).map(|bits| bits.to_bools())
+}
+
+fn parse_dump_line(line: &str) -> Option<(usize, Vec<u8>)> {
+    let (offset, hex_columns) = match line.split_once(':') {
+        Some((offset, rest)) if offset.chars().all(|c| c.is_ascii_hexdigit()) && rest.starts_with(' ') => {
+            (offset, rest.split("  ").next().unwrap_or_default())
+        }
+        _ => match line.split_once("  ") {
+            Some((offset, rest)) => (offset, rest.split_once('|')?.0),
+            None => (line, ""),
+        },
+    };
+    
+    if offset.len() < 4 || !offset.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    
+    let mut bytes = Vec::new();
+    for group in hex_columns.split_whitespace() {
+        if !group.len().is_multiple_of(2) {
+            return None;
+        }
+        for i in (0..group.len()).step_by(2) {
+            bytes.push(u8::from_str_radix(group.get(i..i + 2)?, 16).ok()?);
+        }
+    }
+    Some((usize::from_str_radix(offset, 16).ok()?, bytes))
+}
+
+fn parse_dump(input: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut base = None;
+    let mut previous = Vec::new();
+    let mut repeat = false;
+    
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        if line == "*" {
+            repeat = true;
+            continue;
+        }
+        let (offset, data) = parse_dump_line(line)?;
+        let position = offset.checked_sub(*base.get_or_insert(offset))?;
+        if repeat && !previous.is_empty() {
+            while bytes.len() < position {
+                bytes.extend_from_slice(&previous);
+            }
+            bytes.truncate(position);
+            repeat = false;
+        }
+        if position != bytes.len() {
+            return None;
+        }
+        bytes.extend_from_slice(&data);
+        if !data.is_empty() {
+            previous = data;
+        }
+    }
+    
+    Some(bytes).filter(|bytes| !bytes.is_empty())
+}
+
+fn strip_c_comments(input: &str) -> String {
+    let mut stripped = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find("/*").into_iter().chain(rest.find("//")).min() {
+        stripped.push_str(&rest[..start]);
+        let (terminator, keep) = if rest[start..].starts_with("/*") { ("*/", "") } else { ("\n", "\n") };
+        rest = match rest[start + 2..].find(terminator) {
+            Some(end) => {
+                stripped.push_str(keep);
+                &rest[start + 2 + end + terminator.len()..]
+            }
+            None => "",
+        };
+        stripped.push(' ');
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+fn parse_c_byte(element: &str) -> Option<u8> {
+    let element = match element.strip_prefix('(') {
+        Some(cast) => cast.split_once(')')?.1.trim(),
+        None => element,
+    };
+    if let Some(c) = element.strip_prefix('\'').and_then(|rest| rest.strip_suffix('\'')) {
+        let mut chars = c.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii() => Some(c as u8),
+            _ => None,
+        };
+    }
+    let digits = element.trim_end_matches(['u', 'U', 'l', 'L']);
+    let (digits, radix) = if let Some(hex) = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        (hex, 16)
+    } else if let Some(binary) = digits.strip_prefix("0b").or_else(|| digits.strip_prefix("0B")) {
+        (binary, 2)
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        (&digits[1..], 8)
+    } else {
+        (digits, 10)
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+    u8::from_str_radix(digits, radix).ok()
+}
+
+fn parse_c_array(input: &str) -> Option<Result<Vec<u8>, ParseError>> {
+    let source = strip_c_comments(input);
+    let start = source.find('{')?;
+    let end = source.rfind('}').filter(|&end| end > start)?;
+    let mut bytes = Vec::new();
+    for element in source[start + 1..end].split([',', '{', '}']).map(str::trim).filter(|element| !element.is_empty()) {
+        match parse_c_byte(element) {
+            Some(byte) => bytes.push(byte),
+            None => return Some(Err(ParseError::InvalidArrayElement { element: element.to_string() })),
+        }
+    }
+    if bytes.is_empty() {
+        return Some(Err(ParseError::NoHexData));
+    }
+    Some(Ok(bytes))
+}
+
+pub fn parse_hex_bytes(input: &str) -> Result<Vec<u8>, ParseError> {
+    if input.trim().is_empty() {
+        return Err(ParseError::Empty);
+    }
+    
+    if let Some(bytes) = parse_dump(input) {
+        return Ok(bytes);
+    }
+    
+    if let Some(array) = parse_c_array(input) {
+        return array;
+    }
+    
+    let hex_chars = hex_chars(input);
+    
+    let invalid_chars: Vec<(usize, char)> = hex_chars.iter()
+        .copied()
+        .filter(|(_, c)| !c.is_ascii_hexdigit())
+        .collect();
+    
+    if let Some(&(first, _)) = invalid_chars.first() {
+        return Err(ParseError::InvalidHexChars {
+            chars: invalid_chars.iter().take(5).map(|&(_, c)| c).collect(),
+            index: input[..first].chars().count(),
+        });
+    }
+    
+    let hex_string: String = hex_chars.iter().map(|&(_, c)| c).collect();
+    
+    if hex_string.is_empty() {
+        return Err(ParseError::NoHexData);
+    }
+    
+    if !hex_string.len().is_multiple_of(2) {
+        return Err(ParseError::OddHexLength { digits: hex_string.len() });
+    }
+    
+    (0..hex_string.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex_string[i..i+2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|_| ParseError::InvalidHexFormat)
+}
+
+fn is_hex_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, ',' | ':' | '-')
+}
+
+fn is_binary_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '_' | '.')
+}
+
+fn hex_chars(input: &str) -> Vec<(usize, char)> {
+    literal_chars(input, is_hex_separator, 'x')
+}
+
+pub(crate) fn binary_chars(input: &str) -> Vec<(usize, char)> {
+    literal_chars(input, is_binary_separator, 'b')
+}
+
+fn literal_chars(input: &str, is_separator: fn(char) -> bool, prefix: char) -> Vec<(usize, char)> {
+    let mut kept = Vec::with_capacity(input.len());
+    let mut chars = input.char_indices().peekable();
+    let mut token_start = true;
+    while let Some((index, c)) = chars.next() {
+        if is_separator(c) {
+            token_start = true;
+            continue;
+        }
+        if token_start && c == '0' && chars.peek().is_some_and(|&(_, next)| next.to_ascii_lowercase() == prefix) {
+            chars.next();
+            token_start = false;
+            continue;
+        }
+        token_start = false;
+        kept.push((index, c));
+    }
+    kept
+}
+
+pub fn parse_binary_input_with_order(input: &str, order: BitOrder) -> Result<Vec<bool>, ParseError> {
+    parse_binary_input_with(input, &ParseOptions { bit_order: order, ..ParseOptions::default() })
+}
+
+pub fn parse_hex_input(input: &str) -> Result<Vec<bool>, ParseError> {
+    parse_hex_input_with(input, &ParseOptions::default())
+}
+
+pub fn parse_hex_input_with_order(input: &str, order: BitOrder) -> Result<Vec<bool>, ParseError> {
+    parse_hex_input_with(input, &ParseOptions { bit_order: order, ..ParseOptions::default() })
+}
+
+pub fn parse_hex_input_with(input: &str, options: &ParseOptions) -> Result<Vec<bool>, ParseError> {
+    parse_hex_input_packed(input, options).map(|bits| bits.to_bools())
+}
+
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(len = input.len())))]
+pub fn parse_hex_input_packed(input: &str, options: &ParseOptions) -> Result<PackedBits, ParseError> {
+    BitString::parse_hex(input, options).map(BitString::into_packed)
+}
+
+fn base64_value(c: char) -> Option<u8> {
+    match c {
+        'A'..='Z' => Some(c as u8 - b'A'),
+        'a'..='z' => Some(c as u8 - b'a' + 26),
+        '0'..='9' => Some(c as u8 - b'0' + 52),
+        '+' | '-' => Some(62),
+        '/' | '_' => Some(63),
+        _ => None,
+    }
+}
+
+pub fn parse_base64_bytes(input: &str) -> Result<Vec<u8>, ParseError> {
+    if input.trim().is_empty() {
+        return Err(ParseError::Empty);
+    }
+    
+    let cleaned: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    let data = cleaned.trim_end_matches('=');
+    let padding = cleaned.len() - data.len();
+    
+    let invalid_chars: Vec<char> = data.chars()
+        .filter(|&c| base64_value(c).is_none())
+        .collect();
+    
+    if !invalid_chars.is_empty() {
+        return Err(ParseError::InvalidBase64Chars {
+            chars: invalid_chars.iter().take(5).collect(),
+            index: input.chars().position(|c| !c.is_whitespace() && base64_value(c).is_none()).unwrap_or_default(),
+        });
+    }
+    
+    if data.is_empty() {
+        return Err(ParseError::NoBase64Data);
+    }
+    
+    if data.len() % 4 == 1 || padding > 2 || (padding > 0 && !cleaned.len().is_multiple_of(4)) {
+        return Err(ParseError::InvalidBase64Length { chars: cleaned.len() });
+    }
+    
+    let mut bytes = Vec::with_capacity(data.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut buffered = 0;
+    for value in data.chars().filter_map(base64_value) {
+        buffer = (buffer << 6) | value as u32;
+        buffered += 6;
+        if buffered >= 8 {
+            buffered -= 8;
+            bytes.push((buffer >> buffered) as u8);
+        }
+    }
+    Ok(bytes)
+}
+
+pub fn binary_error_spans(input: &str, error: &ParseError) -> Vec<Range<usize>> {
+    match error {
+        ParseError::InvalidBinaryChars { .. } => merge_spans(binary_chars(input).into_iter().filter(|&(_, c)| c != '0' && c != '1')),
+        ParseError::BinaryTooLong { max_bits, .. } => span_from(binary_chars(input).into_iter().skip(*max_bits)),
+        _ => Vec::new(),
+    }
+}
+
+pub fn hex_error_spans(input: &str, error: &ParseError) -> Vec<Range<usize>> {
+    match error {
+        ParseError::InvalidHexChars { .. } => merge_spans(hex_chars(input).into_iter().filter(|(_, c)| !c.is_ascii_hexdigit())),
+        ParseError::OddHexLength { digits } => span_from(hex_chars(input).into_iter().skip(digits.saturating_sub(1))),
+        ParseError::HexTooLong { max_bytes, .. } => span_from(hex_chars(input).into_iter().skip(max_bytes * 2)),
+        ParseError::InvalidArrayElement { element } => input.find('{')
+            .and_then(|open| input[open..].find(element.as_str()).map(|start| open + start))
+            .map(|start| start..start + element.len())
+            .into_iter()
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+pub fn base64_error_spans(input: &str, error: &ParseError) -> Vec<Range<usize>> {
+    let data_end = input.char_indices()
+        .rfind(|&(_, c)| !c.is_whitespace() && c != '=')
+        .map_or(0, |(index, c)| index + c.len_utf8());
+    let data = &input[..data_end];
+    let data_chars = data.chars().filter(|c| !c.is_whitespace()).count();
+    
+    match error {
+        ParseError::InvalidBase64Chars { .. } => char_spans(data, |c| !c.is_whitespace() && base64_value(c).is_none()),
+        ParseError::InvalidBase64Length { .. } if data_chars % 4 == 1 => spans_after(data, data_chars - 1, |c| !c.is_whitespace()),
+        ParseError::InvalidBase64Length { .. } => char_spans(&input[data_end..], |c| c == '=')
+            .into_iter()
+            .map(|span| span.start + data_end..span.end + data_end)
+            .collect(),
+        ParseError::HexTooLong { max_bytes, .. } => spans_after(data, max_bytes * 8 / 6, |c| !c.is_whitespace()),
+        _ => Vec::new(),
+    }
+}
+
+pub fn error_snippet(input: &str, spans: &[Range<usize>]) -> Option<String> {
+    let first = spans.first()?;
+    let line_start = input[..first.start].rfind('\n').map_or(0, |index| index + 1);
+    let line_end = input[first.start..].find('\n').map_or(input.len(), |index| first.start + index);
+    let line: Vec<(usize, char)> = input[line_start..line_end].char_indices()
+        .map(|(index, c)| (line_start + index, c))
+        .collect();
+    let column = line.iter().position(|&(index, _)| index >= first.start).unwrap_or(line.len());
+    let from = column.saturating_sub(SNIPPET_WIDTH / 2).min(line.len().saturating_sub(SNIPPET_WIDTH));
+    let to = (from + SNIPPET_WIDTH).min(line.len());
+    
+    let mut text = String::new();
+    let mut carets = String::new();
+    if from > 0 {
+        text.push('…');
+        carets.push(' ');
+    }
+    for &(index, c) in &line[from..to] {
+        text.push(if c.is_whitespace() { ' ' } else { c });
+        carets.push(if spans.iter().any(|span| span.contains(&index)) { '^' } else { ' ' });
+    }
+    if to < line.len() {
+        text.push('…');
+    }
+    if !carets.contains('^') {
+        carets.push('^');
+    }
+    Some(format!("{}\n{}", text, carets.trim_end()))
+}
+
+fn char_spans(input: &str, invalid: impl Fn(char) -> bool) -> Vec<Range<usize>> {
+    merge_spans(input.char_indices().filter(|&(_, c)| invalid(c)))
+}
+
+fn merge_spans(chars: impl Iterator<Item = (usize, char)>) -> Vec<Range<usize>> {
+    let mut spans: Vec<Range<usize>> = Vec::new();
+    for (index, c) in chars {
+        let end = index + c.len_utf8();
+        match spans.last_mut() {
+            Some(last) if last.end == index => last.end = end,
+            _ => spans.push(index..end),
+        }
+    }
+    spans
+}
+
+fn spans_after(input: &str, skip: usize, counted: impl Fn(char) -> bool) -> Vec<Range<usize>> {
+    span_from(input.char_indices().filter(|&(_, c)| counted(c)).skip(skip))
+}
+
+fn span_from(mut chars: impl Iterator<Item = (usize, char)>) -> Vec<Range<usize>> {
+    let Some(first) = chars.next() else {
+        return Vec::new();
+    };
+    let (index, c) = chars.last().unwrap_or(first);
+    core::iter::once(first.0..index + c.len_utf8()).collect()
+}
+
+pub fn parse_base64_input(input: &str) -> Result<Vec<bool>, ParseError> {
+    parse_base64_input_with(input, &ParseOptions::default())
+}
+
+pub fn parse_base64_input_with(input: &str, options: &ParseOptions) -> Result<Vec<bool>, ParseError> {
+    parse_base64_input_packed(input, options).map(|bits| bits.to_bools())
+}
+
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(len = input.len())))]
+pub fn parse_base64_input_packed(input: &str, options: &ParseOptions) -> Result<PackedBits, ParseError> {
+    BitString::from_parsed_bytes(parse_base64_bytes(input)?, options).map(BitString::into_packed)
+}
+
+fn escape_value(chars: &mut core::iter::Peekable<core::str::CharIndices>, escape: char) -> Option<u8> {
+    let (radix, max_digits, mut value) = match escape {
+        'x' => (16, 2, 0u32),
+        '0'..='7' => (8, 2, escape.to_digit(8)?),
+        'n' => return Some(b'\n'),
+        'r' => return Some(b'\r'),
+        't' => return Some(b'\t'),
+        'a' => return Some(0x07),
+        'b' => return Some(0x08),
+        'f' => return Some(0x0C),
+        'v' => return Some(0x0B),
+        'e' => return Some(0x1B),
+        '\\' | '"' | '\'' | '?' => return Some(escape as u8),
+        _ => return None,
+    };
+    let mut digits = 0;
+    while let Some(digit) = chars.peek().and_then(|&(_, c)| c.to_digit(radix)).filter(|_| digits < max_digits) {
+        value = value * radix + digit;
+        digits += 1;
+        chars.next();
+    }
+    if radix == 16 && digits == 0 {
+        return None;
+    }
+    u8::try_from(value).ok()
+}
+
+fn unescape_into(content: &str, offset: usize, bytes: &mut Vec<u8>) -> Result<(), ParseError> {
+    let mut chars = content.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        if c != '\\' {
+            let mut buffer = [0u8; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            continue;
+        }
+        let Some((_, escape)) = chars.next() else {
+            return Err(ParseError::InvalidEscape { sequence: "\\".to_string(), index: offset + index });
+        };
+        match escape_value(&mut chars, escape) {
+            Some(byte) => bytes.push(byte),
+            None => {
+                let end = chars.peek().map_or(content.len(), |&(next, _)| next);
+                return Err(ParseError::InvalidEscape { sequence: content[index..end].to_string(), index: offset + index });
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn parse_escaped_bytes(input: &str) -> Result<Vec<u8>, ParseError> {
+    let trimmed = input.trim().trim_end_matches([';', ',']).trim_end();
+    if trimmed.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    
+    let start = input.len() - input.trim_start().len();
+    let char_index = |byte: usize| input[..byte].chars().count();
+    let bytes = unescape_literals(trimmed, start).map_err(|error| match error {
+        ParseError::InvalidEscape { sequence, index } => ParseError::InvalidEscape { sequence, index: char_index(index) },
+        ParseError::UnterminatedString { index } => ParseError::UnterminatedString { index: char_index(index) },
+        error => error,
+    })?;
+    
+    if bytes.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    Ok(bytes)
+}
+
+fn unescape_literals(trimmed: &str, start: usize) -> Result<Vec<u8>, ParseError> {
+    let mut bytes = Vec::new();
+    if !trimmed.trim_start_matches(['b', 'B']).starts_with(['"', '\'']) {
+        unescape_into(trimmed, start, &mut bytes)?;
+    } else {
+        let mut rest = trimmed;
+        while !rest.is_empty() {
+            let literal = rest.trim_start_matches(['b', 'B']);
+            let opening = start + trimmed.len() - literal.len();
+            let mut chars = literal.chars();
+            let Some(quote) = chars.next().filter(|&c| c == '"' || c == '\'') else {
+                return Err(ParseError::InvalidEscape { sequence: literal.chars().take(5).collect(), index: opening });
+            };
+            let body = chars.as_str();
+            let mut escaped = false;
+            let close = body.char_indices()
+                .find(|&(_, c)| {
+                    let closes = c == quote && !escaped;
+                    escaped = c == '\\' && !escaped;
+                    closes
+                })
+                .map(|(index, _)| index)
+                .ok_or(ParseError::UnterminatedString { index: opening })?;
+            unescape_into(&body[..close], opening + 1, &mut bytes)?;
+            rest = body[close + 1..].trim_start();
+        }
+    }
+    Ok(bytes)
+}
+
+pub fn escaped_error_spans(input: &str, error: &ParseError) -> Vec<Range<usize>> {
+    let byte_index = |index: usize| input.char_indices().nth(index).map_or(input.len(), |(byte, _)| byte);
+    let span = match error {
+        ParseError::InvalidEscape { sequence, index } => byte_index(*index)..byte_index(*index) + sequence.len(),
+        ParseError::UnterminatedString { index } => byte_index(*index)..input.trim_end().len(),
+        _ => return Vec::new(),
+    };
+    core::iter::once(span).collect()
+}
+
+pub fn parse_escaped_input(input: &str) -> Result<Vec<bool>, ParseError> {
+    parse_escaped_input_with(input, &ParseOptions::default())
+}
+
+pub fn parse_escaped_input_with(input: &str, options: &ParseOptions) -> Result<Vec<bool>, ParseError> {
+    parse_escaped_input_packed(input, options).map(|bits| bits.to_bools())
+}
+
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(len = input.len())))]
+pub fn parse_escaped_input_packed(input: &str, options: &ParseOptions) -> Result<PackedBits, ParseError> {
+    BitString::from_parsed_bytes(parse_escaped_bytes(input)?, options).map(BitString::into_packed)
+}
+
+pub fn format_binary(bits: &[bool]) -> String {
+    bits.iter().map(|&bit| if bit { '1' } else { '0' }).collect()
+}
+
+pub fn format_binary_grouped(bits: &[bool], group: usize) -> String {
+    if group == 0 {
+        return format_binary(bits);
+    }
+    bits.chunks(group).map(format_binary).collect::<Vec<_>>().join(" ")
+}
+
+pub fn format_hex(bytes: &[u8]) -> String {
+    format_hex_grouped(bytes, 1)
+}
+
+pub fn format_hex_grouped(bytes: &[u8], group: usize) -> String {
+    let digits = |chunk: &[u8]| chunk.iter().map(|b| format!("{:02X}", b)).collect::<String>();
+    if group == 0 {
+        return digits(bytes);
+    }
+    bytes.chunks(group)
+        .map(digits)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn format_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    
+    let mut result = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let buffer = chunk.iter().enumerate().fold(0u32, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                result.push(ALPHABET[((buffer >> (18 - 6 * i)) & 0x3F) as usize] as char);
+            } else {
+                result.push('=');
+            }
+        }
+    }
+    result
+}
+
+pub fn format_escaped(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("\\x{:02x}", b)).collect()
+}
+
+pub fn bits_to_bytes(bits: &[bool]) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|chunk| chunk.iter().enumerate().fold(0u8, |acc, (i, &bit)| acc | ((bit as u8) << (7 - i))))
+        .collect()
+}
+
+pub fn bits_to_bytes_with(bits: &[bool], padding: BitPadding) -> Result<Vec<u8>, ParseError> {
+    let missing = (8 - bits.len() % 8) % 8;
+    match padding {
+        BitPadding::Trailing => Ok(bits_to_bytes(bits)),
+        BitPadding::Leading => {
+            let padded: Vec<bool> = core::iter::repeat_n(false, missing).chain(bits.iter().copied()).collect();
+            Ok(bits_to_bytes(&padded))
+        }
+        BitPadding::Reject if missing > 0 => Err(ParseError::PartialByte { bits: bits.len() }),
+        BitPadding::Reject => Ok(bits_to_bytes(bits)),
+    }
+}
+
+pub fn bytes_to_bits(bytes: &[u8]) -> Vec<bool> {
+    let mut bits = Vec::with_capacity(bytes.len() * 8);
+    for byte in bytes {
+        for i in (0..8).rev() {
+            bits.push((byte >> i) & 1 == 1);
+        }
+    }
+    bits
+}
@@ -0,0 +1,102 @@
+use std::hint::black_box;
+use std::time::Instant;
+
+pub const DEFAULT_WARMUP: u32 = 3;
+pub const DEFAULT_REPETITIONS: u32 = 10;
+pub const NOISY_RELATIVE_STDDEV: f64 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchPlan {
+    pub iterations: u64,
+    pub warmup: u32,
+    pub repetitions: u32,
+}
+
+impl BenchPlan {
+    pub fn single(iterations: u64) -> Self {
+        Self { iterations, warmup: 0, repetitions: 1 }
+    }
+
+    pub fn is_repeated(&self) -> bool {
+        self.repetitions > 1
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimingStats {
+    pub warmup: u32,
+    pub repetitions: u32,
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+    pub mean_ms: f64,
+    pub stddev_ms: f64,
+}
+
+impl TimingStats {
+    pub fn from_samples(warmup: u32, samples: &[f64]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let count = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / count;
+        let variance = if sorted.len() > 1 {
+            sorted.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>() / (count - 1.0)
+        } else {
+            0.0
+        };
+        Some(Self {
+            warmup,
+            repetitions: sorted.len() as u32,
+            min_ms: sorted[0],
+            median_ms: median(&sorted),
+            p95_ms: percentile(&sorted, 95.0),
+            max_ms: sorted[sorted.len() - 1],
+            mean_ms: mean,
+            stddev_ms: variance.sqrt(),
+        })
+    }
+
+    pub fn relative_stddev(&self) -> f64 {
+        if self.mean_ms > 0.0 { self.stddev_ms / self.mean_ms * 100.0 } else { 0.0 }
+    }
+
+    pub fn is_noisy(&self) -> bool {
+        self.relative_stddev() > NOISY_RELATIVE_STDDEV
+    }
+}
+
+pub fn median(sorted: &[f64]) -> f64 {
+    match sorted.len() {
+        0 => 0.0,
+        len if len % 2 == 1 => sorted[len / 2],
+        len => (sorted[len / 2 - 1] + sorted[len / 2]) / 2.0,
+    }
+}
+
+pub fn percentile(sorted: &[f64], percent: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (percent / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+pub fn measure<T>(plan: &BenchPlan, mut run: impl FnMut(u64) -> T) -> (T, TimingStats) {
+    for _ in 0..plan.warmup {
+        black_box(run(plan.iterations));
+    }
+    let mut samples = Vec::with_capacity(plan.repetitions.max(1) as usize);
+    let mut output = None;
+    for _ in 0..plan.repetitions.max(1) {
+        let start = Instant::now();
+        output = Some(black_box(run(plan.iterations)));
+        samples.push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+    let stats = TimingStats::from_samples(plan.warmup, &samples).expect("at least one repetition");
+    (output.expect("at least one repetition"), stats)
+}
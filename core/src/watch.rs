@@ -0,0 +1,53 @@
+use notify::event::{AccessKind, AccessMode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(100);
+
+pub struct FileWatcher {
+    _watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+    path: PathBuf,
+    debounce: Duration,
+}
+
+impl FileWatcher {
+    pub fn new(path: &Path) -> io::Result<Self> {
+        Self::with_debounce(path, DEFAULT_DEBOUNCE)
+    }
+
+    pub fn with_debounce(path: &Path, debounce: Duration) -> io::Result<Self> {
+        let path = path.canonicalize()?;
+        let directory = path.parent().unwrap_or(&path).to_path_buf();
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender).map_err(io::Error::other)?;
+        watcher.watch(&directory, RecursiveMode::NonRecursive).map_err(io::Error::other)?;
+        Ok(Self { _watcher: watcher, events, path, debounce })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn wait(&self) -> io::Result<()> {
+        loop {
+            let event = self.events.recv().map_err(io::Error::other)?.map_err(io::Error::other)?;
+            if self.is_change(&event) {
+                break;
+            }
+        }
+        while self.events.recv_timeout(self.debounce).is_ok() {}
+        Ok(())
+    }
+
+    fn is_change(&self, event: &Event) -> bool {
+        let relevant = matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_) | EventKind::Access(AccessKind::Close(AccessMode::Write))
+        );
+        relevant && event.paths.contains(&self.path)
+    }
+}
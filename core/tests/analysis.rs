@@ -0,0 +1,33 @@
+#![cfg(feature = "std")]
+
+use can_crc_core::analysis::{analyze_bursts, analyze_double_bit, analyze_single_bit, minimum_hamming_distance};
+use can_crc_core::CrcConfig;
+
+#[test]
+fn can_crc_keeps_distance_six_up_to_112_data_bits() {
+    let distance = minimum_hamming_distance(&CrcConfig::CAN, 112, 6, 1 << 20, 1);
+    assert_eq!(distance.distance, Some(6));
+    assert!(distance.exhaustive);
+
+    let distance = minimum_hamming_distance(&CrcConfig::CAN, 113, 6, 1 << 20, 1);
+    assert_eq!(distance.distance, Some(2));
+    assert!(distance.exhaustive);
+}
+
+#[test]
+fn can_crc_misses_one_double_error_past_its_period() {
+    let report = analyze_single_bit(&CrcConfig::CAN, 113);
+    assert_eq!((report.tested, report.undetected), (128, 0));
+
+    let report = analyze_double_bit(&CrcConfig::CAN, 112);
+    assert_eq!((report.tested, report.undetected), (127 * 126 / 2, 0));
+
+    let report = analyze_double_bit(&CrcConfig::CAN, 113);
+    assert_eq!((report.tested, report.undetected), (128 * 127 / 2, 1));
+}
+
+#[test]
+fn bursts_up_to_the_crc_width_are_always_detected() {
+    let report = analyze_bursts(&CrcConfig::CAN, 64, 15);
+    assert_eq!((report.tested, report.undetected), (15 * 65 + 14 * 15 / 2, 0));
+}
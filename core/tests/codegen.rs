@@ -0,0 +1,61 @@
+#![cfg(feature = "std")]
+
+use can_crc_core::codegen::{format_table, generate_hdl, parallel_crc_equations, HdlLanguage, TableLanguage};
+use can_crc_core::{CrcCatalog, CrcConfig};
+
+fn table_values(source: &str) -> Vec<u64> {
+    source
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter_map(|token| token.strip_prefix("0x"))
+        .map(|digits| u64::from_str_radix(digits, 16).unwrap())
+        .collect()
+}
+
+fn evaluate(terms: &[String], state: u64, data: u64) -> u64 {
+    terms.iter().fold(0, |acc, term| {
+        let (name, index) = term.trim_end_matches(']').split_once('[').unwrap();
+        let value = if name == "c" { state } else { data };
+        acc ^ ((value >> index.parse::<u32>().unwrap()) & 1)
+    })
+}
+
+#[test]
+fn generated_tables_match_the_engine_table() {
+    for algorithm in CrcCatalog::all() {
+        let config = algorithm.config;
+        for language in [TableLanguage::C, TableLanguage::Rust, TableLanguage::Python] {
+            let source = format_table(&config, language, "crc_table").unwrap();
+            let body = &source[source.find(['{', '[']).unwrap()..];
+            assert_eq!(table_values(body), config.table().to_vec(), "{} {:?}", algorithm.name, language);
+        }
+    }
+}
+
+#[test]
+fn crc8_equations_for_one_bit_per_clock() {
+    let equations = parallel_crc_equations(&CrcConfig::new(8, 0x07), 1).unwrap();
+    assert_eq!(equations[0], ["c[7]", "d[0]"]);
+    assert_eq!(equations[1], ["c[0]", "c[7]", "d[0]"]);
+    assert_eq!(equations[2], ["c[1]", "c[7]", "d[0]"]);
+    assert_eq!(equations[3], ["c[2]"]);
+
+    let verilog = generate_hdl(&CrcConfig::new(8, 0x07), 1, HdlLanguage::Verilog, "crc8").unwrap();
+    assert!(verilog.contains("assign crc_next[1] = c[0] ^ c[7] ^ d[0];"));
+    let vhdl = generate_hdl(&CrcConfig::new(8, 0x07), 1, HdlLanguage::Vhdl, "crc8").unwrap();
+    assert!(vhdl.contains("crc_next(1) <= c(0) xor c(7) xor d(0);"));
+}
+
+#[test]
+fn byte_wide_equations_compute_the_check_value() {
+    for algorithm in CrcCatalog::all().iter().filter(|a| !a.config.refin && !a.config.refout) {
+        let config = algorithm.config;
+        let equations = parallel_crc_equations(&config, 8).unwrap();
+        let state = b"123456789".iter().fold(config.init, |state, &byte| {
+            equations
+                .iter()
+                .enumerate()
+                .fold(0, |next, (i, terms)| next | (evaluate(terms, state, byte as u64) << i))
+        });
+        assert_eq!(state ^ config.xorout, algorithm.check, "{}", algorithm.name);
+    }
+}
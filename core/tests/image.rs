@@ -0,0 +1,63 @@
+#![cfg(feature = "std")]
+
+use can_crc_core::image::{parse_intel_hex, MemoryImage, DEFAULT_FILL};
+use can_crc_core::{checksum_reader, CrcConfig};
+use proptest::prelude::*;
+use std::io::{self, Read};
+
+#[test]
+fn sparse_images_stream_their_gaps() {
+    let hex = [
+        ":020000040800F2",
+        ":0400000001020304F2",
+        ":020000041FFFDC",
+        ":04780000AABBCCDD76",
+        ":00000001FF",
+    ];
+    let image = parse_intel_hex(&hex.join("\n")).unwrap();
+    let (start, end) = (image.start().unwrap(), image.end().unwrap());
+    assert_eq!((start, end), (0x0800_0000, 0x1FFF_7804));
+
+    let mut head = [0u8; 6];
+    image.reader(start, end, DEFAULT_FILL).read_exact(&mut head).unwrap();
+    assert_eq!(head, [0x01, 0x02, 0x03, 0x04, 0xFF, 0xFF]);
+    let mut tail = Vec::new();
+    image.reader(end - 6, end, DEFAULT_FILL).read_to_end(&mut tail).unwrap();
+    assert_eq!(tail, [0xFF, 0xFF, 0xAA, 0xBB, 0xCC, 0xDD]);
+    assert_eq!(io::copy(&mut image.reader(start, end, DEFAULT_FILL), &mut io::sink()).unwrap(), end - start);
+
+    let mut top = MemoryImage::new();
+    top.write(0, &[0x00]).unwrap();
+    top.write(0xFFFF_0000, &[0x01]).unwrap();
+    let mut reader = top.reader(0, top.end().unwrap(), 0x00);
+    assert_eq!(reader.remaining(), 0xFFFF_0001);
+    let mut chunk = vec![0xEEu8; 1 << 16];
+    assert_eq!(reader.read(&mut chunk).unwrap(), 1);
+    assert_eq!(reader.read(&mut chunk).unwrap(), 1 << 16);
+    assert!(chunk.iter().all(|&byte| byte == 0x00));
+}
+
+proptest! {
+    #[test]
+    fn image_reader_matches_read(
+        blocks in prop::collection::vec((0u64..1 << 20, prop::collection::vec(any::<u8>(), 1..64)), 1..8),
+        fill in any::<u8>(),
+        margin in 0u64..256,
+    ) {
+        let mut image = MemoryImage::new();
+        for (address, data) in &blocks {
+            let _ = image.write(*address, data);
+        }
+        let start = image.start().unwrap().saturating_sub(margin);
+        let end = image.end().unwrap() + margin;
+        let expected = image.read(start, end, fill);
+
+        let mut streamed = Vec::new();
+        image.reader(start, end, fill).read_to_end(&mut streamed).unwrap();
+        prop_assert_eq!(&streamed, &expected);
+        let config = CrcConfig::CRC32;
+        let (crc, total) = checksum_reader(&config, &mut image.reader(start, end, fill)).unwrap();
+        prop_assert_eq!(crc, config.checksum(&expected));
+        prop_assert_eq!(total, end - start);
+    }
+}
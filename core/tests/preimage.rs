@@ -0,0 +1,44 @@
+#![cfg(feature = "std")]
+
+use can_crc_core::preimage::{find_collisions, find_preimages};
+use can_crc_core::{bits_to_bytes, bytes_to_bits, CrcCatalog};
+use proptest::prelude::*;
+
+#[test]
+fn reflected_preimages_match_calc() {
+    for (name, target, bits) in [("modbus", 0x1234, 32), ("crc-32", 0xDEAD_BEEF, 64)] {
+        let config = CrcCatalog::find(name).unwrap().config;
+        let found = find_preimages(&config, &[], bits, target, 4, 1);
+        assert!(!found.is_empty(), "{}", name);
+        for message in found {
+            assert_eq!(config.checksum(&bits_to_bytes(&message)), target, "{}", name);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn preimages_hit_the_target_for_every_algorithm(
+        index in 0..CrcCatalog::all().len(),
+        prefix in prop::collection::vec(any::<u8>(), 0..=2),
+        bytes in 10usize..=16,
+        target in any::<u64>(),
+        seed in any::<u64>(),
+    ) {
+        let config = CrcCatalog::all()[index].config;
+        let target = target & config.mask();
+        let prefix = bytes_to_bits(&prefix);
+        let found = find_preimages(&config, &prefix, bytes * 8, target, 2, seed);
+        prop_assert!(!found.is_empty());
+        for message in &found {
+            prop_assert_eq!(&message[..prefix.len()], &prefix[..]);
+            prop_assert_eq!(config.checksum(&bits_to_bytes(message)), target);
+        }
+
+        let data = found[0].clone();
+        for collision in find_collisions(&config, &data, prefix.len(), 2, seed ^ 1) {
+            prop_assert_ne!(&collision, &data);
+            prop_assert_eq!(config.checksum(&bits_to_bytes(&collision)), target);
+        }
+    }
+}
@@ -0,0 +1,1325 @@
+#![cfg(feature = "std")]
+
+use can_crc_core::{
+    bits_to_bytes, bits_to_bytes_with, bytes_to_bits, format_base64, format_binary_grouped, format_escaped, format_hex_grouped, parse_escaped_bytes, BitPadding, parse_base64_bytes, parse_base64_input_with, parse_binary_input_with, parse_hex_bytes, calculate_can_crc, calculate_can_crc_bytes, calculate_can_crc_bytes_with_order,
+    calculate_can_crc_bytes_with_params, calculate_can_crc_optimized, calculate_can_crc_optimized_with_params,
+    calculate_can_crc_with_params, format_binary, format_hex, parse_binary_input, parse_binary_input_with_order,
+    error_snippet, hex_error_spans, parse_hex_input, parse_hex_input_with, parse_hex_input_with_order, BitOrder, CanCrcParams, ParseError, CanFrame, CrcCatalog, CrcConfig, CrcHasher, crc_from_reader, CrcResult, Endianness, Implementation, CUSTOM_ALGORITHM,
+    calculate_can_crc_bytes_iter, calculate_can_crc_iter, calculate_can_crc_trace, crc15_bytes, crc15_of_u64, calculate_can_crc_packed, compute_batch_checksums_with_progress, BitString, PackedBits, ParseOptions, MAX_INPUT_BITS, PARALLEL_THRESHOLD, SNIPPET_WIDTH, PROGRESS_INTERVAL,
+};
+#[cfg(feature = "parallel")]
+use can_crc_core::{compute_batch_checksums, compute_batch_checksums_in, thread_pool};
+#[cfg(feature = "gpu")]
+use can_crc_core::gpu::{compare, random_batch, GpuCrc, MAX_GPU_WIDTH};
+#[cfg(feature = "gpu")]
+use can_crc_core::GpuError;
+#[cfg(feature = "async")]
+use can_crc_core::async_io::{crc_from_async_reader, FrameSource, FrameVerifier};
+use can_crc_core::auto::{AutoCrc, Calibration, Strategy as CrcStrategy};
+use can_crc_core::canopen::{block_crc, check_block_transfer, end_block, segments, MAX_BLOCK_SIZE, SEGMENT_DATA_LEN};
+use can_crc_core::checksum::Checksum;
+use can_crc_core::crosscheck::cross_check;
+use can_crc_core::distribution::crc_distribution;
+use can_crc_core::dnp3::{add_block_crcs, check_link_frame, link_frame, BLOCK_LEN, MAX_USER_DATA_LEN};
+use can_crc_core::e2e::{DataIdMode, E2eProfile};
+use can_crc_core::fixed::{FixedBits, FixedCanFrame};
+use can_crc_core::FixedError;
+use can_crc_core::flexray::{frame_crc, header_crc, header_crc_bits, Channel, HEADER_CRC_BITS, MAX_FRAME_ID, MAX_PAYLOAD_WORDS};
+use can_crc_core::frame::{check_crc_field, destuff_bits, emit_frame, fd_dlc, stuff_bits, stuff_count_bits, CanFdFrame, EOF_BITS, FD_DATA_LENGTHS, FD_FIXED_STUFF_INTERVAL, MAX_EXTENDED_ID, MAX_FD_DATA_LEN};
+use can_crc_core::inject::{check_flips, inject_errors, verify_double_flips, verify_single_flips, ErrorPattern};
+use can_crc_core::modbus::{check_rtu, decode_adu, rtu_frame, Pdu, TransactionTracker, DEFAULT_RESPONSE_TIMEOUT, MAX_READ_REGISTERS};
+use can_crc_core::j1939::{J1939Id, PDU2_THRESHOLD};
+use can_crc_core::manifest::Manifest;
+use can_crc_core::protocol::Protocol;
+use can_crc_core::rawframe::{check_raw_frame, read_frame, write_frame, LengthPrefix, PrefixSize};
+#[cfg(feature = "serde")]
+use can_crc_core::rawframe::frame_json;
+use can_crc_core::master::{PollOutcome, Poller};
+use can_crc_core::slave::{RegisterMap, SlaveSimulator, Table, ILLEGAL_DATA_ADDRESS};
+use can_crc_core::timing::{measure, BenchPlan, TimingStats};
+use can_crc_core::sweep::{crossover, sweep, sweep_lengths, SweepPoint, SWEEP_MAX_BITS, SWEEP_SHORT_BITS};
+use can_crc_core::replay::{CsvLayout, ReplayStats, TraceRecord};
+use can_crc_core::generator::{DataMode, FrameGenerator, IdMode, LengthMode, PayloadGenerator};
+use can_crc_core::image::{parse_intel_hex, parse_srecord, DEFAULT_FILL};
+#[cfg(feature = "parallel")]
+use can_crc_core::scaling::{best_point, measure_scaling, thread_counts};
+use proptest::prelude::*;
+use std::hash::Hasher;
+use std::sync::Mutex;
+
+fn bit_vec(max_len: usize) -> impl Strategy<Value = Vec<bool>> {
+    prop::collection::vec(any::<bool>(), 0..=max_len)
+}
+
+fn equal_length_pair(max_len: usize) -> impl Strategy<Value = (Vec<bool>, Vec<bool>)> {
+    (0..=max_len).prop_flat_map(|len| {
+        (prop::collection::vec(any::<bool>(), len), prop::collection::vec(any::<bool>(), len))
+    })
+}
+
+proptest! {
+    #[test]
+    fn optimized_matches_reference(bits in bit_vec(1024)) {
+        prop_assert_eq!(calculate_can_crc(&bits), calculate_can_crc_optimized(&bits));
+    }
+
+    #[test]
+    fn byte_path_matches_reference(bytes in prop::collection::vec(any::<u8>(), 0..128)) {
+        let bits = bytes_to_bits(&bytes);
+        prop_assert_eq!(calculate_can_crc_bytes(&bytes), calculate_can_crc(&bits));
+        prop_assert_eq!(CrcConfig::CAN.checksum(&bytes), calculate_can_crc(&bits) as u64);
+    }
+
+    #[test]
+    fn crc_is_linear((a, b) in equal_length_pair(512)) {
+        let xored: Vec<bool> = a.iter().zip(&b).map(|(x, y)| x ^ y).collect();
+        let zeros = vec![false; a.len()];
+        prop_assert_eq!(
+            calculate_can_crc(&xored) ^ calculate_can_crc(&zeros),
+            calculate_can_crc(&a) ^ calculate_can_crc(&b)
+        );
+    }
+
+    #[test]
+    fn binary_round_trip(bits in prop::collection::vec(any::<bool>(), 1..=MAX_INPUT_BITS)) {
+        prop_assert_eq!(parse_binary_input(&format_binary(&bits)).unwrap(), bits);
+    }
+
+    #[test]
+    fn hex_round_trip(bytes in prop::collection::vec(any::<u8>(), 1..=MAX_INPUT_BITS / 8)) {
+        let bits = bytes_to_bits(&bytes);
+        prop_assert_eq!(parse_hex_input(&format_hex(&bytes)).unwrap(), bits.clone());
+        prop_assert_eq!(bits_to_bytes(&bits), bytes);
+    }
+
+    #[test]
+    fn bits_to_bytes_pads_as_requested(bits in prop::collection::vec(any::<bool>(), 0..200), group in 0usize..16) {
+        let missing = (8 - bits.len() % 8) % 8;
+        let trailing = bits_to_bytes_with(&bits, BitPadding::Trailing).unwrap();
+        prop_assert_eq!(&bytes_to_bits(&trailing)[..bits.len()], &bits[..]);
+        let leading = bits_to_bytes_with(&bits, BitPadding::Leading).unwrap();
+        prop_assert_eq!(&bytes_to_bits(&leading)[missing..], &bits[..]);
+        prop_assert_eq!(bits_to_bytes_with(&bits, BitPadding::Reject).is_ok(), missing == 0);
+
+        let binary = format_binary_grouped(&bits, group);
+        prop_assert_eq!(parse_binary_input_with(&binary, &ParseOptions { max_bits: None, ..ParseOptions::default() }).ok(), (!bits.is_empty()).then(|| bits.clone()));
+        prop_assert_eq!(format_binary_grouped(&bits, group.max(1)), BitString::from_bools(&bits).grouped(group.max(1)).to_string());
+        let hex = format_hex_grouped(&trailing, group);
+        prop_assert_eq!(hex.replace(' ', ""), format_hex(&trailing).replace(' ', ""));
+        prop_assert_eq!(format_hex_grouped(&trailing, 1), format_hex(&trailing));
+    }
+
+    #[test]
+    fn hasher_streams_like_checksum(bytes in prop::collection::vec(any::<u8>(), 0..256), split in 0usize..256) {
+        let split = split.min(bytes.len());
+        let mut hasher = CrcHasher::new(CrcConfig::MODBUS);
+        hasher.write(&bytes[..split]);
+        hasher.write(&bytes[split..]);
+        prop_assert_eq!(hasher.finish(), CrcConfig::MODBUS.checksum(&bytes));
+    }
+
+    #[test]
+    fn reader_crc_matches_checksum(bytes in prop::collection::vec(any::<u8>(), 0..4096), chunk in 1usize..100, index in any::<prop::sample::Index>()) {
+        let config = CrcCatalog::all()[index.index(CrcCatalog::all().len())].config;
+        let trickle = Trickle { data: &bytes, chunk };
+        prop_assert_eq!(crc_from_reader(trickle, &config).unwrap(), config.checksum(&bytes));
+    }
+
+    #[test]
+    fn lsb_first_paths_agree(bytes in prop::collection::vec(any::<u8>(), 1..=MAX_INPUT_BITS / 8)) {
+        let from_hex = parse_hex_input_with_order(&format_hex(&bytes), BitOrder::LsbFirst).unwrap();
+        let from_binary = parse_binary_input_with_order(&format_binary(&bytes_to_bits(&bytes)), BitOrder::LsbFirst).unwrap();
+        prop_assert_eq!(&from_hex, &from_binary);
+        prop_assert_eq!(calculate_can_crc_bytes_with_order(&bytes, BitOrder::LsbFirst), calculate_can_crc(&from_hex));
+    }
+
+    #[test]
+    fn init_and_xorout_agree_across_paths(bytes in prop::collection::vec(any::<u8>(), 0..64), init in 0u16..0x8000, xorout in 0u16..0x8000) {
+        let params = CanCrcParams { init, xorout };
+        let bits = bytes_to_bits(&bytes);
+        let reference = calculate_can_crc_with_params(&bits, params);
+        prop_assert_eq!(calculate_can_crc_optimized_with_params(&bits, params), reference);
+        prop_assert_eq!(calculate_can_crc_bytes_with_params(&bytes, params), reference);
+        prop_assert_eq!(params.config().checksum_bits(&bits), reference as u64);
+    }
+}
+
+#[test]
+fn catalog_check_values() {
+    for algorithm in CrcCatalog::all() {
+        assert_eq!(algorithm.config.checksum(b"123456789"), algorithm.check, "{}", algorithm.name);
+        assert_eq!(algorithm.config.checksum_bitwise(b"123456789"), algorithm.check, "{}", algorithm.name);
+        assert_eq!(CrcCatalog::find(algorithm.name).map(|found| found.name), Some(algorithm.name));
+    }
+}
+
+proptest! {
+    #[test]
+    fn e2e_protect_then_check(mut data in prop::collection::vec(any::<u8>(), 2..16), data_id in any::<u16>(), counter in 0u8..15, list in any::<[u8; 16]>()) {
+        for profile in [
+            E2eProfile::Profile1 { data_id, mode: DataIdMode::Both },
+            E2eProfile::Profile1 { data_id, mode: DataIdMode::Alt },
+            E2eProfile::Profile2 { data_id_list: list },
+        ] {
+            profile.protect(&mut data, counter);
+            let check = profile.check(&data).unwrap();
+            prop_assert!(check.valid());
+            prop_assert_eq!(check.counter, counter);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_limit_follows_algorithm(bytes in prop::collection::vec(any::<u8>(), 1..300)) {
+        let hex = format_hex(&bytes);
+        for algorithm in CrcCatalog::all() {
+            let options = ParseOptions::for_config(&algorithm.config);
+            let fits = algorithm.max_input_bits.is_none_or(|max_bits| bytes.len() * 8 <= max_bits);
+            prop_assert_eq!(parse_hex_input_with(&hex, &options).is_ok(), fits, "{}", algorithm.name);
+        }
+    }
+}
+
+struct Trickle<'a> {
+    data: &'a [u8],
+    chunk: usize,
+}
+
+impl std::io::Read for Trickle<'_> {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.chunk.min(buffer.len()).min(self.data.len());
+        buffer[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Ok(n)
+    }
+}
+
+fn intel_hex_record(address: u16, kind: u8, data: &[u8]) -> String {
+    let mut record = vec![data.len() as u8, (address >> 8) as u8, address as u8, kind];
+    record.extend_from_slice(data);
+    let checksum = record.iter().fold(0u8, |sum, &byte| sum.wrapping_add(byte)).wrapping_neg();
+    record.push(checksum);
+    format!(":{}", record.iter().map(|byte| format!("{:02X}", byte)).collect::<String>())
+}
+
+proptest! {
+    #[test]
+    fn intel_hex_rebuilds_image(data in prop::collection::vec(any::<u8>(), 2..200), base in any::<u16>(), gap in 0u16..64) {
+        let mut lines = vec![intel_hex_record(0, 0x04, &base.to_be_bytes())];
+        let (head, tail) = data.split_at(data.len() / 2);
+        for (offset, chunk) in head.chunks(16).enumerate() {
+            lines.push(intel_hex_record(offset as u16 * 16, 0x00, chunk));
+        }
+        let tail_start = head.len() as u16 + gap;
+        for (offset, chunk) in tail.chunks(16).enumerate() {
+            lines.push(intel_hex_record(tail_start + offset as u16 * 16, 0x00, chunk));
+        }
+        lines.push(intel_hex_record(0, 0x01, &[]));
+
+        let image = parse_intel_hex(&lines.join("\n")).unwrap();
+        let mut expected = head.to_vec();
+        expected.extend(std::iter::repeat_n(DEFAULT_FILL, gap as usize));
+        expected.extend_from_slice(tail);
+        prop_assert_eq!(image.start(), Some((base as u64) << 16));
+        prop_assert_eq!(image.len(), data.len());
+        prop_assert_eq!(image.to_bytes(DEFAULT_FILL), expected);
+    }
+}
+
+fn srecord(kind: u8, address: u32, data: &[u8]) -> String {
+    let mut record = vec![data.len() as u8 + 5];
+    record.extend_from_slice(&address.to_be_bytes());
+    record.extend_from_slice(data);
+    record.push(!record.iter().fold(0u8, |sum, &byte| sum.wrapping_add(byte)));
+    format!("S{}{}", kind, record.iter().map(|byte| format!("{:02X}", byte)).collect::<String>())
+}
+
+proptest! {
+    #[test]
+    fn srecord_matches_intel_hex(data in prop::collection::vec(any::<u8>(), 1..200), base in any::<u16>()) {
+        let address = (base as u32) << 16;
+        let mut srec = vec!["S0030000FC".to_string()];
+        let mut ihex = vec![intel_hex_record(0, 0x04, &base.to_be_bytes())];
+        for (offset, chunk) in data.chunks(32).enumerate() {
+            srec.push(srecord(3, address + offset as u32 * 32, chunk));
+            ihex.push(intel_hex_record(offset as u16 * 32, 0x00, chunk));
+        }
+        srec.push(srecord(7, address, &[]));
+        ihex.push(intel_hex_record(0, 0x01, &[]));
+
+        let image = parse_srecord(&srec.join("\n")).unwrap();
+        prop_assert_eq!(image.to_bytes(DEFAULT_FILL), data);
+        prop_assert_eq!(Some(image), parse_intel_hex(&ihex.join("\n")).ok());
+    }
+}
+
+proptest! {
+    #[test]
+    fn base64_round_trip(bytes in prop::collection::vec(any::<u8>(), 1..64)) {
+        let encoded = format_base64(&bytes);
+        prop_assert_eq!(parse_base64_bytes(&encoded).unwrap(), bytes.clone());
+        prop_assert_eq!(parse_base64_bytes(encoded.trim_end_matches('=')).unwrap(), bytes.clone());
+        let url_safe = encoded.replace('+', "-").replace('/', "_");
+        prop_assert_eq!(parse_base64_bytes(&url_safe).unwrap(), bytes.clone());
+        prop_assert_eq!(
+            parse_base64_input_with(&encoded, &ParseOptions { max_bits: None, ..ParseOptions::default() }).unwrap(),
+            bytes_to_bits(&bytes)
+        );
+    }
+}
+
+fn xxd_dump(bytes: &[u8]) -> String {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(line, chunk)| {
+            let hex: Vec<String> = chunk.chunks(2).map(|pair| pair.iter().map(|b| format!("{:02x}", b)).collect()).collect();
+            let ascii: String = chunk.iter().map(|&b| if b.is_ascii_graphic() { b as char } else { '.' }).collect();
+            format!("{:08x}: {:<40} {}", line * 16, hex.join(" "), ascii)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn hexdump_canonical(bytes: &[u8]) -> String {
+    let mut lines: Vec<String> = bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(line, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+            let hex = if hex.len() > 8 { format!("{}  {}", hex[..8].join(" "), hex[8..].join(" ")) } else { hex.join(" ") };
+            let ascii: String = chunk.iter().map(|&b| if b.is_ascii_graphic() { b as char } else { '.' }).collect();
+            format!("{:08x}  {:<49} |{}|", line * 16, hex, ascii)
+        })
+        .collect();
+    lines.push(format!("{:08x}", bytes.len()));
+    lines.join("\n")
+}
+
+proptest! {
+    #[test]
+    fn hex_dumps_parse_back(bytes in prop::collection::vec(any::<u8>(), 1..100)) {
+        prop_assert_eq!(parse_hex_bytes(&xxd_dump(&bytes)).unwrap(), bytes.clone());
+        prop_assert_eq!(parse_hex_bytes(&hexdump_canonical(&bytes)).unwrap(), bytes.clone());
+    }
+}
+
+proptest! {
+    #[test]
+    fn hex_accepts_common_separators(bytes in prop::collection::vec(any::<u8>(), 1..64)) {
+        let pairs: Vec<String> = bytes.iter().map(|b| format!("{:02X}", b)).collect();
+        let c_style = pairs.iter().map(|p| format!("0x{}", p)).collect::<Vec<_>>().join(", ");
+        prop_assert_eq!(parse_hex_bytes(&c_style).unwrap(), bytes.clone());
+        prop_assert_eq!(parse_hex_bytes(&pairs.join(":")).unwrap(), bytes.clone());
+        prop_assert_eq!(parse_hex_bytes(&pairs.join("-").to_lowercase()).unwrap(), bytes.clone());
+        prop_assert_eq!(parse_hex_bytes(&format!("0x{}", pairs.concat())).unwrap(), bytes);
+    }
+}
+
+proptest! {
+    #[test]
+    fn c_array_initializers_parse_as_bytes(bytes in prop::collection::vec(any::<u8>(), 1..64), bad in 256u32..0x10000) {
+        let elements: Vec<String> = bytes.iter().enumerate()
+            .map(|(index, byte)| match index % 3 {
+                0 => format!("0x{:02X}", byte),
+                1 => format!("{}u", byte),
+                _ => format!("(uint8_t)0x{:x}", byte),
+            })
+            .collect();
+        let header = format!("static const uint8_t frame[{}] = {{ /* payload */\n    {}, // end\n}};", bytes.len(), elements.join(", "));
+        prop_assert_eq!(parse_hex_bytes(&header).unwrap(), bytes.clone());
+        prop_assert_eq!(
+            parse_hex_bytes(&format!("{{{}, 0x{:X}}}", elements.join(", "), bad)),
+            Err(ParseError::InvalidArrayElement { element: format!("0x{:X}", bad) })
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn escaped_strings_parse_back(bytes in prop::collection::vec(any::<u8>(), 1..64), split in any::<prop::sample::Index>()) {
+        let escaped = format_escaped(&bytes);
+        prop_assert_eq!(parse_escaped_bytes(&escaped).unwrap(), bytes.clone());
+        let (head, tail) = bytes.split_at(split.index(bytes.len()));
+        let literals = format!("b\"{}\" \"{}\";", format_escaped(head), format_escaped(tail));
+        prop_assert_eq!(parse_escaped_bytes(&literals).unwrap(), bytes.clone());
+        let octal: String = bytes.iter().map(|b| format!("\\{:03o}", b)).collect();
+        prop_assert_eq!(parse_escaped_bytes(&format!("'{}'", octal)).unwrap(), bytes.clone());
+        prop_assert_eq!(
+            parse_escaped_bytes(&format!("\"{}\\q\"", escaped)),
+            Err(ParseError::InvalidEscape { sequence: "\\q".to_string(), index: escaped.len() + 1 })
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn binary_accepts_literal_grouping(bits in prop::collection::vec(any::<bool>(), 1..=MAX_INPUT_BITS), at in any::<prop::sample::Index>()) {
+        let digits: String = bits.iter().map(|&bit| if bit { '1' } else { '0' }).collect();
+        let nibbles: Vec<&str> = digits.as_bytes().chunks(4).map(|chunk| std::str::from_utf8(chunk).unwrap()).collect();
+        prop_assert_eq!(parse_binary_input(&format!("0b{}", nibbles.join("_"))).unwrap(), bits.clone());
+        prop_assert_eq!(parse_binary_input(&nibbles.join(".")).unwrap(), bits.clone());
+
+        let mut broken = format!("0b{}", digits);
+        let index = 2 + at.index(digits.len());
+        broken.insert(index, '2');
+        match parse_binary_input(&broken) {
+            Err(ParseError::InvalidBinaryChars { chars, index: reported }) => {
+                prop_assert_eq!(chars, "2");
+                prop_assert_eq!(reported, index);
+            }
+            other => prop_assert!(false, "unexpected result: {:?}", other),
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn candump_lines_parse_back(id in 0u32..=0x1FFF_FFFF, extended in any::<bool>(), data in prop::collection::vec(any::<u8>(), 0..=8)) {
+        let frame = if extended { CanFrame::new_extended(id, &data) } else { CanFrame::new(id & 0x7FF, &data) }.unwrap();
+        let id_text = if extended { format!("{:08X}", frame.id) } else { format!("{:03X}", frame.id) };
+        let payload: String = data.iter().map(|b| format!("{:02X}", b)).collect();
+        let compact = format!("(1700000000.000000) can0 {}#{}", id_text, payload);
+        let columns = format!("  can0  {}   [{}]  {}", id_text, data.len(), format_hex(&data));
+        prop_assert_eq!(CanFrame::parse_candump(&compact).unwrap(), frame.clone());
+        prop_assert_eq!(CanFrame::parse_candump(&columns).unwrap(), frame);
+    }
+}
+
+proptest! {
+    #[test]
+    fn implementations_cross_check(bits in bit_vec(256), index in 0..CrcCatalog::all().len()) {
+        let config = CrcCatalog::all()[index].config;
+        let check = cross_check(&config, &bits);
+        prop_assert!(check.agrees(), "{:?}", check.results);
+        prop_assert_eq!(cross_check(&CrcConfig::CAN, &bits).value(), Some(calculate_can_crc(&bits) as u64));
+    }
+}
+
+proptest! {
+    #[test]
+    fn appended_crc_leaves_residue(bytes in prop::collection::vec(any::<u8>(), 0..64), index in 0..CrcCatalog::all().len(), flip in any::<prop::sample::Index>()) {
+        let algorithm = &CrcCatalog::all()[index];
+        let config = algorithm.config;
+        prop_assert_eq!(config.residue(), algorithm.residue);
+
+        let mut codeword = config.append_crc(&bytes_to_bits(&bytes));
+        prop_assert!(config.check_codeword(&codeword));
+        let position = flip.index(codeword.len());
+        codeword[position] = !codeword[position];
+        prop_assert!(!config.check_codeword(&codeword));
+    }
+}
+
+proptest! {
+    #[test]
+    fn emitted_can_frame_ends_with_crc_and_delimiter(bits in bit_vec(MAX_INPUT_BITS)) {
+        let frame = emit_frame(&CrcConfig::CAN, &bits, Endianness::Big);
+        prop_assert_eq!(frame.len(), bits.len() + 16);
+        prop_assert_eq!(&frame[..bits.len()], &bits[..]);
+        prop_assert!(frame[frame.len() - 1]);
+        prop_assert_eq!(calculate_can_crc(&frame[..frame.len() - 1]), 0);
+    }
+}
+
+proptest! {
+    #[test]
+    fn appended_crc_follows_requested_endianness(bytes in prop::collection::vec(any::<u8>(), 1..32), index in 0..CrcCatalog::all().len()) {
+        let config = CrcCatalog::all()[index].config;
+        let bits = bytes_to_bits(&bytes);
+        let crc = config.checksum_message(&bits);
+        let len = (config.width as usize).div_ceil(8);
+        prop_assert_eq!(config.crc_bytes(crc, Endianness::Little), crc.to_le_bytes()[..len].to_vec());
+        prop_assert_eq!(config.crc_bytes(crc, Endianness::Big), crc.to_be_bytes()[8 - len..].to_vec());
+        for endianness in [Endianness::Big, Endianness::Little] {
+            let frame = config.append_crc_in(&bits, endianness);
+            prop_assert_eq!(&frame[..bits.len()], &bits[..]);
+            prop_assert_eq!(config.crc_from_bits_in(&frame[bits.len()..], endianness), crc);
+        }
+        prop_assert_eq!(config.append_crc(&bits), config.append_crc_in(&bits, Endianness::for_config(&config)));
+
+        let adu = rtu_frame(bytes[0], 0x03, &bytes[1..]);
+        let payload = bytes_to_bits(&adu[..adu.len() - 2]);
+        prop_assert_eq!(bits_to_bytes(&emit_frame(&CrcConfig::MODBUS, &payload, Endianness::Little)), adu);
+    }
+}
+
+proptest! {
+    #[test]
+    fn frame_check_reports_flipped_crc_bits(bits in bit_vec(MAX_INPUT_BITS), index in 0..CrcCatalog::all().len(), flips in prop::collection::btree_set(0u8..64, 0..4)) {
+        let config = CrcCatalog::all()[index].config;
+        let delimiter = config == CrcConfig::CAN;
+        let mut frame = emit_frame(&config, &bits, Endianness::Big);
+        let check = check_crc_field(&config, &frame, Endianness::Big, delimiter).unwrap();
+        prop_assert!(check.passed());
+        prop_assert_eq!(check.computed, config.checksum_message(&bits));
+
+        let flips: Vec<u8> = flips.into_iter().filter(|&bit| bit < config.width).rev().collect();
+        let field_end = bits.len() + config.width as usize;
+        for &bit in &flips {
+            frame[field_end - 1 - bit as usize] ^= true;
+        }
+        let check = check_crc_field(&config, &frame, Endianness::Big, delimiter).unwrap();
+        prop_assert_eq!(check.passed(), flips.is_empty());
+        prop_assert_eq!(check.differing_bits(), flips);
+        prop_assert!(check_crc_field(&config, &frame[..config.width as usize - 1], Endianness::Big, false).is_err());
+    }
+}
+
+proptest! {
+    #[test]
+    fn crc_distribution_counts_every_message(index in 0..CrcCatalog::all().len(), bits in 1usize..=10, bucket_bits in 1u8..=12, samples in 1u64..5_000, seed in any::<u64>()) {
+        let config = CrcCatalog::all()[index].config;
+        let exhaustive = crc_distribution(&config, bits, bucket_bits, 1 << bits, seed);
+        prop_assert!(exhaustive.exhaustive);
+        let shift = config.width - exhaustive.bucket_bits;
+        let mut expected = vec![0u64; exhaustive.buckets.len()];
+        for value in 0u64..1 << bits {
+            let message: Vec<bool> = (0..bits).rev().map(|bit| (value >> bit) & 1 == 1).collect();
+            expected[(config.checksum_bits(&message) >> shift) as usize] += 1;
+        }
+        prop_assert_eq!(&exhaustive.buckets, &expected);
+        prop_assert_eq!(exhaustive.collisions(), (1u64 << bits) - (expected.len() - exhaustive.empty_buckets()) as u64);
+
+        let sampled = crc_distribution(&config, bits + 64, bucket_bits, samples, seed);
+        prop_assert!(!sampled.exhaustive);
+        prop_assert_eq!(sampled.inputs, samples);
+        prop_assert!((0.0..=1.0).contains(&sampled.p_value()));
+    }
+
+    #[test]
+    fn payload_generator_is_reproducible(bits in 1usize..300, seed in any::<u64>(), other in any::<u64>()) {
+        let payloads: Vec<Vec<bool>> = PayloadGenerator::new(bits, seed).take(4).collect();
+        prop_assert!(payloads.iter().all(|payload| payload.len() == bits));
+        prop_assert_eq!(&PayloadGenerator::new(bits, seed).take(4).collect::<Vec<_>>(), &payloads);
+        prop_assume!(bits >= 32 && other != seed);
+        prop_assert_ne!(&PayloadGenerator::new(bits, other).next_payload(), &payloads[0]);
+    }
+
+    #[test]
+    fn generated_frames_round_trip_through_candump(seed in any::<u64>(), extended in any::<bool>(), len in 0usize..=8, fixed_id in 0u32..0x800) {
+        let random = FrameGenerator::new(IdMode::Random, DataMode::Random, LengthMode::Random, extended, seed).unwrap();
+        for frame in random.take(20) {
+            prop_assert_eq!(CanFrame::parse_candump(&frame.to_candump()), Ok(frame.clone()));
+            prop_assert!(frame.data.len() <= 8);
+        }
+
+        let counting = FrameGenerator::new(IdMode::Fixed(fixed_id), DataMode::Increment, LengthMode::Fixed(len), extended, seed).unwrap();
+        for (index, frame) in counting.take(20).enumerate() {
+            prop_assert_eq!(frame.id, fixed_id);
+            prop_assert_eq!(frame.data, (index as u64).to_le_bytes()[..len].to_vec());
+        }
+        prop_assert!(FrameGenerator::new(IdMode::Fixed(0x800), DataMode::Random, LengthMode::Random, false, seed).is_err());
+    }
+}
+
+proptest! {
+    #[test]
+    fn replay_stats_add_up_over_a_trace(seed in any::<u64>(), count in 1usize..100, corrupt in prop::collection::btree_set(0usize..100, 0..10)) {
+        let layout = CsvLayout::from_header("timestamp,id,data,crc").unwrap();
+        let generator = FrameGenerator::new(IdMode::Random, DataMode::Random, LengthMode::Random, false, seed).unwrap();
+        let mut stats = ReplayStats::new();
+        for (index, frame) in generator.take(count).enumerate() {
+            let crc = frame.crc() ^ u16::from(corrupt.contains(&index));
+            let data: Vec<String> = frame.data.iter().map(|byte| format!("{:02X}", byte)).collect();
+            let line = format!("{}.5,{:03X},{},{:04X}", index, frame.id, data.join(" "), crc);
+            let record = layout.parse(&line).unwrap();
+            prop_assert_eq!(&record.frame, &frame);
+            prop_assert_eq!(stats.record(&record), Some(!corrupt.contains(&index)));
+            prop_assert_eq!(TraceRecord::parse_candump(&frame.to_candump()).unwrap().frame, frame);
+        }
+
+        let expected_mismatches = corrupt.iter().filter(|&&index| index < count).count() as u64;
+        prop_assert_eq!(stats.frames, count as u64);
+        prop_assert_eq!(stats.mismatches, expected_mismatches);
+        prop_assert_eq!(stats.lengths.iter().sum::<u64>(), count as u64);
+        prop_assert_eq!(stats.per_id.values().map(|id| id.frames).sum::<u64>(), count as u64);
+        prop_assert_eq!(stats.per_id.values().map(|id| id.mismatches).sum::<u64>(), expected_mismatches);
+        prop_assert_eq!(stats.trace_duration(), Some(std::time::Duration::from_secs(count as u64 - 1)));
+    }
+}
+
+proptest! {
+    #[test]
+    fn stuffing_limits_runs_and_keeps_data(bits in bit_vec(200)) {
+        let wire = stuff_bits(&bits);
+        let data: Vec<bool> = wire.iter().filter(|bit| !bit.stuff).map(|bit| bit.value).collect();
+        prop_assert_eq!(data, bits);
+        let values: Vec<bool> = wire.iter().map(|bit| bit.value).collect();
+        prop_assert!(values.windows(6).all(|run| run.iter().any(|&bit| bit != run[0])));
+    }
+}
+
+proptest! {
+    #[test]
+    fn destuffing_inverts_stuffing_and_flags_sixth_bits(bits in bit_vec(200), at in any::<prop::sample::Index>()) {
+        let wire = stuff_bits(&bits);
+        let values: Vec<bool> = wire.iter().map(|bit| bit.value).collect();
+        let destuffed = destuff_bits(&values);
+        prop_assert!(destuffed.is_valid());
+        prop_assert_eq!(&destuffed.bits, &bits);
+        let stuffed: Vec<usize> = wire.iter().enumerate().filter(|(_, bit)| bit.stuff).map(|(position, _)| position).collect();
+        prop_assert_eq!(&destuffed.stuff_positions, &stuffed);
+
+        let position = at.index(values.len() + 1);
+        let mut broken = values[..position].to_vec();
+        let value = broken.last().copied().unwrap_or(false);
+        broken.extend(std::iter::repeat_n(value, 6));
+        let errors = destuff_bits(&broken).errors;
+        prop_assert!(!errors.is_empty());
+        prop_assert!(errors.iter().all(|error| error.value == value && error.position < broken.len()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn can_fd_payload_pads_to_dlc_length(data in prop::collection::vec(any::<u8>(), 0..=MAX_FD_DATA_LEN), id in 0u32..0x800) {
+        let frame = CanFdFrame::new(id, false, &data).unwrap();
+        prop_assert_eq!(&frame.data[..data.len()], &data[..]);
+        prop_assert_eq!(FD_DATA_LENGTHS[frame.dlc() as usize], frame.data.len());
+        prop_assert_eq!(fd_dlc(frame.data.len()), Some(frame.dlc()));
+        prop_assert!(FD_DATA_LENGTHS.iter().all(|&len| len < data.len() || len >= frame.data.len()));
+    }
+
+    #[test]
+    fn hex_error_spans_point_at_offending_digits(data in prop::collection::vec(any::<u8>(), 1..32), at in any::<prop::sample::Index>()) {
+        let hex = format_hex(&data);
+        let position = at.index(hex.len() + 1);
+        let stray = format!("{}G{}", &hex[..position], &hex[position..]);
+        let error = parse_hex_input_with(&stray, &ParseOptions::default()).unwrap_err();
+        let spans: Vec<_> = hex_error_spans(&stray, &error).into_iter().map(|span| (span.start, span.end)).collect();
+        prop_assert_eq!(spans, vec![(position, position + 1)]);
+
+        let odd = format!("{}A", hex);
+        let error = parse_hex_input_with(&odd, &ParseOptions::default()).unwrap_err();
+        let spans: Vec<_> = hex_error_spans(&odd, &error).into_iter().map(|span| (span.start, span.end)).collect();
+        prop_assert_eq!(spans, vec![(odd.len() - 1, odd.len())]);
+    }
+
+    #[test]
+    fn error_snippets_point_at_the_first_invalid_character(data in prop::collection::vec(any::<u8>(), 1..64), at in any::<prop::sample::Index>()) {
+        let hex = format_hex(&data);
+        let position = at.index(hex.len() + 1);
+        let stray = format!("{}G{}", &hex[..position], &hex[position..]);
+        let error = parse_hex_bytes(&stray).unwrap_err();
+        prop_assert_eq!(error.index(), Some(position));
+
+        let snippet = error_snippet(&stray, &hex_error_spans(&stray, &error)).unwrap();
+        let (text, carets) = snippet.split_once('\n').unwrap();
+        prop_assert!(text.chars().filter(|&c| c != '…').count() <= SNIPPET_WIDTH);
+        prop_assert_eq!(carets.trim_start(), "^");
+        prop_assert_eq!(text.chars().nth(carets.len() - 1), Some('G'));
+    }
+
+    #[test]
+    fn sweep_covers_short_lengths_and_finds_lasting_crossover(max_bits in prop::option::of(1usize..=SWEEP_MAX_BITS * 2), wins in prop::collection::vec(any::<bool>(), 1..40)) {
+        let lengths = sweep_lengths(max_bits);
+        let limit = max_bits.unwrap_or(SWEEP_MAX_BITS).min(SWEEP_MAX_BITS);
+        prop_assert!(lengths.windows(2).all(|pair| pair[0] < pair[1]));
+        prop_assert_eq!(lengths.last().copied(), Some(limit));
+        prop_assert_eq!(lengths.iter().take_while(|&&bits| bits <= SWEEP_SHORT_BITS).count(), limit.min(SWEEP_SHORT_BITS));
+
+        let points: Vec<SweepPoint> = wins.iter().enumerate()
+            .map(|(index, &win)| SweepPoint { bits: index + 1, bitwise: 2.0, table: if win { 3.0 } else { 1.0 } })
+            .collect();
+        match crossover(&points) {
+            Some(bits) => {
+                prop_assert!(points[bits - 1..].iter().all(SweepPoint::table_wins));
+                prop_assert!(bits == 1 || !points[bits - 2].table_wins());
+            }
+            None => prop_assert!(!points.last().unwrap().table_wins()),
+        }
+
+        let measured = sweep(&CrcConfig::CAN, &[1, 64], std::time::Duration::from_micros(200));
+        prop_assert!(measured.iter().all(|point| point.bitwise > 0.0 && point.table > 0.0));
+    }
+
+    #[test]
+    fn flip_verification_matches_brute_force(data in prop::collection::vec(any::<u8>(), 1..8), index in 0..CrcCatalog::all().len()) {
+        let config = CrcCatalog::all()[index].config;
+        let bits = bytes_to_bits(&data);
+        let crc = config.checksum_bits(&bits);
+        prop_assert!(verify_single_flips(&config, &bits).all_detected());
+
+        let mut expected = Vec::new();
+        for i in 0..bits.len() {
+            for j in i + 1..bits.len() {
+                let mut flipped = bits.clone();
+                flipped[i] = !flipped[i];
+                flipped[j] = !flipped[j];
+                if config.checksum_bits(&flipped) == crc {
+                    expected.push(vec![i, j]);
+                }
+            }
+        }
+        let report = verify_double_flips(&config, &bits);
+        prop_assert_eq!(report.tested as usize, bits.len() * (bits.len() - 1) / 2);
+        prop_assert_eq!(report.undetected, expected);
+    }
+
+    #[test]
+    fn flipped_codeword_check_matches_can_injection(data in prop::collection::vec(any::<u8>(), 1..12), positions in prop::collection::vec(0usize..200, 0..5), index in 0..CrcCatalog::all().len()) {
+        let bits = bytes_to_bits(&data);
+        let mut flipped = positions.clone();
+        flipped.sort_unstable();
+        flipped.dedup();
+        let check = check_flips(&CrcConfig::CAN, &bits, &flipped);
+        let injected = inject_errors(&bits, &ErrorPattern::Bits(positions));
+        prop_assert_eq!(check.original_crc, injected.original_crc as u64);
+        prop_assert_eq!(check.received_crc, injected.received_crc as u64);
+        prop_assert_eq!(check.recomputed_crc, injected.recomputed_crc as u64);
+        prop_assert_eq!(check.detected, injected.detected);
+
+        let config = CrcCatalog::all()[index].config;
+        let crc = config.checksum_message(&bits);
+        prop_assert_eq!(config.crc_from_bits(&config.crc_to_bits(crc)), crc);
+        prop_assert!(!check_flips(&config, &bits, &[]).detected);
+    }
+
+    #[test]
+    fn can_fd_iso_crc_field_has_fixed_stuff_bits(data in prop::collection::vec(any::<u8>(), 0..=MAX_FD_DATA_LEN), id in 0u32..0x800, extended in any::<bool>()) {
+        let frame = CanFdFrame::new(id, extended, &data).unwrap();
+        let width = frame.crc_algorithm().config.width as usize;
+        let stuffed = frame.stuffed_bits();
+        let dynamic: Vec<bool> = stuffed.iter().filter(|bit| !bit.stuff).map(|bit| bit.value).collect();
+        prop_assert_eq!(dynamic, frame.crc_bits());
+
+        let wire = frame.wire_bits();
+        let field_len = 4 + width;
+        let fixed = field_len.div_ceil(FD_FIXED_STUFF_INTERVAL);
+        let crc_field = &wire[stuffed.len()..stuffed.len() + field_len + fixed];
+        prop_assert_eq!(crc_field.iter().filter(|bit| bit.stuff).count(), fixed);
+        for (index, bit) in crc_field.iter().enumerate() {
+            prop_assert_eq!(bit.stuff, index % (FD_FIXED_STUFF_INTERVAL + 1) == 0);
+            if bit.stuff {
+                let previous = if index == 0 { stuffed.last().unwrap() } else { &crc_field[index - 1] };
+                prop_assert_ne!(bit.value, previous.value);
+            }
+        }
+
+        let field: Vec<bool> = crc_field.iter().filter(|bit| !bit.stuff).map(|bit| bit.value).collect();
+        prop_assert_eq!(&field[..4], &stuff_count_bits(frame.stuff_count())[..]);
+        prop_assert_eq!(field[3], field[0] ^ field[1] ^ field[2]);
+        let codeword = [frame.iso_crc_bits(), field[4..].to_vec()].concat();
+        prop_assert!(frame.iso_crc_config().check_codeword(&codeword));
+        prop_assert_eq!(wire.len(), stuffed.len() + field_len + fixed + 3 + EOF_BITS);
+    }
+}
+
+proptest! {
+    #[test]
+    fn modbus_rtu_frame_checks_out(address in 0u8..=247, function in any::<u8>(), data in prop::collection::vec(any::<u8>(), 0..32)) {
+        let frame = rtu_frame(address, function, &data);
+        prop_assert_eq!(&frame[..2], &[address, function][..]);
+        prop_assert!(CrcConfig::MODBUS.check_codeword(&bytes_to_bits(&frame)));
+    }
+
+    #[test]
+    fn modbus_check_flags_corrupted_frames(address in 0u8..=247, function in any::<u8>(), data in prop::collection::vec(any::<u8>(), 0..32), index in any::<prop::sample::Index>(), mask in 1u8..) {
+        let mut frame = rtu_frame(address, function, &data);
+        let check = check_rtu(&frame).unwrap();
+        prop_assert!(check.is_valid());
+        prop_assert_eq!((check.address, check.function), (address, function));
+
+        let index = index.index(frame.len());
+        frame[index] ^= mask;
+        prop_assert!(!check_rtu(&frame).unwrap().is_valid());
+    }
+
+    #[test]
+    fn modbus_pdu_decodes_register_reads(address in 1u8..=247, function in 3u8..=4, start in any::<u16>(), registers in prop::collection::vec(any::<u16>(), 1..=MAX_READ_REGISTERS as usize)) {
+        let count = registers.len() as u16;
+        let request = rtu_frame(address, function, &[start.to_be_bytes(), count.to_be_bytes()].concat());
+        prop_assert_eq!(decode_adu(&request).unwrap(), Pdu::ReadRequest { function, start, count });
+
+        let mut data = vec![registers.len() as u8 * 2];
+        data.extend(registers.iter().flat_map(|value| value.to_be_bytes()));
+        let response = rtu_frame(address, function, &data);
+        prop_assert_eq!(decode_adu(&response).unwrap(), Pdu::ReadRegistersResponse { function, registers });
+
+        let exception = rtu_frame(address, function | 0x80, &[0x02]);
+        prop_assert_eq!(decode_adu(&exception).unwrap(), Pdu::Exception { function, code: 0x02 });
+    }
+
+    #[test]
+    fn modbus_transactions_pair_requests_with_responses(polls in prop::collection::vec((1u8..=247, 1u16..=MAX_READ_REGISTERS, 0u64..1000, any::<bool>()), 1..20)) {
+        let mut tracker = TransactionTracker::new(DEFAULT_RESPONSE_TIMEOUT);
+        let mut time = std::time::Duration::ZERO;
+        for &(address, count, delay_ms, answered) in &polls {
+            let request = rtu_frame(address, 3, &[0, 0, (count >> 8) as u8, count as u8]);
+            let mut data = vec![count as u8 * 2];
+            data.resize(1 + count as usize * 2, 0xA5);
+            let response = rtu_frame(address, 3, &data);
+
+            time += DEFAULT_RESPONSE_TIMEOUT * 2;
+            let done = tracker.push(&request, time).unwrap();
+            prop_assert!(done.iter().all(|transaction| transaction.response.is_none()));
+            if answered {
+                let delay = std::time::Duration::from_millis(delay_ms);
+                let done = tracker.push(&response, time + delay).unwrap();
+                prop_assert_eq!(done.len(), 1);
+                prop_assert!(done[0].is_ok());
+                prop_assert_eq!(done[0].address(), address);
+                prop_assert_eq!(done[0].response_time(), Some(delay));
+            }
+        }
+        let unanswered = tracker.finish().into_iter().count();
+        prop_assert_eq!(unanswered, usize::from(!polls.last().unwrap().3));
+    }
+}
+
+#[cfg(feature = "gpu")]
+static GPU: std::sync::OnceLock<Option<GpuCrc>> = std::sync::OnceLock::new();
+
+#[cfg(feature = "gpu")]
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+    #[test]
+    fn gpu_checksums_match_cpu(count in 0usize..200, message_len in 1usize..40, seed in any::<u64>()) {
+        let Some(gpu) = GPU.get_or_init(|| GpuCrc::new().ok()) else {
+            return Ok(());
+        };
+        let batch = random_batch(count, message_len, seed);
+        for algorithm in CrcCatalog::all() {
+            let config = algorithm.config;
+            match gpu.checksums(&config, &batch, message_len) {
+                Ok(crcs) => prop_assert_eq!(crcs, batch.chunks(message_len).map(|message| config.checksum(message)).collect::<Vec<_>>()),
+                Err(e) => prop_assert_eq!(e, GpuError::UnsupportedWidth { width: config.width, max_width: MAX_GPU_WIDTH }),
+            }
+        }
+        prop_assert!(compare(gpu, &CrcConfig::CAN, &batch, message_len).unwrap().matches);
+    }
+}
+
+#[cfg(feature = "async")]
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+    #[test]
+    fn async_pipeline_matches_blocking_code(bytes in prop::collection::vec(any::<u8>(), 0..4096), frames in prop::collection::vec((1u8..=247, prop::collection::vec(any::<u8>(), 0..16), any::<bool>()), 1..8)) {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let config = CrcCatalog::find("CRC-32/ISO-HDLC").unwrap().config;
+        prop_assert_eq!(runtime.block_on(crc_from_async_reader(&bytes[..], &config)).unwrap(), config.checksum(&bytes));
+
+        let log: String = frames
+            .iter()
+            .map(|(address, data, corrupt)| {
+                let mut adu = rtu_frame(*address, 0x03, data);
+                *adu.last_mut().unwrap() ^= u8::from(*corrupt);
+                format!("{}\n\n", format_hex(&adu))
+            })
+            .collect();
+        let verdicts = runtime.block_on(async {
+            let mut verifier = FrameVerifier::new(log.as_bytes(), FrameSource::ModbusRtu);
+            let mut verdicts = Vec::new();
+            while let Some(verdict) = verifier.next_verdict().await.unwrap() {
+                verdicts.push(verdict.is_valid());
+            }
+            verdicts
+        });
+        prop_assert_eq!(verdicts, frames.iter().map(|(_, _, corrupt)| !corrupt).collect::<Vec<_>>());
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+    #[test]
+    fn batch_progress_reports_every_iteration(bytes in prop::collection::vec(any::<u8>(), 1..4), index in 0..CrcCatalog::all().len(), iterations in 0u64..2 * PARALLEL_THRESHOLD) {
+        let config = CrcCatalog::all()[index].config;
+        let bits = bytes_to_bits(&bytes);
+        let reports = Mutex::new(Vec::new());
+        let crc = compute_batch_checksums_with_progress(&config, &bits, iterations, |done| reports.lock().unwrap().push(done));
+
+        let mut reports = reports.into_inner().unwrap();
+        reports.sort_unstable();
+        prop_assert_eq!(reports.len() as u64, iterations.div_ceil(PROGRESS_INTERVAL));
+        prop_assert_eq!(reports.last().copied().unwrap_or(0), iterations);
+        prop_assert!(reports.windows(2).all(|pair| pair[1] - pair[0] <= PROGRESS_INTERVAL));
+        if iterations > 0 {
+            prop_assert_eq!(crc, config.checksum_message(&bits));
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn thread_pool_size_does_not_change_batch_result(data in prop::collection::vec(any::<u8>(), 1..12), threads in 1usize..=4, iterations in 1u64..=(PARALLEL_THRESHOLD + 1000)) {
+        let pool = thread_pool(threads).unwrap();
+        prop_assert_eq!(pool.current_num_threads(), threads);
+        let bits = bytes_to_bits(&data);
+        for config in [CrcConfig::CAN, CrcCatalog::find("crc-32").unwrap().config] {
+            prop_assert_eq!(compute_batch_checksums_in(&pool, &config, &bits, iterations), compute_batch_checksums(&config, &bits, iterations));
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn scaling_runs_every_thread_count(data in prop::collection::vec(any::<u8>(), 1..12), max_threads in 1usize..=6, iterations in 1u64..=2000) {
+        let counts = thread_counts(max_threads);
+        prop_assert_eq!(counts[0], 1);
+        prop_assert_eq!(*counts.last().unwrap(), max_threads);
+        prop_assert!(counts.windows(2).all(|pair| pair[0] < pair[1] && pair[1] <= pair[0] * 2));
+
+        let bits = bytes_to_bits(&data);
+        let config = CrcCatalog::find("crc-32").unwrap().config;
+        let points = measure_scaling(&config, &bits, &BenchPlan::single(iterations), &counts).unwrap();
+        prop_assert_eq!(points.iter().map(|point| point.threads).collect::<Vec<_>>(), counts);
+        prop_assert_eq!(points[0].speedup, 1.0);
+        prop_assert!(points.iter().all(|point| point.crc == config.checksum_message(&bits)));
+        prop_assert!(best_point(&points).is_some_and(|best| best.speedup >= 1.0));
+    }
+}
+
+proptest! {
+    #[test]
+    fn packed_bits_match_bool_slices(bits in prop::collection::vec(any::<bool>(), 0..300), index in 0..CrcCatalog::all().len()) {
+        let packed = PackedBits::from_bools(&bits);
+        prop_assert_eq!(packed.len(), bits.len());
+        prop_assert_eq!(packed.to_bools(), bits.clone());
+        prop_assert_eq!(packed.whole_bytes(), &bits_to_bytes(&bits)[..bits.len() / 8]);
+        prop_assert_eq!(calculate_can_crc_packed(&packed), calculate_can_crc(&bits));
+
+        let config = CrcCatalog::all()[index].config;
+        let expected = if bits.len().is_multiple_of(8) { config.checksum_bitwise(&bits_to_bytes(&bits)) } else { config.checksum_bits(&bits) };
+        prop_assert_eq!(config.checksum_packed(&packed), expected);
+    }
+}
+
+proptest! {
+    #[test]
+    fn bit_string_round_trips_through_text(bits in prop::collection::vec(any::<bool>(), 1..200), group in 1usize..16, bytes in prop::collection::vec(any::<u8>(), 1..32)) {
+        let bit_string = BitString::from_bools(&bits);
+        prop_assert_eq!(bit_string.to_string().parse::<BitString>(), Ok(bit_string.clone()));
+        prop_assert_eq!(bit_string.grouped(group).to_string().parse::<BitString>(), Ok(bit_string.clone()));
+        prop_assert_eq!(format!("{:#}", bit_string).split(' ').map(str::len).max(), Some(bits.len().min(8)));
+        prop_assert_eq!(bit_string.to_bytes(), bits_to_bytes(&bits));
+
+        let hex = format!("0x{}", format_hex(&bytes));
+        let parsed: BitString = hex.parse().unwrap();
+        prop_assert_eq!(parsed.to_bools(), parse_hex_input_with(&hex[2..], &ParseOptions { max_bits: None, ..ParseOptions::default() }).unwrap());
+        prop_assert_eq!(parsed.to_bytes(), bytes.clone());
+        prop_assert_eq!(BitString::from_bytes(&bytes), parsed);
+    }
+}
+
+proptest! {
+    #[test]
+    fn iterator_crc_matches_slices(bytes in prop::collection::vec(any::<u8>(), 0..64), extra in prop::collection::vec(any::<bool>(), 0..8)) {
+        let mut bits = bytes_to_bits(&bytes);
+        bits.extend(&extra);
+        prop_assert_eq!(calculate_can_crc_iter(bits.iter().copied()), calculate_can_crc(&bits));
+        prop_assert_eq!(calculate_can_crc_bytes_iter(bytes.iter().copied()), calculate_can_crc_bytes(&bytes));
+        let lazy = (0..bytes.len()).map(|i| bytes[i]);
+        prop_assert_eq!(calculate_can_crc_bytes_iter(lazy), calculate_can_crc_bytes(&bytes));
+    }
+}
+
+const CHECK_CRC15: u16 = crc15_bytes(b"123456789");
+
+proptest! {
+    #[test]
+    fn const_crc15_matches_runtime(bytes in prop::collection::vec(any::<u8>(), 0..64)) {
+        prop_assert_eq!(CHECK_CRC15 as u64, CrcCatalog::can().check);
+        prop_assert_eq!(crc15_bytes(&bytes), calculate_can_crc_bytes(&bytes));
+    }
+
+    #[test]
+    fn integer_crcs_match_the_bit_vector(value in any::<u64>(), bit_len in 0usize..=64, index in 0..CrcCatalog::all().len()) {
+        let bits: Vec<bool> = (0..bit_len).rev().map(|bit| (value >> bit) & 1 == 1).collect();
+        prop_assert_eq!(crc15_of_u64(value, bit_len), calculate_can_crc(&bits));
+        let config = CrcCatalog::all()[index].config;
+        prop_assert_eq!(config.checksum_u64(value, bit_len), config.checksum_bits(&bits));
+    }
+}
+
+proptest! {
+    #[test]
+    fn trace_ends_in_the_crc(bits in bit_vec(MAX_INPUT_BITS), index in 0..CrcCatalog::all().len()) {
+        let steps = calculate_can_crc_trace(&bits);
+        prop_assert_eq!(steps.len(), bits.len());
+        for (position, step) in steps.iter().enumerate() {
+            prop_assert_eq!(step.register as u16, calculate_can_crc(&bits[..=position]));
+        }
+
+        let config = CrcCatalog::all()[index].config;
+        let steps = config.trace(&bits);
+        prop_assert_eq!(config.finish_trace(&steps), config.checksum_message(&bits));
+    }
+}
+
+proptest! {
+    #[test]
+    fn timing_stats_are_ordered(samples in prop::collection::vec(0.0f64..1000.0, 1..60), warmup in 0u32..5) {
+        let stats = TimingStats::from_samples(warmup, &samples).unwrap();
+        prop_assert_eq!(stats.repetitions as usize, samples.len());
+        prop_assert!(stats.min_ms <= stats.median_ms && stats.median_ms <= stats.p95_ms && stats.p95_ms <= stats.max_ms);
+        prop_assert!(stats.min_ms <= stats.mean_ms + 1e-9 && stats.mean_ms <= stats.max_ms + 1e-9);
+        prop_assert!(stats.stddev_ms >= 0.0 && stats.stddev_ms <= stats.max_ms - stats.min_ms + 1e-9);
+
+        let mut reversed = samples.clone();
+        reversed.reverse();
+        let reversed = TimingStats::from_samples(warmup, &reversed).unwrap();
+        prop_assert_eq!(reversed.median_ms, stats.median_ms);
+        prop_assert_eq!(reversed.p95_ms, stats.p95_ms);
+
+        let constant = TimingStats::from_samples(warmup, &vec![samples[0]; samples.len()]).unwrap();
+        prop_assert!(constant.stddev_ms <= 1e-9 * samples[0].max(1.0));
+        prop_assert!(TimingStats::from_samples(warmup, &[]).is_none());
+
+        let mut calls = 0;
+        let plan = BenchPlan { iterations: 7, warmup, repetitions: samples.len() as u32 };
+        let (output, measured) = measure(&plan, |iterations| { calls += 1; iterations });
+        prop_assert_eq!(output, 7);
+        prop_assert_eq!(calls, warmup + samples.len() as u32);
+        prop_assert_eq!(measured.repetitions as usize, samples.len());
+    }
+}
+
+proptest! {
+    #[test]
+    fn every_strategy_matches_the_table_path(
+        index in 0..CrcCatalog::all().len(),
+        bytes in prop::collection::vec(any::<u8>(), 0..200),
+        extra in 0usize..8,
+        table_from in 0usize..32,
+        slice8_from in 0usize..128,
+    ) {
+        let config = CrcCatalog::all()[index].config;
+        let auto = AutoCrc::with_calibration(config, Calibration { table_from, slice8_from });
+        let expected = config.checksum(&bytes);
+        for strategy in CrcStrategy::ALL {
+            prop_assert_eq!(auto.checksum_with(strategy, &bytes), expected);
+        }
+        prop_assert_eq!(auto.checksum(&bytes), expected);
+
+        let split = bytes.len() / 3;
+        let crc = auto.update(auto.update(config.start(), &bytes[..split]), &bytes[split..]);
+        prop_assert_eq!(config.finalize(crc), expected);
+
+        let mut bits = bytes_to_bits(&bytes);
+        bits.extend((0..extra).map(|i| i % 2 == 0));
+        prop_assert_eq!(auto.checksum_message(&bits), config.checksum_message(&bits));
+
+        let strategy = auto.strategy_for(bytes.len());
+        prop_assert_eq!(strategy == CrcStrategy::Slice8, bytes.len() >= slice8_from);
+    }
+}
+
+proptest! {
+    #[test]
+    fn crc_result_carries_algorithm_metadata(index in 0..CrcCatalog::all().len(), bytes in prop::collection::vec(any::<u8>(), 0..64), iterations in 1u64..=(PARALLEL_THRESHOLD * 2)) {
+        let algorithm = &CrcCatalog::all()[index];
+        let crc = algorithm.config.checksum(&bytes);
+        let result = CrcResult::for_config(&algorithm.config, crc, 1.5).with_input(bytes.len() * 8, iterations);
+        prop_assert_eq!(&result.algorithm, algorithm.name);
+        prop_assert_eq!(result.poly, algorithm.config.poly);
+        prop_assert_eq!(result.width, algorithm.config.width);
+        prop_assert_eq!(result.bits, bytes.len() * 8);
+        prop_assert_eq!(result.implementation, Implementation::for_iterations(iterations));
+        prop_assert_eq!(
+            result.implementation == Implementation::Parallel,
+            cfg!(feature = "parallel") && iterations >= PARALLEL_THRESHOLD
+        );
+
+        let shown = result.to_string();
+        let head = format!("{} = 0x{}", algorithm.name, result.crc_hex);
+        let runs = format!("{} iterations, {}", iterations, result.implementation);
+        prop_assert!(shown.starts_with(&head));
+        prop_assert!(shown.contains(&runs));
+
+        let custom = CrcResult::for_config(&CrcConfig { init: 1, ..CrcConfig::CAN }, crc, 0.0);
+        prop_assert_eq!(custom.algorithm, CUSTOM_ALGORITHM);
+    }
+}
+
+proptest! {
+    #[test]
+    fn slave_simulator_answers_from_its_register_map(
+        start in 0u16..1000,
+        values in prop::collection::vec(any::<u16>(), 1..=MAX_READ_REGISTERS as usize),
+        offset in 0u16..200,
+        count in 1u16..=MAX_READ_REGISTERS,
+        seed in any::<u64>(),
+    ) {
+        let mut map = RegisterMap::default();
+        map.insert(Table::HoldingRegisters, &format!("{}={}", start, values.iter().map(u16::to_string).collect::<Vec<_>>().join(",")).parse().unwrap());
+        let mut slave = SlaveSimulator::new(7, map);
+
+        let first = start + offset;
+        let request = rtu_frame(7, 3, &[(first >> 8) as u8, first as u8, (count >> 8) as u8, count as u8]);
+        let reply = slave.handle(&request).unwrap().unwrap();
+        prop_assert!(check_rtu(&reply.adu).unwrap().is_valid());
+        let in_map = (offset + count) as usize <= values.len();
+        match decode_adu(&reply.adu).unwrap() {
+            Pdu::ReadRegistersResponse { registers, .. } => {
+                prop_assert!(in_map);
+                prop_assert_eq!(&registers[..], &values[offset as usize..(offset + count) as usize]);
+            }
+            Pdu::Exception { function, code } => {
+                prop_assert!(!in_map);
+                prop_assert_eq!((function, code), (3, ILLEGAL_DATA_ADDRESS));
+            }
+            other => prop_assert!(false, "unexpected reply {:?}", other),
+        }
+
+        let mut bad = request.clone();
+        *bad.last_mut().unwrap() ^= 0x01;
+        prop_assert_eq!(slave.handle(&bad).unwrap(), None);
+        prop_assert_eq!(slave.handle(&rtu_frame(8, 3, &request[2..6])).unwrap(), None);
+        prop_assert_eq!((slave.stats.requests, slave.stats.request_crc_errors, slave.stats.ignored), (3, 1, 1));
+
+        let mut noisy = slave.with_bad_crc_rate(1.0, seed);
+        let reply = noisy.handle(&request).unwrap().unwrap();
+        prop_assert!(reply.corrupted);
+        prop_assert!(!check_rtu(&reply.adu).unwrap().is_valid());
+    }
+
+    #[test]
+    fn poller_accepts_only_matching_valid_responses(
+        start in 0u16..1000,
+        values in prop::collection::vec(any::<u16>(), 1..=MAX_READ_REGISTERS as usize),
+        flip in 0usize..16,
+    ) {
+        let mut map = RegisterMap::default();
+        map.insert(Table::HoldingRegisters, &format!("{}={}", start, values.iter().map(u16::to_string).collect::<Vec<_>>().join(",")).parse().unwrap());
+        let mut slave = SlaveSimulator::new(9, map);
+        let mut poller = Poller::new(9, start, values.len() as u16);
+
+        let reply = slave.handle(&poller.request()).unwrap().unwrap();
+        prop_assert_eq!(poller.evaluate(Some(&reply.adu)), PollOutcome::Registers(values.clone()));
+
+        let mut corrupted = reply.adu.clone();
+        let len = corrupted.len();
+        corrupted[len - 2 + flip / 8] ^= 1 << (flip % 8);
+        prop_assert!(matches!(poller.evaluate(Some(&corrupted)), PollOutcome::CrcError(_)));
+        prop_assert_eq!(poller.evaluate(None), PollOutcome::Timeout);
+
+        let mut other = Poller::new(9, start, values.len() as u16 + 1);
+        let outcome = other.evaluate(Some(&reply.adu));
+        prop_assert!(matches!(outcome, PollOutcome::Unexpected(_)));
+
+        let stats = poller.stats;
+        prop_assert_eq!((stats.polls, stats.ok, stats.crc_errors, stats.timeouts, stats.failures()), (3, 1, 1, 1, 2));
+    }
+
+    #[test]
+    fn j1939_fields_round_trip_through_the_can_id(id in 0u32..=MAX_EXTENDED_ID) {
+        let decoded = J1939Id::from_can_id(id);
+        prop_assert_eq!(decoded.can_id(), id);
+        prop_assert_eq!(decoded.priority as u32, id >> 26);
+        prop_assert_eq!(decoded.source as u32, id & 0xFF);
+        let pdu_format = (id >> 16) as u8;
+        prop_assert_eq!(decoded.pdu_format(), pdu_format);
+        if pdu_format < PDU2_THRESHOLD {
+            prop_assert_eq!(decoded.destination, Some((id >> 8) as u8));
+            prop_assert_eq!(decoded.pgn & 0xFF, 0);
+        } else {
+            prop_assert_eq!(decoded.destination, None);
+            prop_assert_eq!(decoded.pgn, (id >> 8) & 0x3_FFFF);
+        }
+    }
+
+    #[test]
+    fn sdo_block_transfer_reassembles_and_checks_its_crc(
+        data in prop::collection::vec(any::<u8>(), 0..2000),
+        block_size in 1u8..=MAX_BLOCK_SIZE,
+        flip in any::<prop::sample::Index>(),
+    ) {
+        let mut frames = segments(&data, block_size);
+        prop_assert_eq!(frames.len(), data.len().div_ceil(SEGMENT_DATA_LEN).max(1));
+        let end = end_block(&data);
+        let transfer = check_block_transfer(&frames, &end).unwrap();
+        prop_assert!(transfer.is_valid());
+        prop_assert_eq!(&transfer.data, &data);
+        prop_assert_eq!(transfer.computed, CrcCatalog::find("canopen").unwrap().config.checksum(&data) as u16);
+
+        if !data.is_empty() {
+            let bit = flip.index(data.len() * 8);
+            frames[bit / 8 / SEGMENT_DATA_LEN][1 + bit / 8 % SEGMENT_DATA_LEN] ^= 1 << (bit % 8);
+            let corrupted = check_block_transfer(&frames, &end).unwrap();
+            prop_assert!(!corrupted.is_valid());
+            prop_assert_eq!(corrupted.computed, block_crc(&corrupted.data));
+        }
+        if frames.len() > 1 {
+            let last = frames.len() - 1;
+            frames.swap(last - 1, last);
+            prop_assert!(check_block_transfer(&frames, &end).is_err());
+        }
+    }
+
+    #[test]
+    fn flexray_crcs_match_their_catalog_presets(
+        sync in any::<bool>(),
+        startup in any::<bool>(),
+        frame_id in 0u16..=MAX_FRAME_ID,
+        payload_words in 0u8..=MAX_PAYLOAD_WORDS,
+        frame in prop::collection::vec(any::<u8>(), 5..64),
+    ) {
+        let bits = header_crc_bits(sync, startup, frame_id, payload_words);
+        prop_assert_eq!(bits.len(), HEADER_CRC_BITS);
+        let header = CrcCatalog::find("flexray-header").unwrap();
+        prop_assert_eq!(header.max_input_bits, Some(HEADER_CRC_BITS));
+        let crc = header_crc(sync, startup, frame_id, payload_words);
+        prop_assert_eq!(crc as u64, header.config.checksum_bits(&bits));
+
+        let mut codeword = bits.clone();
+        codeword.extend(header.config.crc_to_bits(crc as u64));
+        prop_assert_eq!(header.config.checksum_bits(&codeword), header.residue);
+
+        let a = frame_crc(&frame, Channel::A);
+        let b = frame_crc(&frame, Channel::B);
+        prop_assert_eq!(a as u64, CrcCatalog::find("flexray-a").unwrap().config.checksum(&frame));
+        prop_assert_eq!(b as u64, CrcCatalog::find("flexray-b").unwrap().config.checksum(&frame));
+    }
+
+    #[test]
+    fn checksums_verify_their_appended_field(
+        data in prop::collection::vec(any::<u8>(), 0..512),
+        index in 0..Checksum::ALL.len(),
+        extra_bits in 1usize..8,
+    ) {
+        let checksum = Checksum::ALL[index];
+        prop_assert_eq!(Checksum::find(checksum.name()), Some(checksum));
+        prop_assert_eq!(checksum.compute(b"123456789"), checksum.check());
+        let value = checksum.compute(&data);
+        prop_assert!(value < 1u64 << checksum.width());
+
+        let mut codeword = data.clone();
+        codeword.extend_from_slice(&value.to_be_bytes()[8 - checksum.field_len()..]);
+        prop_assert_eq!(checksum.check_codeword(&codeword), Some((value, value)));
+
+        let mut bits = bytes_to_bits(&data);
+        prop_assert_eq!(checksum.compute_bits(&bits), Ok(value));
+        bits.extend(std::iter::repeat_n(false, extra_bits));
+        prop_assert!(checksum.compute_bits(&bits).is_err());
+
+        let xor_as_crc = CrcConfig { width: 8, poly: 0x01, init: 0, refin: false, refout: false, xorout: 0 };
+        prop_assert_eq!(Checksum::Xor8.compute(&data), xor_as_crc.checksum(&data));
+    }
+
+    #[test]
+    fn dnp3_link_frames_carry_one_crc_per_block(
+        control in any::<u8>(),
+        destination in any::<u16>(),
+        source in any::<u16>(),
+        user_data in prop::collection::vec(any::<u8>(), 0..=MAX_USER_DATA_LEN),
+        flip in any::<prop::sample::Index>(),
+    ) {
+        let mut frame = link_frame(control, destination, source, &user_data).unwrap();
+        let checked = check_link_frame(&frame).unwrap();
+        prop_assert!(checked.is_valid());
+        prop_assert_eq!(&checked.user_data, &user_data);
+        prop_assert_eq!((checked.control(), checked.destination(), checked.source()), (control, destination, source));
+        prop_assert_eq!(checked.blocks.len(), 1 + user_data.len().div_ceil(BLOCK_LEN));
+        prop_assert_eq!(&frame[10..], &add_block_crcs(&user_data)[..]);
+
+        let dnp = CrcCatalog::find("dnp3").unwrap();
+        for block in &checked.blocks {
+            prop_assert_eq!(dnp.config.codeword_residue(&bytes_to_bits(&frame[block.offset..block.offset + block.len + 2])), dnp.residue);
+        }
+
+        let bit = flip.index((frame.len() - 3) * 8) + 3 * 8;
+        frame[bit / 8] ^= 1 << (bit % 8);
+        let corrupted = check_link_frame(&frame).unwrap();
+        prop_assert_eq!(corrupted.blocks.iter().filter(|block| !block.is_valid()).count(), 1);
+    }
+
+    #[test]
+    fn protocol_frames_carry_the_protocol_crc(data in prop::collection::vec(any::<u8>(), 0..=64)) {
+        let bits = bytes_to_bits(&data);
+        let can = Protocol::Can.algorithm(bits.len()).unwrap();
+        prop_assert_eq!(Protocol::Can.frame(&bits).unwrap(), Some(format_binary(&emit_frame(&can.config, &bits, Endianness::Big))));
+        let fd = Protocol::CanFd.algorithm(bits.len()).unwrap();
+        prop_assert_eq!(fd.config.width, if Some(bits.len()) <= CrcCatalog::find("CRC-17/CAN-FD").unwrap().max_input_bits { 17 } else { 21 });
+
+        let rtu = Protocol::ModbusRtu.frame(&bits).unwrap().unwrap();
+        let mut expected = data.clone();
+        expected.extend_from_slice(&(CrcCatalog::find("modbus").unwrap().config.checksum(&data) as u16).to_le_bytes());
+        prop_assert_eq!(rtu, format_hex(&expected));
+
+        let ascii = Protocol::ModbusAscii.frame(&bits).unwrap().unwrap();
+        let bytes = parse_hex_bytes(ascii.strip_prefix(':').unwrap()).unwrap();
+        prop_assert_eq!(&bytes[..data.len()], &data[..]);
+        prop_assert_eq!(bytes.iter().fold(0u8, |acc, &byte| acc.wrapping_add(byte)), 0);
+        prop_assert_eq!(Protocol::Generic.frame(&bits).unwrap(), None);
+        prop_assert!(Protocol::ModbusRtu.frame(&bits[..bits.len().saturating_sub(1)]).is_err() || data.is_empty());
+    }
+}
+
+proptest! {
+    #[test]
+    fn manifests_parse_back(entries in prop::collection::vec(("[a-z0-9_/.-]{1,12}( [a-z0-9_.-]{1,8})?", any::<u64>()), 0..20), index in any::<prop::sample::Index>()) {
+        let algorithm = &CrcCatalog::all()[index.index(CrcCatalog::all().len())];
+        let mut manifest = Manifest::new(algorithm);
+        for (path, crc) in &entries {
+            manifest.push(path, crc & algorithm.config.mask());
+        }
+        prop_assert_eq!(Manifest::parse(&manifest.to_string()), Ok(manifest));
+    }
+
+    #[test]
+    fn raw_frames_round_trip_through_length_prefix(
+        messages in prop::collection::vec((prop::collection::vec(any::<u8>(), 0..64), any::<bool>()), 0..8),
+        prefix_index in 0..PrefixSize::ALL.len(),
+        little in any::<bool>(),
+        index in any::<prop::sample::Index>(),
+    ) {
+        let config = CrcCatalog::all()[index.index(CrcCatalog::all().len())].config;
+        let endianness = if little { Endianness::Little } else { Endianness::Big };
+        let prefix = LengthPrefix::new(PrefixSize::ALL[prefix_index], endianness);
+        let frames: Vec<Vec<u8>> = messages
+            .iter()
+            .map(|(message, corrupt)| {
+                let mut frame = message.clone();
+                frame.extend(config.crc_bytes(config.checksum(message), endianness));
+                frame[message.len()] ^= u8::from(*corrupt);
+                frame
+            })
+            .collect();
+
+        let mut stream = Vec::new();
+        for frame in &frames {
+            write_frame(&mut stream, prefix, frame).unwrap();
+        }
+        let mut reader = &stream[..];
+        for ((message, corrupt), frame) in messages.iter().zip(&frames) {
+            prop_assert_eq!(read_frame(&mut reader, prefix).unwrap(), Some(frame.clone()));
+            let check = check_raw_frame(&config, frame, endianness).unwrap();
+            prop_assert_eq!(check.computed, config.checksum(message));
+            prop_assert_eq!(check.passed(), !corrupt);
+            #[cfg(feature = "serde")]
+            {
+                let suffix = format!("\"valid\":{}}}", !corrupt);
+                prop_assert!(frame_json("peer", 1, frame, &Ok(check)).ends_with(&suffix));
+            }
+        }
+        prop_assert_eq!(read_frame(&mut reader, prefix).unwrap(), None);
+    }
+}
+
+proptest! {
+    #[test]
+    fn fixed_frames_match_allocating_frames(
+        id in 0u32..=MAX_EXTENDED_ID,
+        extended in any::<bool>(),
+        rtr in any::<bool>(),
+        data in prop::collection::vec(any::<u8>(), 0..=8),
+        bits in bit_vec(64),
+    ) {
+        let mut frame = if extended { CanFrame::new_extended(id, &data) } else { CanFrame::new(id & 0x7FF, &data) }.unwrap();
+        frame.rtr = rtr;
+        let fixed = FixedCanFrame::try_from(&frame).unwrap();
+        let wire: Vec<bool> = frame.wire_bits().iter().map(|bit| bit.value).collect();
+        prop_assert_eq!(fixed.crc_bits().iter().collect::<Vec<_>>(), frame.crc_bits());
+        prop_assert_eq!(fixed.wire_bits().iter().collect::<Vec<_>>(), wire);
+        prop_assert_eq!(fixed.crc(), frame.crc());
+        prop_assert!(fixed.verify_crc(frame.crc()));
+        prop_assert_eq!(CanFrame::from(fixed), frame);
+
+        let packed = FixedBits::<8>::from_bits(bits.iter().copied()).unwrap();
+        let reference = PackedBits::from_bools(&bits);
+        prop_assert_eq!(packed.as_bytes(), reference.as_bytes());
+        prop_assert_eq!(packed.can_crc(), calculate_can_crc(&bits));
+        let mut full = FixedBits::<1>::from_bytes(&[0xA5]).unwrap();
+        prop_assert_eq!(full.push(true), Err(FixedError::CapacityExceeded { capacity: 8 }));
+    }
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "std")]
+
+use can_crc_core::recover::{recover, RecoverOptions, Sample};
+use can_crc_core::CrcConfig;
+
+fn samples(config: &CrcConfig, messages: &[&[u8]]) -> Vec<Sample> {
+    messages.iter().map(|data| Sample { data: data.to_vec(), crc: config.checksum(data) }).collect()
+}
+
+#[test]
+fn modbus_is_recovered_from_message_crc_pairs() {
+    let samples = samples(
+        &CrcConfig::MODBUS,
+        &[
+            &[0x01, 0x03, 0x00, 0x00, 0x00, 0x0A],
+            &[0x11, 0x03, 0x00, 0x6B, 0x00, 0x03],
+            &[0x01, 0x06, 0x00, 0x01, 0x00, 0x03],
+            &[0x02, 0x07],
+        ],
+    );
+    let found = recover(16, &samples, &RecoverOptions::default());
+    assert!(found.contains(&CrcConfig::MODBUS), "{:?}", found);
+    for config in &found {
+        assert!(samples.iter().all(|s| config.checksum(&s.data) == s.crc), "{:?}", config);
+    }
+}
+
+#[test]
+fn known_polynomial_narrows_the_search() {
+    let samples = samples(&CrcConfig::MODBUS, &[b"123456789", b"987654321", b"1"]);
+    let options = RecoverOptions { poly: Some(0x8005), max_results: 0 };
+    let found = recover(16, &samples, &options);
+    assert!(found.contains(&CrcConfig::MODBUS), "{:?}", found);
+    assert!(found.iter().all(|config| config.poly == 0x8005));
+}
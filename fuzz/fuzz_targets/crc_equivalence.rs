@@ -0,0 +1,27 @@
+#![no_main]
+
+use can_crc_core::{bytes_to_bits, calculate_can_crc, calculate_can_crc_bytes, calculate_can_crc_optimized};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&trim, bytes)) = data.split_first() else {
+        return;
+    };
+    let mut bits = bytes_to_bits(bytes);
+    bits.truncate(bits.len().saturating_sub((trim % 8) as usize));
+
+    let reference = calculate_can_crc(&bits);
+    assert_eq!(reference, calculate_can_crc_optimized(&bits));
+
+    let zeros = vec![false; bits.len()];
+    let ones = vec![true; bits.len()];
+    let flipped: Vec<bool> = bits.iter().map(|b| !b).collect();
+    assert_eq!(
+        calculate_can_crc(&flipped) ^ calculate_can_crc(&zeros),
+        reference ^ calculate_can_crc(&ones)
+    );
+
+    if trim % 8 == 0 {
+        assert_eq!(reference, calculate_can_crc_bytes(bytes));
+    }
+});
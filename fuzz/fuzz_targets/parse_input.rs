@@ -0,0 +1,17 @@
+#![no_main]
+
+use can_crc_core::{bits_to_bytes, format_binary, format_hex, parse_binary_input, parse_hex_input};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(bits) = parse_binary_input(text) {
+        assert_eq!(parse_binary_input(&format_binary(&bits)).unwrap(), bits);
+    }
+    if let Ok(bits) = parse_hex_input(text) {
+        assert_eq!(parse_hex_input(&format_hex(&bits_to_bytes(&bits))).unwrap(), bits);
+    }
+});
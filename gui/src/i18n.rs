@@ -0,0 +1,961 @@
+use can_crc_core::modbus::Pdu;
+use can_crc_core::{format_hex, ConfigError, DbcError, FrameError, ImageError, ParseError};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    #[default]
+    Pl,
+    En,
+}
+
+impl Lang {
+    pub const ALL: [Lang; 2] = [Lang::Pl, Lang::En];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::Pl => "pl",
+            Lang::En => "en",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Lang::Pl => "Polski",
+            Lang::En => "English",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim().to_lowercase().as_str() {
+            "pl" => Some(Lang::Pl),
+            "en" => Some(Lang::En),
+            _ => None,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Lang::from_code(value.get(..2).unwrap_or("")))
+            .unwrap_or_default()
+    }
+
+    pub fn strings(self) -> &'static Strings {
+        match self {
+            Lang::Pl => &PL,
+            Lang::En => &EN,
+        }
+    }
+
+    pub fn error(self, message: &str) -> String {
+        format!("{}{}", self.strings().err_prefix, message)
+    }
+
+    pub fn describe(self, error: &ParseError) -> String {
+        let t = self.strings();
+        match error {
+            ParseError::Empty => t.err_empty_input.to_string(),
+            ParseError::InvalidBinaryChars { chars, index } => fill(t.err_invalid_binary_chars, &[chars, index]),
+            ParseError::NoBinaryData => t.err_no_binary_data.to_string(),
+            ParseError::BinaryTooLong { bits, max_bits } => {
+                fill(t.err_binary_too_long, &[bits, max_bits])
+            }
+            ParseError::InvalidHexChars { chars, index } => fill(t.err_invalid_hex_chars, &[chars, index]),
+            ParseError::NoHexData => t.err_no_hex_data.to_string(),
+            ParseError::OddHexLength { digits } => fill(t.err_odd_hex_length, &[digits]),
+            ParseError::HexTooLong { bytes, max_bytes } => {
+                fill(t.err_hex_too_long, &[bytes, &(bytes * 8), max_bytes, &(max_bytes * 8)])
+            }
+            ParseError::InvalidHexFormat => t.err_invalid_hex_format.to_string(),
+            ParseError::InvalidBase64Chars { chars, index } => fill(t.err_invalid_base64_chars, &[chars, index]),
+            ParseError::NoBase64Data => t.err_no_base64_data.to_string(),
+            ParseError::InvalidBase64Length { chars } => fill(t.err_invalid_base64_length, &[chars]),
+            ParseError::PartialByte { bits } => fill(t.err_partial_byte, &[bits]),
+            ParseError::InvalidArrayElement { element } => fill(t.err_invalid_array_element, &[element]),
+            ParseError::InvalidEscape { sequence, index } => fill(t.err_invalid_escape, &[sequence, index]),
+            ParseError::UnterminatedString { index } => fill(t.err_unterminated_string, &[index]),
+        }
+    }
+
+    pub fn describe_config(self, error: &ConfigError) -> String {
+        let t = self.strings();
+        match error {
+            ConfigError::InvalidWidth { width } => fill(t.err_invalid_width, &[width]),
+            ConfigError::PolyTooWide { poly, width } => {
+                fill(t.err_poly_too_wide, &[&format!("{:X}", poly), width])
+            }
+            ConfigError::EvenPoly { poly } => fill(t.err_even_poly, &[&format!("{:X}", poly)]),
+            ConfigError::InvalidNumber { value } => fill(t.err_invalid_number, &[value]),
+            ConfigError::InvalidDataWidth { width } => fill(t.err_invalid_data_width, &[width]),
+        }
+    }
+
+    pub fn describe_frame(self, error: &FrameError) -> String {
+        let t = self.strings();
+        match error {
+            FrameError::IdOutOfRange { id, extended } => {
+                let bits = if *extended { 29 } else { 11 };
+                fill(t.err_id_out_of_range, &[&format!("{:X}", id), &bits])
+            }
+            FrameError::DataTooLong { len, max_len } => fill(t.err_frame_data_too_long, &[len, max_len]),
+            FrameError::Syntax { content } => fill(t.err_frame_syntax, &[content]),
+            FrameError::TooShort { len, min_len } => fill(t.err_frame_too_short, &[len, min_len]),
+            FrameError::MissingCrc { bits, crc_bits } => fill(t.err_frame_missing_crc, &[bits, crc_bits]),
+            FrameError::SegmentSequence { index, sequence } => fill(t.err_frame_segment_sequence, &[&(index + 1), sequence]),
+            FrameError::CapacityExceeded { capacity } => fill(t.err_frame_capacity, &[capacity]),
+        }
+    }
+
+    pub fn describe_dbc(self, error: &DbcError) -> String {
+        let t = self.strings();
+        match error {
+            DbcError::Syntax { line, content } => fill(t.err_dbc_syntax, &[line, content]),
+            DbcError::UnknownMessage { name } => fill(t.err_dbc_unknown_message, &[name]),
+            DbcError::UnknownSignal { name } => fill(t.err_dbc_unknown_signal, &[name]),
+            DbcError::ValueOutOfRange { signal, value } => fill(t.err_dbc_value_out_of_range, &[value, signal]),
+            DbcError::Frame(e) => self.describe_frame(e),
+        }
+    }
+
+    pub fn describe_pdu(self, pdu: &Pdu) -> String {
+        let t = self.strings();
+        let name = self.modbus_function_name(pdu.function());
+        match pdu {
+            Pdu::ReadRequest { start, count, .. } => fill(t.modbus_pdu_read_request, &[&name, start, count]),
+            Pdu::ReadBitsResponse { bits, .. } => {
+                let values: String = bits.iter().map(|&bit| if bit { '1' } else { '0' }).collect();
+                fill(t.modbus_pdu_bits_response, &[&name, &bits.len(), &values])
+            }
+            Pdu::ReadRegistersResponse { registers, .. } => {
+                let values = registers.iter().map(|value| format!("{} (0x{:04X})", value, value)).collect::<Vec<_>>().join(", ");
+                fill(t.modbus_pdu_registers_response, &[&name, &registers.len(), &values])
+            }
+            Pdu::WriteSingle { address, value, .. } => fill(t.modbus_pdu_write_single, &[&name, address, &format!("{:04X}", value)]),
+            Pdu::WriteMultipleRequest { start, count, data, .. } => {
+                fill(t.modbus_pdu_write_multiple_request, &[&name, start, count, &format_hex(data)])
+            }
+            Pdu::WriteMultipleResponse { start, count, .. } => fill(t.modbus_pdu_write_multiple_response, &[&name, count, start]),
+            Pdu::Exception { code, .. } => {
+                let reason = match code {
+                    0x01 => t.modbus_ex_illegal_function,
+                    0x02 => t.modbus_ex_illegal_data_address,
+                    0x03 => t.modbus_ex_illegal_data_value,
+                    0x04 => t.modbus_ex_server_failure,
+                    0x05 => t.modbus_ex_acknowledge,
+                    0x06 => t.modbus_ex_server_busy,
+                    0x08 => t.modbus_ex_memory_parity,
+                    0x0A => t.modbus_ex_gateway_path,
+                    0x0B => t.modbus_ex_gateway_target,
+                    _ => t.modbus_ex_other,
+                };
+                fill(t.modbus_pdu_exception, &[&name, &reason, &format!("{:02X}", code)])
+            }
+            Pdu::Malformed { len, .. } => fill(t.modbus_pdu_malformed, &[&name, len]),
+            Pdu::Other { data, .. } => fill(t.modbus_pdu_other, &[&name, &data.len()]),
+        }
+    }
+
+    pub fn modbus_function_name(self, function: u8) -> String {
+        let t = self.strings();
+        match function {
+            0x01 => t.modbus_fc_read_coils.to_string(),
+            0x02 => t.modbus_fc_read_discrete_inputs.to_string(),
+            0x03 => t.modbus_fc_read_holding_registers.to_string(),
+            0x04 => t.modbus_fc_read_input_registers.to_string(),
+            0x05 => t.modbus_fc_write_single_coil.to_string(),
+            0x06 => t.modbus_fc_write_single_register.to_string(),
+            0x0F => t.modbus_fc_write_multiple_coils.to_string(),
+            0x10 => t.modbus_fc_write_multiple_registers.to_string(),
+            _ => fill(t.modbus_fc_other, &[&format!("{:02X}", function)]),
+        }
+    }
+
+    pub fn describe_image(self, error: &ImageError) -> String {
+        let t = self.strings();
+        match error {
+            ImageError::Syntax { line } => fill(t.err_image_syntax, &[line]),
+            ImageError::Checksum { line, expected, actual } => fill(t.err_image_checksum, &[
+                line,
+                &format!("{:02X}", expected),
+                &format!("{:02X}", actual),
+            ]),
+            ImageError::UnsupportedRecord { line, kind } => fill(t.err_image_unsupported_record, &[kind, line]),
+            ImageError::Overlap { address } => fill(t.err_image_overlap, &[&format!("{:08X}", address)]),
+            ImageError::AddressOverflow { address } => fill(t.err_image_address_overflow, &[&format!("{:X}", address)]),
+            ImageError::MissingEndRecord => t.err_image_missing_end.to_string(),
+        }
+    }
+}
+
+pub fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut rest = template;
+
+    while let Some(pos) = rest.find("{}") {
+        result.push_str(&rest[..pos]);
+        if let Some(arg) = args.next() {
+            result.push_str(&arg.to_string());
+        }
+        rest = &rest[pos + 2..];
+    }
+    result.push_str(rest);
+
+    result
+}
+
+pub struct Strings {
+    pub app_title: &'static str,
+    pub err_prefix: &'static str,
+    pub err_empty_input: &'static str,
+    pub err_invalid_binary_chars: &'static str,
+    pub err_no_binary_data: &'static str,
+    pub err_binary_too_long: &'static str,
+    pub err_invalid_hex_chars: &'static str,
+    pub err_no_hex_data: &'static str,
+    pub err_odd_hex_length: &'static str,
+    pub err_hex_too_long: &'static str,
+    pub err_invalid_hex_format: &'static str,
+    pub err_invalid_width: &'static str,
+    pub err_poly_too_wide: &'static str,
+    pub err_even_poly: &'static str,
+    pub err_invalid_number: &'static str,
+    pub err_invalid_data_width: &'static str,
+    pub err_id_out_of_range: &'static str,
+    pub err_frame_data_too_long: &'static str,
+    pub err_dbc_syntax: &'static str,
+    pub err_dbc_unknown_message: &'static str,
+    pub err_dbc_unknown_signal: &'static str,
+    pub err_dbc_value_out_of_range: &'static str,
+
+    pub err_image_syntax: &'static str,
+    pub err_image_checksum: &'static str,
+    pub err_image_unsupported_record: &'static str,
+    pub err_image_overlap: &'static str,
+    pub err_image_address_overflow: &'static str,
+    pub err_image_missing_end: &'static str,
+    pub err_invalid_base64_chars: &'static str,
+    pub err_no_base64_data: &'static str,
+    pub err_invalid_base64_length: &'static str,
+    pub err_partial_byte: &'static str,
+    pub err_invalid_array_element: &'static str,
+    pub err_invalid_escape: &'static str,
+    pub err_unterminated_string: &'static str,
+    pub err_frame_syntax: &'static str,
+    pub err_frame_too_short: &'static str,
+    pub modbus_fc_read_coils: &'static str,
+    pub modbus_fc_read_discrete_inputs: &'static str,
+    pub modbus_fc_read_holding_registers: &'static str,
+    pub modbus_fc_read_input_registers: &'static str,
+    pub modbus_fc_write_single_coil: &'static str,
+    pub modbus_fc_write_single_register: &'static str,
+    pub modbus_fc_write_multiple_coils: &'static str,
+    pub modbus_fc_write_multiple_registers: &'static str,
+    pub modbus_fc_other: &'static str,
+    pub modbus_ex_illegal_function: &'static str,
+    pub modbus_ex_illegal_data_address: &'static str,
+    pub modbus_ex_illegal_data_value: &'static str,
+    pub modbus_ex_server_failure: &'static str,
+    pub modbus_ex_acknowledge: &'static str,
+    pub modbus_ex_server_busy: &'static str,
+    pub modbus_ex_memory_parity: &'static str,
+    pub modbus_ex_gateway_path: &'static str,
+    pub modbus_ex_gateway_target: &'static str,
+    pub modbus_ex_other: &'static str,
+    pub modbus_pdu_read_request: &'static str,
+    pub modbus_pdu_bits_response: &'static str,
+    pub modbus_pdu_registers_response: &'static str,
+    pub modbus_pdu_write_single: &'static str,
+    pub modbus_pdu_write_multiple_request: &'static str,
+    pub modbus_pdu_write_multiple_response: &'static str,
+    pub modbus_pdu_exception: &'static str,
+    pub modbus_pdu_malformed: &'static str,
+    pub modbus_pdu_other: &'static str,
+    pub err_frame_missing_crc: &'static str,
+    pub err_frame_segment_sequence: &'static str,
+    pub err_frame_capacity: &'static str,
+
+    pub gui_language: &'static str,
+    pub gui_theme_light: &'static str,
+    pub gui_theme_dark: &'static str,
+    pub gui_input_format: &'static str,
+    pub gui_format_binary: &'static str,
+    pub gui_format_hex: &'static str,
+    pub gui_binary_sequence: &'static str,
+    pub gui_binary_help: &'static str,
+    pub gui_binary_entered: &'static str,
+    pub gui_hex_sequence: &'static str,
+    pub gui_hex_help: &'static str,
+    pub gui_hex_entered: &'static str,
+    pub gui_iterations: &'static str,
+    pub gui_iterations_range: &'static str,
+    pub gui_calculating_button: &'static str,
+    pub gui_calculate_button: &'static str,
+    pub gui_compare_button: &'static str,
+    pub gui_calculating: &'static str,
+    pub gui_results: &'static str,
+    pub gui_crc_dec: &'static str,
+    pub gui_crc_bin: &'static str,
+    pub gui_duration: &'static str,
+    pub gui_avg_time: &'static str,
+    pub gui_performance: &'static str,
+    pub gui_mode: &'static str,
+    pub gui_mode_parallel: &'static str,
+    pub gui_examples: &'static str,
+    pub gui_examples_binary: &'static str,
+    pub gui_examples_hex: &'static str,
+    pub gui_examples_iterations: &'static str,
+    pub gui_info_polynomial: &'static str,
+    pub gui_info_parallel: &'static str,
+    pub gui_info_drop: &'static str,
+    pub gui_last_calculation: &'static str,
+    pub gui_drop_overlay: &'static str,
+    pub gui_err_read_file: &'static str,
+    pub gui_err_open_file: &'static str,
+    pub gui_err_no_file_data: &'static str,
+    pub gui_file_loaded: &'static str,
+    pub gui_file_streamed: &'static str,
+    pub gui_err_no_bits: &'static str,
+    pub gui_err_iterations_zero: &'static str,
+    pub gui_err_iterations_too_large: &'static str,
+    pub gui_err_iterations_missing: &'static str,
+    pub gui_err_iterations_invalid: &'static str,
+    pub gui_perf_chart: &'static str,
+    pub gui_clear: &'static str,
+    pub gui_run: &'static str,
+    pub gui_path_sequential: &'static str,
+    pub gui_path_parallel: &'static str,
+    pub gui_dbc_section: &'static str,
+    pub gui_dbc_path: &'static str,
+    pub gui_dbc_load: &'static str,
+    pub gui_dbc_hint: &'static str,
+    pub gui_dbc_loaded: &'static str,
+    pub gui_dbc_message: &'static str,
+    pub gui_dbc_encode: &'static str,
+    pub gui_dbc_frame_result: &'static str,
+    pub gui_distribution_exhaustive: &'static str,
+    pub gui_distribution_sampled: &'static str,
+    pub gui_algorithm: &'static str,
+    pub gui_compare_can_only: &'static str,
+    pub gui_binary_help_unlimited: &'static str,
+    pub gui_hex_help_unlimited: &'static str,
+    pub gui_file_image: &'static str,
+    pub gui_format_escaped: &'static str,
+    pub gui_escaped_sequence: &'static str,
+    pub gui_escaped_help: &'static str,
+    pub gui_format_base64: &'static str,
+    pub gui_base64_sequence: &'static str,
+    pub gui_base64_help: &'static str,
+    pub gui_batch_section: &'static str,
+    pub gui_batch_hint: &'static str,
+    pub gui_batch_compute: &'static str,
+    pub gui_batch_summary: &'static str,
+    pub gui_batch_col_input: &'static str,
+    pub gui_batch_col_bits: &'static str,
+    pub gui_batch_col_time: &'static str,
+    pub gui_batch_csv_path: &'static str,
+    pub gui_batch_export: &'static str,
+    pub gui_batch_exported: &'static str,
+    pub gui_err_write_file: &'static str,
+    pub gui_emit_frame: &'static str,
+    pub gui_emit_frame_hint: &'static str,
+    pub gui_protocol_generic: &'static str,
+    pub gui_protocol_can: &'static str,
+    pub gui_protocol_can_fd: &'static str,
+    pub gui_protocol_modbus: &'static str,
+    pub gui_frame_id: &'static str,
+    pub gui_frame_extended: &'static str,
+    pub gui_frame_data: &'static str,
+    pub gui_can_rtr: &'static str,
+    pub gui_can_data_help: &'static str,
+    pub gui_frame_dlc: &'static str,
+    pub gui_frame_crc_bits: &'static str,
+    pub gui_frame_crc: &'static str,
+    pub gui_frame_wire: &'static str,
+    pub gui_frame_stuff_bits: &'static str,
+    pub gui_fd_brs: &'static str,
+    pub gui_fd_data_help: &'static str,
+    pub gui_fd_length: &'static str,
+    pub gui_fd_padded: &'static str,
+    pub gui_fd_crc_algorithm: &'static str,
+    pub gui_modbus_address: &'static str,
+    pub gui_modbus_function: &'static str,
+    pub gui_modbus_help: &'static str,
+    pub gui_modbus_crc_bytes: &'static str,
+    pub gui_modbus_length: &'static str,
+    pub gui_modbus_frame: &'static str,
+    pub gui_copy: &'static str,
+    pub gui_err_frame_id: &'static str,
+    pub gui_err_modbus_address: &'static str,
+    pub gui_err_modbus_function: &'static str,
+    pub gui_err_modbus_too_long: &'static str,
+    pub gui_custom_section: &'static str,
+    pub gui_custom_enabled: &'static str,
+    pub gui_custom_from_algorithm: &'static str,
+    pub gui_custom_algorithm: &'static str,
+    pub gui_custom_poly: &'static str,
+    pub gui_custom_width: &'static str,
+    pub gui_custom_init: &'static str,
+    pub gui_custom_xorout: &'static str,
+    pub gui_custom_valid: &'static str,
+    pub gui_err_custom_top_bit: &'static str,
+    pub gui_err_custom_value_too_wide: &'static str,
+    pub gui_calculating_progress: &'static str,
+    pub gui_trace_section: &'static str,
+    pub gui_trace_per_byte: &'static str,
+    pub gui_trace_step: &'static str,
+    pub gui_trace_index: &'static str,
+    pub gui_trace_register: &'static str,
+    pub gui_trace_final: &'static str,
+    pub gui_trace_too_long: &'static str,
+    pub gui_modbus_meaning: &'static str,
+    pub gui_fd_non_iso: &'static str,
+    pub gui_fd_non_iso_hint: &'static str,
+    pub gui_fd_stuff_count: &'static str,
+    pub gui_live_crc: &'static str,
+    pub gui_live_crc_hint: &'static str,
+    pub gui_live_too_long: &'static str,
+    pub gui_save_results: &'static str,
+    pub gui_save_results_hint: &'static str,
+    pub gui_results_saved: &'static str,
+    pub report_title: &'static str,
+    pub report_algorithm: &'static str,
+    pub report_parameters: &'static str,
+    pub report_input: &'static str,
+    pub report_result: &'static str,
+    pub report_value: &'static str,
+    pub report_path: &'static str,
+    pub report_error: &'static str,
+    pub report_runs: &'static str,
+    pub report_batch: &'static str,
+    pub gui_distribution_section: &'static str,
+    pub gui_distribution_bits: &'static str,
+    pub gui_distribution_compute: &'static str,
+    pub gui_distribution_invalid: &'static str,
+    pub gui_distribution_summary: &'static str,
+    pub gui_distribution_bucket: &'static str,
+    pub gui_distribution_count: &'static str,
+    pub gui_playground_section: &'static str,
+    pub gui_playground_hint: &'static str,
+    pub gui_playground_no_input: &'static str,
+    pub gui_playground_too_long: &'static str,
+    pub gui_playground_data: &'static str,
+    pub gui_playground_bit: &'static str,
+    pub gui_playground_flipped: &'static str,
+    pub gui_playground_original: &'static str,
+    pub gui_playground_received: &'static str,
+    pub gui_playground_recomputed: &'static str,
+    pub gui_playground_none: &'static str,
+    pub gui_playground_detected: &'static str,
+    pub gui_playground_undetected: &'static str,
+    pub gui_bits_section: &'static str,
+    pub gui_bits_too_long: &'static str,
+    pub gui_bits_tooltip: &'static str,
+    pub gui_crc_endianness_auto: &'static str,
+    pub gui_crc_endianness_hint: &'static str,
+}
+
+static PL: Strings = Strings {
+    app_title: "Kalkulator CRC CAN",
+    err_prefix: "❌ Błąd: ",
+    err_empty_input: "Dane wejściowe są puste",
+    err_invalid_binary_chars: "Znaleziono nieprawidłowe znaki: '{}' (pierwszy pod indeksem {}; dozwolone tylko: 0, 1, spacje, podkreślniki, kropki, prefiks 0b)",
+    err_no_binary_data: "Brak prawidłowych danych binarnych (tylko 0 i 1)",
+    err_binary_too_long: "Dane za długie: {} bitów (maksymalnie dozwolone: {} bitów)",
+    err_invalid_hex_chars: "Znaleziono nieprawidłowe znaki: '{}' (pierwszy pod indeksem {}; dozwolone tylko: 0-9, A-F, spacje, przecinki, dwukropki, myślniki, prefiks 0x)",
+    err_no_hex_data: "Brak prawidłowych danych hex",
+    err_odd_hex_length: "Nieparzysta liczba znaków hex: {} (wymagana parzysta liczba)",
+    err_hex_too_long: "Dane za długie: {} bajtów = {} bitów (maksymalnie: {} bajtów = {} bitów)",
+    err_invalid_hex_format: "Nieprawidłowy format hex",
+    err_invalid_width: "Nieobsługiwana szerokość CRC: {} (dozwolone 1-64 bity)",
+    err_poly_too_wide: "Wielomian 0x{} nie mieści się w {} bitach",
+    err_even_poly: "Wielomian 0x{} nie zawiera wyrazu x^0",
+    err_invalid_number: "Nieprawidłowa liczba: '{}'",
+    err_invalid_data_width: "Nieobsługiwana szerokość danych: {} (dozwolone 1-64 bity)",
+    err_id_out_of_range: "Identyfikator 0x{} nie mieści się w {} bitach",
+    err_frame_data_too_long: "Za dużo danych w ramce: {} bajtów (maks. {})",
+    err_dbc_syntax: "Błąd składni DBC w linii {}: '{}'",
+    err_dbc_unknown_message: "Nieznana wiadomość DBC: '{}'",
+    err_dbc_unknown_signal: "Nieznany sygnał DBC: '{}'",
+    err_dbc_value_out_of_range: "Wartość {} nie mieści się w sygnale '{}'",
+
+    err_image_syntax: "nieprawidłowy rekord w linii {}",
+    err_image_checksum: "błędna suma kontrolna rekordu w linii {}: oczekiwano {}, jest {}",
+    err_image_unsupported_record: "nieobsługiwany typ rekordu {} w linii {}",
+    err_image_overlap: "rekordy danych nakładają się pod adresem 0x{}",
+    err_image_address_overflow: "dane pod adresem 0x{} wychodzą poza 32-bitową przestrzeń adresową",
+    err_image_missing_end: "brak rekordu końca pliku",
+    err_invalid_base64_chars: "Znaleziono nieprawidłowe znaki base64: '{}' (pierwszy pod indeksem {}; dozwolone: A-Z, a-z, 0-9, +, /, -, _, =)",
+    err_no_base64_data: "Brak prawidłowych danych base64",
+    err_invalid_base64_length: "Nieprawidłowa długość danych base64: {} znaków",
+    err_partial_byte: "Wejście ma {} bitów - wymagane są pełne bajty",
+    err_invalid_array_element: "Element tablicy '{}' nie jest wartością bajtu (0-255)",
+    err_invalid_escape: "Nieprawidłowa sekwencja ucieczki '{}' pod indeksem {}",
+    err_unterminated_string: "Łańcuch otwarty pod indeksem {} nie jest zamknięty",
+    err_frame_syntax: "Nierozpoznana ramka CAN: '{}'",
+    err_frame_too_short: "Za krótka ramka: {} bajtów (min. {})",
+    modbus_fc_read_coils: "Odczyt cewek (0x01)",
+    modbus_fc_read_discrete_inputs: "Odczyt wejść dyskretnych (0x02)",
+    modbus_fc_read_holding_registers: "Odczyt rejestrów pamiętających (0x03)",
+    modbus_fc_read_input_registers: "Odczyt rejestrów wejściowych (0x04)",
+    modbus_fc_write_single_coil: "Zapis pojedynczej cewki (0x05)",
+    modbus_fc_write_single_register: "Zapis pojedynczego rejestru (0x06)",
+    modbus_fc_write_multiple_coils: "Zapis wielu cewek (0x0F)",
+    modbus_fc_write_multiple_registers: "Zapis wielu rejestrów (0x10)",
+    modbus_fc_other: "Funkcja 0x{}",
+    modbus_ex_illegal_function: "niedozwolona funkcja",
+    modbus_ex_illegal_data_address: "niedozwolony adres danych",
+    modbus_ex_illegal_data_value: "niedozwolona wartość danych",
+    modbus_ex_server_failure: "awaria urządzenia",
+    modbus_ex_acknowledge: "potwierdzenie, przetwarzanie trwa",
+    modbus_ex_server_busy: "urządzenie zajęte",
+    modbus_ex_memory_parity: "błąd parzystości pamięci",
+    modbus_ex_gateway_path: "ścieżka bramy niedostępna",
+    modbus_ex_gateway_target: "urządzenie za bramą nie odpowiada",
+    modbus_ex_other: "nieznany wyjątek",
+    modbus_pdu_read_request: "{} - żądanie: adres początkowy {}, liczba {}",
+    modbus_pdu_bits_response: "{} - odpowiedź: {} bitów (od najmłodszego): {}",
+    modbus_pdu_registers_response: "{} - odpowiedź: {} rejestrów: {}",
+    modbus_pdu_write_single: "{}: adres {}, wartość 0x{}",
+    modbus_pdu_write_multiple_request: "{} - żądanie: adres początkowy {}, liczba {}, dane: {}",
+    modbus_pdu_write_multiple_response: "{} - odpowiedź: zapisano {} od adresu {}",
+    modbus_pdu_exception: "❌ Wyjątek dla {}: {} (kod 0x{})",
+    modbus_pdu_malformed: "{}: nieoczekiwana długość danych ({} bajtów)",
+    modbus_pdu_other: "{}: {} bajtów danych",
+    err_frame_missing_crc: "Ramka ma {} bitów - za mało na pole CRC ({} bitów)",
+    err_frame_segment_sequence: "Segment {} poza kolejnością (numer sekwencyjny {})",
+    err_frame_capacity: "Bufor o stałej pojemności {} bitów jest pełny",
+
+    gui_language: "🌐 Język:",
+    gui_theme_light: "☀ Jasny",
+    gui_theme_dark: "🌙 Ciemny",
+    gui_input_format: "📋 Format wejściowy:",
+    gui_format_binary: "Binarny",
+    gui_format_hex: "Heksadecymalny",
+    gui_binary_sequence: "🔢 Sekwencja binarna:",
+    gui_binary_help: "Format: tylko 0 i 1, maksymalnie {} bitów",
+    gui_binary_entered: "Wprowadzono: {} bitów",
+    gui_hex_sequence: "📝 Sekwencja hex:",
+    gui_hex_help: "Format: AA BB CC DD lub zrzut xxd / hexdump -C (maks. {} bajtów = {} bitów)",
+    gui_hex_entered: "Wprowadzono: {} bajtów = {} bitów",
+    gui_iterations: "🔄 Liczba iteracji:",
+    gui_iterations_range: "(1 do 1 000 000 000)",
+    gui_calculating_button: "⏳ Obliczanie...",
+    gui_calculate_button: "🚀 Oblicz CRC",
+    gui_compare_button: "⚖ Porównaj: sekwencyjnie vs równolegle",
+    gui_calculating: "Obliczanie CRC CAN...",
+    gui_results: "📊 Wyniki",
+    gui_crc_dec: "🔢 CRC (dziesiętnie):",
+    gui_crc_bin: "🔢 CRC (binarnie):",
+    gui_duration: "⏱️ Czas wykonania:",
+    gui_avg_time: "⏱️ Średni czas na CRC:",
+    gui_performance: "⚡ Wydajność:",
+    gui_mode: "🔥 Tryb:",
+    gui_mode_parallel: "Przetwarzanie równoległe",
+    gui_examples: "📋 Przykładowe dane",
+    gui_examples_binary: "Przykłady binarne:",
+    gui_examples_hex: "Przykłady hex:",
+    gui_examples_iterations: "Liczba iteracji:",
+    gui_info_polynomial: "CAN używa 15-bitowego CRC z wielomianem 0x4599",
+    gui_info_parallel: "Automatyczne przetwarzanie równoległe dla >100k iteracji",
+    gui_info_drop: "Upuść plik binarny lub tekstowy (hex/bin) na okno, aby obliczyć jego CRC",
+    gui_last_calculation: "Ostatnie obliczenie: {}ms",
+    gui_drop_overlay: "📂 Upuść plik, aby obliczyć CRC",
+    gui_err_read_file: "Nie udało się odczytać pliku '{}': {}",
+    gui_err_open_file: "Nie udało się otworzyć pliku '{}': {}",
+    gui_err_no_file_data: "Brak danych upuszczonego pliku '{}'",
+    gui_file_loaded: "Wczytano plik: {} ({} bajtów)",
+    gui_file_streamed: "Obliczono strumieniowo CRC pliku: {} ({} bajtów = {} bitów)",
+    gui_err_no_bits: "Proszę wprowadzić przynajmniej jeden bit danych.",
+    gui_err_iterations_zero: "Liczba iteracji musi być większa od 0",
+    gui_err_iterations_too_large: "Liczba iteracji za duża: {} (maks. 1 000 000 000)",
+    gui_err_iterations_missing: "Proszę podać liczbę iteracji",
+    gui_err_iterations_invalid: "Nieprawidłowa liczba iteracji (użyj tylko cyfr)",
+    gui_perf_chart: "📈 Wydajność w kolejnych uruchomieniach",
+    gui_clear: "🗑 Wyczyść",
+    gui_run: "Uruchomienie",
+    gui_path_sequential: "Sekwencyjnie",
+    gui_path_parallel: "Równolegle",
+    gui_dbc_section: "📄 Wiadomość z pliku DBC",
+    gui_dbc_path: "Plik DBC:",
+    gui_dbc_load: "📂 Wczytaj",
+    gui_dbc_hint: "Wskaż ścieżkę lub upuść plik .dbc na okno",
+    gui_dbc_loaded: "Wczytano {} wiadomości z {}",
+    gui_dbc_message: "Wiadomość:",
+    gui_dbc_encode: "🧮 Zakoduj i oblicz CRC ramki",
+    gui_dbc_frame_result: "Ramka 0x{} ({}): dane {} → CRC ramki 0x{} ({} bitów)",
+    gui_distribution_exhaustive: "wszystkie",
+    gui_distribution_sampled: "losowe, ziarno {}",
+    gui_algorithm: "🧮 Algorytm:",
+    gui_compare_can_only: "Porównanie ścieżek wykonania jest dostępne dla CRC-15/CAN",
+    gui_binary_help_unlimited: "Format: tylko 0 i 1, bez limitu długości",
+    gui_hex_help_unlimited: "Format: AA BB CC DD lub zrzut xxd / hexdump -C (bez limitu długości)",
+    gui_file_image: "Obliczono CRC obrazu pamięci: {} ({} bajtów, 0x{}..0x{}, luki wypełnione 0x{})",
+    gui_format_escaped: "\\x..",
+    gui_escaped_sequence: "📝 Łańcuch z sekwencjami ucieczki:",
+    gui_escaped_help: "Format: \\xHH, ósemkowe \\NNN, \\n \\r \\t \\0 itp.; cudzysłowy i prefiks b opcjonalne",
+    gui_format_base64: "Base64",
+    gui_base64_sequence: "📝 Dane base64:",
+    gui_base64_help: "Format: base64 (standardowy lub URL-safe, dopełnienie = opcjonalne)",
+    gui_batch_section: "📋 Wiele wiadomości",
+    gui_batch_hint: "Jedna wiadomość w linii, w wybranym formacie wejściowym",
+    gui_batch_compute: "🚀 Oblicz wszystkie",
+    gui_batch_summary: "Wiadomości: {}, błędy: {}",
+    gui_batch_col_input: "Wejście",
+    gui_batch_col_bits: "Bity",
+    gui_batch_col_time: "Czas [ms]",
+    gui_batch_csv_path: "Plik CSV:",
+    gui_batch_export: "💾 Eksportuj CSV",
+    gui_batch_exported: "Zapisano {} wierszy do '{}'",
+    gui_err_write_file: "Nie udało się zapisać pliku '{}': {}",
+    gui_emit_frame: "📤 Ramka z CRC",
+    gui_emit_frame_hint: "Dane z dołączonym CRC w kolejności protokołu (CAN: 15 bitów + ogranicznik, Modbus: 2 bajty little-endian) - kopiowane do schowka",
+    gui_protocol_generic: "🧮 Ogólny",
+    gui_protocol_can: "🚌 CAN",
+    gui_protocol_can_fd: "⚡ CAN FD",
+    gui_protocol_modbus: "🏭 Modbus RTU",
+    gui_frame_id: "Identyfikator (hex):",
+    gui_frame_extended: "Rozszerzony (29 bitów)",
+    gui_frame_data: "Dane (hex):",
+    gui_can_rtr: "Ramka zdalna (RTR)",
+    gui_can_data_help: "Do 8 bajtów danych, DLC wyznaczany automatycznie",
+    gui_frame_dlc: "DLC:",
+    gui_frame_crc_bits: "Bity objęte CRC:",
+    gui_frame_crc: "CRC:",
+    gui_frame_wire: "Na magistrali:",
+    gui_frame_stuff_bits: "Bity wypychania:",
+    gui_fd_brs: "Przełączanie prędkości (BRS)",
+    gui_fd_data_help: "Do 64 bajtów; dane są dopełniane do najbliższej długości z tabeli DLC",
+    gui_fd_length: "Długość danych:",
+    gui_fd_padded: "{} → {} (dopełnienie 0x{})",
+    gui_fd_crc_algorithm: "Algorytm CRC:",
+    gui_modbus_address: "Adres urządzenia:",
+    gui_modbus_function: "Kod funkcji (hex):",
+    gui_modbus_help: "Ramka RTU z CRC może mieć najwyżej {} bajtów",
+    gui_modbus_crc_bytes: "Bajty CRC (młodszy, starszy):",
+    gui_modbus_length: "Długość ramki:",
+    gui_modbus_frame: "Ramka z CRC:",
+    gui_copy: "Kopiuj do schowka",
+    gui_err_frame_id: "Nieprawidłowy identyfikator: {}",
+    gui_err_modbus_address: "Adres musi być liczbą od 0 do {}",
+    gui_err_modbus_function: "Kod funkcji musi być bajtem szesnastkowym",
+    gui_err_modbus_too_long: "Ramka ma {} bajtów, maksimum to {}",
+    gui_custom_section: "⚙ Zaawansowane: własny wielomian",
+    gui_custom_enabled: "Użyj własnych parametrów",
+    gui_custom_from_algorithm: "Skopiuj z wybranego algorytmu",
+    gui_custom_algorithm: "Własny",
+    gui_custom_poly: "Wielomian (hex):",
+    gui_custom_width: "Szerokość:",
+    gui_custom_init: "Wartość początkowa:",
+    gui_custom_xorout: "XOR na wyjściu:",
+    gui_custom_valid: "✔ Poprawny wielomian {}-bitowy, check(\"123456789\") = 0x{}",
+    gui_err_custom_top_bit: "Wielomian 0x{} zawiera człon x^{}, który jest domyślny - wpisz go bez najstarszego bitu: 0x{}",
+    gui_err_custom_value_too_wide: "Wartość początkowa i XOR na wyjściu muszą mieścić się w {} bitach",
+    gui_calculating_progress: "{} / {} iteracji",
+    gui_trace_section: "🔍 Przebieg obliczeń (rejestr po każdym bicie)",
+    gui_trace_per_byte: "Tylko granice bajtów",
+    gui_trace_step: "Krok",
+    gui_trace_index: "Indeks bitu",
+    gui_trace_register: "Rejestr",
+    gui_trace_final: "CRC po refout/xorout: 0x{}",
+    gui_trace_too_long: "Przebieg jest dostępny dla wiadomości do {} bitów",
+    gui_modbus_meaning: "Znaczenie:",
+    gui_fd_non_iso: "Non-ISO (szkic Bosch)",
+    gui_fd_non_iso_hint: "CRC bez bitów wypychania i licznika wypychań, rejestr startuje od zera - jak w kontrolerach sprzed ISO 11898-1:2015",
+    gui_fd_stuff_count: "Licznik wypychań (Gray + parzystość):",
+    gui_live_crc: "⚡ CRC na bieżąco",
+    gui_live_crc_hint: "Przelicza CRC po każdej zmianie poprawnych danych (bez przycisku Oblicz i bez iteracji)",
+    gui_live_too_long: "wyłączone dla {} bitów (maks. {})",
+    gui_save_results: "💾 Zapisz wyniki…",
+    gui_save_results_hint: "Zapisz wynik, historię uruchomień i tabelę wielu wiadomości jako JSON, CSV lub raport Markdown",
+    gui_results_saved: "✅ Zapisano wyniki do '{}'",
+    report_title: "Raport CRC",
+    report_algorithm: "Algorytm",
+    report_parameters: "Parametry",
+    report_input: "Dane wejściowe",
+    report_result: "Wynik",
+    report_value: "Wartość",
+    report_path: "Ścieżka",
+    report_error: "Błąd",
+    report_runs: "Kolejne uruchomienia",
+    report_batch: "Wiele wiadomości",
+    gui_distribution_section: "📊 Rozkład wartości CRC",
+    gui_distribution_bits: "Bity danych:",
+    gui_distribution_compute: "Oblicz rozkład",
+    gui_distribution_invalid: "⚠️ Podaj długość od 1 do {} bitów",
+    gui_distribution_summary: "{} wiadomości ({}), {} kubełków według najstarszych {} bitów CRC, kolizje: {}, χ² = {} (p = {})",
+    gui_distribution_bucket: "Kubełek",
+    gui_distribution_count: "Wiadomości",
+    gui_playground_section: "🧪 Wstrzykiwanie błędów",
+    gui_playground_hint: "Kliknij bit danych lub pola CRC, aby go odwrócić - wynik jest przeliczany od razu",
+    gui_playground_no_input: "ℹ️ Wpisz poprawną wiadomość powyżej",
+    gui_playground_too_long: "ℹ️ Wstrzykiwanie błędów obsługuje wiadomości do {} bitów",
+    gui_playground_data: "Dane:",
+    gui_playground_bit: "Bit {}",
+    gui_playground_flipped: "Odwrócone bity:",
+    gui_playground_original: "CRC wiadomości:",
+    gui_playground_received: "Odebrane pole CRC:",
+    gui_playground_recomputed: "CRC przeliczone z odebranych danych:",
+    gui_playground_none: "ℹ️ Nie odwrócono żadnego bitu",
+    gui_playground_detected: "✅ Błąd wykryty - CRC się nie zgadza",
+    gui_playground_undetected: "❌ Błąd NIEWYKRYTY - odebrana ramka wygląda na poprawną",
+    gui_bits_section: "🔢 Widok bitów",
+    gui_bits_too_long: "ℹ️ Widok bitów obsługuje wiadomości do {} bitów",
+    gui_bits_tooltip: "Bit {} (bajt {}, bit {} w bajcie)\nWartość bajtu: 0x{} = {} = {}",
+    gui_crc_endianness_auto: "według algorytmu",
+    gui_crc_endianness_hint: "Kolejność bajtów dołączanego CRC: big-endian (MSB-first, kolejność bitów CAN) lub little-endian (Modbus)",
+};
+
+static EN: Strings = Strings {
+    app_title: "CAN CRC Calculator",
+    err_prefix: "❌ Error: ",
+    err_empty_input: "Input data is empty",
+    err_invalid_binary_chars: "Invalid characters found: '{}' (first at index {}; allowed: 0, 1, spaces, underscores, dots, 0b prefixes)",
+    err_no_binary_data: "No valid binary data (only 0 and 1)",
+    err_binary_too_long: "Data too long: {} bits (maximum allowed: {} bits)",
+    err_invalid_hex_chars: "Invalid characters found: '{}' (first at index {}; allowed: 0-9, A-F, spaces, commas, colons, dashes, 0x prefixes)",
+    err_no_hex_data: "No valid hex data",
+    err_odd_hex_length: "Odd number of hex characters: {} (an even number is required)",
+    err_hex_too_long: "Data too long: {} bytes = {} bits (maximum: {} bytes = {} bits)",
+    err_invalid_hex_format: "Invalid hex format",
+    err_invalid_width: "Unsupported CRC width: {} (allowed 1-64 bits)",
+    err_poly_too_wide: "Polynomial 0x{} does not fit in {} bits",
+    err_even_poly: "Polynomial 0x{} has no x^0 term",
+    err_invalid_number: "Invalid number: '{}'",
+    err_invalid_data_width: "Unsupported data width: {} (allowed 1-64 bits)",
+    err_id_out_of_range: "Identifier 0x{} does not fit in {} bits",
+    err_frame_data_too_long: "Too much frame data: {} bytes (max. {})",
+    err_dbc_syntax: "DBC syntax error on line {}: '{}'",
+    err_dbc_unknown_message: "Unknown DBC message: '{}'",
+    err_dbc_unknown_signal: "Unknown DBC signal: '{}'",
+    err_dbc_value_out_of_range: "Value {} does not fit in signal '{}'",
+
+    err_image_syntax: "malformed record on line {}",
+    err_image_checksum: "record checksum mismatch on line {}: expected {}, found {}",
+    err_image_unsupported_record: "unsupported record type {} on line {}",
+    err_image_overlap: "data records overlap at address 0x{}",
+    err_image_address_overflow: "data at address 0x{} exceeds the 32-bit address space",
+    err_image_missing_end: "missing end-of-file record",
+    err_invalid_base64_chars: "Invalid base64 characters found: '{}' (first at index {}; allowed: A-Z, a-z, 0-9, +, /, -, _, =)",
+    err_no_base64_data: "No valid base64 data",
+    err_invalid_base64_length: "Invalid base64 length: {} characters",
+    err_partial_byte: "Input has {} bits - whole bytes are required",
+    err_invalid_array_element: "Array element '{}' is not a byte value (0-255)",
+    err_invalid_escape: "Invalid escape sequence '{}' at index {}",
+    err_unterminated_string: "The string opened at index {} is not closed",
+    err_frame_syntax: "Unrecognized CAN frame: '{}'",
+    err_frame_too_short: "Frame too short: {} bytes (min. {})",
+    modbus_fc_read_coils: "Read Coils (0x01)",
+    modbus_fc_read_discrete_inputs: "Read Discrete Inputs (0x02)",
+    modbus_fc_read_holding_registers: "Read Holding Registers (0x03)",
+    modbus_fc_read_input_registers: "Read Input Registers (0x04)",
+    modbus_fc_write_single_coil: "Write Single Coil (0x05)",
+    modbus_fc_write_single_register: "Write Single Register (0x06)",
+    modbus_fc_write_multiple_coils: "Write Multiple Coils (0x0F)",
+    modbus_fc_write_multiple_registers: "Write Multiple Registers (0x10)",
+    modbus_fc_other: "Function 0x{}",
+    modbus_ex_illegal_function: "illegal function",
+    modbus_ex_illegal_data_address: "illegal data address",
+    modbus_ex_illegal_data_value: "illegal data value",
+    modbus_ex_server_failure: "server device failure",
+    modbus_ex_acknowledge: "acknowledge, processing in progress",
+    modbus_ex_server_busy: "server device busy",
+    modbus_ex_memory_parity: "memory parity error",
+    modbus_ex_gateway_path: "gateway path unavailable",
+    modbus_ex_gateway_target: "gateway target device failed to respond",
+    modbus_ex_other: "unknown exception",
+    modbus_pdu_read_request: "{} request: start address {}, count {}",
+    modbus_pdu_bits_response: "{} response: {} bits (LSB first): {}",
+    modbus_pdu_registers_response: "{} response: {} registers: {}",
+    modbus_pdu_write_single: "{}: address {}, value 0x{}",
+    modbus_pdu_write_multiple_request: "{} request: start address {}, count {}, data: {}",
+    modbus_pdu_write_multiple_response: "{} response: wrote {} starting at address {}",
+    modbus_pdu_exception: "❌ Exception for {}: {} (code 0x{})",
+    modbus_pdu_malformed: "{}: unexpected data length ({} bytes)",
+    modbus_pdu_other: "{}: {} data bytes",
+    err_frame_missing_crc: "Frame has {} bits - too few for the CRC field ({} bits)",
+    err_frame_segment_sequence: "Segment {} is out of sequence (sequence number {})",
+    err_frame_capacity: "Fixed-capacity buffer of {} bits is full",
+
+    gui_language: "🌐 Language:",
+    gui_theme_light: "☀ Light",
+    gui_theme_dark: "🌙 Dark",
+    gui_input_format: "📋 Input format:",
+    gui_format_binary: "Binary",
+    gui_format_hex: "Hexadecimal",
+    gui_binary_sequence: "🔢 Binary sequence:",
+    gui_binary_help: "Format: only 0 and 1, at most {} bits",
+    gui_binary_entered: "Entered: {} bits",
+    gui_hex_sequence: "📝 Hex sequence:",
+    gui_hex_help: "Format: AA BB CC DD or an xxd / hexdump -C dump (max. {} bytes = {} bits)",
+    gui_hex_entered: "Entered: {} bytes = {} bits",
+    gui_iterations: "🔄 Iterations:",
+    gui_iterations_range: "(1 to 1 000 000 000)",
+    gui_calculating_button: "⏳ Calculating...",
+    gui_calculate_button: "🚀 Calculate CRC",
+    gui_compare_button: "⚖ Compare: sequential vs parallel",
+    gui_calculating: "Calculating CAN CRC...",
+    gui_results: "📊 Results",
+    gui_crc_dec: "🔢 CRC (decimal):",
+    gui_crc_bin: "🔢 CRC (binary):",
+    gui_duration: "⏱️ Execution time:",
+    gui_avg_time: "⏱️ Average time per CRC:",
+    gui_performance: "⚡ Throughput:",
+    gui_mode: "🔥 Mode:",
+    gui_mode_parallel: "Parallel processing",
+    gui_examples: "📋 Sample data",
+    gui_examples_binary: "Binary examples:",
+    gui_examples_hex: "Hex examples:",
+    gui_examples_iterations: "Iterations:",
+    gui_info_polynomial: "CAN uses a 15-bit CRC with polynomial 0x4599",
+    gui_info_parallel: "Automatic parallel processing for >100k iterations",
+    gui_info_drop: "Drop a binary or text (hex/bin) file onto the window to compute its CRC",
+    gui_last_calculation: "Last calculation: {}ms",
+    gui_drop_overlay: "📂 Drop the file to compute its CRC",
+    gui_err_read_file: "Failed to read file '{}': {}",
+    gui_err_open_file: "Failed to open file '{}': {}",
+    gui_err_no_file_data: "No data for dropped file '{}'",
+    gui_file_loaded: "Loaded file: {} ({} bytes)",
+    gui_file_streamed: "Streamed CRC of file: {} ({} bytes = {} bits)",
+    gui_err_no_bits: "Please enter at least one bit of data.",
+    gui_err_iterations_zero: "The number of iterations must be greater than 0",
+    gui_err_iterations_too_large: "Too many iterations: {} (max. 1 000 000 000)",
+    gui_err_iterations_missing: "Please enter the number of iterations",
+    gui_err_iterations_invalid: "Invalid number of iterations (use digits only)",
+    gui_perf_chart: "📈 Throughput across runs",
+    gui_clear: "🗑 Clear",
+    gui_run: "Run",
+    gui_path_sequential: "Sequential",
+    gui_path_parallel: "Parallel",
+    gui_dbc_section: "📄 Message from a DBC file",
+    gui_dbc_path: "DBC file:",
+    gui_dbc_load: "📂 Load",
+    gui_dbc_hint: "Enter a path or drop a .dbc file onto the window",
+    gui_dbc_loaded: "Loaded {} messages from {}",
+    gui_dbc_message: "Message:",
+    gui_dbc_encode: "🧮 Encode and compute frame CRC",
+    gui_dbc_frame_result: "Frame 0x{} ({}): payload {} → frame CRC 0x{} ({} bits)",
+    gui_distribution_exhaustive: "all",
+    gui_distribution_sampled: "random, seed {}",
+    gui_algorithm: "🧮 Algorithm:",
+    gui_compare_can_only: "Execution path comparison is available for CRC-15/CAN",
+    gui_binary_help_unlimited: "Format: only 0 and 1, no length limit",
+    gui_hex_help_unlimited: "Format: AA BB CC DD or an xxd / hexdump -C dump (no length limit)",
+    gui_file_image: "Computed CRC of memory image: {} ({} bytes, 0x{}..0x{}, gaps filled with 0x{})",
+    gui_format_escaped: "\\x..",
+    gui_escaped_sequence: "📝 Escaped string:",
+    gui_escaped_help: "Format: \\xHH, octal \\NNN, \\n \\r \\t \\0 etc.; quotes and b prefix optional",
+    gui_format_base64: "Base64",
+    gui_base64_sequence: "📝 Base64 data:",
+    gui_base64_help: "Format: base64 (standard or URL-safe, = padding optional)",
+    gui_batch_section: "📋 Multiple messages",
+    gui_batch_hint: "One message per line, in the selected input format",
+    gui_batch_compute: "🚀 Compute all",
+    gui_batch_summary: "Messages: {}, errors: {}",
+    gui_batch_col_input: "Input",
+    gui_batch_col_bits: "Bits",
+    gui_batch_col_time: "Time [ms]",
+    gui_batch_csv_path: "CSV file:",
+    gui_batch_export: "💾 Export CSV",
+    gui_batch_exported: "Saved {} rows to '{}'",
+    gui_err_write_file: "Failed to write file '{}': {}",
+    gui_emit_frame: "📤 Frame with CRC",
+    gui_emit_frame_hint: "Data with the CRC appended in protocol order (CAN: 15 bits + delimiter, Modbus: 2 bytes little-endian) - copied to the clipboard",
+    gui_protocol_generic: "🧮 Generic",
+    gui_protocol_can: "🚌 CAN",
+    gui_protocol_can_fd: "⚡ CAN FD",
+    gui_protocol_modbus: "🏭 Modbus RTU",
+    gui_frame_id: "Identifier (hex):",
+    gui_frame_extended: "Extended (29-bit)",
+    gui_frame_data: "Data (hex):",
+    gui_can_rtr: "Remote frame (RTR)",
+    gui_can_data_help: "Up to 8 data bytes, DLC derived automatically",
+    gui_frame_dlc: "DLC:",
+    gui_frame_crc_bits: "Bits covered by CRC:",
+    gui_frame_crc: "CRC:",
+    gui_frame_wire: "On the bus:",
+    gui_frame_stuff_bits: "Stuff bits:",
+    gui_fd_brs: "Bit rate switch (BRS)",
+    gui_fd_data_help: "Up to 64 bytes; data is padded to the next length in the DLC table",
+    gui_fd_length: "Data length:",
+    gui_fd_padded: "{} → {} (padded with 0x{})",
+    gui_fd_crc_algorithm: "CRC algorithm:",
+    gui_modbus_address: "Slave address:",
+    gui_modbus_function: "Function code (hex):",
+    gui_modbus_help: "An RTU frame including the CRC may be at most {} bytes",
+    gui_modbus_crc_bytes: "CRC bytes (low, high):",
+    gui_modbus_length: "Frame length:",
+    gui_modbus_frame: "Frame with CRC:",
+    gui_copy: "Copy to clipboard",
+    gui_err_frame_id: "Invalid identifier: {}",
+    gui_err_modbus_address: "Address must be a number from 0 to {}",
+    gui_err_modbus_function: "Function code must be a hex byte",
+    gui_err_modbus_too_long: "Frame is {} bytes, the maximum is {}",
+    gui_custom_section: "⚙ Advanced: custom polynomial",
+    gui_custom_enabled: "Use custom parameters",
+    gui_custom_from_algorithm: "Copy from selected algorithm",
+    gui_custom_algorithm: "Custom",
+    gui_custom_poly: "Polynomial (hex):",
+    gui_custom_width: "Width:",
+    gui_custom_init: "Init:",
+    gui_custom_xorout: "Xorout:",
+    gui_custom_valid: "✔ Valid {}-bit polynomial, check(\"123456789\") = 0x{}",
+    gui_err_custom_top_bit: "Polynomial 0x{} includes the implicit x^{} term - enter it without the top bit: 0x{}",
+    gui_err_custom_value_too_wide: "Init and xorout must fit in {} bits",
+    gui_calculating_progress: "{} / {} iterations",
+    gui_trace_section: "🔍 Step-by-step trace (register after each bit)",
+    gui_trace_per_byte: "Byte boundaries only",
+    gui_trace_step: "Step",
+    gui_trace_index: "Bit index",
+    gui_trace_register: "Register",
+    gui_trace_final: "CRC after refout/xorout: 0x{}",
+    gui_trace_too_long: "The trace is available for messages up to {} bits",
+    gui_modbus_meaning: "Meaning:",
+    gui_fd_non_iso: "Non-ISO (Bosch draft)",
+    gui_fd_non_iso_hint: "CRC without stuff bits or stuff count, register starting at zero - as in controllers predating ISO 11898-1:2015",
+    gui_fd_stuff_count: "Stuff count (Gray + parity):",
+    gui_live_crc: "⚡ Live CRC",
+    gui_live_crc_hint: "Recomputes the CRC after every edit of valid input (no Calculate button, no iterations)",
+    gui_live_too_long: "off for {} bits (max. {})",
+    gui_save_results: "💾 Save results…",
+    gui_save_results_hint: "Save the result, run history and multi-message table as JSON, CSV or a Markdown report",
+    gui_results_saved: "✅ Results saved to '{}'",
+    report_title: "CRC report",
+    report_algorithm: "Algorithm",
+    report_parameters: "Parameters",
+    report_input: "Input",
+    report_result: "Result",
+    report_value: "Value",
+    report_path: "Path",
+    report_error: "Error",
+    report_runs: "Runs",
+    report_batch: "Multiple messages",
+    gui_distribution_section: "📊 CRC value distribution",
+    gui_distribution_bits: "Data bits:",
+    gui_distribution_compute: "Compute distribution",
+    gui_distribution_invalid: "⚠️ Enter a length from 1 to {} bits",
+    gui_distribution_summary: "{} messages ({}), {} buckets by the top {} CRC bits, collisions: {}, χ² = {} (p = {})",
+    gui_distribution_bucket: "Bucket",
+    gui_distribution_count: "Messages",
+    gui_playground_section: "🧪 Error injection playground",
+    gui_playground_hint: "Click a data or CRC bit to flip it - the result is recomputed immediately",
+    gui_playground_no_input: "ℹ️ Enter a valid message above",
+    gui_playground_too_long: "ℹ️ The playground handles messages up to {} bits",
+    gui_playground_data: "Data:",
+    gui_playground_bit: "Bit {}",
+    gui_playground_flipped: "Flipped bits:",
+    gui_playground_original: "Message CRC:",
+    gui_playground_received: "Received CRC field:",
+    gui_playground_recomputed: "CRC recomputed from received data:",
+    gui_playground_none: "ℹ️ No bits flipped",
+    gui_playground_detected: "✅ Corruption detected - the CRC does not match",
+    gui_playground_undetected: "❌ Corruption UNDETECTED - the received frame looks valid",
+    gui_bits_section: "🔢 Bit view",
+    gui_bits_too_long: "ℹ️ The bit view shows messages up to {} bits",
+    gui_bits_tooltip: "Bit {} (byte {}, bit {} of the byte)\nByte value: 0x{} = {} = {}",
+    gui_crc_endianness_auto: "per algorithm",
+    gui_crc_endianness_hint: "Byte order of the appended CRC: big-endian (MSB-first, CAN bit order) or little-endian (Modbus)",
+};
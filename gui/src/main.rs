@@ -0,0 +1,2287 @@
+mod i18n;
+
+use eframe::egui;
+use egui_plot::{Bar, BarChart, Legend, Line, Plot, PlotPoints, Points};
+use can_crc_core::dbc::Dbc;
+use can_crc_core::distribution::{crc_distribution, Distribution, DEFAULT_BUCKET_BITS, DEFAULT_SAMPLES};
+use can_crc_core::engine::parse_param;
+use can_crc_core::inject::check_flips;
+use can_crc_core::frame::{emit_frame, format_wire_bits, stuff_count_bits, CanFdFrame, CanFrame, FD_PADDING_BYTE};
+use can_crc_core::modbus::{decode_adu, rtu_frame, MAX_ADU_LEN, MAX_SLAVE_ADDRESS};
+use can_crc_core::image::{ImageFormat, DEFAULT_FILL};
+use can_crc_core::{
+    bits_to_bytes, format_base64, format_binary, format_escaped, format_hex, parse_base64_bytes, parse_escaped_bytes, parse_hex_bytes, parse_base64_input_with, parse_escaped_input_with, parse_binary_input_with, parse_hex_input_with, compute_batch_checksums, compute_batch_checksums_with_progress, compute_batch_crcs_parallel,
+    compute_batch_crcs_sequential, checksum_reader, CrcAlgorithm, CrcCatalog, CrcConfig, CrcResult, CrcStep, Endianness, Implementation,
+    base64_error_spans, binary_error_spans, error_snippet, escaped_error_spans, hex_error_spans, ParseError, ParseOptions,
+};
+use crate::i18n::{fill, Lang, Strings};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, Read};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const SETTINGS_KEY: &str = "can_crc_settings";
+const MAX_TEXT_FILE_BYTES: u64 = 1024;
+const DEFAULT_BATCH_CSV: &str = "crc_results.csv";
+const DEFAULT_RESULTS_FILE: &str = "crc_report.json";
+const TRACE_MAX_BITS: usize = 4096;
+const PLAYGROUND_MAX_BITS: usize = 512;
+const DISTRIBUTION_MAX_BITS: usize = 128;
+const BIT_VIEW_MAX_BITS: usize = 1024;
+const LIVE_MAX_BITS: usize = 4096;
+const LIVE_DEBOUNCE: Duration = Duration::from_millis(150);
+
+fn main() -> Result<(), eframe::Error> {
+    let title = Lang::from_env().strings().app_title;
+    let options = eframe::NativeOptions {
+        viewport: egui::ViewportBuilder::default()
+            .with_inner_size([900.0, 850.0])
+            .with_title(title),
+        ..Default::default()
+    };
+    
+    eframe::run_native(
+        "Kalkulator CRC CAN",
+        options,
+        Box::new(|cc| Ok(Box::new(CanCrcApp::new(cc)))),
+    )
+}
+
+struct Calculation {
+    iterations: u64,
+    bits: usize,
+    config: CrcConfig,
+    progress: Arc<AtomicU64>,
+    receiver: mpsc::Receiver<(u64, f64)>,
+}
+
+struct LiveCrc {
+    input: (InputFormat, String, Option<CrcConfig>),
+    changed_at: Instant,
+    state: LiveState,
+}
+
+enum LiveState {
+    Pending,
+    Invalid,
+    TooLong(usize),
+    Ready(CrcConfig, u64),
+}
+
+#[derive(Default)]
+struct Playground {
+    source: Option<(InputFormat, String, CrcConfig)>,
+    flipped: BTreeSet<usize>,
+}
+
+#[derive(Default)]
+struct CanCrcApp {
+    input_format: InputFormat,
+    binary_input: String,
+    hex_input: String,
+    base64_input: String,
+    escaped_input: String,
+    iterations_input: String,
+    result: Option<CrcResult>,
+    error_message: String,
+    is_calculating: bool,
+    calculation: Option<Calculation>,
+    trace: Option<(CrcConfig, Vec<CrcStep>)>,
+    trace_per_byte: bool,
+    live_crc: bool,
+    live: Option<LiveCrc>,
+    playground: Playground,
+    distribution_bits: String,
+    distribution: Option<Result<Distribution, String>>,
+    last_calculation_time: Option<f64>,
+    loaded_file: Option<String>,
+    perf_history: Vec<PerfSample>,
+    theme: Theme,
+    lang: Lang,
+    dbc: Option<Dbc>,
+    dbc_path: String,
+    dbc_status: String,
+    dbc_message: usize,
+    dbc_values: Vec<f64>,
+    frame_summary: Option<String>,
+    algorithm: usize,
+    batch_input: String,
+    batch_rows: Vec<BatchRow>,
+    batch_sort: Option<(BatchColumn, bool)>,
+    batch_csv_path: String,
+    batch_status: String,
+    results_status: String,
+    emitted_frame: Option<String>,
+    emit_endianness: Option<Endianness>,
+    custom: CustomParams,
+    protocol: Protocol,
+    can_id: String,
+    can_extended: bool,
+    can_rtr: bool,
+    can_data: String,
+    fd_id: String,
+    fd_extended: bool,
+    fd_brs: bool,
+    fd_non_iso: bool,
+    fd_data: String,
+    modbus_address: String,
+    modbus_function: String,
+    modbus_data: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+enum Theme {
+    #[default]
+    Dark,
+    Light,
+}
+
+impl Theme {
+    fn visuals(self) -> egui::Visuals {
+        match self {
+            Theme::Dark => egui::Visuals::dark(),
+            Theme::Light => egui::Visuals::light(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct Settings {
+    input_format: InputFormat,
+    iterations_input: String,
+    theme: Theme,
+    lang: String,
+    algorithm: String,
+    protocol: Protocol,
+    custom: CustomParams,
+    live_crc: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            input_format: InputFormat::default(),
+            iterations_input: String::new(),
+            theme: Theme::default(),
+            lang: String::new(),
+            algorithm: String::new(),
+            protocol: Protocol::default(),
+            custom: CustomParams::default(),
+            live_crc: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct CustomParams {
+    enabled: bool,
+    poly: String,
+    width: String,
+    init: String,
+    refin: bool,
+    refout: bool,
+    xorout: String,
+}
+
+impl Default for CustomParams {
+    fn default() -> Self {
+        Self::from_config(&CrcConfig::CAN)
+    }
+}
+
+impl CustomParams {
+    fn from_config(config: &CrcConfig) -> Self {
+        Self {
+            enabled: false,
+            poly: format!("0x{:X}", config.poly),
+            width: config.width.to_string(),
+            init: format!("0x{:X}", config.init),
+            refin: config.refin,
+            refout: config.refout,
+            xorout: format!("0x{:X}", config.xorout),
+        }
+    }
+    
+    fn to_config(&self, lang: Lang) -> Result<CrcConfig, String> {
+        let t = lang.strings();
+        let number = |value: &str| parse_param(value).map_err(|e| lang.describe_config(&e));
+        let width = number(&self.width)?;
+        let width = u8::try_from(width).map_err(|_| fill(lang.strings().err_invalid_width, &[&width]))?;
+        let config = CrcConfig {
+            width,
+            poly: number(&self.poly)?,
+            init: number(&self.init)?,
+            refin: self.refin,
+            refout: self.refout,
+            xorout: number(&self.xorout)?,
+        };
+        if (1..64).contains(&width) && config.poly >> width == 1 {
+            let trimmed = config.poly & config.mask();
+            return Err(fill(t.gui_err_custom_top_bit, &[&format!("{:X}", config.poly), &width, &format!("{:X}", trimmed)]));
+        }
+        config.validate().map_err(|e| lang.describe_config(&e))?;
+        if config.init & !config.mask() != 0 || config.xorout & !config.mask() != 0 {
+            return Err(fill(t.gui_err_custom_value_too_wide, &[&width]));
+        }
+        Ok(config)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+enum Protocol {
+    #[default]
+    Generic,
+    Can,
+    CanFd,
+    Modbus,
+}
+
+impl Protocol {
+    const ALL: [Protocol; 4] = [Protocol::Generic, Protocol::Can, Protocol::CanFd, Protocol::Modbus];
+    
+    fn label(self, lang: Lang) -> &'static str {
+        let t = lang.strings();
+        match self {
+            Protocol::Generic => t.gui_protocol_generic,
+            Protocol::Can => t.gui_protocol_can,
+            Protocol::CanFd => t.gui_protocol_can_fd,
+            Protocol::Modbus => t.gui_protocol_modbus,
+        }
+    }
+}
+
+fn implementation_label(implementation: Implementation, lang: Lang) -> &'static str {
+    match implementation {
+        Implementation::Sequential => lang.strings().gui_path_sequential,
+        Implementation::Parallel => lang.strings().gui_path_parallel,
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct PerfSample {
+    run: usize,
+    path: Implementation,
+    throughput: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BatchColumn {
+    Input,
+    Bits,
+    Crc,
+    Time,
+}
+
+#[derive(Clone)]
+struct BatchRow {
+    input: String,
+    bits: usize,
+    crc: Result<CrcResult, String>,
+}
+
+impl BatchRow {
+    fn compare(&self, other: &BatchRow, column: BatchColumn) -> Ordering {
+        let crc = |row: &BatchRow| row.crc.as_ref().ok().map(|result| result.crc_value);
+        let time = |row: &BatchRow| row.crc.as_ref().ok().map(|result| result.duration_ms);
+        match column {
+            BatchColumn::Input => self.input.cmp(&other.input),
+            BatchColumn::Bits => self.bits.cmp(&other.bits),
+            BatchColumn::Crc => crc(self).cmp(&crc(other)),
+            BatchColumn::Time => time(self).partial_cmp(&time(other)).unwrap_or(Ordering::Equal),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReportFormat {
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl ReportFormat {
+    fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("csv") => ReportFormat::Csv,
+            Some("md" | "markdown") => ReportFormat::Markdown,
+            _ => ReportFormat::Json,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ResultsReport {
+    algorithm: Option<String>,
+    config: CrcConfig,
+    input_format: InputFormat,
+    input: String,
+    bits: usize,
+    result: Option<CrcResult>,
+    runs: Vec<PerfSample>,
+    batch: Vec<BatchRecord>,
+}
+
+#[derive(Serialize)]
+struct BatchRecord {
+    input: String,
+    bits: usize,
+    crc: Option<CrcResult>,
+    error: Option<String>,
+}
+
+impl From<&BatchRow> for BatchRecord {
+    fn from(row: &BatchRow) -> Self {
+        Self {
+            input: row.input.clone(),
+            bits: row.bits,
+            crc: row.crc.as_ref().ok().cloned(),
+            error: row.crc.as_ref().err().cloned(),
+        }
+    }
+}
+
+impl ResultsReport {
+    fn csv(&self) -> String {
+        let mut rows: Vec<BatchRow> = self.result.iter()
+            .map(|result| BatchRow { input: self.input.clone(), bits: self.bits, crc: Ok(result.clone()) })
+            .collect();
+        rows.extend(self.batch.iter().map(|record| BatchRow {
+            input: record.input.clone(),
+            bits: record.bits,
+            crc: record.crc.clone().ok_or_else(|| record.error.clone().unwrap_or_default()),
+        }));
+        batch_csv(&rows)
+    }
+    
+    fn markdown(&self, lang: Lang) -> String {
+        let t = lang.strings();
+        let c = &self.config;
+        let mut out = format!("# {}\n\n", t.report_title);
+        let _ = writeln!(out, "- **{}:** {}", t.report_algorithm, self.algorithm.as_deref().unwrap_or(t.gui_custom_algorithm));
+        let _ = writeln!(
+            out,
+            "- **{}:** width={} poly=0x{:X} init=0x{:X} refin={} refout={} xorout=0x{:X}",
+            t.report_parameters, c.width, c.poly, c.init, c.refin, c.refout, c.xorout
+        );
+        if !self.input.is_empty() {
+            let _ = writeln!(out, "- **{}:** `{}` ({} {})", t.report_input, self.input, self.bits, t.gui_batch_col_bits);
+        }
+        
+        if let Some(result) = &self.result {
+            let _ = writeln!(out, "\n## {}\n\n| | {} |\n|---|---|", t.report_result, t.report_value);
+            let _ = writeln!(out, "| CRC (hex) | `0x{}` |", result.crc_hex);
+            let _ = writeln!(out, "| CRC (dec) | `{}` |", result.crc_value);
+            let _ = writeln!(out, "| CRC (bin) | `{}` |", result.crc_bin());
+            let _ = writeln!(out, "| {} | {:.3} |", t.gui_batch_col_time, result.duration_ms);
+        }
+        
+        if !self.runs.is_empty() {
+            let _ = writeln!(out, "\n## {}\n\n| {} | {} | CRC/s |\n|---:|---|---:|", t.report_runs, t.gui_run, t.report_path);
+            for sample in &self.runs {
+                let _ = writeln!(out, "| {} | {} | {:.0} |", sample.run, implementation_label(sample.path, lang), sample.throughput);
+            }
+        }
+        
+        if !self.batch.is_empty() {
+            let _ = writeln!(
+                out,
+                "\n## {}\n\n| {} | {} | CRC | {} | {} |\n|---|---:|---|---:|---|",
+                t.report_batch, t.gui_batch_col_input, t.gui_batch_col_bits, t.gui_batch_col_time, t.report_error
+            );
+            for record in &self.batch {
+                let (crc, time) = record.crc.as_ref()
+                    .map_or((String::new(), String::new()), |result| (format!("`0x{}`", result.crc_hex), format!("{:.3}", result.duration_ms)));
+                let _ = writeln!(
+                    out,
+                    "| `{}` | {} | {} | {} | {} |",
+                    markdown_cell(&record.input), record.bits, crc, time, markdown_cell(record.error.as_deref().unwrap_or_default())
+                );
+            }
+        }
+        out
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+enum InputFormat {
+    Binary,
+    #[default]
+    Hex,
+    Base64,
+    Escaped,
+}
+
+impl eframe::App for CanCrcApp {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        let settings = Settings {
+            input_format: self.input_format,
+            iterations_input: self.iterations_input.clone(),
+            theme: self.theme,
+            lang: self.lang.code().to_string(),
+            algorithm: self.algorithm().name.to_string(),
+            protocol: self.protocol,
+            custom: self.custom.clone(),
+            live_crc: self.live_crc,
+        };
+        eframe::set_value(storage, SETTINGS_KEY, &settings);
+    }
+    
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        let t = self.lang.strings();
+        self.handle_dropped_files(ctx);
+        self.poll_calculation();
+        self.update_live_crc(ctx);
+        
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.heading(t.app_title);
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let previous = self.theme;
+                        ui.selectable_value(&mut self.theme, Theme::Light, t.gui_theme_light);
+                        ui.selectable_value(&mut self.theme, Theme::Dark, t.gui_theme_dark);
+                        if self.theme != previous {
+                            ctx.set_visuals(self.theme.visuals());
+                        }
+                        
+                        ui.separator();
+                        let previous_lang = self.lang;
+                        egui::ComboBox::from_id_source("language")
+                            .selected_text(self.lang.name())
+                            .show_ui(ui, |ui| {
+                                for lang in Lang::ALL {
+                                    ui.selectable_value(&mut self.lang, lang, lang.name());
+                                }
+                            });
+                        ui.label(t.gui_language);
+                        if self.lang != previous_lang {
+                            let title = self.lang.strings().app_title.to_string();
+                            ctx.send_viewport_cmd(egui::ViewportCommand::Title(title));
+                        }
+                    });
+                });
+                ui.add_space(10.0);
+                ui.separator();
+                ui.add_space(10.0);
+                
+                ui.horizontal(|ui| {
+                    for protocol in Protocol::ALL {
+                        ui.selectable_value(&mut self.protocol, protocol, protocol.label(self.lang));
+                    }
+                });
+                ui.add_space(15.0);
+                
+                if self.protocol != Protocol::Generic {
+                    self.show_protocol_tab(ui);
+                    return;
+                }
+                
+                ui.horizontal(|ui| {
+                    ui.label(t.gui_algorithm);
+                    let selected = if self.custom.enabled { t.gui_custom_algorithm } else { self.algorithm().name };
+                    ui.add_enabled_ui(!self.custom.enabled, |ui| {
+                        egui::ComboBox::from_id_source("algorithm")
+                            .selected_text(selected)
+                            .show_ui(ui, |ui| {
+                                for (index, algorithm) in CrcCatalog::all().iter().enumerate() {
+                                    ui.selectable_value(&mut self.algorithm, index, algorithm.name);
+                                }
+                            });
+                    });
+                    if let Ok(config) = self.config() {
+                        ui.small(format!(
+                            "poly=0x{:X} init=0x{:X} refin={} refout={} xorout=0x{:X}",
+                            config.poly, config.init, config.refin, config.refout, config.xorout
+                        ));
+                    }
+                });
+                
+                self.show_custom_panel(ui);
+                
+                ui.add_space(10.0);
+                
+                ui.horizontal(|ui| {
+                    ui.label(t.gui_input_format);
+                    ui.radio_value(&mut self.input_format, InputFormat::Binary, t.gui_format_binary);
+                    ui.radio_value(&mut self.input_format, InputFormat::Hex, t.gui_format_hex);
+                    ui.radio_value(&mut self.input_format, InputFormat::Base64, t.gui_format_base64);
+                    ui.radio_value(&mut self.input_format, InputFormat::Escaped, t.gui_format_escaped);
+                });
+                
+                ui.add_space(10.0);
+                
+                let options = ParseOptions::for_config(&self.display_config());
+                let format = self.input_format;
+                let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
+                    let spans = input_problem(format, text, &options).map(|(_, spans)| spans).unwrap_or_default();
+                    highlight_spans(ui, text, &spans, wrap_width)
+                };
+                match self.input_format {
+                    InputFormat::Binary => {
+                        ui.horizontal(|ui| {
+                            ui.label(t.gui_binary_sequence);
+                            let response = ui.add(egui::TextEdit::singleline(&mut self.binary_input)
+                                .desired_width(400.0)
+                                .hint_text("101010111100...")
+                                .layouter(&mut layouter));
+                            
+                            if response.changed() {
+                                self.binary_input = self.binary_input.chars()
+                                    .filter(|c| c.is_whitespace() || matches!(c, '0' | '1' | '_' | '.' | 'b' | 'B'))
+                                    .collect();
+                            }
+                            if let Some((error, spans)) = input_problem(format, &self.binary_input, &options) {
+                                show_input_error(ui, self.lang, &self.binary_input, &error, &spans);
+                            }
+                        });
+                        match CrcCatalog::max_input_bits(&self.display_config()) {
+                            Some(max_bits) => ui.small(fill(t.gui_binary_help, &[&max_bits])),
+                            None => ui.small(t.gui_binary_help_unlimited),
+                        };
+                        
+                        let unlimited = ParseOptions { max_bits: None, ..ParseOptions::default() };
+                        let bit_count = parse_binary_input_with(&self.binary_input, &unlimited).map_or(0, |bits| bits.len());
+                        if bit_count > 0 {
+                            ui.small(fill(t.gui_binary_entered, &[&bit_count]));
+                        }
+                    }
+                    InputFormat::Hex => {
+                        ui.horizontal(|ui| {
+                            ui.label(t.gui_hex_sequence);
+                            let response = ui.add(egui::TextEdit::multiline(&mut self.hex_input)
+                                .desired_width(400.0)
+                                .desired_rows(1)
+                                .hint_text("AA BB CC DD")
+                                .layouter(&mut layouter));
+                            
+                            if response.changed() {
+                                self.hex_input = self.hex_input.to_uppercase();
+                            }
+                            if let Some((error, spans)) = input_problem(format, &self.hex_input, &options) {
+                                show_input_error(ui, self.lang, &self.hex_input, &error, &spans);
+                            }
+                        });
+                        match CrcCatalog::max_input_bits(&self.display_config()) {
+                            Some(max_bits) => ui.small(fill(t.gui_hex_help, &[&(max_bits / 8), &max_bits])),
+                            None => ui.small(t.gui_hex_help_unlimited),
+                        };
+                        
+                        if let Ok(bytes) = parse_hex_bytes(&self.hex_input) {
+                            ui.small(fill(t.gui_hex_entered, &[&bytes.len(), &(bytes.len() * 8)]));
+                        }
+                    }
+                    InputFormat::Base64 => {
+                        ui.horizontal(|ui| {
+                            ui.label(t.gui_base64_sequence);
+                            ui.add(egui::TextEdit::singleline(&mut self.base64_input)
+                                .desired_width(400.0)
+                                .hint_text("ESIzRA==")
+                                .layouter(&mut layouter));
+                            if let Some((error, spans)) = input_problem(format, &self.base64_input, &options) {
+                                show_input_error(ui, self.lang, &self.base64_input, &error, &spans);
+                            }
+                        });
+                        ui.small(t.gui_base64_help);
+                        
+                        if let Ok(bytes) = parse_base64_bytes(&self.base64_input) {
+                            ui.small(fill(t.gui_hex_entered, &[&bytes.len(), &(bytes.len() * 8)]));
+                        }
+                    }
+                    InputFormat::Escaped => {
+                        ui.horizontal(|ui| {
+                            ui.label(t.gui_escaped_sequence);
+                            ui.add(egui::TextEdit::singleline(&mut self.escaped_input)
+                                .desired_width(400.0)
+                                .hint_text("\"\\x01\\x04\\x00\\x00\"")
+                                .layouter(&mut layouter));
+                            if let Some((error, spans)) = input_problem(format, &self.escaped_input, &options) {
+                                show_input_error(ui, self.lang, &self.escaped_input, &error, &spans);
+                            }
+                        });
+                        ui.small(t.gui_escaped_help);
+                        
+                        if let Ok(bytes) = parse_escaped_bytes(&self.escaped_input) {
+                            ui.small(fill(t.gui_hex_entered, &[&bytes.len(), &(bytes.len() * 8)]));
+                        }
+                    }
+                }
+                
+                self.show_bit_view(ui);
+                
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.live_crc, t.gui_live_crc).on_hover_text(t.gui_live_crc_hint);
+                    match self.live.as_ref().map(|live| &live.state) {
+                        Some(LiveState::Ready(config, crc)) => {
+                            let digits = (config.width as usize).div_ceil(4);
+                            ui.label(egui::RichText::new(format!("0x{:0digits$X}", crc)).code().strong());
+                            ui.small(format!("({})", crc));
+                        }
+                        Some(LiveState::TooLong(bits)) => {
+                            ui.small(fill(t.gui_live_too_long, &[bits, &LIVE_MAX_BITS]));
+                        }
+                        _ => {}
+                    }
+                });
+                
+                ui.add_space(10.0);
+                
+                ui.horizontal(|ui| {
+                    ui.label(t.gui_iterations);
+                    let response = ui.add(egui::TextEdit::singleline(&mut self.iterations_input)
+                        .desired_width(150.0)
+                        .hint_text("1000000"));
+                    
+                    if response.changed() {
+                        self.iterations_input = self.iterations_input.chars()
+                            .filter(|c| c.is_ascii_digit())
+                            .collect();
+                    }
+                    
+                    ui.label(t.gui_iterations_range);
+                });
+                
+                ui.add_space(10.0);
+                self.show_batch_panel(ui);
+                self.show_playground_panel(ui);
+                self.show_distribution_panel(ui);
+                
+                ui.add_space(15.0);
+                            
+                let calc_button = egui::Button::new(if self.is_calculating { 
+                    t.gui_calculating_button
+                } else { 
+                    t.gui_calculate_button
+                }).min_size(egui::vec2(120.0, 30.0));
+                
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(!self.is_calculating, calc_button).clicked() {
+                        self.calculate_crc();
+                    }
+                    
+                    let compare_button = egui::Button::new(t.gui_compare_button)
+                        .min_size(egui::vec2(120.0, 30.0));
+                    let can_selected = self.config() == Ok(CrcConfig::CAN);
+                    if ui.add_enabled(!self.is_calculating && can_selected, compare_button)
+                        .on_disabled_hover_text(t.gui_compare_can_only)
+                        .clicked()
+                    {
+                        self.compare_execution_paths();
+                    }
+                });
+                
+                if let Some(calculation) = &self.calculation {
+                    let done = calculation.progress.load(AtomicOrdering::Relaxed);
+                    ui.add_space(5.0);
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(t.gui_calculating);
+                        ui.add(egui::ProgressBar::new(done as f32 / calculation.iterations.max(1) as f32)
+                            .desired_width(250.0)
+                            .text(fill(t.gui_calculating_progress, &[&done, &calculation.iterations])));
+                    });
+                }
+                
+                ui.add_space(15.0);
+                
+                if let Some(file_info) = &self.loaded_file {
+                    ui.horizontal(|ui| {
+                        ui.label("📂");
+                        ui.label(file_info);
+                    });
+                    ui.add_space(10.0);
+                }
+                
+                if !self.error_message.is_empty() {
+                    ui.group(|ui| {
+                        ui.colored_label(egui::Color32::from_rgb(255, 100, 100), self.lang.error(&self.error_message));
+                    });
+                    ui.add_space(10.0);
+                }
+                
+                let mut emit_frame_clicked = false;
+                let mut save_results_clicked = false;
+                if let Some(result) = &self.result {
+                    ui.separator();
+                    ui.add_space(10.0);
+                    ui.heading(t.gui_results);
+                    ui.add_space(10.0);
+                    
+                    egui::Grid::new("results_grid")
+                        .num_columns(2)
+                        .spacing([20.0, 8.0])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.label("🎯 CRC (hex):");
+                            ui.code(format!("0x{}", result.crc_hex));
+                            ui.end_row();
+                            
+                            ui.label(t.gui_crc_dec);
+                            ui.code(format!("{}", result.crc_value));
+                            ui.end_row();
+                            
+                            ui.label(t.gui_crc_bin);
+                            ui.code(result.crc_bin());
+                            ui.end_row();
+                            
+                            ui.label(t.gui_duration);
+                            ui.code(format!("{:.3} ms", result.duration_ms));
+                            ui.end_row();
+                            
+                            if let Ok(iterations) = self.iterations_input.parse::<u64>() {
+                                if iterations > 1 {
+                                    let avg_time = result.duration_ms / iterations as f64;
+                                    ui.label(t.gui_avg_time);
+                                    ui.code(format!("{:.6} ms ({:.3} µs)", avg_time, avg_time * 1000.0));
+                                    ui.end_row();
+                                    
+                                    let ops_per_sec = (iterations as f64 / result.duration_ms) * 1000.0;
+                                    ui.label(t.gui_performance);
+                                    ui.code(format!("{} CRC/s", format_number(ops_per_sec as u64)));
+                                    ui.end_row();
+                                    
+                                    if iterations >= 100_000 {
+                                        ui.label(t.gui_mode);
+                                        ui.code(t.gui_mode_parallel);
+                                        ui.end_row();
+                                    }
+                                }
+                            }
+                        });
+                    
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        if ui.button(t.gui_emit_frame).on_hover_text(t.gui_emit_frame_hint).clicked() {
+                            emit_frame_clicked = true;
+                        }
+                        let endianness_name = |endianness: Option<Endianness>| match endianness {
+                            None => t.gui_crc_endianness_auto,
+                            Some(Endianness::Big) => "big-endian",
+                            Some(Endianness::Little) => "little-endian",
+                        };
+                        egui::ComboBox::from_id_source("emit_endianness")
+                            .selected_text(endianness_name(self.emit_endianness))
+                            .show_ui(ui, |ui| {
+                                for endianness in [None, Some(Endianness::Big), Some(Endianness::Little)] {
+                                    ui.selectable_value(&mut self.emit_endianness, endianness, endianness_name(endianness));
+                                }
+                            })
+                            .response
+                            .on_hover_text(t.gui_crc_endianness_hint);
+                        if let Some(frame) = &self.emitted_frame {
+                            ui.code(frame);
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        if ui.button(t.gui_save_results).on_hover_text(t.gui_save_results_hint).clicked() {
+                            save_results_clicked = true;
+                        }
+                        if !self.results_status.is_empty() {
+                            ui.small(&self.results_status);
+                        }
+                    });
+                    self.show_trace(ui);
+                }
+                
+                if emit_frame_clicked {
+                    self.emit_frame(ui.ctx());
+                }
+                if save_results_clicked {
+                    self.save_results();
+                }
+                
+                if !self.perf_history.is_empty() {
+                    ui.add_space(10.0);
+                    self.show_perf_chart(ui);
+                }
+                
+                ui.add_space(20.0);
+                ui.separator();
+                ui.add_space(10.0);
+                
+                ui.heading(t.gui_examples);
+                ui.add_space(10.0);
+                
+                ui.label(t.gui_examples_binary);
+                ui.horizontal(|ui| {
+                    if ui.button("10101010").clicked() {
+                        self.binary_input = "10101010".to_string();
+                        self.input_format = InputFormat::Binary;
+                    }
+                    if ui.button("11110000 11110000").clicked() {
+                        self.binary_input = "11110000 11110000".to_string();
+                        self.input_format = InputFormat::Binary;
+                    }
+                    if ui.button("10011001 10011001 10011001").clicked() {
+                        self.binary_input = "10011001 10011001 10011001".to_string();
+                        self.input_format = InputFormat::Binary;
+                    }
+                });
+                
+                ui.add_space(5.0);
+                ui.label(t.gui_examples_hex);
+                ui.horizontal(|ui| {
+                    if ui.button("AA").clicked() {
+                        self.hex_input = "AA".to_string();
+                        self.input_format = InputFormat::Hex;
+                    }
+                    if ui.button("01 04 00 00").clicked() {
+                        self.hex_input = "01 04 00 00".to_string();
+                        self.input_format = InputFormat::Hex;
+                    }
+                    if ui.button("FF EE DD CC BB AA").clicked() {
+                        self.hex_input = "FF EE DD CC BB AA".to_string();
+                        self.input_format = InputFormat::Hex;
+                    }
+                });
+                
+                ui.add_space(5.0);
+                ui.label(t.gui_examples_iterations);
+                ui.horizontal(|ui| {
+                    if ui.button("1 000").clicked() {
+                        self.iterations_input = "1000".to_string();
+                    }
+                    if ui.button("100 000").clicked() {
+                        self.iterations_input = "100000".to_string();
+                    }
+                    if ui.button("1 000 000").clicked() {
+                        self.iterations_input = "1000000".to_string();
+                    }
+                    if ui.button("10 000 000").clicked() {
+                        self.iterations_input = "10000000".to_string();
+                    }
+                });
+                
+                ui.add_space(15.0);
+                
+                ui.separator();
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    ui.label("💡");
+                    ui.label(t.gui_info_polynomial);
+                });
+                ui.horizontal(|ui| {
+                    ui.label("⚡");
+                    ui.label(t.gui_info_parallel);
+                });
+                ui.horizontal(|ui| {
+                    ui.label("📂");
+                    ui.label(t.gui_info_drop);
+                });
+                
+                if let Some(calc_time) = self.last_calculation_time {
+                    ui.horizontal(|ui| {
+                        ui.label("⏰");
+                        ui.label(fill(t.gui_last_calculation, &[&format!("{:.1}", calc_time)]));
+                    });
+                }
+            });
+        });
+        
+        if self.is_calculating {
+            ctx.request_repaint();
+        }
+        
+        Self::paint_drop_overlay(ctx, t);
+    }
+}
+
+impl CanCrcApp {
+    fn algorithm(&self) -> &'static CrcAlgorithm {
+        CrcCatalog::all().get(self.algorithm).unwrap_or(CrcCatalog::can())
+    }
+    
+    fn config(&self) -> Result<CrcConfig, String> {
+        if self.custom.enabled {
+            self.custom.to_config(self.lang)
+        } else {
+            Ok(self.algorithm().config)
+        }
+    }
+    
+    fn display_config(&self) -> CrcConfig {
+        self.config().unwrap_or(self.algorithm().config)
+    }
+    
+    fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        let settings: Settings = cc.storage
+            .and_then(|storage| eframe::get_value(storage, SETTINGS_KEY))
+            .unwrap_or_default();
+        
+        let lang = Lang::from_code(&settings.lang).unwrap_or_else(Lang::from_env);
+        
+        cc.egui_ctx.set_visuals(settings.theme.visuals());
+        cc.egui_ctx.send_viewport_cmd(egui::ViewportCommand::Title(lang.strings().app_title.to_string()));
+        
+        Self {
+            input_format: settings.input_format,
+            iterations_input: settings.iterations_input,
+            theme: settings.theme,
+            lang,
+            algorithm: CrcCatalog::all()
+                .iter()
+                .position(|algorithm| algorithm.name == settings.algorithm)
+                .unwrap_or(0),
+            batch_csv_path: DEFAULT_BATCH_CSV.to_string(),
+            protocol: settings.protocol,
+            custom: settings.custom,
+            live_crc: settings.live_crc,
+            can_id: "123".to_string(),
+            can_data: "11 22 33".to_string(),
+            fd_id: "123".to_string(),
+            fd_data: "11 22 33 44 55 66 77 88 99".to_string(),
+            modbus_address: "1".to_string(),
+            modbus_function: "03".to_string(),
+            modbus_data: "00 00 00 0A".to_string(),
+            ..Default::default()
+        }
+    }
+    
+    fn handle_dropped_files(&mut self, ctx: &egui::Context) {
+        let dropped = ctx.input(|i| i.raw.dropped_files.clone());
+        if let Some(file) = dropped.into_iter().next() {
+            self.load_dropped_file(file);
+        }
+    }
+    
+    fn paint_drop_overlay(ctx: &egui::Context, t: &Strings) {
+        let hovering = ctx.input(|i| !i.raw.hovered_files.is_empty());
+        if !hovering {
+            return;
+        }
+        
+        let painter = ctx.layer_painter(egui::LayerId::new(
+            egui::Order::Foreground,
+            egui::Id::new("drop_overlay"),
+        ));
+        let screen_rect = ctx.screen_rect();
+        painter.rect_filled(screen_rect, 0.0, egui::Color32::from_black_alpha(180));
+        painter.text(
+            screen_rect.center(),
+            egui::Align2::CENTER_CENTER,
+            t.gui_drop_overlay,
+            egui::FontId::proportional(24.0),
+            egui::Color32::WHITE,
+        );
+    }
+    
+    fn load_dropped_file(&mut self, file: egui::DroppedFile) {
+        let t = self.lang.strings();
+        self.error_message.clear();
+        self.loaded_file = None;
+        
+        let name = file.path.as_ref()
+            .and_then(|p| p.file_name())
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| file.name.clone());
+        
+        let is_dbc = name.to_lowercase().ends_with(".dbc");
+        let image_format = Path::new(&name)
+            .extension()
+            .and_then(|extension| ImageFormat::from_extension(&extension.to_string_lossy()));
+        if is_dbc || image_format.is_some() {
+            let text = match (&file.bytes, &file.path) {
+                (Some(bytes), _) => Ok(String::from_utf8_lossy(bytes).into_owned()),
+                (None, Some(path)) => std::fs::read_to_string(path),
+                (None, None) => Err(io::Error::from(io::ErrorKind::NotFound)),
+            };
+            match (text, image_format) {
+                (Ok(text), Some(format)) => self.load_image(&text, &name, format),
+                (Ok(text), None) => self.load_dbc(&text, &name),
+                (Err(e), _) => self.error_message = fill(t.gui_err_read_file, &[&name, &e]),
+            }
+            return;
+        }
+        
+        let bytes = if let Some(bytes) = &file.bytes {
+            bytes.to_vec()
+        } else if let Some(path) = &file.path {
+            let size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+            if size > MAX_TEXT_FILE_BYTES {
+                self.stream_file(path, &name);
+                return;
+            }
+            match std::fs::read(path) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    self.error_message = fill(t.gui_err_read_file, &[&name, &e]);
+                    return;
+                }
+            }
+        } else {
+            self.error_message = fill(t.gui_err_no_file_data, &[&name]);
+            return;
+        };
+        
+        let max_bytes = self.config().ok()
+            .and_then(|config| ParseOptions::for_config(&config).max_bits)
+            .map(|bits| bits.div_ceil(8));
+        match detect_dropped_content(&bytes) {
+            DroppedContent::Binary(text) => {
+                self.binary_input = text;
+                self.input_format = InputFormat::Binary;
+            }
+            DroppedContent::Hex(text) => {
+                self.hex_input = text.to_uppercase();
+                self.input_format = InputFormat::Hex;
+            }
+            DroppedContent::Raw if max_bytes.is_some_and(|max_bytes| bytes.len() > max_bytes) => {
+                self.compute_streamed(&name, &mut bytes.as_slice());
+                return;
+            }
+            DroppedContent::Raw => {
+                self.hex_input = format_hex(&bytes);
+                self.input_format = InputFormat::Hex;
+            }
+        }
+        
+        if self.iterations_input.trim().is_empty() {
+            self.iterations_input = "1".to_string();
+        }
+        self.loaded_file = Some(fill(t.gui_file_loaded, &[&name, &bytes.len()]));
+        self.calculate_crc();
+    }
+    
+    fn stream_file(&mut self, path: &Path, name: &str) {
+        let t = self.lang.strings();
+        match File::open(path) {
+            Ok(mut file) => self.compute_streamed(name, &mut file),
+            Err(e) => {
+                self.error_message = fill(t.gui_err_open_file, &[&name, &e]);
+            }
+        }
+    }
+    
+    fn compute_streamed(&mut self, name: &str, reader: &mut dyn Read) {
+        let t = self.lang.strings();
+        let config = match self.config() {
+            Ok(config) => config,
+            Err(e) => {
+                self.error_message = e;
+                return;
+            }
+        };
+        let start = Instant::now();
+        
+        let (crc_val, total) = match checksum_reader(&config, reader) {
+            Ok(checksum) => checksum,
+            Err(e) => {
+                self.error_message = fill(t.gui_err_read_file, &[&name, &e]);
+                return;
+            }
+        };
+        
+        let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
+        self.result = Some(CrcResult::for_config(&config, crc_val, duration_ms).with_input(total as usize * 8, 1));
+        self.last_calculation_time = Some(duration_ms);
+        self.iterations_input = "1".to_string();
+        self.loaded_file = Some(fill(t.gui_file_streamed, &[
+            &name,
+            &format_number(total),
+            &format_number(total * 8),
+        ]));
+    }
+    
+    fn load_image(&mut self, text: &str, name: &str, format: ImageFormat) {
+        let t = self.lang.strings();
+        let image = match format.parse(text) {
+            Ok(image) => image,
+            Err(e) => {
+                self.error_message = fill(t.gui_err_read_file, &[&name, &self.lang.describe_image(&e)]);
+                return;
+            }
+        };
+        
+        let (start, end) = (image.start().unwrap_or(0), image.end().unwrap_or(0));
+        self.compute_streamed(name, &mut image.reader(start, end, DEFAULT_FILL));
+        self.loaded_file = Some(fill(t.gui_file_image, &[
+            &name,
+            &format_number(end - start),
+            &format!("{:08X}", start),
+            &format!("{:08X}", end),
+            &format!("{:02X}", DEFAULT_FILL),
+        ]));
+    }
+    
+    fn load_dbc(&mut self, text: &str, source: &str) {
+        let t = self.lang.strings();
+        match Dbc::parse(text) {
+            Ok(dbc) => {
+                self.dbc_status = fill(t.gui_dbc_loaded, &[&dbc.messages.len(), &source]);
+                self.dbc = Some(dbc);
+                self.protocol = Protocol::Can;
+                self.select_dbc_message(0);
+            }
+            Err(e) => {
+                self.error_message = self.lang.describe_dbc(&e);
+            }
+        }
+    }
+    
+    fn select_dbc_message(&mut self, index: usize) {
+        self.dbc_message = index;
+        self.dbc_values = self.dbc.as_ref()
+            .and_then(|dbc| dbc.messages.get(index))
+            .map(|message| message.signals.iter().map(|s| s.offset.max(s.min)).collect())
+            .unwrap_or_default();
+    }
+    
+    fn show_trace(&mut self, ui: &mut egui::Ui) {
+        let t = self.lang.strings();
+        ui.add_space(5.0);
+        egui::CollapsingHeader::new(t.gui_trace_section)
+            .id_source("trace_panel")
+            .show(ui, |ui| {
+                let Some((config, steps)) = &self.trace else {
+                    ui.label(fill(t.gui_trace_too_long, &[&TRACE_MAX_BITS]));
+                    return;
+                };
+                let digits = (config.width as usize).div_ceil(4);
+                let width = config.width as usize;
+                ui.checkbox(&mut self.trace_per_byte, t.gui_trace_per_byte);
+                egui::ScrollArea::vertical().max_height(300.0).id_source("trace_scroll").show(ui, |ui| {
+                    egui::Grid::new("trace_grid").num_columns(5).spacing([16.0, 2.0]).striped(true).show(ui, |ui| {
+                        for header in [t.gui_trace_step, t.gui_trace_index, "bit", "CRCNXT", t.gui_trace_register] {
+                            ui.strong(header);
+                        }
+                        ui.end_row();
+                        
+                        for (position, step) in steps.iter().enumerate() {
+                            if self.trace_per_byte && (position + 1) % 8 != 0 && position + 1 != steps.len() {
+                                continue;
+                            }
+                            ui.label(position.to_string());
+                            ui.label(step.index.to_string());
+                            ui.monospace((step.bit as u8).to_string());
+                            ui.monospace((step.feedback as u8).to_string());
+                            ui.monospace(format!("0x{:0digits$X}  {:0width$b}", step.register, step.register));
+                            ui.end_row();
+                        }
+                    });
+                });
+                ui.small(fill(t.gui_trace_final, &[&format!("{:0digits$X}", config.finish_trace(steps))]));
+            });
+    }
+    
+    fn show_bit_view(&self, ui: &mut egui::Ui) {
+        let t = self.lang.strings();
+        let Ok(bits) = self.parse_message(self.current_input()) else {
+            return;
+        };
+        egui::CollapsingHeader::new(t.gui_bits_section)
+            .id_source("bit_view")
+            .default_open(true)
+            .show(ui, |ui| {
+                if bits.len() > BIT_VIEW_MAX_BITS {
+                    ui.label(fill(t.gui_bits_too_long, &[&BIT_VIEW_MAX_BITS]));
+                    return;
+                }
+                let bytes = bits_to_bytes(&bits);
+                let visuals = ui.visuals().clone();
+                ui.horizontal_wrapped(|ui| {
+                    ui.spacing_mut().item_spacing = egui::vec2(1.0, 4.0);
+                    for (byte_index, chunk) in bits.chunks(8).enumerate() {
+                        if byte_index > 0 {
+                            ui.add_space(6.0);
+                        }
+                        let byte = bytes[byte_index];
+                        for (offset, &bit) in chunk.iter().enumerate() {
+                            if offset == 4 {
+                                ui.add_space(2.0);
+                            }
+                            let (rect, response) = ui.allocate_exact_size(egui::vec2(14.0, 20.0), egui::Sense::hover());
+                            let (fill_color, text_color) = match (bit, offset < 4) {
+                                (true, true) => (egui::Color32::from_rgb(50, 110, 200), egui::Color32::WHITE),
+                                (true, false) => (egui::Color32::from_rgb(90, 150, 230), egui::Color32::WHITE),
+                                (false, true) => (visuals.extreme_bg_color, visuals.text_color()),
+                                (false, false) => (visuals.faint_bg_color, visuals.text_color()),
+                            };
+                            ui.painter().rect_filled(rect, 2.0, fill_color);
+                            ui.painter().text(
+                                rect.center(),
+                                egui::Align2::CENTER_CENTER,
+                                if bit { "1" } else { "0" },
+                                egui::FontId::monospace(12.0),
+                                text_color,
+                            );
+                            response.on_hover_text(fill(t.gui_bits_tooltip, &[
+                                &(byte_index * 8 + offset),
+                                &byte_index,
+                                &offset,
+                                &format!("{:02X}", byte),
+                                &byte,
+                                &format!("{:08b}", byte),
+                            ]));
+                        }
+                    }
+                });
+            });
+    }
+    
+    fn show_playground_panel(&mut self, ui: &mut egui::Ui) {
+        let t = self.lang.strings();
+        egui::CollapsingHeader::new(t.gui_playground_section)
+            .id_source("playground_panel")
+            .show(ui, |ui| {
+                let config = self.display_config();
+                let bits = match self.parse_message(self.current_input()) {
+                    Ok(bits) if bits.len() > PLAYGROUND_MAX_BITS => {
+                        ui.label(fill(t.gui_playground_too_long, &[&PLAYGROUND_MAX_BITS]));
+                        return;
+                    }
+                    Ok(bits) => bits,
+                    Err(_) => {
+                        ui.label(t.gui_playground_no_input);
+                        return;
+                    }
+                };
+                let source = (self.input_format, self.current_input().to_string(), config);
+                if self.playground.source.as_ref() != Some(&source) {
+                    self.playground = Playground { source: Some(source), flipped: BTreeSet::new() };
+                }
+                
+                ui.small(t.gui_playground_hint);
+                ui.add_space(5.0);
+                let codeword = config.append_crc(&bits);
+                let mut clicked = None;
+                for (label, range) in [(t.gui_playground_data, 0..bits.len()), ("CRC:", bits.len()..codeword.len())] {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.spacing_mut().item_spacing.x = 2.0;
+                        ui.label(label);
+                        for position in range.clone() {
+                            if position > range.start && (position - range.start) % 8 == 0 {
+                                ui.add_space(6.0);
+                            }
+                            let flipped = self.playground.flipped.contains(&position);
+                            let bit = codeword[position] ^ flipped;
+                            let mut button = egui::Button::new(egui::RichText::new(if bit { "1" } else { "0" }).monospace())
+                                .min_size(egui::vec2(16.0, 18.0));
+                            if flipped {
+                                button = button.fill(egui::Color32::from_rgb(255, 100, 100));
+                            }
+                            if ui.add(button).on_hover_text(fill(t.gui_playground_bit, &[&position])).clicked() {
+                                clicked = Some(position);
+                            }
+                        }
+                    });
+                }
+                if let Some(position) = clicked {
+                    if !self.playground.flipped.remove(&position) {
+                        self.playground.flipped.insert(position);
+                    }
+                }
+                
+                ui.add_space(5.0);
+                let flipped: Vec<usize> = self.playground.flipped.iter().copied().collect();
+                let check = check_flips(&config, &bits, &flipped);
+                let digits = (config.width as usize).div_ceil(4);
+                egui::Grid::new("playground_grid").num_columns(2).spacing([20.0, 4.0]).show(ui, |ui| {
+                    ui.label(t.gui_playground_flipped);
+                    let positions: Vec<String> = flipped.iter().map(|position| position.to_string()).collect();
+                    ui.monospace(format!("{} ({})", flipped.len(), positions.join(", ")));
+                    ui.end_row();
+                    
+                    for (label, crc) in [
+                        (t.gui_playground_original, check.original_crc),
+                        (t.gui_playground_received, check.received_crc),
+                        (t.gui_playground_recomputed, check.recomputed_crc),
+                    ] {
+                        ui.label(label);
+                        ui.monospace(format!("0x{:0digits$X}", crc));
+                        ui.end_row();
+                    }
+                });
+                
+                ui.horizontal(|ui| {
+                    if flipped.is_empty() {
+                        ui.label(t.gui_playground_none);
+                    } else if check.detected {
+                        ui.colored_label(egui::Color32::from_rgb(100, 200, 100), t.gui_playground_detected);
+                    } else {
+                        ui.colored_label(egui::Color32::from_rgb(255, 100, 100), t.gui_playground_undetected);
+                    }
+                    if ui.button(t.gui_clear).clicked() {
+                        self.playground.flipped.clear();
+                    }
+                });
+            });
+    }
+    
+    fn show_distribution_panel(&mut self, ui: &mut egui::Ui) {
+        let t = self.lang.strings();
+        egui::CollapsingHeader::new(t.gui_distribution_section)
+            .id_source("distribution_panel")
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(t.gui_distribution_bits);
+                    ui.add(egui::TextEdit::singleline(&mut self.distribution_bits).hint_text("16").desired_width(60.0));
+                    if ui.button(t.gui_distribution_compute).clicked() {
+                        let text = self.distribution_bits.trim();
+                        let bits = if text.is_empty() { Ok(16) } else { text.parse::<usize>() };
+                        self.distribution = Some(match bits {
+                            Ok(bits) if (1..=DISTRIBUTION_MAX_BITS).contains(&bits) => {
+                                Ok(crc_distribution(&self.display_config(), bits, DEFAULT_BUCKET_BITS, DEFAULT_SAMPLES, 0))
+                            }
+                            _ => Err(fill(t.gui_distribution_invalid, &[&DISTRIBUTION_MAX_BITS])),
+                        });
+                    }
+                });
+                
+                match &self.distribution {
+                    Some(Ok(distribution)) => {
+                        let mode = if distribution.exhaustive { t.gui_distribution_exhaustive.to_string() } else { fill(t.gui_distribution_sampled, &[&0]) };
+                        ui.label(fill(t.gui_distribution_summary, &[
+                            &format_number(distribution.inputs),
+                            &mode,
+                            &distribution.buckets.len(),
+                            &distribution.bucket_bits,
+                            &format_number(distribution.collisions()),
+                            &format!("{:.2}", distribution.chi_square()),
+                            &format!("{:.4}", distribution.p_value()),
+                        ]));
+                        let bars: Vec<Bar> = distribution.buckets.iter().enumerate()
+                            .map(|(bucket, &count)| Bar::new(bucket as f64, count as f64).width(1.0))
+                            .collect();
+                        Plot::new("distribution_chart")
+                            .height(180.0)
+                            .x_axis_label(t.gui_distribution_bucket)
+                            .y_axis_label(t.gui_distribution_count)
+                            .allow_scroll(false)
+                            .show(ui, |plot_ui| plot_ui.bar_chart(BarChart::new(bars)));
+                    }
+                    Some(Err(message)) => {
+                        ui.colored_label(egui::Color32::from_rgb(255, 100, 100), self.lang.error(message));
+                    }
+                    None => {}
+                }
+            });
+    }
+    
+    fn show_custom_panel(&mut self, ui: &mut egui::Ui) {
+        let t = self.lang.strings();
+        egui::CollapsingHeader::new(t.gui_custom_section)
+            .id_source("custom_panel")
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.custom.enabled, t.gui_custom_enabled);
+                    if ui.button(t.gui_custom_from_algorithm).clicked() {
+                        let enabled = self.custom.enabled;
+                        self.custom = CustomParams::from_config(&self.algorithm().config);
+                        self.custom.enabled = enabled;
+                    }
+                });
+                
+                egui::Grid::new("custom_params").num_columns(4).spacing([20.0, 6.0]).show(ui, |ui| {
+                    ui.label(t.gui_custom_poly);
+                    ui.add(egui::TextEdit::singleline(&mut self.custom.poly).desired_width(160.0).hint_text("0x4599"));
+                    ui.label(t.gui_custom_width);
+                    ui.add(egui::TextEdit::singleline(&mut self.custom.width).desired_width(60.0).hint_text("15"));
+                    ui.end_row();
+                    
+                    ui.label(t.gui_custom_init);
+                    ui.add(egui::TextEdit::singleline(&mut self.custom.init).desired_width(160.0).hint_text("0x0"));
+                    ui.label(t.gui_custom_xorout);
+                    ui.add(egui::TextEdit::singleline(&mut self.custom.xorout).desired_width(160.0).hint_text("0x0"));
+                    ui.end_row();
+                    
+                    ui.label("");
+                    ui.checkbox(&mut self.custom.refin, "refin");
+                    ui.label("");
+                    ui.checkbox(&mut self.custom.refout, "refout");
+                    ui.end_row();
+                });
+                
+                match self.custom.to_config(self.lang) {
+                    Ok(config) => {
+                        let check = config.checksum(b"123456789");
+                        ui.colored_label(
+                            egui::Color32::from_rgb(100, 200, 100),
+                            fill(t.gui_custom_valid, &[&config.width, &format!("{:X}", check)]),
+                        );
+                    }
+                    Err(e) => {
+                        ui.colored_label(egui::Color32::from_rgb(255, 100, 100), self.lang.error(&e));
+                    }
+                }
+            });
+    }
+    
+    fn show_protocol_tab(&mut self, ui: &mut egui::Ui) {
+        let t = self.lang.strings();
+        match self.protocol {
+            Protocol::Generic => {}
+            Protocol::Can => {
+                egui::Grid::new("can_inputs").num_columns(2).spacing([20.0, 8.0]).show(ui, |ui| {
+                    ui.label(t.gui_frame_id);
+                    ui.add(egui::TextEdit::singleline(&mut self.can_id).desired_width(120.0).hint_text("123"));
+                    ui.end_row();
+                    
+                    ui.label("");
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut self.can_extended, t.gui_frame_extended);
+                        ui.checkbox(&mut self.can_rtr, t.gui_can_rtr);
+                    });
+                    ui.end_row();
+                    
+                    ui.label(t.gui_frame_data);
+                    ui.add(egui::TextEdit::singleline(&mut self.can_data).desired_width(400.0).hint_text("11 22 33"));
+                    ui.end_row();
+                });
+                ui.small(t.gui_can_data_help);
+                ui.add_space(10.0);
+                
+                match self.can_frame() {
+                    Ok(frame) => {
+                        let wire = frame.wire_bits();
+                        let stuffed = wire.iter().filter(|bit| bit.stuff).count();
+                        egui::Grid::new("can_results").num_columns(2).spacing([20.0, 8.0]).striped(true).show(ui, |ui| {
+                            ui.label(t.gui_frame_dlc);
+                            ui.code(frame.dlc().to_string());
+                            ui.end_row();
+                            
+                            ui.label(t.gui_frame_crc_bits);
+                            ui.code(frame.crc_bits().len().to_string());
+                            ui.end_row();
+                            
+                            ui.label(t.gui_frame_crc);
+                            ui.code(format!("0x{:04X}", frame.crc()));
+                            ui.end_row();
+                            
+                            ui.label(t.gui_frame_wire);
+                            ui.add(egui::Label::new(egui::RichText::new(format_wire_bits(&wire)).code()).wrap());
+                            ui.end_row();
+                            
+                            ui.label(t.gui_frame_stuff_bits);
+                            ui.code(format!("{} / {}", stuffed, wire.len()));
+                            ui.end_row();
+                        });
+                    }
+                    Err(e) => {
+                        ui.colored_label(egui::Color32::from_rgb(255, 100, 100), self.lang.error(&e));
+                    }
+                }
+                
+                ui.add_space(15.0);
+                self.show_dbc_panel(ui);
+                if let Some(frame_summary) = &self.frame_summary {
+                    ui.horizontal(|ui| {
+                        ui.label("🚌");
+                        ui.code(frame_summary);
+                    });
+                }
+            }
+            Protocol::CanFd => {
+                egui::Grid::new("fd_inputs").num_columns(2).spacing([20.0, 8.0]).show(ui, |ui| {
+                    ui.label(t.gui_frame_id);
+                    ui.add(egui::TextEdit::singleline(&mut self.fd_id).desired_width(120.0).hint_text("123"));
+                    ui.end_row();
+                    
+                    ui.label("");
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut self.fd_extended, t.gui_frame_extended);
+                        ui.checkbox(&mut self.fd_brs, t.gui_fd_brs);
+                        ui.checkbox(&mut self.fd_non_iso, t.gui_fd_non_iso).on_hover_text(t.gui_fd_non_iso_hint);
+                    });
+                    ui.end_row();
+                    
+                    ui.label(t.gui_frame_data);
+                    ui.add(egui::TextEdit::multiline(&mut self.fd_data).desired_width(400.0).desired_rows(2));
+                    ui.end_row();
+                });
+                ui.small(t.gui_fd_data_help);
+                ui.add_space(10.0);
+                
+                match self.fd_frame() {
+                    Ok((frame, entered)) => {
+                        let algorithm = frame.crc_algorithm();
+                        let digits = (algorithm.config.width as usize).div_ceil(4);
+                        egui::Grid::new("fd_results").num_columns(2).spacing([20.0, 8.0]).striped(true).show(ui, |ui| {
+                            ui.label(t.gui_frame_dlc);
+                            ui.code(frame.dlc().to_string());
+                            ui.end_row();
+                            
+                            ui.label(t.gui_fd_length);
+                            if entered == frame.data.len() {
+                                ui.code(entered.to_string());
+                            } else {
+                                ui.code(fill(t.gui_fd_padded, &[&entered, &frame.data.len(), &format!("{:02X}", FD_PADDING_BYTE)]));
+                            }
+                            ui.end_row();
+                            
+                            ui.label(t.gui_fd_crc_algorithm);
+                            ui.code(algorithm.name);
+                            ui.end_row();
+                            
+                            if self.fd_non_iso {
+                                ui.label(t.gui_frame_crc_bits);
+                                ui.code(frame.crc_bits().len().to_string());
+                                ui.end_row();
+                                
+                                ui.label(t.gui_frame_crc);
+                                ui.code(format!("0x{:0digits$X}", frame.non_iso_crc()));
+                                ui.end_row();
+                                return;
+                            }
+                            
+                            let stuff_count = frame.stuff_count();
+                            let count_bits: String = stuff_count_bits(stuff_count).iter().map(|&bit| if bit { '1' } else { '0' }).collect();
+                            ui.label(t.gui_fd_stuff_count);
+                            ui.code(format!("{} → {}", stuff_count, count_bits));
+                            ui.end_row();
+                            
+                            ui.label(t.gui_frame_crc_bits);
+                            ui.code(frame.iso_crc_bits().len().to_string());
+                            ui.end_row();
+                            
+                            ui.label(t.gui_frame_crc);
+                            ui.code(format!("0x{:0digits$X}", frame.crc()));
+                            ui.end_row();
+                            
+                            let wire = frame.wire_bits();
+                            ui.label(t.gui_frame_wire);
+                            ui.add(egui::Label::new(egui::RichText::new(format_wire_bits(&wire)).code()).wrap());
+                            ui.end_row();
+                            
+                            ui.label(t.gui_frame_stuff_bits);
+                            ui.code(format!("{} / {}", wire.iter().filter(|bit| bit.stuff).count(), wire.len()));
+                            ui.end_row();
+                        });
+                    }
+                    Err(e) => {
+                        ui.colored_label(egui::Color32::from_rgb(255, 100, 100), self.lang.error(&e));
+                    }
+                }
+            }
+            Protocol::Modbus => {
+                egui::Grid::new("modbus_inputs").num_columns(2).spacing([20.0, 8.0]).show(ui, |ui| {
+                    ui.label(t.gui_modbus_address);
+                    ui.add(egui::TextEdit::singleline(&mut self.modbus_address).desired_width(60.0).hint_text("1"));
+                    ui.end_row();
+                    
+                    ui.label(t.gui_modbus_function);
+                    ui.add(egui::TextEdit::singleline(&mut self.modbus_function).desired_width(60.0).hint_text("03"));
+                    ui.end_row();
+                    
+                    ui.label(t.gui_frame_data);
+                    ui.add(egui::TextEdit::multiline(&mut self.modbus_data).desired_width(400.0).desired_rows(2));
+                    ui.end_row();
+                });
+                ui.small(fill(t.gui_modbus_help, &[&MAX_ADU_LEN]));
+                ui.add_space(10.0);
+                
+                match self.modbus_frame() {
+                    Ok(frame) => {
+                        let (body, crc) = frame.split_at(frame.len() - 2);
+                        let text = format_hex(&frame);
+                        egui::Grid::new("modbus_results").num_columns(2).spacing([20.0, 8.0]).striped(true).show(ui, |ui| {
+                            ui.label(t.gui_frame_crc);
+                            ui.code(format!("0x{:04X}", u16::from_le_bytes([crc[0], crc[1]])));
+                            ui.end_row();
+                            
+                            ui.label(t.gui_modbus_crc_bytes);
+                            ui.code(format_hex(crc));
+                            ui.end_row();
+                            
+                            ui.label(t.gui_modbus_length);
+                            ui.code(format!("{} + 2", body.len()));
+                            ui.end_row();
+                            
+                            ui.label(t.gui_modbus_frame);
+                            ui.horizontal(|ui| {
+                                ui.add(egui::Label::new(egui::RichText::new(&text).code()).wrap());
+                                if ui.small_button("📋").on_hover_text(t.gui_copy).clicked() {
+                                    ui.ctx().copy_text(text.clone());
+                                }
+                            });
+                            ui.end_row();
+                            
+                            if let Ok(pdu) = decode_adu(&frame) {
+                                ui.label(t.gui_modbus_meaning);
+                                ui.add(egui::Label::new(self.lang.describe_pdu(&pdu)).wrap());
+                                ui.end_row();
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        ui.colored_label(egui::Color32::from_rgb(255, 100, 100), self.lang.error(&e));
+                    }
+                }
+            }
+        }
+        
+        if !self.error_message.is_empty() {
+            ui.add_space(10.0);
+            ui.colored_label(egui::Color32::from_rgb(255, 100, 100), self.lang.error(&self.error_message));
+        }
+    }
+    
+    fn can_frame(&self) -> Result<CanFrame, String> {
+        let id = parse_frame_id(&self.can_id, self.lang)?;
+        let data = parse_payload(&self.can_data, self.lang)?;
+        let frame = if self.can_extended { CanFrame::new_extended(id, &data) } else { CanFrame::new(id, &data) };
+        let mut frame = frame.map_err(|e| self.lang.describe_frame(&e))?;
+        frame.rtr = self.can_rtr;
+        Ok(frame)
+    }
+    
+    fn fd_frame(&self) -> Result<(CanFdFrame, usize), String> {
+        let id = parse_frame_id(&self.fd_id, self.lang)?;
+        let data = parse_payload(&self.fd_data, self.lang)?;
+        let mut frame = CanFdFrame::new(id, self.fd_extended, &data).map_err(|e| self.lang.describe_frame(&e))?;
+        frame.brs = self.fd_brs;
+        Ok((frame, data.len()))
+    }
+    
+    fn modbus_frame(&self) -> Result<Vec<u8>, String> {
+        let t = self.lang.strings();
+        let address = self.modbus_address.trim().parse::<u8>().ok()
+            .filter(|&address| address <= MAX_SLAVE_ADDRESS)
+            .ok_or_else(|| fill(t.gui_err_modbus_address, &[&MAX_SLAVE_ADDRESS]))?;
+        let function = u8::from_str_radix(self.modbus_function.trim().trim_start_matches("0x"), 16)
+            .map_err(|_| t.gui_err_modbus_function.to_string())?;
+        let data = parse_payload(&self.modbus_data, self.lang)?;
+        let frame = rtu_frame(address, function, &data);
+        if frame.len() > MAX_ADU_LEN {
+            return Err(fill(t.gui_err_modbus_too_long, &[&frame.len(), &MAX_ADU_LEN]));
+        }
+        Ok(frame)
+    }
+    
+    fn show_dbc_panel(&mut self, ui: &mut egui::Ui) {
+        let t = self.lang.strings();
+        
+        egui::CollapsingHeader::new(t.gui_dbc_section)
+            .id_source("dbc_panel")
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(t.gui_dbc_path);
+                    ui.add(egui::TextEdit::singleline(&mut self.dbc_path)
+                        .desired_width(300.0)
+                        .hint_text("vehicle.dbc"));
+                    if ui.button(t.gui_dbc_load).clicked() {
+                        self.error_message.clear();
+                        let path = self.dbc_path.trim().to_string();
+                        match std::fs::read_to_string(&path) {
+                            Ok(text) => self.load_dbc(&text, &path),
+                            Err(e) => self.error_message = fill(t.gui_err_read_file, &[&path, &e]),
+                        }
+                    }
+                });
+                
+                let Some(dbc) = &self.dbc else {
+                    ui.small(t.gui_dbc_hint);
+                    return;
+                };
+                ui.small(&self.dbc_status);
+                
+                let mut selected = self.dbc_message;
+                let selected_name = dbc.messages.get(selected).map(|m| m.name.as_str()).unwrap_or("-");
+                ui.horizontal(|ui| {
+                    ui.label(t.gui_dbc_message);
+                    egui::ComboBox::from_id_source("dbc_message")
+                        .selected_text(selected_name)
+                        .show_ui(ui, |ui| {
+                            for (index, message) in dbc.messages.iter().enumerate() {
+                                let label = format!("{} (0x{:X})", message.name, message.id);
+                                ui.selectable_value(&mut selected, index, label);
+                            }
+                        });
+                });
+                
+                let Some(message) = dbc.messages.get(self.dbc_message) else {
+                    return;
+                };
+                egui::Grid::new("dbc_signals")
+                    .num_columns(3)
+                    .spacing([20.0, 4.0])
+                    .show(ui, |ui| {
+                        for (signal, value) in message.signals.iter().zip(self.dbc_values.iter_mut()) {
+                            ui.label(&signal.name);
+                            let mut drag = egui::DragValue::new(value).speed(signal.factor.abs().max(0.001));
+                            if signal.max > signal.min {
+                                drag = drag.range(signal.min..=signal.max);
+                            }
+                            ui.add(drag);
+                            ui.label(&signal.unit);
+                            ui.end_row();
+                        }
+                    });
+                
+                let encode_clicked = ui.button(t.gui_dbc_encode).clicked();
+                if selected != self.dbc_message {
+                    self.select_dbc_message(selected);
+                } else if encode_clicked {
+                    self.encode_dbc_frame();
+                }
+            });
+    }
+    
+    fn encode_dbc_frame(&mut self) {
+        let t = self.lang.strings();
+        self.error_message.clear();
+        
+        let Some(message) = self.dbc.as_ref().and_then(|dbc| dbc.messages.get(self.dbc_message)) else {
+            return;
+        };
+        let values: Vec<(String, f64)> = message.signals.iter()
+            .map(|s| s.name.clone())
+            .zip(self.dbc_values.iter().copied())
+            .collect();
+        
+        let frame = message.encode(&values)
+            .and_then(|data| message.to_frame(&data));
+        match frame {
+            Ok(frame) => {
+                let payload = format_hex(&frame.data);
+                self.frame_summary = Some(fill(t.gui_dbc_frame_result, &[
+                    &format!("{:X}", frame.id),
+                    &message.name,
+                    &payload,
+                    &format!("{:04X}", frame.crc()),
+                    &frame.crc_bits().len(),
+                ]));
+                self.can_id = format!("{:X}", frame.id);
+                self.can_extended = frame.extended;
+                self.can_rtr = frame.rtr;
+                self.can_data = payload;
+            }
+            Err(e) => {
+                self.error_message = self.lang.describe_dbc(&e);
+            }
+        }
+    }
+    
+    fn show_batch_panel(&mut self, ui: &mut egui::Ui) {
+        let t = self.lang.strings();
+        
+        egui::CollapsingHeader::new(t.gui_batch_section)
+            .id_source("batch_panel")
+            .show(ui, |ui| {
+                ui.small(t.gui_batch_hint);
+                ui.add(egui::TextEdit::multiline(&mut self.batch_input)
+                    .desired_width(500.0)
+                    .desired_rows(4)
+                    .hint_text("AA BB CC\n01 04 00 00"));
+                if ui.button(t.gui_batch_compute).clicked() {
+                    self.calculate_batch();
+                }
+                
+                if self.batch_rows.is_empty() {
+                    return;
+                }
+                
+                let errors = self.batch_rows.iter().filter(|row| row.crc.is_err()).count();
+                ui.small(fill(t.gui_batch_summary, &[&self.batch_rows.len(), &errors]));
+                
+                let mut clicked = None;
+                egui::Grid::new("batch_grid")
+                    .num_columns(5)
+                    .spacing([20.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for (column, label) in [
+                            (BatchColumn::Input, t.gui_batch_col_input),
+                            (BatchColumn::Bits, t.gui_batch_col_bits),
+                            (BatchColumn::Crc, "CRC (hex)"),
+                            (BatchColumn::Crc, "CRC (dec)"),
+                            (BatchColumn::Time, t.gui_batch_col_time),
+                        ] {
+                            let marker = match self.batch_sort {
+                                Some((sorted, true)) if sorted == column => " ⬆",
+                                Some((sorted, false)) if sorted == column => " ⬇",
+                                _ => "",
+                            };
+                            if ui.button(format!("{}{}", label, marker)).clicked() {
+                                clicked = Some(column);
+                            }
+                        }
+                        ui.end_row();
+                        
+                        for row in &self.batch_rows {
+                            ui.code(&row.input);
+                            match &row.crc {
+                                Ok(result) => {
+                                    ui.label(row.bits.to_string());
+                                    ui.code(format!("0x{}", result.crc_hex));
+                                    ui.code(result.crc_value.to_string());
+                                    ui.label(format!("{:.3}", result.duration_ms));
+                                }
+                                Err(e) => {
+                                    ui.label("-");
+                                    ui.colored_label(egui::Color32::from_rgb(255, 100, 100), e);
+                                    ui.label("-");
+                                    ui.label("-");
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+                
+                if let Some(column) = clicked {
+                    let ascending = !matches!(self.batch_sort, Some((sorted, true)) if sorted == column);
+                    self.batch_sort = Some((column, ascending));
+                    self.sort_batch();
+                }
+                
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    ui.label(t.gui_batch_csv_path);
+                    ui.add(egui::TextEdit::singleline(&mut self.batch_csv_path)
+                        .desired_width(300.0)
+                        .hint_text(DEFAULT_BATCH_CSV));
+                    if ui.button(t.gui_batch_export).clicked() {
+                        self.export_batch_csv();
+                    }
+                    if ui.button(t.gui_save_results).on_hover_text(t.gui_save_results_hint).clicked() {
+                        self.save_results();
+                    }
+                });
+                if !self.batch_status.is_empty() {
+                    ui.small(&self.batch_status);
+                }
+            });
+    }
+    
+    fn calculate_batch(&mut self) {
+        self.batch_status.clear();
+        let config = match self.config() {
+            Ok(config) => config,
+            Err(e) => {
+                self.batch_status = e;
+                return;
+            }
+        };
+        self.batch_rows = self.batch_input.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let parsed = self.parse_message(line);
+                let bits = parsed.as_ref().map_or(0, Vec::len);
+                let crc = parsed.map(|bits| {
+                    let start = Instant::now();
+                    let crc_val = compute_batch_checksums(&config, &bits, 1);
+                    CrcResult::for_config(&config, crc_val, start.elapsed().as_secs_f64() * 1000.0).with_input(bits.len(), 1)
+                });
+                BatchRow { input: line.to_string(), bits, crc }
+            })
+            .collect();
+        self.sort_batch();
+    }
+    
+    fn sort_batch(&mut self) {
+        if let Some((column, ascending)) = self.batch_sort {
+            self.batch_rows.sort_by(|a, b| {
+                let ordering = a.compare(b, column);
+                if ascending { ordering } else { ordering.reverse() }
+            });
+        }
+    }
+    
+    fn export_batch_csv(&mut self) {
+        let t = self.lang.strings();
+        let path = match self.batch_csv_path.trim() {
+            "" => DEFAULT_BATCH_CSV.to_string(),
+            path => path.to_string(),
+        };
+        match std::fs::write(&path, batch_csv(&self.batch_rows)) {
+            Ok(()) => self.batch_status = fill(t.gui_batch_exported, &[&self.batch_rows.len(), &path]),
+            Err(e) => self.error_message = fill(t.gui_err_write_file, &[&path, &e]),
+        }
+    }
+    
+    fn results_report(&self) -> ResultsReport {
+        ResultsReport {
+            algorithm: (!self.custom.enabled).then(|| self.algorithm().name.to_string()),
+            config: self.display_config(),
+            input_format: self.input_format,
+            input: self.current_input().trim().to_string(),
+            bits: self.parse_message(self.current_input()).map_or(0, |bits| bits.len()),
+            result: self.result.clone(),
+            runs: self.perf_history.clone(),
+            batch: self.batch_rows.iter().map(BatchRecord::from).collect(),
+        }
+    }
+    
+    fn save_results(&mut self) {
+        let t = self.lang.strings();
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("JSON", &["json"])
+            .add_filter("CSV", &["csv"])
+            .add_filter("Markdown", &["md"])
+            .set_file_name(DEFAULT_RESULTS_FILE)
+            .save_file()
+        else {
+            return;
+        };
+        
+        let report = self.results_report();
+        let contents = match ReportFormat::from_path(&path) {
+            ReportFormat::Json => serde_json::to_string_pretty(&report).map_err(|e| e.to_string()),
+            ReportFormat::Csv => Ok(report.csv()),
+            ReportFormat::Markdown => Ok(report.markdown(self.lang)),
+        };
+        match contents.and_then(|contents| std::fs::write(&path, contents).map_err(|e| e.to_string())) {
+            Ok(()) => self.results_status = fill(t.gui_results_saved, &[&path.display()]),
+            Err(e) => self.error_message = fill(t.gui_err_write_file, &[&path.display(), &e]),
+        }
+    }
+    
+    fn parse_message(&self, input: &str) -> Result<Vec<bool>, String> {
+        let options = ParseOptions::for_config(&self.config()?);
+        match self.input_format {
+            InputFormat::Binary => parse_binary_input_with(input, &options),
+            InputFormat::Hex => parse_hex_input_with(input, &options),
+            InputFormat::Base64 => parse_base64_input_with(input, &options),
+            InputFormat::Escaped => parse_escaped_input_with(input, &options),
+        }.map_err(|e| self.lang.describe(&e))
+    }
+    
+    fn current_input(&self) -> &str {
+        match self.input_format {
+            InputFormat::Binary => &self.binary_input,
+            InputFormat::Hex => &self.hex_input,
+            InputFormat::Base64 => &self.base64_input,
+            InputFormat::Escaped => &self.escaped_input,
+        }
+    }
+    
+    fn parse_inputs(&self) -> Result<(Vec<bool>, u64), String> {
+        let t = self.lang.strings();
+        let bits = self.parse_message(self.current_input())?;
+        
+        if bits.is_empty() {
+            return Err(t.gui_err_no_bits.to_string());
+        }
+        
+        let iterations: u64 = match self.iterations_input.trim().parse() {
+            Ok(num) => {
+                if num == 0 {
+                    return Err(t.gui_err_iterations_zero.to_string());
+                }
+                if num > 1_000_000_000 {
+                    return Err(fill(t.gui_err_iterations_too_large, &[&format_number(num)]));
+                }
+                num
+            }
+            Err(_) => {
+                if self.iterations_input.is_empty() {
+                    return Err(t.gui_err_iterations_missing.to_string());
+                } else {
+                    return Err(t.gui_err_iterations_invalid.to_string());
+                }
+            }
+        };
+        
+        Ok((bits, iterations))
+    }
+    
+    fn update_live_crc(&mut self, ctx: &egui::Context) {
+        if !self.live_crc {
+            self.live = None;
+            return;
+        }
+        let input = (self.input_format, self.current_input().to_string(), self.config().ok());
+        let live = match &mut self.live {
+            Some(live) if live.input == input => live,
+            _ => {
+                self.live = Some(LiveCrc { input, changed_at: Instant::now(), state: LiveState::Pending });
+                ctx.request_repaint_after(LIVE_DEBOUNCE);
+                return;
+            }
+        };
+        if !matches!(live.state, LiveState::Pending) {
+            return;
+        }
+        let elapsed = live.changed_at.elapsed();
+        if elapsed < LIVE_DEBOUNCE {
+            ctx.request_repaint_after(LIVE_DEBOUNCE - elapsed);
+            return;
+        }
+        
+        let state = match (live.input.2, self.parse_message(self.current_input())) {
+            (Some(_), Ok(bits)) if bits.len() > LIVE_MAX_BITS => LiveState::TooLong(bits.len()),
+            (Some(config), Ok(bits)) if !bits.is_empty() => LiveState::Ready(config, compute_batch_checksums(&config, &bits, 1)),
+            _ => LiveState::Invalid,
+        };
+        if let Some(live) = &mut self.live {
+            live.state = state;
+        }
+    }
+    
+    fn calculate_crc(&mut self) {
+        self.error_message.clear();
+        self.emitted_frame = None;
+        self.is_calculating = true;
+        
+        let (bits, iterations) = match self.parse_inputs() {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                self.error_message = e;
+                self.is_calculating = false;
+                return;
+            }
+        };
+        
+        let config = self.display_config();
+        self.trace = (bits.len() <= TRACE_MAX_BITS).then(|| (config, config.trace(&bits)));
+        let calculated_bits = bits.len();
+        let progress = Arc::new(AtomicU64::new(0));
+        let counter = Arc::clone(&progress);
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let start = Instant::now();
+            let crc_val = compute_batch_checksums_with_progress(&config, &bits, iterations, |done| {
+                counter.store(done, AtomicOrdering::Relaxed);
+            });
+            let _ = sender.send((crc_val, start.elapsed().as_secs_f64() * 1000.0));
+        });
+        self.calculation = Some(Calculation { iterations, bits: calculated_bits, config, progress, receiver });
+    }
+    
+    fn poll_calculation(&mut self) {
+        let Some(calculation) = &self.calculation else {
+            return;
+        };
+        let (crc_val, duration_ms) = match calculation.receiver.try_recv() {
+            Ok(finished) => finished,
+            Err(mpsc::TryRecvError::Empty) => return,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                self.calculation = None;
+                self.is_calculating = false;
+                return;
+            }
+        };
+        let result = CrcResult::for_config(&calculation.config, crc_val, duration_ms)
+            .with_input(calculation.bits, calculation.iterations);
+        self.record_perf_sample(result.implementation, result.iterations, duration_ms);
+        
+        self.result = Some(result);
+        self.last_calculation_time = Some(duration_ms);
+        self.calculation = None;
+        self.is_calculating = false;
+    }
+    
+    fn emit_frame(&mut self, ctx: &egui::Context) {
+        let bits = match self.parse_message(self.current_input()) {
+            Ok(bits) => bits,
+            Err(e) => {
+                self.error_message = e;
+                return;
+            }
+        };
+        
+        let config = self.display_config();
+        let endianness = self.emit_endianness.unwrap_or_else(|| Endianness::for_config(&config));
+        let frame = emit_frame(&config, &bits, endianness);
+        let text = match self.input_format {
+            InputFormat::Hex if frame.len().is_multiple_of(8) => format_hex(&bits_to_bytes(&frame)),
+            InputFormat::Base64 if frame.len().is_multiple_of(8) => format_base64(&bits_to_bytes(&frame)),
+            InputFormat::Escaped if frame.len().is_multiple_of(8) => format_escaped(&bits_to_bytes(&frame)),
+            _ => format_binary(&frame),
+        };
+        ctx.copy_text(text.clone());
+        self.emitted_frame = Some(text);
+    }
+    
+    fn compare_execution_paths(&mut self) {
+        self.error_message.clear();
+        
+        let (bits, iterations) = match self.parse_inputs() {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                self.error_message = e;
+                return;
+            }
+        };
+        
+        for path in [Implementation::Sequential, Implementation::Parallel] {
+            let start = Instant::now();
+            let crc_val = match path {
+                Implementation::Parallel => compute_batch_crcs_parallel(&bits, iterations),
+                _ => compute_batch_crcs_sequential(&bits, iterations),
+            };
+            let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
+            
+            self.record_perf_sample(path, iterations, duration_ms);
+            self.result = Some(CrcResult::new(crc_val, duration_ms).with_input(bits.len(), iterations).with_implementation(path));
+            self.last_calculation_time = Some(duration_ms);
+        }
+    }
+    
+    fn record_perf_sample(&mut self, path: Implementation, iterations: u64, duration_ms: f64) {
+        if iterations <= 1 || duration_ms <= 0.0 {
+            return;
+        }
+        
+        self.perf_history.push(PerfSample {
+            run: self.perf_history.len() + 1,
+            path,
+            throughput: iterations as f64 / duration_ms * 1000.0,
+        });
+    }
+    
+    fn show_perf_chart(&mut self, ui: &mut egui::Ui) {
+        let t = self.lang.strings();
+        ui.separator();
+        ui.add_space(10.0);
+        ui.horizontal(|ui| {
+            ui.heading(t.gui_perf_chart);
+            if ui.button(t.gui_clear).clicked() {
+                self.perf_history.clear();
+            }
+        });
+        ui.add_space(5.0);
+        
+        Plot::new("perf_chart")
+            .height(220.0)
+            .legend(Legend::default())
+            .x_axis_label(t.gui_run)
+            .y_axis_label("CRC/s")
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                for path in [Implementation::Sequential, Implementation::Parallel] {
+                    let samples: Vec<[f64; 2]> = self.perf_history.iter()
+                        .filter(|s| s.path == path)
+                        .map(|s| [s.run as f64, s.throughput])
+                        .collect();
+                    plot_ui.line(Line::new(PlotPoints::from(samples.clone())).name(implementation_label(path, self.lang)));
+                    plot_ui.points(Points::new(PlotPoints::from(samples)).radius(3.0).name(implementation_label(path, self.lang)));
+                }
+            });
+    }
+}
+
+fn format_number(num: u64) -> String {
+    let s = num.to_string();
+    let mut result = String::new();
+    
+    for (count, ch) in s.chars().rev().enumerate() {
+        if count > 0 && count % 3 == 0 {
+            result.push(' ');
+        }
+        result.push(ch);
+    }
+    
+    result.chars().rev().collect()
+}
+
+fn input_problem(format: InputFormat, text: &str, options: &ParseOptions) -> Option<(ParseError, Vec<Range<usize>>)> {
+    let error = match format {
+        InputFormat::Binary => parse_binary_input_with(text, options).err()?,
+        InputFormat::Hex => parse_hex_input_with(text, options).err()?,
+        InputFormat::Base64 => parse_base64_input_with(text, options).err()?,
+        InputFormat::Escaped => parse_escaped_input_with(text, options).err()?,
+    };
+    if error == ParseError::Empty {
+        return None;
+    }
+    let spans = match format {
+        InputFormat::Binary => binary_error_spans(text, &error),
+        InputFormat::Hex => hex_error_spans(text, &error),
+        InputFormat::Base64 => base64_error_spans(text, &error),
+        InputFormat::Escaped => escaped_error_spans(text, &error),
+    };
+    Some((error, spans))
+}
+
+fn show_input_error(ui: &mut egui::Ui, lang: Lang, text: &str, error: &ParseError, spans: &[Range<usize>]) {
+    let color = egui::Color32::from_rgb(255, 100, 100);
+    ui.vertical(|ui| {
+        ui.colored_label(color, lang.error(&lang.describe(error)));
+        if let Some(snippet) = error_snippet(text, spans) {
+            ui.label(egui::RichText::new(snippet).monospace().color(color));
+        }
+    });
+}
+
+fn highlight_spans(ui: &egui::Ui, text: &str, spans: &[Range<usize>], wrap_width: f32) -> Arc<egui::Galley> {
+    let font_id = egui::FontSelection::default().resolve(ui.style());
+    let color = ui.visuals().override_text_color.unwrap_or_else(|| ui.visuals().widgets.inactive.text_color());
+    let normal = egui::TextFormat::simple(font_id, color);
+    let invalid = egui::TextFormat {
+        color: egui::Color32::BLACK,
+        background: egui::Color32::from_rgb(255, 100, 100),
+        ..normal.clone()
+    };
+    
+    let mut job = egui::text::LayoutJob::default();
+    let mut position = 0;
+    for span in spans {
+        if span.start < position || span.end > text.len() {
+            continue;
+        }
+        job.append(&text[position..span.start], 0.0, normal.clone());
+        job.append(&text[span.clone()], 0.0, invalid.clone());
+        position = span.end;
+    }
+    job.append(&text[position..], 0.0, normal);
+    job.wrap.max_width = wrap_width;
+    ui.fonts(|fonts| fonts.layout_job(job))
+}
+
+fn parse_frame_id(input: &str, lang: Lang) -> Result<u32, String> {
+    let digits = input.trim().trim_start_matches("0x");
+    u32::from_str_radix(digits, 16).map_err(|_| fill(lang.strings().gui_err_frame_id, &[&input.trim()]))
+}
+
+fn parse_payload(input: &str, lang: Lang) -> Result<Vec<u8>, String> {
+    if input.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    parse_hex_bytes(input).map_err(|e| lang.describe(&e))
+}
+
+fn csv_field(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+fn batch_csv(rows: &[BatchRow]) -> String {
+    let mut csv = String::from("input,bits,crc_hex,crc_dec,time_ms,error\n");
+    for row in rows {
+        let line = match &row.crc {
+            Ok(result) => format!(
+                "{},{},0x{},{},{:.6},\n",
+                csv_field(&row.input), row.bits, result.crc_hex, result.crc_value, result.duration_ms
+            ),
+            Err(e) => format!("{},,,,,{}\n", csv_field(&row.input), csv_field(e)),
+        };
+        csv.push_str(&line);
+    }
+    csv
+}
+
+enum DroppedContent {
+    Binary(String),
+    Hex(String),
+    Raw,
+}
+
+fn detect_dropped_content(bytes: &[u8]) -> DroppedContent {
+    let text = match std::str::from_utf8(bytes) {
+        Ok(text) if !text.trim().is_empty() => text.trim(),
+        _ => return DroppedContent::Raw,
+    };
+    
+    if text.chars().all(|c| c.is_whitespace() || c == '0' || c == '1') {
+        DroppedContent::Binary(text.split_whitespace().collect::<Vec<_>>().join(" "))
+    } else if text.chars().all(|c| c.is_whitespace() || c.is_ascii_hexdigit()) {
+        DroppedContent::Hex(text.split_whitespace().collect::<Vec<_>>().join(" "))
+    } else if let Ok(bytes) = parse_hex_bytes(text) {
+        DroppedContent::Hex(format_hex(&bytes))
+    } else {
+        DroppedContent::Raw
+    }
+}
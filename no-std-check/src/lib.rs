@@ -0,0 +1,23 @@
+#![no_std]
+
+use can_crc_core::fixed::FixedCanFrame;
+use can_crc_core::CrcConfig;
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
+
+#[no_mangle]
+pub extern "C" fn can_frame_crc(id: u32, data: &[u8; 8], len: usize) -> u32 {
+    match FixedCanFrame::new(id, &data[..len.min(8)]) {
+        Ok(frame) => frame.crc() as u32,
+        Err(_) => u32::MAX,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn modbus_crc(adu: &[u8; 6]) -> u32 {
+    let config = CrcConfig::MODBUS;
+    config.finalize(config.update_bitwise(config.start(), adu)) as u32
+}